@@ -0,0 +1,455 @@
+//! Storage backends for the maps that an [`InMemoryCache`] stores resources in.
+//!
+//! By default the cache stores every resource in a [`DashMap`], which is
+//! exposed as the [`DashMapBackend`]. Advanced users wanting to experiment with
+//! other concurrency strategies may instead provide their own backend and use
+//! it via [`InMemoryCacheBuilder::build_with_backend`].
+//!
+//! # Implementing a backend
+//!
+//! A backend is a type implementing [`MapBackend`] for every key and value
+//! pair the cache stores. This is usually done via a single blanket
+//! implementation, such that the backend type then automatically implements
+//! [`CacheBackend`]. The map named by [`MapBackend::Map`] implements
+//! [`CacheMap`], and for every lifetime [`CacheMapRef`], for the borrowing
+//! operations of the map.
+//!
+//! The cache may hold a reference to an entry of one map while mutating
+//! another map, but never holds a reference to an entry while mutating the map
+//! it belongs to.
+//!
+//! [`InMemoryCache`]: crate::InMemoryCache
+//! [`InMemoryCacheBuilder::build_with_backend`]: crate::InMemoryCacheBuilder::build_with_backend
+
+use crate::{
+    model::{
+        CachedEmoji, CachedGuild, CachedMember, CachedMessage, CachedPresence, CachedSticker,
+        CachedVoiceState,
+    },
+    GuildResource,
+};
+use dashmap::{
+    iter::Iter,
+    mapref::{
+        multiple::RefMulti,
+        one::{Ref, RefMut},
+    },
+    DashMap,
+};
+use std::{
+    collections::{BTreeSet, HashSet, VecDeque},
+    fmt::Debug,
+    hash::Hash,
+    ops::{Deref, DerefMut},
+};
+use twilight_model::{
+    application::command::Command,
+    channel::{Channel, StageInstance},
+    guild::{GuildIntegration, Role},
+    id::{
+        marker::{
+            ChannelMarker, CommandMarker, EmojiMarker, GuildMarker, IntegrationMarker,
+            MessageMarker, RoleMarker, StageMarker, StickerMarker, UserMarker,
+        },
+        Id,
+    },
+    user::User,
+};
+
+/// Map of a backend for a key and value pair.
+pub(crate) type Map<B, K, V> = <B as MapBackend<K, V>>::Map;
+
+/// Reference to an entry in a backend's map.
+pub(crate) type MapEntryRef<'a, B, K, V> = <Map<B, K, V> as CacheMapRef<'a, K, V>>::Ref;
+
+/// Iterator over the entries in a backend's map.
+pub(crate) type MapIter<'a, B, K, V> = <Map<B, K, V> as CacheMapRef<'a, K, V>>::Iter;
+
+/// Reference to an entry yielded by a backend map's iterator.
+pub(crate) type MapIterRef<'a, B, K, V> = <Map<B, K, V> as CacheMapRef<'a, K, V>>::IterRef;
+
+/// Immutable reference to an entry in a [`CacheMap`].
+///
+/// Dereferences to the value of the entry.
+pub trait EntryRef<K, V>: Deref<Target = V> {
+    /// Immutable reference to the key of the entry.
+    fn key(&self) -> &K;
+
+    /// Immutable reference to the value of the entry.
+    fn value(&self) -> &V {
+        self
+    }
+}
+
+/// Borrowing operations of a [`CacheMap`] for the lifetime `'a`.
+pub trait CacheMapRef<'a, K, V> {
+    /// Immutable reference to an entry.
+    type Ref: EntryRef<K, V>;
+
+    /// Mutable reference to the value of an entry.
+    type RefMut: DerefMut<Target = V>;
+
+    /// Immutable reference to an entry yielded by [`Self::Iter`].
+    type IterRef: EntryRef<K, V>;
+
+    /// Iterator over all entries of the map.
+    type Iter: Iterator<Item = Self::IterRef>;
+
+    /// Immutable reference to the entry of a key, if present.
+    fn get(&'a self, key: &K) -> Option<Self::Ref>;
+
+    /// Mutable reference to the value of a key, if present.
+    fn get_mut(&'a self, key: &K) -> Option<Self::RefMut>;
+
+    /// Mutable reference to the value of a key, inserting the default value
+    /// if the key is not present.
+    fn get_or_default(&'a self, key: K) -> Self::RefMut
+    where
+        V: Default;
+
+    /// Create an iterator over all entries of the map.
+    ///
+    /// The iteration order is arbitrary.
+    fn iter(&'a self) -> Self::Iter;
+}
+
+/// Concurrent map storing one type of resource in the cache.
+///
+/// All operations take an immutable reference to the map, so implementations
+/// must provide their own interior mutability.
+pub trait CacheMap<K, V>: Debug + Default + for<'a> CacheMapRef<'a, K, V> {
+    /// Remove all entries from the map.
+    fn clear(&self);
+
+    /// Whether the map contains an entry for a key.
+    fn contains_key(&self, key: &K) -> bool;
+
+    /// Insert a value, returning the previous value of the key if present.
+    fn insert(&self, key: K, value: V) -> Option<V>;
+
+    /// Whether the map contains no entries.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Number of entries in the map.
+    fn len(&self) -> usize;
+
+    /// Remove the entry of a key, returning it if present.
+    fn remove(&self, key: &K) -> Option<(K, V)>;
+}
+
+/// Backend providing the map type for a key and value pair.
+pub trait MapBackend<K, V> {
+    /// Map storing the values of the pair by their keys.
+    type Map: CacheMap<K, V>;
+}
+
+macro_rules! cache_backend {
+    ($($key: ty => $value: ty,)*) => {
+        /// Backend providing maps for every resource stored in the cache.
+        ///
+        /// This is automatically implemented for types implementing
+        /// [`MapBackend`] for all key and value pairs stored by the cache, and
+        /// can't be implemented manually.
+        pub trait CacheBackend: Debug + Default $(+ MapBackend<$key, $value>)* {}
+
+        impl<T: Debug + Default $(+ MapBackend<$key, $value>)*> CacheBackend for T {}
+    };
+}
+
+cache_backend! {
+    Id<ChannelMarker> => Channel,
+    Id<ChannelMarker> => HashSet<(Id<GuildMarker>, Id<UserMarker>)>,
+    Id<ChannelMarker> => VecDeque<Id<MessageMarker>>,
+    Id<CommandMarker> => (),
+    Id<CommandMarker> => Command,
+    Id<EmojiMarker> => GuildResource<CachedEmoji>,
+    Id<GuildMarker> => (),
+    Id<GuildMarker> => CachedGuild,
+    Id<GuildMarker> => HashSet<Id<ChannelMarker>>,
+    Id<GuildMarker> => HashSet<Id<CommandMarker>>,
+    Id<GuildMarker> => HashSet<Id<EmojiMarker>>,
+    Id<GuildMarker> => HashSet<Id<IntegrationMarker>>,
+    Id<GuildMarker> => HashSet<Id<RoleMarker>>,
+    Id<GuildMarker> => HashSet<Id<StageMarker>>,
+    Id<GuildMarker> => HashSet<Id<StickerMarker>>,
+    Id<GuildMarker> => HashSet<Id<UserMarker>>,
+    (Id<GuildMarker>, Id<IntegrationMarker>) => GuildResource<GuildIntegration>,
+    (Id<GuildMarker>, Id<UserMarker>) => CachedMember,
+    (Id<GuildMarker>, Id<UserMarker>) => CachedPresence,
+    (Id<GuildMarker>, Id<UserMarker>) => CachedVoiceState,
+    Id<MessageMarker> => CachedMessage,
+    Id<RoleMarker> => GuildResource<Role>,
+    Id<StageMarker> => GuildResource<StageInstance>,
+    Id<StickerMarker> => GuildResource<CachedSticker>,
+    Id<UserMarker> => BTreeSet<Id<GuildMarker>>,
+    Id<UserMarker> => User,
+}
+
+/// Default backend, storing resources in [`DashMap`]s.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct DashMapBackend;
+
+impl<K: Debug + Eq + Hash + 'static, V: Debug + 'static> MapBackend<K, V> for DashMapBackend {
+    type Map = DashMap<K, V>;
+}
+
+impl<K: Eq + Hash, V> EntryRef<K, V> for Ref<'_, K, V> {
+    fn key(&self) -> &K {
+        Ref::key(self)
+    }
+}
+
+impl<K: Eq + Hash, V> EntryRef<K, V> for RefMulti<'_, K, V> {
+    fn key(&self) -> &K {
+        RefMulti::key(self)
+    }
+}
+
+impl<'a, K: Eq + Hash + 'a, V: 'a> CacheMapRef<'a, K, V> for DashMap<K, V> {
+    type Ref = Ref<'a, K, V>;
+    type RefMut = RefMut<'a, K, V>;
+    type IterRef = RefMulti<'a, K, V>;
+    type Iter = Iter<'a, K, V>;
+
+    fn get(&'a self, key: &K) -> Option<Self::Ref> {
+        DashMap::get(self, key)
+    }
+
+    fn get_mut(&'a self, key: &K) -> Option<Self::RefMut> {
+        DashMap::get_mut(self, key)
+    }
+
+    fn get_or_default(&'a self, key: K) -> Self::RefMut
+    where
+        V: Default,
+    {
+        self.entry(key).or_default()
+    }
+
+    fn iter(&'a self) -> Self::Iter {
+        DashMap::iter(self)
+    }
+}
+
+impl<K: Debug + Eq + Hash + 'static, V: Debug + 'static> CacheMap<K, V> for DashMap<K, V> {
+    fn clear(&self) {
+        DashMap::clear(self);
+    }
+
+    fn contains_key(&self, key: &K) -> bool {
+        DashMap::contains_key(self, key)
+    }
+
+    fn insert(&self, key: K, value: V) -> Option<V> {
+        DashMap::insert(self, key, value)
+    }
+
+    fn is_empty(&self) -> bool {
+        DashMap::is_empty(self)
+    }
+
+    fn len(&self) -> usize {
+        DashMap::len(self)
+    }
+
+    fn remove(&self, key: &K) -> Option<(K, V)> {
+        DashMap::remove(self, key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CacheMap, CacheMapRef, DashMapBackend, EntryRef, MapBackend};
+    use crate::{test, InMemoryCache};
+    use static_assertions::assert_impl_all;
+    use std::{
+        collections::HashMap,
+        fmt::Debug,
+        hash::Hash,
+        ops::{Deref, DerefMut},
+        sync::{RwLock, RwLockReadGuard, RwLockWriteGuard},
+        vec::IntoIter,
+    };
+    use twilight_model::{
+        gateway::payload::incoming::{ChannelCreate, GuildDelete, MemberAdd, RoleCreate},
+        id::Id,
+    };
+
+    assert_impl_all!(DashMapBackend: Clone, Copy, Debug, Default, Send, Sync);
+
+    /// Backend storing resources in a locked standard library map.
+    #[derive(Clone, Copy, Debug, Default)]
+    struct LockedBackend;
+
+    #[derive(Debug)]
+    struct LockedMap<K, V>(RwLock<HashMap<K, V>>);
+
+    struct LockedRef<'a, K, V> {
+        guard: RwLockReadGuard<'a, HashMap<K, V>>,
+        key: K,
+    }
+
+    struct LockedRefMut<'a, K, V> {
+        guard: RwLockWriteGuard<'a, HashMap<K, V>>,
+        key: K,
+    }
+
+    struct LockedIter<'a, K, V> {
+        keys: IntoIter<K>,
+        map: &'a LockedMap<K, V>,
+    }
+
+    impl<K: Clone + Debug + Eq + Hash + 'static, V: Debug + 'static> MapBackend<K, V>
+        for LockedBackend
+    {
+        type Map = LockedMap<K, V>;
+    }
+
+    impl<K, V> Default for LockedMap<K, V> {
+        fn default() -> Self {
+            Self(RwLock::new(HashMap::new()))
+        }
+    }
+
+    impl<K: Eq + Hash, V> Deref for LockedRef<'_, K, V> {
+        type Target = V;
+
+        fn deref(&self) -> &Self::Target {
+            self.guard.get(&self.key).expect("entry present")
+        }
+    }
+
+    impl<K: Eq + Hash, V> EntryRef<K, V> for LockedRef<'_, K, V> {
+        fn key(&self) -> &K {
+            &self.key
+        }
+    }
+
+    impl<K: Eq + Hash, V> Deref for LockedRefMut<'_, K, V> {
+        type Target = V;
+
+        fn deref(&self) -> &Self::Target {
+            self.guard.get(&self.key).expect("entry present")
+        }
+    }
+
+    impl<K: Eq + Hash, V> DerefMut for LockedRefMut<'_, K, V> {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            self.guard.get_mut(&self.key).expect("entry present")
+        }
+    }
+
+    impl<'a, K: Clone + Eq + Hash, V> Iterator for LockedIter<'a, K, V> {
+        type Item = LockedRef<'a, K, V>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            self.keys.find_map(|key| self.map.get(&key))
+        }
+    }
+
+    impl<'a, K: Clone + Eq + Hash + 'a, V: 'a> CacheMapRef<'a, K, V> for LockedMap<K, V> {
+        type Ref = LockedRef<'a, K, V>;
+        type RefMut = LockedRefMut<'a, K, V>;
+        type IterRef = LockedRef<'a, K, V>;
+        type Iter = LockedIter<'a, K, V>;
+
+        fn get(&'a self, key: &K) -> Option<Self::Ref> {
+            let guard = self.0.read().unwrap();
+
+            guard.contains_key(key).then(|| LockedRef {
+                guard,
+                key: key.clone(),
+            })
+        }
+
+        fn get_mut(&'a self, key: &K) -> Option<Self::RefMut> {
+            let guard = self.0.write().unwrap();
+
+            guard.contains_key(key).then(|| LockedRefMut {
+                guard,
+                key: key.clone(),
+            })
+        }
+
+        fn get_or_default(&'a self, key: K) -> Self::RefMut
+        where
+            V: Default,
+        {
+            let mut guard = self.0.write().unwrap();
+            guard.entry(key.clone()).or_default();
+
+            LockedRefMut { guard, key }
+        }
+
+        fn iter(&'a self) -> Self::Iter {
+            let keys = self.0.read().unwrap().keys().cloned().collect::<Vec<_>>();
+
+            LockedIter {
+                keys: keys.into_iter(),
+                map: self,
+            }
+        }
+    }
+
+    impl<K: Clone + Debug + Eq + Hash + 'static, V: Debug + 'static> CacheMap<K, V>
+        for LockedMap<K, V>
+    {
+        fn clear(&self) {
+            self.0.write().unwrap().clear();
+        }
+
+        fn contains_key(&self, key: &K) -> bool {
+            self.0.read().unwrap().contains_key(key)
+        }
+
+        fn insert(&self, key: K, value: V) -> Option<V> {
+            self.0.write().unwrap().insert(key, value)
+        }
+
+        fn len(&self) -> usize {
+            self.0.read().unwrap().len()
+        }
+
+        fn remove(&self, key: &K) -> Option<(K, V)> {
+            self.0.write().unwrap().remove_entry(key)
+        }
+    }
+
+    #[test]
+    fn custom_backend() {
+        let cache = InMemoryCache::builder().build_with_backend::<LockedBackend>();
+        let (guild_id, channel_id, channel) = test::guild_channel_text();
+        let user_id = Id::new(3);
+
+        cache.update(&ChannelCreate(channel.clone()));
+        cache.update(&RoleCreate {
+            guild_id,
+            role: test::role(Id::new(4)),
+        });
+        let mut member = test::member(user_id, guild_id);
+        member.roles.push(Id::new(4));
+        cache.update(&MemberAdd(member));
+
+        assert_eq!(Some(&channel), cache.channel(channel_id).as_deref());
+        assert!(cache
+            .guild_channels(guild_id)
+            .map_or(false, |channels| channels.contains(&channel_id)));
+        assert_eq!(
+            Some(Id::new(4)),
+            cache.member_highest_role(guild_id, user_id)
+        );
+        assert_eq!(1, cache.iter().channels().count());
+        assert_eq!(1, cache.stats().members());
+
+        cache.update(&GuildDelete {
+            id: guild_id,
+            unavailable: false,
+        });
+
+        assert!(cache.channel(channel_id).is_none());
+        assert!(cache.member(guild_id, user_id).is_none());
+        assert_eq!(0, cache.stats().roles());
+    }
+}
@@ -1,4 +1,5 @@
 use super::{
+    backend::CacheBackend,
     config::{Config, ResourceType},
     InMemoryCache,
 };
@@ -19,6 +20,16 @@ impl InMemoryCacheBuilder {
         InMemoryCache::new_with_config(self.0)
     }
 
+    /// Consume the builder, returning a configured cache storing resources in
+    /// a custom backend.
+    ///
+    /// Refer to the [`backend`] module for more information.
+    ///
+    /// [`backend`]: crate::backend
+    pub fn build_with_backend<B: CacheBackend>(self) -> InMemoryCache<B> {
+        InMemoryCache::new_with_config(self.0)
+    }
+
     /// Sets the list of resource types for the cache to handle.
     ///
     /// Defaults to all types.
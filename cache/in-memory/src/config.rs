@@ -36,6 +36,8 @@ bitflags! {
         const INTEGRATION = 1 << 12;
         /// Information relating to guild stickers.
         const STICKER = 1 << 13;
+        /// Information relating to application commands.
+        const COMMAND = 1 << 14;
     }
 }
 
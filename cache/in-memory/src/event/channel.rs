@@ -1,11 +1,15 @@
-use crate::{config::ResourceType, InMemoryCache, UpdateCache};
+use crate::{
+    backend::{CacheBackend, CacheMap, CacheMapRef},
+    config::ResourceType,
+    InMemoryCache, UpdateCache,
+};
 use twilight_model::{
     channel::Channel,
     gateway::payload::incoming::{ChannelCreate, ChannelDelete, ChannelPinsUpdate, ChannelUpdate},
     id::{marker::ChannelMarker, Id},
 };
 
-impl InMemoryCache {
+impl<B: CacheBackend> InMemoryCache<B> {
     pub(crate) fn cache_channels(&self, channels: impl IntoIterator<Item = Channel>) {
         for channel in channels {
             self.cache_channel(channel);
@@ -15,8 +19,7 @@ impl InMemoryCache {
     pub(crate) fn cache_channel(&self, channel: Channel) {
         if let Some(guild_id) = channel.guild_id {
             self.guild_channels
-                .entry(guild_id)
-                .or_default()
+                .get_or_default(guild_id)
                 .insert(channel.id);
         }
 
@@ -40,8 +43,8 @@ impl InMemoryCache {
     }
 }
 
-impl UpdateCache for ChannelCreate {
-    fn update(&self, cache: &InMemoryCache) {
+impl<B: CacheBackend> UpdateCache<B> for ChannelCreate {
+    fn update(&self, cache: &InMemoryCache<B>) {
         if !cache.wants(ResourceType::CHANNEL) {
             return;
         }
@@ -50,8 +53,8 @@ impl UpdateCache for ChannelCreate {
     }
 }
 
-impl UpdateCache for ChannelDelete {
-    fn update(&self, cache: &InMemoryCache) {
+impl<B: CacheBackend> UpdateCache<B> for ChannelDelete {
+    fn update(&self, cache: &InMemoryCache<B>) {
         if !cache.wants(ResourceType::CHANNEL) {
             return;
         }
@@ -60,8 +63,8 @@ impl UpdateCache for ChannelDelete {
     }
 }
 
-impl UpdateCache for ChannelPinsUpdate {
-    fn update(&self, cache: &InMemoryCache) {
+impl<B: CacheBackend> UpdateCache<B> for ChannelPinsUpdate {
+    fn update(&self, cache: &InMemoryCache<B>) {
         if !cache.wants(ResourceType::CHANNEL) {
             return;
         }
@@ -72,8 +75,8 @@ impl UpdateCache for ChannelPinsUpdate {
     }
 }
 
-impl UpdateCache for ChannelUpdate {
-    fn update(&self, cache: &InMemoryCache) {
+impl<B: CacheBackend> UpdateCache<B> for ChannelUpdate {
+    fn update(&self, cache: &InMemoryCache<B>) {
         if !cache.wants(ResourceType::CHANNEL) {
             return;
         }
@@ -0,0 +1,155 @@
+use crate::{
+    backend::{CacheBackend, CacheMap, CacheMapRef, EntryRef},
+    config::ResourceType,
+    InMemoryCache,
+};
+use std::collections::HashSet;
+use twilight_model::{
+    application::command::Command,
+    id::{marker::GuildMarker, Id},
+};
+
+impl<B: CacheBackend> InMemoryCache<B> {
+    /// Set the application commands of a guild, or the global commands if no
+    /// guild is provided.
+    ///
+    /// Discord doesn't send application commands over the gateway, so they
+    /// must be provided from HTTP responses, such as those of getting or
+    /// bulk-overwriting commands. The provided commands replace any previously
+    /// cached commands of the same scope. Commands without an ID are ignored.
+    ///
+    /// This requires the [`COMMAND`] resource type.
+    ///
+    /// [`COMMAND`]: crate::config::ResourceType::COMMAND
+    pub fn set_commands(
+        &self,
+        guild_id: Option<Id<GuildMarker>>,
+        commands: impl IntoIterator<Item = Command>,
+    ) {
+        if !self.wants(ResourceType::COMMAND) {
+            return;
+        }
+
+        let mut ids = HashSet::new();
+
+        for command in commands {
+            if let Some(id) = command.id {
+                ids.insert(id);
+                self.commands.insert(id, command);
+            }
+        }
+
+        let old_ids = if let Some(guild_id) = guild_id {
+            self.guild_commands
+                .insert(guild_id, ids.clone())
+                .unwrap_or_default()
+        } else {
+            let old_ids = self
+                .global_commands
+                .iter()
+                .map(|entry| *entry.key())
+                .collect::<HashSet<_>>();
+            self.global_commands.clear();
+
+            for id in &ids {
+                self.global_commands.insert(*id, ());
+            }
+
+            old_ids
+        };
+
+        for id in old_ids.difference(&ids) {
+            self.commands.remove(id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test, InMemoryCache, ResourceType};
+    use twilight_model::{
+        application::command::{Command, CommandType},
+        id::{
+            marker::{CommandMarker, GuildMarker},
+            Id,
+        },
+    };
+
+    const GUILD_ID: Id<GuildMarker> = Id::new(1);
+
+    fn command(id: u64, guild_id: Option<Id<GuildMarker>>) -> Command {
+        Command {
+            application_id: Some(Id::new(2)),
+            contexts: None,
+            default_member_permissions: None,
+            dm_permission: None,
+            description: "a command".to_owned(),
+            description_localizations: None,
+            guild_id,
+            id: Some(Id::new(id)),
+            integration_types: None,
+            kind: CommandType::ChatInput,
+            name: "command".to_owned(),
+            name_localizations: None,
+            options: Vec::new(),
+            version: Id::new(1),
+        }
+    }
+
+    #[test]
+    fn set_commands() {
+        let cache = test::cache();
+        cache.set_commands(None, [command(10, None)]);
+        cache.set_commands(
+            Some(GUILD_ID),
+            [command(20, Some(GUILD_ID)), command(21, Some(GUILD_ID))],
+        );
+
+        assert_eq!(cache.global_commands(), [Id::new(10)].into_iter().collect());
+        let guild_commands = cache.guild_commands(GUILD_ID).unwrap();
+        assert_eq!(2, guild_commands.len());
+        assert!(guild_commands.contains(&Id::new(20)));
+        assert!(guild_commands.contains(&Id::new(21)));
+        drop(guild_commands);
+
+        assert_eq!(Some(GUILD_ID), cache.command(Id::new(21)).unwrap().guild_id);
+        assert!(cache.guild_commands(Id::new(3)).is_none());
+
+        assert_eq!(3, cache.stats().commands());
+        assert_eq!(Some(2), cache.stats().guild_commands(GUILD_ID));
+        assert!(cache.stats().guild_commands(Id::new(3)).is_none());
+    }
+
+    /// Test that setting commands replaces the previous commands of the same
+    /// scope, without affecting those of other scopes.
+    #[test]
+    fn set_commands_replaces() {
+        let cache = test::cache();
+        cache.set_commands(None, [command(10, None)]);
+        cache.set_commands(Some(GUILD_ID), [command(20, Some(GUILD_ID))]);
+        cache.set_commands(Some(GUILD_ID), [command(21, Some(GUILD_ID))]);
+
+        let ids: [Id<CommandMarker>; 1] = [Id::new(21)];
+        assert_eq!(
+            *cache.guild_commands(GUILD_ID).unwrap(),
+            ids.into_iter().collect()
+        );
+        assert!(cache.command(Id::new(20)).is_none());
+        assert!(cache.command(Id::new(10)).is_some());
+
+        cache.set_commands(None, []);
+        assert!(cache.global_commands().is_empty());
+        assert!(cache.command(Id::new(10)).is_none());
+    }
+
+    #[test]
+    fn set_commands_unwanted() {
+        let cache = InMemoryCache::builder()
+            .resource_types(ResourceType::all() - ResourceType::COMMAND)
+            .build();
+        cache.set_commands(None, [command(10, None)]);
+
+        assert!(cache.global_commands().is_empty());
+        assert!(cache.command(Id::new(10)).is_none());
+    }
+}
@@ -1,4 +1,9 @@
-use crate::{config::ResourceType, model::CachedEmoji, GuildResource, InMemoryCache, UpdateCache};
+use crate::{
+    backend::{CacheBackend, CacheMap, CacheMapRef},
+    config::ResourceType,
+    model::CachedEmoji,
+    GuildResource, InMemoryCache, UpdateCache,
+};
 use std::borrow::Cow;
 use twilight_model::{
     gateway::payload::incoming::GuildEmojisUpdate,
@@ -6,7 +11,7 @@ use twilight_model::{
     id::{marker::GuildMarker, Id},
 };
 
-impl InMemoryCache {
+impl<B: CacheBackend> InMemoryCache<B> {
     pub(crate) fn cache_emojis(&self, guild_id: Id<GuildMarker>, emojis: Vec<Emoji>) {
         if let Some(mut guild_emojis) = self.guild_emojis.get_mut(&guild_id) {
             let incoming: Vec<_> = emojis.iter().map(|e| e.id).collect();
@@ -53,15 +58,12 @@ impl InMemoryCache {
             },
         );
 
-        self.guild_emojis
-            .entry(guild_id)
-            .or_default()
-            .insert(emoji_id);
+        self.guild_emojis.get_or_default(guild_id).insert(emoji_id);
     }
 }
 
-impl UpdateCache for GuildEmojisUpdate {
-    fn update(&self, cache: &InMemoryCache) {
+impl<B: CacheBackend> UpdateCache<B> for GuildEmojisUpdate {
+    fn update(&self, cache: &InMemoryCache<B>) {
         if !cache.wants(ResourceType::EMOJI) {
             return;
         }
@@ -1,17 +1,17 @@
 use crate::{
+    backend::{CacheBackend, CacheMap, CacheMapRef},
     config::ResourceType,
     model::{CachedGuild, CachedPresence},
     InMemoryCache, UpdateCache,
 };
-use dashmap::DashMap;
-use std::{collections::HashSet, hash::Hash};
+use std::collections::HashSet;
 use twilight_model::{
     gateway::payload::incoming::{GuildCreate, GuildDelete, GuildUpdate},
     guild::Guild,
     id::{marker::GuildMarker, Id},
 };
 
-impl InMemoryCache {
+impl<B: CacheBackend> InMemoryCache<B> {
     #[allow(clippy::too_many_lines)]
     fn cache_guild(&self, guild: Guild) {
         let Guild {
@@ -28,6 +28,7 @@ impl InMemoryCache {
             emojis,
             explicit_content_filter,
             features,
+            guild_scheduled_events: _,
             icon,
             id,
             joined_at,
@@ -64,6 +65,10 @@ impl InMemoryCache {
             widget_enabled,
         } = guild;
 
+        // Evict anything left over from a previous `GuildCreate`, such as data
+        // retained while the guild was unavailable.
+        self.delete_guild_resources(id);
+
         // The map and set creation needs to occur first, so caching states and
         // objects always has a place to put them.
         if self.wants(ResourceType::CHANNEL) {
@@ -105,7 +110,7 @@ impl InMemoryCache {
         }
 
         if self.wants(ResourceType::STICKER) {
-            self.guild_stage_instances.insert(id, HashSet::new());
+            self.guild_stickers.insert(id, HashSet::new());
             self.cache_stickers(id, stickers);
         }
 
@@ -162,19 +167,12 @@ impl InMemoryCache {
         self.guilds.insert(guild.id(), guild);
     }
 
+    /// Remove a guild from the cache.
+    ///
+    /// A guild that is only unavailable, such as during an outage, is marked
+    /// as such and its data is retained until it becomes available again.
+    /// Otherwise the guild and everything associated with it is evicted.
     pub(crate) fn delete_guild(&self, id: Id<GuildMarker>, unavailable: bool) {
-        fn remove_ids<T: Eq + Hash, U>(
-            guild_map: &DashMap<Id<GuildMarker>, HashSet<T>>,
-            container: &DashMap<T, U>,
-            guild_id: Id<GuildMarker>,
-        ) {
-            if let Some((_, ids)) = guild_map.remove(&guild_id) {
-                for id in ids {
-                    container.remove(&id);
-                }
-            }
-        }
-
         if !self.wants(ResourceType::GUILD) {
             return;
         }
@@ -183,10 +181,22 @@ impl InMemoryCache {
             if let Some(mut guild) = self.guilds.get_mut(&id) {
                 guild.unavailable = true;
             }
-        } else {
-            self.guilds.remove(&id);
+
+            return;
+        }
+
+        self.guilds.remove(&id);
+        self.unavailable_guilds.remove(&id);
+
+        if self.wants(ResourceType::COMMAND) {
+            remove_ids(&self.guild_commands, &self.commands, id);
         }
 
+        self.delete_guild_resources(id);
+    }
+
+    /// Remove the resources sent as part of a guild's `GuildCreate`.
+    fn delete_guild_resources(&self, id: Id<GuildMarker>) {
         if self.wants(ResourceType::CHANNEL) {
             remove_ids(&self.guild_channels, &self.channels, id);
         }
@@ -199,6 +209,10 @@ impl InMemoryCache {
             remove_ids(&self.guild_roles, &self.roles, id);
         }
 
+        if self.wants(ResourceType::STAGE_INSTANCE) {
+            remove_ids(&self.guild_stage_instances, &self.stage_instances, id);
+        }
+
         if self.wants(ResourceType::STICKER) {
             remove_ids(&self.guild_stickers, &self.stickers, id);
         }
@@ -226,8 +240,21 @@ impl InMemoryCache {
     }
 }
 
-impl UpdateCache for GuildCreate {
-    fn update(&self, cache: &InMemoryCache) {
+/// Remove a guild's set of IDs and the resources they refer to.
+fn remove_ids<T, U>(
+    guild_map: &impl CacheMap<Id<GuildMarker>, HashSet<T>>,
+    container: &impl CacheMap<T, U>,
+    guild_id: Id<GuildMarker>,
+) {
+    if let Some((_, ids)) = guild_map.remove(&guild_id) {
+        for id in ids {
+            container.remove(&id);
+        }
+    }
+}
+
+impl<B: CacheBackend> UpdateCache<B> for GuildCreate {
+    fn update(&self, cache: &InMemoryCache<B>) {
         if !cache.wants(ResourceType::GUILD) {
             return;
         }
@@ -236,14 +263,18 @@ impl UpdateCache for GuildCreate {
     }
 }
 
-impl UpdateCache for GuildDelete {
-    fn update(&self, cache: &InMemoryCache) {
-        cache.delete_guild(self.id, false);
+impl<B: CacheBackend> UpdateCache<B> for GuildDelete {
+    fn update(&self, cache: &InMemoryCache<B>) {
+        if self.unavailable {
+            cache.unavailable_guild(self.id);
+        } else {
+            cache.delete_guild(self.id, false);
+        }
     }
 }
 
-impl UpdateCache for GuildUpdate {
-    fn update(&self, cache: &InMemoryCache) {
+impl<B: CacheBackend> UpdateCache<B> for GuildUpdate {
+    fn update(&self, cache: &InMemoryCache<B>) {
         if !cache.wants(ResourceType::GUILD) {
             return;
         }
@@ -281,7 +312,7 @@ impl UpdateCache for GuildUpdate {
 
 #[cfg(test)]
 mod tests {
-    use crate::{test, InMemoryCache};
+    use crate::{test, InMemoryCache, ResourceType};
     use std::str::FromStr;
     use twilight_model::{
         channel::{
@@ -289,7 +320,7 @@ mod tests {
             Channel, ChannelType,
         },
         gateway::payload::incoming::{
-            GuildCreate, GuildUpdate, MemberAdd, MemberRemove, UnavailableGuild,
+            GuildCreate, GuildDelete, GuildUpdate, MemberAdd, MemberRemove, UnavailableGuild,
         },
         guild::{
             DefaultMessageNotificationLevel, ExplicitContentFilter, Guild, MfaLevel, NSFWLevel,
@@ -308,8 +339,10 @@ mod tests {
 
         let channels = Vec::from([Channel {
             application_id: None,
+            applied_tags: None,
             bitrate: None,
             default_auto_archive_duration: None,
+            flags: None,
             guild_id: None,
             icon: None,
             id: Id::new(111),
@@ -338,8 +371,10 @@ mod tests {
 
         let threads = Vec::from([Channel {
             application_id: None,
+            applied_tags: None,
             bitrate: None,
             default_auto_archive_duration: None,
+            flags: None,
             guild_id: None,
             icon: None,
             id: Id::new(222),
@@ -394,6 +429,7 @@ mod tests {
             emojis: Vec::new(),
             explicit_content_filter: ExplicitContentFilter::AllMembers,
             features: vec![],
+            guild_scheduled_events: Vec::new(),
             icon: None,
             id: Id::new(123),
             joined_at: Some(Timestamp::from_secs(1_632_072_645).expect("non zero")),
@@ -539,7 +575,7 @@ mod tests {
         cache.update(&UnavailableGuild { id: guild_id });
 
         assert_eq!(
-            0,
+            1,
             cache
                 .guild_members(guild_id)
                 .map(|members| members.len())
@@ -558,4 +594,76 @@ mod tests {
         );
         assert!(!cache.guild(guild_id).unwrap().unavailable);
     }
+
+    /// Create a cache containing a guild with a channel, member, role, and
+    /// sticker.
+    fn cache_with_guild() -> InMemoryCache {
+        let (guild_id, channel_id, channel) = test::guild_channel_text();
+        let mut guild = test::guild(guild_id, Some(1));
+        guild.channels.push(channel);
+        guild.members.push(test::member(Id::new(3), guild_id));
+        guild.roles.push(test::role(Id::new(4)));
+        guild.stickers.push(test::sticker(Id::new(5), guild_id));
+
+        let cache = InMemoryCache::new();
+        cache.update(&GuildCreate(guild));
+        assert!(cache.channel(channel_id).is_some());
+
+        cache
+    }
+
+    /// Test that caching stickers of a guild creates its set of stickers, not
+    /// its set of stage instances.
+    #[test]
+    fn guild_create_stickers() {
+        let cache = InMemoryCache::builder()
+            .resource_types(ResourceType::GUILD | ResourceType::STICKER)
+            .build();
+        let guild_id = Id::new(1);
+        cache.update(&GuildCreate(test::guild(guild_id, None)));
+
+        assert!(cache.guild_stickers(guild_id).unwrap().is_empty());
+        assert!(cache.guild_stage_instances(guild_id).is_none());
+    }
+
+    #[test]
+    fn guild_delete_unavailable_retains() {
+        let cache = cache_with_guild();
+        let guild_id = Id::new(1);
+
+        cache.update(&GuildDelete {
+            id: guild_id,
+            unavailable: true,
+        });
+
+        assert!(cache.guild(guild_id).unwrap().unavailable);
+        assert_eq!(1, cache.stats().unavailable_guilds());
+        assert!(cache.channel(Id::new(2)).is_some());
+        assert!(cache.member(guild_id, Id::new(3)).is_some());
+        assert!(cache.role(Id::new(4)).is_some());
+        assert!(cache.sticker(Id::new(5)).is_some());
+        assert_eq!(1, cache.guild_stickers(guild_id).unwrap().len());
+    }
+
+    #[test]
+    fn guild_delete_removed_evicts() {
+        let cache = cache_with_guild();
+        let guild_id = Id::new(1);
+
+        cache.update(&GuildDelete {
+            id: guild_id,
+            unavailable: false,
+        });
+
+        assert!(cache.guild(guild_id).is_none());
+        assert_eq!(0, cache.stats().unavailable_guilds());
+        assert!(cache.channel(Id::new(2)).is_none());
+        assert!(cache.guild_channels(guild_id).is_none());
+        assert!(cache.member(guild_id, Id::new(3)).is_none());
+        assert!(cache.guild_members(guild_id).is_none());
+        assert!(cache.role(Id::new(4)).is_none());
+        assert!(cache.guild_roles(guild_id).is_none());
+        assert!(cache.sticker(Id::new(5)).is_none());
+        assert!(cache.guild_stickers(guild_id).is_none());
+    }
 }
@@ -1,4 +1,8 @@
-use crate::{config::ResourceType, InMemoryCache, UpdateCache};
+use crate::{
+    backend::{CacheBackend, CacheMap, CacheMapRef},
+    config::ResourceType,
+    InMemoryCache, UpdateCache,
+};
 use twilight_model::{
     gateway::payload::incoming::{IntegrationCreate, IntegrationDelete, IntegrationUpdate},
     guild::GuildIntegration,
@@ -8,11 +12,10 @@ use twilight_model::{
     },
 };
 
-impl InMemoryCache {
+impl<B: CacheBackend> InMemoryCache<B> {
     fn cache_integration(&self, guild_id: Id<GuildMarker>, integration: GuildIntegration) {
         self.guild_integrations
-            .entry(guild_id)
-            .or_default()
+            .get_or_default(guild_id)
             .insert(integration.id);
 
         crate::upsert_guild_item(
@@ -36,8 +39,8 @@ impl InMemoryCache {
     }
 }
 
-impl UpdateCache for IntegrationCreate {
-    fn update(&self, cache: &InMemoryCache) {
+impl<B: CacheBackend> UpdateCache<B> for IntegrationCreate {
+    fn update(&self, cache: &InMemoryCache<B>) {
         if !cache.wants(ResourceType::INTEGRATION) {
             return;
         }
@@ -53,8 +56,8 @@ impl UpdateCache for IntegrationCreate {
     }
 }
 
-impl UpdateCache for IntegrationDelete {
-    fn update(&self, cache: &InMemoryCache) {
+impl<B: CacheBackend> UpdateCache<B> for IntegrationDelete {
+    fn update(&self, cache: &InMemoryCache<B>) {
         if !cache.wants(ResourceType::INTEGRATION) {
             return;
         }
@@ -63,8 +66,8 @@ impl UpdateCache for IntegrationDelete {
     }
 }
 
-impl UpdateCache for IntegrationUpdate {
-    fn update(&self, cache: &InMemoryCache) {
+impl<B: CacheBackend> UpdateCache<B> for IntegrationUpdate {
+    fn update(&self, cache: &InMemoryCache<B>) {
         if !cache.wants(ResourceType::INTEGRATION) {
             return;
         }
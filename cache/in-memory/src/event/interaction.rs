@@ -1,11 +1,11 @@
-use crate::{config::ResourceType, InMemoryCache, UpdateCache};
+use crate::{backend::CacheBackend, config::ResourceType, InMemoryCache, UpdateCache};
 use std::borrow::Cow;
 use twilight_model::{
     application::interaction::Interaction, gateway::payload::incoming::InteractionCreate,
 };
 
-impl UpdateCache for InteractionCreate {
-    fn update(&self, cache: &InMemoryCache) {
+impl<B: CacheBackend> UpdateCache<B> for InteractionCreate {
+    fn update(&self, cache: &InMemoryCache<B>) {
         #[allow(clippy::single_match)]
         match &self.0 {
             Interaction::ApplicationCommand(command) => {
@@ -72,7 +72,7 @@ mod tests {
                 application_command::{
                     CommandData, CommandInteractionDataResolved, InteractionMember,
                 },
-                ApplicationCommand, Interaction, InteractionType,
+                ApplicationCommand, AuthorizingIntegrationOwners, Interaction, InteractionType,
             },
         },
         channel::{
@@ -83,7 +83,7 @@ mod tests {
             Message,
         },
         gateway::payload::incoming::InteractionCreate,
-        guild::{PartialMember, Permissions, Role},
+        guild::{PartialMember, Permissions, Role, RoleFlags},
         id::Id,
         user::User,
         util::{image_hash::ImageHashParseError, ImageHash, Timestamp},
@@ -101,8 +101,11 @@ mod tests {
 
         cache.update(&InteractionCreate(Interaction::ApplicationCommand(
             Box::new(ApplicationCommand {
+                app_permissions: None,
                 application_id: Id::new(1),
+                authorizing_integration_owners: AuthorizingIntegrationOwners::default(),
                 channel_id: Id::new(2),
+                context: None,
                 data: CommandData {
                     guild_id: None,
                     id: Id::new(5),
@@ -176,7 +179,10 @@ mod tests {
                                 mention_everyone: false,
                                 mention_roles: Vec::new(),
                                 mentions: Vec::new(),
+                                nonce: None,
                                 pinned: false,
+                                poll: None,
+                                position: None,
                                 reactions: Vec::new(),
                                 reference: None,
                                 sticker_items: vec![MessageSticker {
@@ -196,6 +202,7 @@ mod tests {
                             Id::new(8),
                             Role {
                                 color: 0u32,
+                                flags: RoleFlags::empty(),
                                 hoist: false,
                                 icon: None,
                                 id: Id::new(8),
@@ -1,4 +1,5 @@
 use crate::{
+    backend::{CacheBackend, CacheMap, CacheMapRef},
     config::ResourceType,
     model::{member::ComputedInteractionMemberFields, CachedMember},
     InMemoryCache, UpdateCache,
@@ -14,7 +15,7 @@ use twilight_model::{
     },
 };
 
-impl InMemoryCache {
+impl<B: CacheBackend> InMemoryCache<B> {
     pub(crate) fn cache_members(
         &self,
         guild_id: Id<GuildMarker>,
@@ -39,8 +40,7 @@ impl InMemoryCache {
         let cached = CachedMember::from_model(member);
         self.members.insert(id, cached);
         self.guild_members
-            .entry(guild_id)
-            .or_default()
+            .get_or_default(guild_id)
             .insert(member_id);
     }
 
@@ -58,10 +58,7 @@ impl InMemoryCache {
             }
         }
 
-        self.guild_members
-            .entry(guild_id)
-            .or_default()
-            .insert(user_id);
+        self.guild_members.get_or_default(guild_id).insert(user_id);
 
         let cached = CachedMember::from_partial_member(guild_id, user_id, member.clone());
         self.members.insert(id, cached);
@@ -81,10 +78,7 @@ impl InMemoryCache {
             None => (None, None, None),
         };
 
-        self.guild_members
-            .entry(guild_id)
-            .or_default()
-            .insert(user_id);
+        self.guild_members.get_or_default(guild_id).insert(user_id);
 
         let cached = CachedMember::from_interaction_member(
             guild_id,
@@ -97,8 +91,8 @@ impl InMemoryCache {
     }
 }
 
-impl UpdateCache for MemberAdd {
-    fn update(&self, cache: &InMemoryCache) {
+impl<B: CacheBackend> UpdateCache<B> for MemberAdd {
+    fn update(&self, cache: &InMemoryCache<B>) {
         if cache.wants(ResourceType::GUILD) {
             if let Some(mut guild) = cache.guilds.get_mut(&self.guild_id) {
                 guild.member_count = guild.member_count.map(|count| count + 1);
@@ -113,14 +107,13 @@ impl UpdateCache for MemberAdd {
 
         cache
             .guild_members
-            .entry(self.guild_id)
-            .or_default()
+            .get_or_default(self.guild_id)
             .insert(self.0.user.id);
     }
 }
 
-impl UpdateCache for MemberChunk {
-    fn update(&self, cache: &InMemoryCache) {
+impl<B: CacheBackend> UpdateCache<B> for MemberChunk {
+    fn update(&self, cache: &InMemoryCache<B>) {
         if !cache.wants(ResourceType::MEMBER) {
             return;
         }
@@ -130,13 +123,13 @@ impl UpdateCache for MemberChunk {
         }
 
         cache.cache_members(self.guild_id, self.members.clone());
-        let mut guild = cache.guild_members.entry(self.guild_id).or_default();
+        let mut guild = cache.guild_members.get_or_default(self.guild_id);
         guild.extend(self.members.iter().map(|member| member.user.id));
     }
 }
 
-impl UpdateCache for MemberRemove {
-    fn update(&self, cache: &InMemoryCache) {
+impl<B: CacheBackend> UpdateCache<B> for MemberRemove {
+    fn update(&self, cache: &InMemoryCache<B>) {
         if cache.wants(ResourceType::GUILD) {
             if let Some(mut guild) = cache.guilds.get_mut(&self.guild_id) {
                 guild.member_count = guild.member_count.map(|count| count - 1);
@@ -169,8 +162,8 @@ impl UpdateCache for MemberRemove {
     }
 }
 
-impl UpdateCache for MemberUpdate {
-    fn update(&self, cache: &InMemoryCache) {
+impl<B: CacheBackend> UpdateCache<B> for MemberUpdate {
+    fn update(&self, cache: &InMemoryCache<B>) {
         if !cache.wants(ResourceType::MEMBER) {
             return;
         }
@@ -196,7 +189,7 @@ impl UpdateCache for MemberUpdate {
 
 #[cfg(test)]
 mod tests {
-    use crate::{test, InMemoryCache};
+    use crate::{test, InMemoryCache, ResourceType};
     use std::borrow::Cow;
     use twilight_model::{gateway::payload::incoming::MemberRemove, id::Id};
 
@@ -257,6 +250,52 @@ mod tests {
         }
     }
 
+    #[test]
+    fn extend_members_pages() {
+        let guild_id = Id::new(1);
+        let cache = InMemoryCache::new();
+
+        // Mocked guild members endpoint returning up to 1000 members after a
+        // user ID, out of 1500 members in total.
+        let guild_members = |after: u64| {
+            (after + 1..=1500.min(after + 1000))
+                .map(|id| test::member(Id::new(id), guild_id))
+                .collect::<Vec<_>>()
+        };
+
+        let mut after = 0;
+        let mut pages = 0;
+
+        loop {
+            let members = guild_members(after);
+            let last_page = members.len() < 1000;
+            after = members.last().map_or(after, |member| member.user.id.get());
+            pages += 1;
+            cache.extend_members(members);
+
+            if last_page {
+                break;
+            }
+        }
+
+        assert_eq!(2, pages);
+        assert_eq!(1500, cache.guild_members(guild_id).unwrap().len());
+        assert!(cache.member(guild_id, Id::new(1)).is_some());
+        assert!(cache.member(guild_id, Id::new(1500)).is_some());
+        assert_eq!(1500, cache.stats().users());
+    }
+
+    #[test]
+    fn extend_members_unwanted() {
+        let cache = InMemoryCache::builder()
+            .resource_types(ResourceType::USER)
+            .build();
+        cache.extend_members(vec![test::member(Id::new(2), Id::new(1))]);
+
+        assert!(cache.guild_members(Id::new(1)).is_none());
+        assert!(cache.user(Id::new(2)).is_none());
+    }
+
     #[test]
     fn cache_user_guild_state() {
         let user_id = Id::new(2);
@@ -1,11 +1,16 @@
-use crate::{config::ResourceType, model::CachedMessage, InMemoryCache, UpdateCache};
+use crate::{
+    backend::{CacheBackend, CacheMap, CacheMapRef},
+    config::ResourceType,
+    model::CachedMessage,
+    InMemoryCache, UpdateCache,
+};
 use std::borrow::Cow;
 use twilight_model::gateway::payload::incoming::{
     MessageCreate, MessageDelete, MessageDeleteBulk, MessageUpdate,
 };
 
-impl UpdateCache for MessageCreate {
-    fn update(&self, cache: &InMemoryCache) {
+impl<B: CacheBackend> UpdateCache<B> for MessageCreate {
+    fn update(&self, cache: &InMemoryCache<B>) {
         if cache.wants(ResourceType::USER) {
             cache.cache_user(Cow::Borrowed(&self.author), self.guild_id);
         }
@@ -22,7 +27,7 @@ impl UpdateCache for MessageCreate {
             return;
         }
 
-        let mut channel_messages = cache.channel_messages.entry(self.0.channel_id).or_default();
+        let mut channel_messages = cache.channel_messages.get_or_default(self.0.channel_id);
 
         // If the channel has more messages than the cache size the user has
         // requested then we pop a message ID out. Once we have the popped ID we
@@ -41,15 +46,15 @@ impl UpdateCache for MessageCreate {
     }
 }
 
-impl UpdateCache for MessageDelete {
-    fn update(&self, cache: &InMemoryCache) {
+impl<B: CacheBackend> UpdateCache<B> for MessageDelete {
+    fn update(&self, cache: &InMemoryCache<B>) {
         if !cache.wants(ResourceType::MESSAGE) {
             return;
         }
 
         cache.messages.remove(&self.id);
 
-        let mut channel_messages = cache.channel_messages.entry(self.channel_id).or_default();
+        let mut channel_messages = cache.channel_messages.get_or_default(self.channel_id);
 
         if let Some(idx) = channel_messages.iter().position(|id| *id == self.id) {
             channel_messages.remove(idx);
@@ -57,13 +62,13 @@ impl UpdateCache for MessageDelete {
     }
 }
 
-impl UpdateCache for MessageDeleteBulk {
-    fn update(&self, cache: &InMemoryCache) {
+impl<B: CacheBackend> UpdateCache<B> for MessageDeleteBulk {
+    fn update(&self, cache: &InMemoryCache<B>) {
         if !cache.wants(ResourceType::MESSAGE) {
             return;
         }
 
-        let mut channel_messages = cache.channel_messages.entry(self.channel_id).or_default();
+        let mut channel_messages = cache.channel_messages.get_or_default(self.channel_id);
 
         for id in &self.ids {
             cache.messages.remove(id);
@@ -78,8 +83,8 @@ impl UpdateCache for MessageDeleteBulk {
     }
 }
 
-impl UpdateCache for MessageUpdate {
-    fn update(&self, cache: &InMemoryCache) {
+impl<B: CacheBackend> UpdateCache<B> for MessageUpdate {
+    fn update(&self, cache: &InMemoryCache<B>) {
         if !cache.wants(ResourceType::MESSAGE) {
             return;
         }
@@ -197,7 +202,10 @@ mod tests {
             mention_everyone: false,
             mention_roles: Vec::new(),
             mentions: Vec::new(),
+            nonce: None,
             pinned: false,
+            poll: None,
+            position: None,
             reactions: Vec::new(),
             reference: None,
             sticker_items: Vec::new(),
@@ -1,4 +1,5 @@
 pub mod channel;
+pub mod command;
 pub mod emoji;
 pub mod guild;
 pub mod integration;
@@ -13,7 +14,11 @@ pub mod sticker;
 pub mod thread;
 pub mod voice_state;
 
-use crate::{config::ResourceType, InMemoryCache, UpdateCache};
+use crate::{
+    backend::{CacheBackend, CacheMap, CacheMapRef},
+    config::ResourceType,
+    InMemoryCache, UpdateCache,
+};
 use std::{borrow::Cow, collections::BTreeSet};
 use twilight_model::{
     gateway::payload::incoming::{Ready, UnavailableGuild, UserUpdate},
@@ -21,7 +26,7 @@ use twilight_model::{
     user::{CurrentUser, User},
 };
 
-impl InMemoryCache {
+impl<B: CacheBackend> InMemoryCache<B> {
     fn cache_current_user(&self, current_user: CurrentUser) {
         self.current_user
             .lock()
@@ -31,12 +36,9 @@ impl InMemoryCache {
 
     pub(crate) fn cache_user(&self, user: Cow<'_, User>, guild_id: Option<Id<GuildMarker>>) {
         if let Some(cached_user) = self.users.get_mut(&user.id) {
-            if cached_user.value() == user.as_ref() {
+            if *cached_user == *user {
                 if let Some(guild_id) = guild_id {
-                    self.user_guilds
-                        .entry(user.id)
-                        .or_default()
-                        .insert(guild_id);
+                    self.user_guilds.get_or_default(user.id).insert(guild_id);
                 }
 
                 return;
@@ -56,13 +58,13 @@ impl InMemoryCache {
     }
 
     fn unavailable_guild(&self, guild_id: Id<GuildMarker>) {
-        self.unavailable_guilds.insert(guild_id);
+        self.unavailable_guilds.insert(guild_id, ());
         self.delete_guild(guild_id, true);
     }
 }
 
-impl UpdateCache for Ready {
-    fn update(&self, cache: &InMemoryCache) {
+impl<B: CacheBackend> UpdateCache<B> for Ready {
+    fn update(&self, cache: &InMemoryCache<B>) {
         if cache.wants(ResourceType::USER_CURRENT) {
             cache.cache_current_user(self.user.clone());
         }
@@ -75,8 +77,8 @@ impl UpdateCache for Ready {
     }
 }
 
-impl UpdateCache for UnavailableGuild {
-    fn update(&self, cache: &InMemoryCache) {
+impl<B: CacheBackend> UpdateCache<B> for UnavailableGuild {
+    fn update(&self, cache: &InMemoryCache<B>) {
         if !cache.wants(ResourceType::GUILD) {
             return;
         }
@@ -85,8 +87,8 @@ impl UpdateCache for UnavailableGuild {
     }
 }
 
-impl UpdateCache for UserUpdate {
-    fn update(&self, cache: &InMemoryCache) {
+impl<B: CacheBackend> UpdateCache<B> for UserUpdate {
+    fn update(&self, cache: &InMemoryCache<B>) {
         if !cache.wants(ResourceType::USER_CURRENT) {
             return;
         }
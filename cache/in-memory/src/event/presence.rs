@@ -1,10 +1,15 @@
-use crate::{config::ResourceType, model::CachedPresence, InMemoryCache, UpdateCache};
+use crate::{
+    backend::{CacheBackend, CacheMap, CacheMapRef},
+    config::ResourceType,
+    model::CachedPresence,
+    InMemoryCache, UpdateCache,
+};
 use twilight_model::{
     gateway::payload::incoming::PresenceUpdate,
     id::{marker::GuildMarker, Id},
 };
 
-impl InMemoryCache {
+impl<B: CacheBackend> InMemoryCache<B> {
     pub(crate) fn cache_presences(
         &self,
         guild_id: Id<GuildMarker>,
@@ -17,8 +22,7 @@ impl InMemoryCache {
 
     fn cache_presence(&self, guild_id: Id<GuildMarker>, presence: CachedPresence) {
         self.guild_presences
-            .entry(guild_id)
-            .or_default()
+            .get_or_default(guild_id)
             .insert(presence.user_id);
 
         self.presences
@@ -26,8 +30,8 @@ impl InMemoryCache {
     }
 }
 
-impl UpdateCache for PresenceUpdate {
-    fn update(&self, cache: &InMemoryCache) {
+impl<B: CacheBackend> UpdateCache<B> for PresenceUpdate {
+    fn update(&self, cache: &InMemoryCache<B>) {
         if !cache.wants(ResourceType::PRESENCE) {
             return;
         }
@@ -1,13 +1,17 @@
-use crate::{config::ResourceType, InMemoryCache, UpdateCache};
+use crate::{
+    backend::{CacheBackend, CacheMapRef},
+    config::ResourceType,
+    InMemoryCache, UpdateCache,
+};
 use twilight_model::{
-    channel::message::MessageReaction,
+    channel::message::{MessageReaction, ReactionCountDetails},
     gateway::payload::incoming::{
         ReactionAdd, ReactionRemove, ReactionRemoveAll, ReactionRemoveEmoji,
     },
 };
 
-impl UpdateCache for ReactionAdd {
-    fn update(&self, cache: &InMemoryCache) {
+impl<B: CacheBackend> UpdateCache<B> for ReactionAdd {
+    fn update(&self, cache: &InMemoryCache<B>) {
         if !cache.wants(ResourceType::REACTION) {
             return;
         }
@@ -20,37 +24,65 @@ impl UpdateCache for ReactionAdd {
             return;
         };
 
+        let is_current_user = || {
+            cache
+                .current_user()
+                .map_or(false, |user| user.id == self.0.user_id)
+        };
+
         if let Some(reaction) = message
             .reactions
             .iter_mut()
             .find(|r| r.emoji == self.0.emoji)
         {
-            if !reaction.me {
-                if let Some(current_user) = cache.current_user() {
-                    if current_user.id == self.0.user_id {
-                        reaction.me = true;
-                    }
+            if self.0.burst {
+                if !reaction.me_burst && is_current_user() {
+                    reaction.me_burst = true;
+                }
+
+                if reaction.burst_colors.is_empty() {
+                    reaction.burst_colors.clone_from(&self.0.burst_colors);
                 }
+
+                reaction.count_details.burst += 1;
+            } else {
+                if !reaction.me && is_current_user() {
+                    reaction.me = true;
+                }
+
+                reaction.count_details.normal += 1;
             }
 
             reaction.count += 1;
         } else {
-            let me = cache
-                .current_user()
-                .map(|user| user.id == self.0.user_id)
-                .unwrap_or_default();
+            let me = is_current_user();
+
+            let count_details = if self.0.burst {
+                ReactionCountDetails {
+                    burst: 1,
+                    normal: 0,
+                }
+            } else {
+                ReactionCountDetails {
+                    burst: 0,
+                    normal: 1,
+                }
+            };
 
             message.reactions.push(MessageReaction {
+                burst_colors: self.0.burst_colors.clone(),
                 count: 1,
+                count_details,
                 emoji: self.0.emoji.clone(),
-                me,
+                me: me && !self.0.burst,
+                me_burst: me && self.0.burst,
             });
         }
     }
 }
 
-impl UpdateCache for ReactionRemove {
-    fn update(&self, cache: &InMemoryCache) {
+impl<B: CacheBackend> UpdateCache<B> for ReactionRemove {
+    fn update(&self, cache: &InMemoryCache<B>) {
         if !cache.wants(ResourceType::REACTION) {
             return;
         }
@@ -66,12 +98,22 @@ impl UpdateCache for ReactionRemove {
             .iter_mut()
             .find(|r| r.emoji == self.0.emoji)
         {
-            if reaction.me {
-                if let Some(current_user) = cache.current_user() {
-                    if current_user.id == self.0.user_id {
-                        reaction.me = false;
-                    }
+            let is_current_user = cache
+                .current_user()
+                .map_or(false, |user| user.id == self.0.user_id);
+
+            if self.0.burst {
+                if is_current_user {
+                    reaction.me_burst = false;
+                }
+
+                reaction.count_details.burst = reaction.count_details.burst.saturating_sub(1);
+            } else {
+                if is_current_user {
+                    reaction.me = false;
                 }
+
+                reaction.count_details.normal = reaction.count_details.normal.saturating_sub(1);
             }
 
             if reaction.count > 1 {
@@ -83,8 +125,8 @@ impl UpdateCache for ReactionRemove {
     }
 }
 
-impl UpdateCache for ReactionRemoveAll {
-    fn update(&self, cache: &InMemoryCache) {
+impl<B: CacheBackend> UpdateCache<B> for ReactionRemoveAll {
+    fn update(&self, cache: &InMemoryCache<B>) {
         if !cache.wants(ResourceType::REACTION) {
             return;
         }
@@ -99,8 +141,8 @@ impl UpdateCache for ReactionRemoveAll {
     }
 }
 
-impl UpdateCache for ReactionRemoveEmoji {
-    fn update(&self, cache: &InMemoryCache) {
+impl<B: CacheBackend> UpdateCache<B> for ReactionRemoveEmoji {
+    fn update(&self, cache: &InMemoryCache<B>) {
         if !cache.wants(ResourceType::REACTION) {
             return;
         }
@@ -124,7 +166,9 @@ mod tests {
     use crate::test;
     use twilight_model::{
         channel::{Reaction, ReactionType},
-        gateway::payload::incoming::{ReactionRemove, ReactionRemoveAll, ReactionRemoveEmoji},
+        gateway::payload::incoming::{
+            ReactionAdd, ReactionRemove, ReactionRemoveAll, ReactionRemoveEmoji,
+        },
         id::Id,
     };
 
@@ -150,10 +194,41 @@ mod tests {
         assert_eq!(smiley_react.unwrap().count, 2);
     }
 
+    #[test]
+    fn reaction_add_burst() {
+        let cache = test::cache_with_message_and_reactions();
+        cache.update(&ReactionAdd(Reaction {
+            burst: true,
+            burst_colors: vec!["#ff0000".to_owned()],
+            channel_id: Id::new(2),
+            emoji: ReactionType::Unicode {
+                name: "😀".to_owned(),
+            },
+            guild_id: Some(Id::new(1)),
+            member: None,
+            message_id: Id::new(4),
+            user_id: Id::new(5),
+        }));
+
+        let msg = cache.message(Id::new(4)).unwrap();
+        let smiley_react = msg
+            .reactions
+            .iter()
+            .find(|&r| matches!(&r.emoji, ReactionType::Unicode {name} if name == "😀"))
+            .unwrap();
+
+        assert_eq!(smiley_react.count, 3);
+        assert_eq!(smiley_react.count_details.burst, 1);
+        assert_eq!(smiley_react.count_details.normal, 2);
+        assert_eq!(smiley_react.burst_colors, ["#ff0000"]);
+    }
+
     #[test]
     fn reaction_remove() {
         let cache = test::cache_with_message_and_reactions();
         cache.update(&ReactionRemove(Reaction {
+            burst: false,
+            burst_colors: Vec::new(),
             channel_id: Id::new(2),
             emoji: ReactionType::Unicode {
                 name: "😀".to_owned(),
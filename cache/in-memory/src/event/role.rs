@@ -1,4 +1,8 @@
-use crate::{config::ResourceType, InMemoryCache, UpdateCache};
+use crate::{
+    backend::{CacheBackend, CacheMap, CacheMapRef},
+    config::ResourceType,
+    InMemoryCache, UpdateCache,
+};
 use twilight_model::{
     gateway::payload::incoming::{RoleCreate, RoleDelete, RoleUpdate},
     guild::Role,
@@ -8,7 +12,7 @@ use twilight_model::{
     },
 };
 
-impl InMemoryCache {
+impl<B: CacheBackend> InMemoryCache<B> {
     pub(crate) fn cache_roles(
         &self,
         guild_id: Id<GuildMarker>,
@@ -21,10 +25,7 @@ impl InMemoryCache {
 
     fn cache_role(&self, guild_id: Id<GuildMarker>, role: Role) {
         // Insert the role into the guild_roles map
-        self.guild_roles
-            .entry(guild_id)
-            .or_default()
-            .insert(role.id);
+        self.guild_roles.get_or_default(guild_id).insert(role.id);
 
         // Insert the role into the all roles map
         crate::upsert_guild_item(&self.roles, guild_id, role.id, role);
@@ -39,8 +40,8 @@ impl InMemoryCache {
     }
 }
 
-impl UpdateCache for RoleCreate {
-    fn update(&self, cache: &InMemoryCache) {
+impl<B: CacheBackend> UpdateCache<B> for RoleCreate {
+    fn update(&self, cache: &InMemoryCache<B>) {
         if !cache.wants(ResourceType::ROLE) {
             return;
         }
@@ -49,8 +50,8 @@ impl UpdateCache for RoleCreate {
     }
 }
 
-impl UpdateCache for RoleDelete {
-    fn update(&self, cache: &InMemoryCache) {
+impl<B: CacheBackend> UpdateCache<B> for RoleDelete {
+    fn update(&self, cache: &InMemoryCache<B>) {
         if !cache.wants(ResourceType::ROLE) {
             return;
         }
@@ -59,8 +60,8 @@ impl UpdateCache for RoleDelete {
     }
 }
 
-impl UpdateCache for RoleUpdate {
-    fn update(&self, cache: &InMemoryCache) {
+impl<B: CacheBackend> UpdateCache<B> for RoleUpdate {
+    fn update(&self, cache: &InMemoryCache<B>) {
         if !cache.wants(ResourceType::ROLE) {
             return;
         }
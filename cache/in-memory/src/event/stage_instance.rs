@@ -1,4 +1,8 @@
-use crate::{config::ResourceType, InMemoryCache, UpdateCache};
+use crate::{
+    backend::{CacheBackend, CacheMap, CacheMapRef},
+    config::ResourceType,
+    InMemoryCache, UpdateCache,
+};
 use twilight_model::{
     channel::StageInstance,
     gateway::payload::incoming::{StageInstanceCreate, StageInstanceDelete, StageInstanceUpdate},
@@ -8,7 +12,7 @@ use twilight_model::{
     },
 };
 
-impl InMemoryCache {
+impl<B: CacheBackend> InMemoryCache<B> {
     pub(crate) fn cache_stage_instances(
         &self,
         guild_id: Id<GuildMarker>,
@@ -21,8 +25,7 @@ impl InMemoryCache {
 
     fn cache_stage_instance(&self, guild_id: Id<GuildMarker>, stage_instance: StageInstance) {
         self.guild_stage_instances
-            .entry(guild_id)
-            .or_default()
+            .get_or_default(guild_id)
             .insert(stage_instance.id);
 
         crate::upsert_guild_item(
@@ -44,8 +47,8 @@ impl InMemoryCache {
     }
 }
 
-impl UpdateCache for StageInstanceCreate {
-    fn update(&self, cache: &InMemoryCache) {
+impl<B: CacheBackend> UpdateCache<B> for StageInstanceCreate {
+    fn update(&self, cache: &InMemoryCache<B>) {
         if !cache.wants(ResourceType::STAGE_INSTANCE) {
             return;
         }
@@ -54,8 +57,8 @@ impl UpdateCache for StageInstanceCreate {
     }
 }
 
-impl UpdateCache for StageInstanceDelete {
-    fn update(&self, cache: &InMemoryCache) {
+impl<B: CacheBackend> UpdateCache<B> for StageInstanceDelete {
+    fn update(&self, cache: &InMemoryCache<B>) {
         if !cache.wants(ResourceType::STAGE_INSTANCE) {
             return;
         }
@@ -64,8 +67,8 @@ impl UpdateCache for StageInstanceDelete {
     }
 }
 
-impl UpdateCache for StageInstanceUpdate {
-    fn update(&self, cache: &InMemoryCache) {
+impl<B: CacheBackend> UpdateCache<B> for StageInstanceUpdate {
+    fn update(&self, cache: &InMemoryCache<B>) {
         if !cache.wants(ResourceType::STAGE_INSTANCE) {
             return;
         }
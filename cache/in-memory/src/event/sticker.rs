@@ -1,5 +1,8 @@
 use crate::{
-    config::ResourceType, model::CachedSticker, GuildResource, InMemoryCache, UpdateCache,
+    backend::{CacheBackend, CacheMap, CacheMapRef},
+    config::ResourceType,
+    model::CachedSticker,
+    GuildResource, InMemoryCache, UpdateCache,
 };
 use std::{borrow::Cow, collections::HashSet};
 use twilight_model::{
@@ -8,7 +11,7 @@ use twilight_model::{
     id::{marker::GuildMarker, Id},
 };
 
-impl InMemoryCache {
+impl<B: CacheBackend> InMemoryCache<B> {
     pub(crate) fn cache_stickers(&self, guild_id: Id<GuildMarker>, stickers: Vec<Sticker>) {
         if let Some(mut guild_stickers) = self.guild_stickers.get_mut(&guild_id) {
             let incoming_sticker_ids = stickers
@@ -61,14 +64,13 @@ impl InMemoryCache {
         );
 
         self.guild_stickers
-            .entry(guild_id)
-            .or_default()
+            .get_or_default(guild_id)
             .insert(sticker_id);
     }
 }
 
-impl UpdateCache for GuildStickersUpdate {
-    fn update(&self, cache: &InMemoryCache) {
+impl<B: CacheBackend> UpdateCache<B> for GuildStickersUpdate {
+    fn update(&self, cache: &InMemoryCache<B>) {
         if !cache.wants(ResourceType::STICKER) {
             return;
         }
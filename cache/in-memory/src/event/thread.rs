@@ -1,10 +1,10 @@
-use crate::{config::ResourceType, InMemoryCache, UpdateCache};
+use crate::{backend::CacheBackend, config::ResourceType, InMemoryCache, UpdateCache};
 use twilight_model::gateway::payload::incoming::{
     ThreadCreate, ThreadDelete, ThreadListSync, ThreadUpdate,
 };
 
-impl UpdateCache for ThreadCreate {
-    fn update(&self, cache: &InMemoryCache) {
+impl<B: CacheBackend> UpdateCache<B> for ThreadCreate {
+    fn update(&self, cache: &InMemoryCache<B>) {
         if !cache.wants(ResourceType::CHANNEL) {
             return;
         }
@@ -13,8 +13,8 @@ impl UpdateCache for ThreadCreate {
     }
 }
 
-impl UpdateCache for ThreadDelete {
-    fn update(&self, cache: &InMemoryCache) {
+impl<B: CacheBackend> UpdateCache<B> for ThreadDelete {
+    fn update(&self, cache: &InMemoryCache<B>) {
         if !cache.wants(ResourceType::CHANNEL) {
             return;
         }
@@ -23,8 +23,8 @@ impl UpdateCache for ThreadDelete {
     }
 }
 
-impl UpdateCache for ThreadListSync {
-    fn update(&self, cache: &InMemoryCache) {
+impl<B: CacheBackend> UpdateCache<B> for ThreadListSync {
+    fn update(&self, cache: &InMemoryCache<B>) {
         if !cache.wants(ResourceType::CHANNEL) {
             return;
         }
@@ -33,8 +33,8 @@ impl UpdateCache for ThreadListSync {
     }
 }
 
-impl UpdateCache for ThreadUpdate {
-    fn update(&self, cache: &InMemoryCache) {
+impl<B: CacheBackend> UpdateCache<B> for ThreadUpdate {
+    fn update(&self, cache: &InMemoryCache<B>) {
         if !cache.wants(ResourceType::CHANNEL) {
             return;
         }
@@ -1,7 +1,12 @@
-use crate::{config::ResourceType, model::CachedVoiceState, InMemoryCache, UpdateCache};
+use crate::{
+    backend::{CacheBackend, CacheMap, CacheMapRef},
+    config::ResourceType,
+    model::CachedVoiceState,
+    InMemoryCache, UpdateCache,
+};
 use twilight_model::{gateway::payload::incoming::VoiceStateUpdate, voice::VoiceState};
 
-impl InMemoryCache {
+impl<B: CacheBackend> InMemoryCache<B> {
     pub(crate) fn cache_voice_states(&self, voice_states: impl IntoIterator<Item = VoiceState>) {
         for voice_state in voice_states {
             self.cache_voice_state(voice_state);
@@ -43,13 +48,11 @@ impl InMemoryCache {
                 .insert((guild_id, user_id), cached_voice_state);
 
             self.voice_state_guilds
-                .entry(guild_id)
-                .or_default()
+                .get_or_default(guild_id)
                 .insert(user_id);
 
             self.voice_state_channels
-                .entry(channel_id)
-                .or_default()
+                .get_or_default(channel_id)
                 .insert((guild_id, user_id));
         } else {
             // voice channel_id does not exist, signifying that the user has left
@@ -74,8 +77,8 @@ impl InMemoryCache {
     }
 }
 
-impl UpdateCache for VoiceStateUpdate {
-    fn update(&self, cache: &InMemoryCache) {
+impl<B: CacheBackend> UpdateCache<B> for VoiceStateUpdate {
+    fn update(&self, cache: &InMemoryCache<B>) {
         if !cache.wants(ResourceType::VOICE_STATE) {
             return;
         }
@@ -13,17 +13,17 @@
 //! dereferences to the value.
 
 use crate::{
+    backend::{
+        CacheBackend, CacheMapRef, DashMapBackend, EntryRef, MapBackend, MapEntryRef, MapIter,
+        MapIterRef,
+    },
     model::{
         CachedEmoji, CachedGuild, CachedMember, CachedMessage, CachedPresence, CachedSticker,
         CachedVoiceState,
     },
     GuildResource, InMemoryCache,
 };
-use dashmap::{
-    iter::Iter,
-    mapref::{multiple::RefMulti, one::Ref},
-};
-use std::{collections::VecDeque, hash::Hash, ops::Deref};
+use std::{collections::VecDeque, ops::Deref};
 use twilight_model::{
     channel::{Channel, StageInstance},
     guild::{GuildIntegration, Role},
@@ -41,22 +41,22 @@ use twilight_model::{
 ///
 /// [`std::ops::Deref`] is implemented on this type and derefs to an immutable
 /// reference of the underlying value.
-// We need a separate type from [`Reference`] due to DashMap's iterators
+// We need a separate type from [`Reference`] due to backends' iterators
 // returning a different reference type from that of retrieval methods.
 //
 // [`Reference`]: super::Reference
-pub struct IterReference<'a, K, V> {
-    inner: RefMulti<'a, K, V>,
+pub struct IterReference<'a, K, V, B: MapBackend<K, V> = DashMapBackend> {
+    inner: MapIterRef<'a, B, K, V>,
 }
 
-impl<'a, K, V> IterReference<'a, K, V> {
+impl<'a, K, V, B: MapBackend<K, V>> IterReference<'a, K, V, B> {
     /// Create a new iterator element reference.
-    const fn new(inner: RefMulti<'a, K, V>) -> Self {
+    const fn new(inner: MapIterRef<'a, B, K, V>) -> Self {
         Self { inner }
     }
 }
 
-impl<K: Eq + Hash, V> IterReference<'_, K, V> {
+impl<K, V, B: MapBackend<K, V>> IterReference<'_, K, V, B> {
     /// Immutable reference to the resource's key.
     pub fn key(&self) -> &K {
         self.inner.key()
@@ -68,7 +68,7 @@ impl<K: Eq + Hash, V> IterReference<'_, K, V> {
     }
 }
 
-impl<K: Eq + Hash, V> Deref for IterReference<'_, K, V> {
+impl<K, V, B: MapBackend<K, V>> Deref for IterReference<'_, K, V, B> {
     type Target = V;
 
     fn deref(&self) -> &Self::Target {
@@ -133,83 +133,89 @@ impl<K: Eq + Hash, V> Deref for IterReference<'_, K, V> {
 /// }
 /// ```
 #[derive(Debug)]
-pub struct InMemoryCacheIter<'a>(&'a InMemoryCache);
+pub struct InMemoryCacheIter<'a, B: CacheBackend = DashMapBackend>(&'a InMemoryCache<B>);
 
-impl<'a> InMemoryCacheIter<'a> {
+impl<'a, B: CacheBackend> InMemoryCacheIter<'a, B> {
     /// Create a new interface to create iterators over various resource types.
-    pub(super) const fn new(cache: &'a InMemoryCache) -> Self {
+    pub(super) const fn new(cache: &'a InMemoryCache<B>) -> Self {
         Self(cache)
     }
 
     /// Immutable reference to the underlying cache.
-    pub const fn cache_ref(&'a self) -> &'a InMemoryCache {
+    pub const fn cache_ref(&'a self) -> &'a InMemoryCache<B> {
         self.0
     }
 
     /// Create an iterator over the channels in the cache.
-    pub fn channels(&self) -> ResourceIter<'a, Id<ChannelMarker>, Channel> {
+    pub fn channels(&self) -> ResourceIter<'a, Id<ChannelMarker>, Channel, B> {
         ResourceIter::new(self.0.channels.iter())
     }
 
     /// Create an iterator over the emojis in the cache.
-    pub fn emojis(&self) -> ResourceIter<'a, Id<EmojiMarker>, GuildResource<CachedEmoji>> {
+    pub fn emojis(&self) -> ResourceIter<'a, Id<EmojiMarker>, GuildResource<CachedEmoji>, B> {
         ResourceIter::new(self.0.emojis.iter())
     }
 
     /// Create an iterator over the guilds in the cache.
-    pub fn guilds(&self) -> ResourceIter<'a, Id<GuildMarker>, CachedGuild> {
+    pub fn guilds(&self) -> ResourceIter<'a, Id<GuildMarker>, CachedGuild, B> {
         ResourceIter::new(self.0.guilds.iter())
     }
 
     /// Create an iterator over the integrations in the cache.
     pub fn integrations(
         &self,
-    ) -> ResourceIter<'a, (Id<GuildMarker>, Id<IntegrationMarker>), GuildResource<GuildIntegration>>
-    {
+    ) -> ResourceIter<
+        'a,
+        (Id<GuildMarker>, Id<IntegrationMarker>),
+        GuildResource<GuildIntegration>,
+        B,
+    > {
         ResourceIter::new(self.0.integrations.iter())
     }
 
     /// Create an iterator over the members across all guilds in the cache.
-    pub fn members(&self) -> ResourceIter<'a, (Id<GuildMarker>, Id<UserMarker>), CachedMember> {
+    pub fn members(&self) -> ResourceIter<'a, (Id<GuildMarker>, Id<UserMarker>), CachedMember, B> {
         ResourceIter::new(self.0.members.iter())
     }
 
     /// Create an iterator over the messages in the cache.
-    pub fn messages(&self) -> ResourceIter<'a, Id<MessageMarker>, CachedMessage> {
+    pub fn messages(&self) -> ResourceIter<'a, Id<MessageMarker>, CachedMessage, B> {
         ResourceIter::new(self.0.messages.iter())
     }
 
     /// Create an iterator over the presences in the cache.
-    pub fn presences(&self) -> ResourceIter<'a, (Id<GuildMarker>, Id<UserMarker>), CachedPresence> {
+    pub fn presences(
+        &self,
+    ) -> ResourceIter<'a, (Id<GuildMarker>, Id<UserMarker>), CachedPresence, B> {
         ResourceIter::new(self.0.presences.iter())
     }
 
     /// Create an iterator over the roles in the cache.
-    pub fn roles(&self) -> ResourceIter<'a, Id<RoleMarker>, GuildResource<Role>> {
+    pub fn roles(&self) -> ResourceIter<'a, Id<RoleMarker>, GuildResource<Role>, B> {
         ResourceIter::new(self.0.roles.iter())
     }
 
     /// Create an iterator over the stage instances in the cache.
     pub fn stage_instances(
         &self,
-    ) -> ResourceIter<'a, Id<StageMarker>, GuildResource<StageInstance>> {
+    ) -> ResourceIter<'a, Id<StageMarker>, GuildResource<StageInstance>, B> {
         ResourceIter::new(self.0.stage_instances.iter())
     }
 
     /// Create an iterator over the stickers in the cache.
-    pub fn stickers(&self) -> ResourceIter<'a, Id<StickerMarker>, GuildResource<CachedSticker>> {
+    pub fn stickers(&self) -> ResourceIter<'a, Id<StickerMarker>, GuildResource<CachedSticker>, B> {
         ResourceIter::new(self.0.stickers.iter())
     }
 
     /// Create an iterator over the users in the cache.
-    pub fn users(&self) -> ResourceIter<'a, Id<UserMarker>, User> {
+    pub fn users(&self) -> ResourceIter<'a, Id<UserMarker>, User, B> {
         ResourceIter::new(self.0.users.iter())
     }
 
     /// Create an iterator over the voice states in the cache.
     pub fn voice_states(
         &self,
-    ) -> ResourceIter<'a, (Id<GuildMarker>, Id<UserMarker>), CachedVoiceState> {
+    ) -> ResourceIter<'a, (Id<GuildMarker>, Id<UserMarker>), CachedVoiceState, B> {
         ResourceIter::new(self.0.voice_states.iter())
     }
 }
@@ -235,19 +241,19 @@ impl<'a> InMemoryCacheIter<'a> {
 ///
 /// println!("pending users: {count}");
 /// ```
-pub struct ResourceIter<'a, K, V> {
-    iter: Iter<'a, K, V>,
+pub struct ResourceIter<'a, K, V, B: MapBackend<K, V> = DashMapBackend> {
+    iter: MapIter<'a, B, K, V>,
 }
 
-impl<'a, K, V> ResourceIter<'a, K, V> {
+impl<'a, K, V, B: MapBackend<K, V>> ResourceIter<'a, K, V, B> {
     /// Create a new iterator over a resource.
-    pub(super) const fn new(iter: Iter<'a, K, V>) -> Self {
+    pub(super) const fn new(iter: MapIter<'a, B, K, V>) -> Self {
         Self { iter }
     }
 }
 
-impl<'a, K: Eq + Hash, V> Iterator for ResourceIter<'a, K, V> {
-    type Item = IterReference<'a, K, V>;
+impl<'a, K, V, B: MapBackend<K, V>> Iterator for ResourceIter<'a, K, V, B> {
+    type Item = IterReference<'a, K, V, B>;
 
     fn next(&mut self) -> Option<Self::Item> {
         self.iter.next().map(IterReference::new)
@@ -280,14 +286,14 @@ impl<'a, K: Eq + Hash, V> Iterator for ResourceIter<'a, K, V> {
 /// }
 /// # Some(()) }
 /// ```
-pub struct ChannelMessages<'a> {
+pub struct ChannelMessages<'a, B: CacheBackend = DashMapBackend> {
     index: usize,
-    message_ids: Ref<'a, Id<ChannelMarker>, VecDeque<Id<MessageMarker>>>,
+    message_ids: MapEntryRef<'a, B, Id<ChannelMarker>, VecDeque<Id<MessageMarker>>>,
 }
 
-impl<'a> ChannelMessages<'a> {
+impl<'a, B: CacheBackend> ChannelMessages<'a, B> {
     pub(super) const fn new(
-        message_ids: Ref<'a, Id<ChannelMarker>, VecDeque<Id<MessageMarker>>>,
+        message_ids: MapEntryRef<'a, B, Id<ChannelMarker>, VecDeque<Id<MessageMarker>>>,
     ) -> Self {
         Self {
             index: 0,
@@ -296,7 +302,7 @@ impl<'a> ChannelMessages<'a> {
     }
 }
 
-impl<'a> Iterator for ChannelMessages<'a> {
+impl<'a, B: CacheBackend> Iterator for ChannelMessages<'a, B> {
     type Item = Id<MessageMarker>;
 
     fn next(&mut self) -> Option<Self::Item> {
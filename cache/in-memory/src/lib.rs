@@ -19,6 +19,7 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![doc = include_str!("../README.md")]
 
+pub mod backend;
 pub mod iter;
 pub mod model;
 
@@ -43,32 +44,31 @@ pub use self::{
 pub use self::permission::InMemoryCachePermissions;
 
 use self::{
+    backend::{
+        CacheBackend, CacheMap, CacheMapRef, DashMapBackend, EntryRef, Map, MapBackend, MapEntryRef,
+    },
     iter::InMemoryCacheIter,
     model::{
         CachedEmoji, CachedGuild, CachedMember, CachedMessage, CachedPresence, CachedSticker,
         CachedVoiceState,
     },
 };
-use dashmap::{
-    mapref::{entry::Entry, one::Ref},
-    DashMap, DashSet,
-};
 use iter::ChannelMessages;
 use std::{
     collections::{BTreeSet, HashSet, VecDeque},
     fmt::{Debug, Formatter, Result as FmtResult},
-    hash::Hash,
     ops::Deref,
     sync::Mutex,
 };
 use twilight_model::{
+    application::command::Command,
     channel::{Channel, StageInstance},
     gateway::event::Event,
-    guild::{GuildIntegration, Role},
+    guild::{GuildIntegration, Member, Role},
     id::{
         marker::{
-            ChannelMarker, EmojiMarker, GuildMarker, IntegrationMarker, MessageMarker, RoleMarker,
-            StageMarker, StickerMarker, UserMarker,
+            ChannelMarker, CommandMarker, EmojiMarker, GuildMarker, IntegrationMarker,
+            MessageMarker, RoleMarker, StageMarker, StickerMarker, UserMarker,
         },
         Id,
     },
@@ -108,29 +108,28 @@ impl<T> Deref for GuildResource<T> {
 
 /// Immutable reference to a resource in the cache.
 // We need this so as not to expose the underlying cache implementation.
-pub struct Reference<'a, K, V> {
-    inner: Ref<'a, K, V>,
+pub struct Reference<'a, K, V, B: MapBackend<K, V> = DashMapBackend> {
+    inner: MapEntryRef<'a, B, K, V>,
 }
 
-impl<'a, K: Eq + Hash, V> Reference<'a, K, V> {
-    /// Create a new reference from a `DashMap` reference.
-    #[allow(clippy::missing_const_for_fn)]
-    fn new(inner: Ref<'a, K, V>) -> Self {
+impl<'a, K, V, B: MapBackend<K, V>> Reference<'a, K, V, B> {
+    /// Create a new reference from a backend's map reference.
+    const fn new(inner: MapEntryRef<'a, B, K, V>) -> Self {
         Self { inner }
     }
 
     /// Immutable reference to the key identifying the resource.
-    pub fn key(&'a self) -> &'a K {
+    pub fn key(&self) -> &K {
         self.inner.key()
     }
 
     /// Immutable reference to the underlying value.
-    pub fn value(&'a self) -> &'a V {
+    pub fn value(&self) -> &V {
         self.inner.value()
     }
 }
 
-impl<K: Eq + Hash, V: Debug> Debug for Reference<'_, K, V> {
+impl<K, V: Debug, B: MapBackend<K, V>> Debug for Reference<'_, K, V, B> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         f.debug_struct("Reference")
             .field("inner", self.value())
@@ -138,7 +137,7 @@ impl<K: Eq + Hash, V: Debug> Debug for Reference<'_, K, V> {
     }
 }
 
-impl<'a, K: Eq + Hash, V> Deref for Reference<'a, K, V> {
+impl<'a, K, V, B: MapBackend<K, V>> Deref for Reference<'a, K, V, B> {
     type Target = V;
 
     fn deref(&self) -> &Self::Target {
@@ -146,21 +145,17 @@ impl<'a, K: Eq + Hash, V> Deref for Reference<'a, K, V> {
     }
 }
 
-fn upsert_guild_item<K: Eq + Hash, V: PartialEq>(
-    map: &DashMap<K, GuildResource<V>>,
+fn upsert_guild_item<K, V: PartialEq>(
+    map: &impl CacheMap<K, GuildResource<V>>,
     guild_id: Id<GuildMarker>,
     key: K,
     value: V,
 ) {
-    match map.entry(key) {
-        Entry::Occupied(entry) if entry.get().value == value => {}
-        Entry::Occupied(mut entry) => {
-            entry.insert(GuildResource { guild_id, value });
-        }
-        Entry::Vacant(entry) => {
-            entry.insert(GuildResource { guild_id, value });
-        }
+    if map.get(&key).map_or(false, |entry| entry.value == value) {
+        return;
     }
+
+    map.insert(key, GuildResource { guild_id, value });
 }
 
 /// An in-memory cache of Discord data.
@@ -176,6 +171,13 @@ fn upsert_guild_item<K: Eq + Hash, V: PartialEq>(
 /// To use a cache instance in multiple tasks, consider wrapping it in an
 /// [`std::sync::Arc`] or [`std::rc::Rc`].
 ///
+/// # Storage backends
+///
+/// Resources are stored in the maps of a [`CacheBackend`], which defaults to
+/// [`DashMapBackend`]. A cache using another backend can be created via
+/// [`InMemoryCacheBuilder::build_with_backend`]; refer to the [`backend`]
+/// module for more information.
+///
 /// # Caution required
 ///
 /// The cache uses a concurrent map for mutability of cached resources. Return
@@ -196,40 +198,42 @@ fn upsert_guild_item<K: Eq + Hash, V: PartialEq>(
 // When adding a field here, be sure to add it to `InMemoryCache::clear` if
 // necessary.
 #[derive(Debug, Default)]
-pub struct InMemoryCache {
+pub struct InMemoryCache<B: CacheBackend = DashMapBackend> {
     config: Config,
-    channels: DashMap<Id<ChannelMarker>, Channel>,
-    channel_messages: DashMap<Id<ChannelMarker>, VecDeque<Id<MessageMarker>>>,
+    channels: Map<B, Id<ChannelMarker>, Channel>,
+    channel_messages: Map<B, Id<ChannelMarker>, VecDeque<Id<MessageMarker>>>,
+    commands: Map<B, Id<CommandMarker>, Command>,
     // So long as the lock isn't held across await or panic points this is fine.
     current_user: Mutex<Option<CurrentUser>>,
-    emojis: DashMap<Id<EmojiMarker>, GuildResource<CachedEmoji>>,
-    guilds: DashMap<Id<GuildMarker>, CachedGuild>,
-    guild_channels: DashMap<Id<GuildMarker>, HashSet<Id<ChannelMarker>>>,
-    guild_emojis: DashMap<Id<GuildMarker>, HashSet<Id<EmojiMarker>>>,
-    guild_integrations: DashMap<Id<GuildMarker>, HashSet<Id<IntegrationMarker>>>,
-    guild_members: DashMap<Id<GuildMarker>, HashSet<Id<UserMarker>>>,
-    guild_presences: DashMap<Id<GuildMarker>, HashSet<Id<UserMarker>>>,
-    guild_roles: DashMap<Id<GuildMarker>, HashSet<Id<RoleMarker>>>,
-    guild_stage_instances: DashMap<Id<GuildMarker>, HashSet<Id<StageMarker>>>,
-    guild_stickers: DashMap<Id<GuildMarker>, HashSet<Id<StickerMarker>>>,
-    integrations:
-        DashMap<(Id<GuildMarker>, Id<IntegrationMarker>), GuildResource<GuildIntegration>>,
-    members: DashMap<(Id<GuildMarker>, Id<UserMarker>), CachedMember>,
-    messages: DashMap<Id<MessageMarker>, CachedMessage>,
-    presences: DashMap<(Id<GuildMarker>, Id<UserMarker>), CachedPresence>,
-    roles: DashMap<Id<RoleMarker>, GuildResource<Role>>,
-    stage_instances: DashMap<Id<StageMarker>, GuildResource<StageInstance>>,
-    stickers: DashMap<Id<StickerMarker>, GuildResource<CachedSticker>>,
-    unavailable_guilds: DashSet<Id<GuildMarker>>,
-    users: DashMap<Id<UserMarker>, User>,
-    user_guilds: DashMap<Id<UserMarker>, BTreeSet<Id<GuildMarker>>>,
+    emojis: Map<B, Id<EmojiMarker>, GuildResource<CachedEmoji>>,
+    global_commands: Map<B, Id<CommandMarker>, ()>,
+    guilds: Map<B, Id<GuildMarker>, CachedGuild>,
+    guild_channels: Map<B, Id<GuildMarker>, HashSet<Id<ChannelMarker>>>,
+    guild_commands: Map<B, Id<GuildMarker>, HashSet<Id<CommandMarker>>>,
+    guild_emojis: Map<B, Id<GuildMarker>, HashSet<Id<EmojiMarker>>>,
+    guild_integrations: Map<B, Id<GuildMarker>, HashSet<Id<IntegrationMarker>>>,
+    guild_members: Map<B, Id<GuildMarker>, HashSet<Id<UserMarker>>>,
+    guild_presences: Map<B, Id<GuildMarker>, HashSet<Id<UserMarker>>>,
+    guild_roles: Map<B, Id<GuildMarker>, HashSet<Id<RoleMarker>>>,
+    guild_stage_instances: Map<B, Id<GuildMarker>, HashSet<Id<StageMarker>>>,
+    guild_stickers: Map<B, Id<GuildMarker>, HashSet<Id<StickerMarker>>>,
+    integrations: Map<B, (Id<GuildMarker>, Id<IntegrationMarker>), GuildResource<GuildIntegration>>,
+    members: Map<B, (Id<GuildMarker>, Id<UserMarker>), CachedMember>,
+    messages: Map<B, Id<MessageMarker>, CachedMessage>,
+    presences: Map<B, (Id<GuildMarker>, Id<UserMarker>), CachedPresence>,
+    roles: Map<B, Id<RoleMarker>, GuildResource<Role>>,
+    stage_instances: Map<B, Id<StageMarker>, GuildResource<StageInstance>>,
+    stickers: Map<B, Id<StickerMarker>, GuildResource<CachedSticker>>,
+    unavailable_guilds: Map<B, Id<GuildMarker>, ()>,
+    users: Map<B, Id<UserMarker>, User>,
+    user_guilds: Map<B, Id<UserMarker>, BTreeSet<Id<GuildMarker>>>,
     /// Mapping of channels and the users currently connected.
     #[allow(clippy::type_complexity)]
-    voice_state_channels: DashMap<Id<ChannelMarker>, HashSet<(Id<GuildMarker>, Id<UserMarker>)>>,
+    voice_state_channels: Map<B, Id<ChannelMarker>, HashSet<(Id<GuildMarker>, Id<UserMarker>)>>,
     /// Mapping of guilds and users currently connected to its voice channels.
-    voice_state_guilds: DashMap<Id<GuildMarker>, HashSet<Id<UserMarker>>>,
+    voice_state_guilds: Map<B, Id<GuildMarker>, HashSet<Id<UserMarker>>>,
     /// Mapping of guild ID and user ID pairs to their voice states.
-    voice_states: DashMap<(Id<GuildMarker>, Id<UserMarker>), CachedVoiceState>,
+    voice_states: Map<B, (Id<GuildMarker>, Id<UserMarker>), CachedVoiceState>,
 }
 
 /// Implemented methods and types for the cache.
@@ -254,20 +258,25 @@ impl InMemoryCache {
     pub const fn builder() -> InMemoryCacheBuilder {
         InMemoryCacheBuilder::new()
     }
+}
 
+impl<B: CacheBackend> InMemoryCache<B> {
     /// Clear the state of the Cache.
     ///
     /// This is equal to creating a new empty cache.
     pub fn clear(&self) {
         self.channels.clear();
         self.channel_messages.clear();
+        self.commands.clear();
         self.current_user
             .lock()
             .expect("current user poisoned")
             .take();
         self.emojis.clear();
+        self.global_commands.clear();
         self.guilds.clear();
         self.guild_channels.clear();
+        self.guild_commands.clear();
         self.guild_emojis.clear();
         self.guild_integrations.clear();
         self.guild_members.clear();
@@ -314,7 +323,7 @@ impl InMemoryCache {
     /// }
     /// ```
     #[allow(clippy::iter_not_returning_iterator)]
-    pub const fn iter(&self) -> InMemoryCacheIter<'_> {
+    pub const fn iter(&self) -> InMemoryCacheIter<'_, B> {
         InMemoryCacheIter::new(self)
     }
 
@@ -333,7 +342,7 @@ impl InMemoryCache {
     /// let guilds = cache.stats().guilds();
     /// println!("guild count: {guilds}");
     /// ```
-    pub const fn stats(&self) -> InMemoryCacheStats<'_> {
+    pub const fn stats(&self) -> InMemoryCacheStats<'_, B> {
         InMemoryCacheStats::new(self)
     }
 
@@ -369,15 +378,68 @@ impl InMemoryCache {
     /// # Ok(()) }
     /// ```
     #[cfg(feature = "permission-calculator")]
-    pub const fn permissions(&self) -> InMemoryCachePermissions<'_> {
+    pub const fn permissions(&self) -> InMemoryCachePermissions<'_, B> {
         InMemoryCachePermissions::new(self)
     }
 
     /// Update the cache with an event from the gateway.
-    pub fn update(&self, value: &impl UpdateCache) {
+    pub fn update(&self, value: &impl UpdateCache<B>) {
         value.update(self);
     }
 
+    /// Cache members fetched outside of the gateway, such as pages of members
+    /// from [`Client::guild_members`].
+    ///
+    /// This lets members fetched over HTTP be cached without requesting
+    /// chunks over the gateway. Members are only cached if the cache is
+    /// configured to cache [`ResourceType::MEMBER`].
+    ///
+    /// # Examples
+    ///
+    /// Cache all members of a guild by paging through them 1000 at a time:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use twilight_cache_inmemory::InMemoryCache;
+    /// use twilight_http::Client;
+    /// use twilight_model::id::Id;
+    ///
+    /// let cache = InMemoryCache::new();
+    /// let client = Client::new("my token".to_owned());
+    /// let guild_id = Id::new(1);
+    ///
+    /// let mut after = None;
+    ///
+    /// loop {
+    ///     let mut request = client.guild_members(guild_id).limit(1000)?;
+    ///
+    ///     if let Some(after) = after {
+    ///         request = request.after(after);
+    ///     }
+    ///
+    ///     let members = request.exec().await?.model().await?;
+    ///     after = members.last().map(|member| member.user.id);
+    ///     let last_page = members.len() < 1000;
+    ///     cache.extend_members(members);
+    ///
+    ///     if last_page {
+    ///         break;
+    ///     }
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`Client::guild_members`]: https://docs.rs/twilight-http/*/twilight_http/client/struct.Client.html#method.guild_members
+    pub fn extend_members(&self, members: impl IntoIterator<Item = Member>) {
+        if !self.wants(ResourceType::MEMBER) {
+            return;
+        }
+
+        for member in members {
+            self.cache_member(member.guild_id, member);
+        }
+    }
+
     /// Gets the current user.
     pub fn current_user(&self) -> Option<CurrentUser> {
         self.current_user
@@ -390,7 +452,7 @@ impl InMemoryCache {
     pub fn channel(
         &self,
         channel_id: Id<ChannelMarker>,
-    ) -> Option<Reference<'_, Id<ChannelMarker>, Channel>> {
+    ) -> Option<Reference<'_, Id<ChannelMarker>, Channel, B>> {
         self.channels.get(&channel_id).map(Reference::new)
     }
 
@@ -406,12 +468,29 @@ impl InMemoryCache {
     ///
     /// [`DIRECT_MESSAGES`]: ::twilight_model::gateway::Intents::DIRECT_MESSAGES
     /// [`GUILD_MESSAGES`]: ::twilight_model::gateway::Intents::GUILD_MESSAGES
-    pub fn channel_messages(&self, channel_id: Id<ChannelMarker>) -> Option<ChannelMessages<'_>> {
+    pub fn channel_messages(
+        &self,
+        channel_id: Id<ChannelMarker>,
+    ) -> Option<ChannelMessages<'_, B>> {
         let channel = self.channel_messages.get(&channel_id)?;
 
         Some(ChannelMessages::new(channel))
     }
 
+    /// Gets an application command by ID.
+    ///
+    /// Discord doesn't send commands over the gateway, so they are only cached
+    /// via [`set_commands`]. The [`COMMAND`] resource type must be enabled.
+    ///
+    /// [`COMMAND`]: crate::config::ResourceType::COMMAND
+    /// [`set_commands`]: Self::set_commands
+    pub fn command(
+        &self,
+        command_id: Id<CommandMarker>,
+    ) -> Option<Reference<'_, Id<CommandMarker>, Command, B>> {
+        self.commands.get(&command_id).map(Reference::new)
+    }
+
     /// Gets an emoji by ID.
     ///
     /// This requires the [`GUILD_EMOJIS_AND_STICKERS`] intent.
@@ -420,10 +499,24 @@ impl InMemoryCache {
     pub fn emoji(
         &self,
         emoji_id: Id<EmojiMarker>,
-    ) -> Option<Reference<'_, Id<EmojiMarker>, GuildResource<CachedEmoji>>> {
+    ) -> Option<Reference<'_, Id<EmojiMarker>, GuildResource<CachedEmoji>, B>> {
         self.emojis.get(&emoji_id).map(Reference::new)
     }
 
+    /// Gets the set of global application commands.
+    ///
+    /// Commands are only cached via [`set_commands`]. The [`COMMAND`] resource
+    /// type must be enabled.
+    ///
+    /// [`COMMAND`]: crate::config::ResourceType::COMMAND
+    /// [`set_commands`]: Self::set_commands
+    pub fn global_commands(&self) -> HashSet<Id<CommandMarker>> {
+        self.global_commands
+            .iter()
+            .map(|entry| *entry.key())
+            .collect()
+    }
+
     /// Gets a guild by ID.
     ///
     /// This requires the [`GUILDS`] intent.
@@ -432,7 +525,7 @@ impl InMemoryCache {
     pub fn guild(
         &self,
         guild_id: Id<GuildMarker>,
-    ) -> Option<Reference<'_, Id<GuildMarker>, CachedGuild>> {
+    ) -> Option<Reference<'_, Id<GuildMarker>, CachedGuild, B>> {
         self.guilds.get(&guild_id).map(Reference::new)
     }
 
@@ -444,10 +537,24 @@ impl InMemoryCache {
     pub fn guild_channels(
         &self,
         guild_id: Id<GuildMarker>,
-    ) -> Option<Reference<'_, Id<GuildMarker>, HashSet<Id<ChannelMarker>>>> {
+    ) -> Option<Reference<'_, Id<GuildMarker>, HashSet<Id<ChannelMarker>>, B>> {
         self.guild_channels.get(&guild_id).map(Reference::new)
     }
 
+    /// Gets the set of application commands in a guild.
+    ///
+    /// Commands are only cached via [`set_commands`]. The [`COMMAND`] resource
+    /// type must be enabled.
+    ///
+    /// [`COMMAND`]: crate::config::ResourceType::COMMAND
+    /// [`set_commands`]: Self::set_commands
+    pub fn guild_commands(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Option<Reference<'_, Id<GuildMarker>, HashSet<Id<CommandMarker>>, B>> {
+        self.guild_commands.get(&guild_id).map(Reference::new)
+    }
+
     /// Gets the set of emojis in a guild.
     ///
     /// This requires both the [`GUILDS`] and [`GUILD_EMOJIS_AND_STICKERS`]
@@ -458,7 +565,7 @@ impl InMemoryCache {
     pub fn guild_emojis(
         &self,
         guild_id: Id<GuildMarker>,
-    ) -> Option<Reference<'_, Id<GuildMarker>, HashSet<Id<EmojiMarker>>>> {
+    ) -> Option<Reference<'_, Id<GuildMarker>, HashSet<Id<EmojiMarker>>, B>> {
         self.guild_emojis.get(&guild_id).map(Reference::new)
     }
 
@@ -471,7 +578,7 @@ impl InMemoryCache {
     pub fn guild_integrations(
         &self,
         guild_id: Id<GuildMarker>,
-    ) -> Option<Reference<'_, Id<GuildMarker>, HashSet<Id<IntegrationMarker>>>> {
+    ) -> Option<Reference<'_, Id<GuildMarker>, HashSet<Id<IntegrationMarker>>, B>> {
         self.guild_integrations.get(&guild_id).map(Reference::new)
     }
 
@@ -485,7 +592,7 @@ impl InMemoryCache {
     pub fn guild_members(
         &self,
         guild_id: Id<GuildMarker>,
-    ) -> Option<Reference<'_, Id<GuildMarker>, HashSet<Id<UserMarker>>>> {
+    ) -> Option<Reference<'_, Id<GuildMarker>, HashSet<Id<UserMarker>>, B>> {
         self.guild_members.get(&guild_id).map(Reference::new)
     }
 
@@ -499,7 +606,7 @@ impl InMemoryCache {
     pub fn guild_presences(
         &self,
         guild_id: Id<GuildMarker>,
-    ) -> Option<Reference<'_, Id<GuildMarker>, HashSet<Id<UserMarker>>>> {
+    ) -> Option<Reference<'_, Id<GuildMarker>, HashSet<Id<UserMarker>>, B>> {
         self.guild_presences.get(&guild_id).map(Reference::new)
     }
 
@@ -511,7 +618,7 @@ impl InMemoryCache {
     pub fn guild_roles(
         &self,
         guild_id: Id<GuildMarker>,
-    ) -> Option<Reference<'_, Id<GuildMarker>, HashSet<Id<RoleMarker>>>> {
+    ) -> Option<Reference<'_, Id<GuildMarker>, HashSet<Id<RoleMarker>>, B>> {
         self.guild_roles.get(&guild_id).map(Reference::new)
     }
 
@@ -523,7 +630,7 @@ impl InMemoryCache {
     pub fn guild_stage_instances(
         &self,
         guild_id: Id<GuildMarker>,
-    ) -> Option<Reference<'_, Id<GuildMarker>, HashSet<Id<StageMarker>>>> {
+    ) -> Option<Reference<'_, Id<GuildMarker>, HashSet<Id<StageMarker>>, B>> {
         self.guild_stage_instances
             .get(&guild_id)
             .map(Reference::new)
@@ -541,7 +648,7 @@ impl InMemoryCache {
     pub fn guild_stickers(
         &self,
         guild_id: Id<GuildMarker>,
-    ) -> Option<Reference<'_, Id<GuildMarker>, HashSet<Id<StickerMarker>>>> {
+    ) -> Option<Reference<'_, Id<GuildMarker>, HashSet<Id<StickerMarker>>, B>> {
         self.guild_stickers.get(&guild_id).map(Reference::new)
     }
 
@@ -554,7 +661,7 @@ impl InMemoryCache {
     pub fn guild_voice_states(
         &self,
         guild_id: Id<GuildMarker>,
-    ) -> Option<Reference<'_, Id<GuildMarker>, HashSet<Id<UserMarker>>>> {
+    ) -> Option<Reference<'_, Id<GuildMarker>, HashSet<Id<UserMarker>>, B>> {
         self.voice_state_guilds.get(&guild_id).map(Reference::new)
     }
 
@@ -570,7 +677,7 @@ impl InMemoryCache {
         guild_id: Id<GuildMarker>,
         integration_id: Id<IntegrationMarker>,
     ) -> Option<
-        Reference<'_, (Id<GuildMarker>, Id<IntegrationMarker>), GuildResource<GuildIntegration>>,
+        Reference<'_, (Id<GuildMarker>, Id<IntegrationMarker>), GuildResource<GuildIntegration>, B>,
     > {
         self.integrations
             .get(&(guild_id, integration_id))
@@ -587,7 +694,7 @@ impl InMemoryCache {
         &self,
         guild_id: Id<GuildMarker>,
         user_id: Id<UserMarker>,
-    ) -> Option<Reference<'_, (Id<GuildMarker>, Id<UserMarker>), CachedMember>> {
+    ) -> Option<Reference<'_, (Id<GuildMarker>, Id<UserMarker>), CachedMember, B>> {
         self.members.get(&(guild_id, user_id)).map(Reference::new)
     }
 
@@ -601,7 +708,7 @@ impl InMemoryCache {
     pub fn message(
         &self,
         message_id: Id<MessageMarker>,
-    ) -> Option<Reference<'_, Id<MessageMarker>, CachedMessage>> {
+    ) -> Option<Reference<'_, Id<MessageMarker>, CachedMessage, B>> {
         self.messages.get(&message_id).map(Reference::new)
     }
 
@@ -615,7 +722,7 @@ impl InMemoryCache {
         &self,
         guild_id: Id<GuildMarker>,
         user_id: Id<UserMarker>,
-    ) -> Option<Reference<'_, (Id<GuildMarker>, Id<UserMarker>), CachedPresence>> {
+    ) -> Option<Reference<'_, (Id<GuildMarker>, Id<UserMarker>), CachedPresence, B>> {
         self.presences.get(&(guild_id, user_id)).map(Reference::new)
     }
 
@@ -627,7 +734,7 @@ impl InMemoryCache {
     pub fn role(
         &self,
         role_id: Id<RoleMarker>,
-    ) -> Option<Reference<'_, Id<RoleMarker>, GuildResource<Role>>> {
+    ) -> Option<Reference<'_, Id<RoleMarker>, GuildResource<Role>, B>> {
         self.roles.get(&role_id).map(Reference::new)
     }
 
@@ -639,7 +746,7 @@ impl InMemoryCache {
     pub fn stage_instance(
         &self,
         stage_id: Id<StageMarker>,
-    ) -> Option<Reference<'_, Id<StageMarker>, GuildResource<StageInstance>>> {
+    ) -> Option<Reference<'_, Id<StageMarker>, GuildResource<StageInstance>, B>> {
         self.stage_instances.get(&stage_id).map(Reference::new)
     }
 
@@ -654,7 +761,7 @@ impl InMemoryCache {
     pub fn sticker(
         &self,
         sticker_id: Id<StickerMarker>,
-    ) -> Option<Reference<'_, Id<StickerMarker>, GuildResource<CachedSticker>>> {
+    ) -> Option<Reference<'_, Id<StickerMarker>, GuildResource<CachedSticker>, B>> {
         self.stickers.get(&sticker_id).map(Reference::new)
     }
 
@@ -663,7 +770,7 @@ impl InMemoryCache {
     /// This requires the [`GUILD_MEMBERS`] intent.
     ///
     /// [`GUILD_MEMBERS`]: ::twilight_model::gateway::Intents::GUILD_MEMBERS
-    pub fn user(&self, user_id: Id<UserMarker>) -> Option<Reference<'_, Id<UserMarker>, User>> {
+    pub fn user(&self, user_id: Id<UserMarker>) -> Option<Reference<'_, Id<UserMarker>, User, B>> {
         self.users.get(&user_id).map(Reference::new)
     }
 
@@ -676,7 +783,7 @@ impl InMemoryCache {
     pub fn voice_channel_states(
         &self,
         channel_id: Id<ChannelMarker>,
-    ) -> Option<VoiceChannelStates<'_>> {
+    ) -> Option<VoiceChannelStates<'_, B>> {
         let user_ids = self.voice_state_channels.get(&channel_id)?;
 
         Some(VoiceChannelStates {
@@ -697,7 +804,7 @@ impl InMemoryCache {
         &self,
         user_id: Id<UserMarker>,
         guild_id: Id<GuildMarker>,
-    ) -> Option<Reference<'_, (Id<GuildMarker>, Id<UserMarker>), CachedVoiceState>> {
+    ) -> Option<Reference<'_, (Id<GuildMarker>, Id<UserMarker>), CachedVoiceState, B>> {
         self.voice_states
             .get(&(guild_id, user_id))
             .map(Reference::new)
@@ -736,7 +843,7 @@ impl InMemoryCache {
     fn new_with_config(config: Config) -> Self {
         Self {
             config,
-            ..InMemoryCache::default()
+            ..Self::default()
         }
     }
 
@@ -810,23 +917,23 @@ mod private {
 /// Implemented for dispatch events.
 ///
 /// This trait is sealed and cannot be implemented.
-pub trait UpdateCache: private::Sealed {
+pub trait UpdateCache<B: CacheBackend = DashMapBackend>: private::Sealed {
     /// Updates the cache based on data contained within an event.
     // Allow this for presentation purposes in documentation.
     #[allow(unused_variables)]
-    fn update(&self, cache: &InMemoryCache) {}
+    fn update(&self, cache: &InMemoryCache<B>) {}
 }
 
 /// Iterator over a voice channel's list of voice states.
-pub struct VoiceChannelStates<'a> {
+pub struct VoiceChannelStates<'a, B: CacheBackend = DashMapBackend> {
     index: usize,
     #[allow(clippy::type_complexity)]
-    user_ids: Ref<'a, Id<ChannelMarker>, HashSet<(Id<GuildMarker>, Id<UserMarker>)>>,
-    voice_states: &'a DashMap<(Id<GuildMarker>, Id<UserMarker>), CachedVoiceState>,
+    user_ids: MapEntryRef<'a, B, Id<ChannelMarker>, HashSet<(Id<GuildMarker>, Id<UserMarker>)>>,
+    voice_states: &'a Map<B, (Id<GuildMarker>, Id<UserMarker>), CachedVoiceState>,
 }
 
-impl<'a> Iterator for VoiceChannelStates<'a> {
-    type Item = Reference<'a, (Id<GuildMarker>, Id<UserMarker>), CachedVoiceState>;
+impl<'a, B: CacheBackend> Iterator for VoiceChannelStates<'a, B> {
+    type Item = Reference<'a, (Id<GuildMarker>, Id<UserMarker>), CachedVoiceState, B>;
 
     fn next(&mut self) -> Option<Self::Item> {
         while let Some((guild_id, user_id)) = self.user_ids.iter().nth(self.index) {
@@ -841,10 +948,10 @@ impl<'a> Iterator for VoiceChannelStates<'a> {
     }
 }
 
-impl UpdateCache for Event {
+impl<B: CacheBackend> UpdateCache<B> for Event {
     // clippy: using `.deref()` is cleaner
     #[allow(clippy::cognitive_complexity, clippy::explicit_deref_methods)]
-    fn update(&self, c: &InMemoryCache) {
+    fn update(&self, c: &InMemoryCache<B>) {
         match self {
             Event::ChannelCreate(v) => c.update(v.deref()),
             Event::ChannelDelete(v) => c.update(v.deref()),
@@ -902,8 +1009,11 @@ impl UpdateCache for Event {
             | Event::GuildScheduledEventUpdate(_)
             | Event::GuildScheduledEventUserAdd(_)
             | Event::GuildScheduledEventUserRemove(_)
+            | Event::GuildsReady(_)
             | Event::InviteCreate(_)
             | Event::InviteDelete(_)
+            | Event::MessagePollVoteAdd(_)
+            | Event::MessagePollVoteRemove(_)
             | Event::PresencesReplace
             | Event::Resumed
             | Event::ShardConnected(_)
@@ -927,7 +1037,7 @@ mod tests {
     use crate::{test, InMemoryCache};
     use twilight_model::{
         gateway::payload::incoming::RoleDelete,
-        guild::{Member, Permissions, Role},
+        guild::{Member, Permissions, Role, RoleFlags},
         id::Id,
         util::Timestamp,
     };
@@ -979,6 +1089,7 @@ mod tests {
             vec![
                 Role {
                     color: 0,
+                    flags: RoleFlags::empty(),
                     hoist: false,
                     icon: None,
                     id: Id::new(1),
@@ -992,6 +1103,7 @@ mod tests {
                 },
                 Role {
                     color: 0,
+                    flags: RoleFlags::empty(),
                     hoist: false,
                     icon: None,
                     id: Id::new(2),
@@ -19,6 +19,7 @@ use twilight_model::{
         },
         Id,
     },
+    poll::Poll,
     util::Timestamp,
 };
 
@@ -102,6 +103,8 @@ pub struct CachedMessage {
     pub(crate) mention_roles: Vec<Id<RoleMarker>>,
     pub(crate) mentions: Vec<Id<UserMarker>>,
     pub(crate) pinned: bool,
+    poll: Option<Poll>,
+    position: Option<u64>,
     pub(crate) reactions: Vec<MessageReaction>,
     reference: Option<MessageReference>,
     sticker_items: Vec<MessageSticker>,
@@ -241,6 +244,18 @@ impl CachedMessage {
         self.pinned
     }
 
+    /// Poll attached to the message.
+    ///
+    /// Vote counts aren't updated when users vote.
+    pub const fn poll(&self) -> Option<&Poll> {
+        self.poll.as_ref()
+    }
+
+    /// Approximate position of the message in a thread.
+    pub const fn position(&self) -> Option<u64> {
+        self.position
+    }
+
     /// Reactions to the message.
     pub fn reactions(&self) -> &[MessageReaction] {
         &self.reactions
@@ -299,7 +314,10 @@ impl CachedMessage {
             mention_everyone,
             mention_roles,
             mentions,
+            nonce: _,
             pinned,
+            poll,
+            position,
             reactions,
             reference,
             referenced_message: _,
@@ -332,6 +350,8 @@ impl CachedMessage {
             mention_roles,
             mentions: mentions.into_iter().map(|mention| mention.id).collect(),
             pinned,
+            poll,
+            position,
             reactions,
             reference,
             sticker_items,
@@ -379,6 +399,8 @@ mod tests {
         mention_roles,
         mentions,
         pinned,
+        poll,
+        position,
         reactions,
         reference,
         sticker_items,
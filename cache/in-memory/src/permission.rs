@@ -36,7 +36,10 @@
 //! [current system time]: SystemTime::now
 //! [read-only permissions]: MEMBER_COMMUNICATION_DISABLED_ALLOWLIST
 
-use super::InMemoryCache;
+use super::{
+    backend::{CacheBackend, CacheMapRef, DashMapBackend},
+    InMemoryCache,
+};
 use crate::model::member::CachedMember;
 use std::{
     error::Error,
@@ -298,13 +301,13 @@ struct MemberRoles {
 /// Calculate the permissions of a member with information from the cache.
 #[derive(Clone, Debug)]
 #[must_use = "has no effect if unused"]
-pub struct InMemoryCachePermissions<'a> {
-    cache: &'a InMemoryCache,
+pub struct InMemoryCachePermissions<'a, B: CacheBackend = DashMapBackend> {
+    cache: &'a InMemoryCache<B>,
     check_member_communication_disabled: bool,
 }
 
-impl<'a> InMemoryCachePermissions<'a> {
-    pub(super) const fn new(cache: &'a InMemoryCache) -> Self {
+impl<'a, B: CacheBackend> InMemoryCachePermissions<'a, B> {
+    pub(super) const fn new(cache: &'a InMemoryCache<B>) -> Self {
         Self {
             cache,
             check_member_communication_disabled: true,
@@ -312,13 +315,13 @@ impl<'a> InMemoryCachePermissions<'a> {
     }
 
     /// Immutable reference to the underlying cache.
-    pub const fn cache_ref(&'a self) -> &'a InMemoryCache {
+    pub const fn cache_ref(&'a self) -> &'a InMemoryCache<B> {
         self.cache
     }
 
     /// Consume the statistics interface, returning the underlying cache
     /// reference.
-    pub const fn into_cache(self) -> &'a InMemoryCache {
+    pub const fn into_cache(self) -> &'a InMemoryCache<B> {
         self.cache
     }
 
@@ -3,7 +3,10 @@ use twilight_model::id::{
     Id,
 };
 
-use super::InMemoryCache;
+use super::{
+    backend::{CacheBackend, CacheMap, CacheMapRef, DashMapBackend},
+    InMemoryCache,
+};
 
 /// Retrieve statistics about the number of entities of each resource in the
 /// cache.
@@ -28,21 +31,21 @@ use super::InMemoryCache;
 /// [`channel_messages`]: Self::channel_messages
 /// [`users`]: Self::users
 #[derive(Clone, Debug)]
-pub struct InMemoryCacheStats<'a>(&'a InMemoryCache);
+pub struct InMemoryCacheStats<'a, B: CacheBackend = DashMapBackend>(&'a InMemoryCache<B>);
 
-impl<'a> InMemoryCacheStats<'a> {
-    pub(super) const fn new(cache: &'a InMemoryCache) -> Self {
+impl<'a, B: CacheBackend> InMemoryCacheStats<'a, B> {
+    pub(super) const fn new(cache: &'a InMemoryCache<B>) -> Self {
         Self(cache)
     }
 
     /// Return an immutable reference to the underlying cache.
-    pub const fn cache_ref(&'a self) -> &'a InMemoryCache {
+    pub const fn cache_ref(&'a self) -> &'a InMemoryCache<B> {
         self.0
     }
 
     /// Consume the statistics interface, returning the underlying cache
     /// reference.
-    pub const fn into_cache(self) -> &'a InMemoryCache {
+    pub const fn into_cache(self) -> &'a InMemoryCache<B> {
         self.0
     }
 
@@ -73,6 +76,11 @@ impl<'a> InMemoryCacheStats<'a> {
         Some(channel.len())
     }
 
+    /// Number of application commands in the cache.
+    pub fn commands(&self) -> usize {
+        self.0.commands.len()
+    }
+
     /// Number of emojis in the cache.
     pub fn emojis(&self) -> usize {
         self.0.emojis.len()
@@ -92,6 +100,15 @@ impl<'a> InMemoryCacheStats<'a> {
         Some(guild.len())
     }
 
+    /// Number of application commands of a given guild in the cache.
+    ///
+    /// Returns `None` if the guild's commands haven't yet been cached.
+    pub fn guild_commands(&self, guild_id: Id<GuildMarker>) -> Option<usize> {
+        let guild = self.0.guild_commands.get(&guild_id)?;
+
+        Some(guild.len())
+    }
+
     /// Number of emojis in a given guild in the cache.
     ///
     /// Returns `None` if the guild hasn't yet been cached.
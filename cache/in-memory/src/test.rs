@@ -11,7 +11,7 @@ use twilight_model::{
     gateway::payload::incoming::{MessageCreate, ReactionAdd},
     guild::{
         DefaultMessageNotificationLevel, Emoji, ExplicitContentFilter, Guild, Member, MfaLevel,
-        NSFWLevel, PartialMember, Permissions, PremiumTier, Role, SystemChannelFlags,
+        NSFWLevel, PartialMember, Permissions, PremiumTier, Role, RoleFlags, SystemChannelFlags,
         VerificationLevel,
     },
     id::{
@@ -81,7 +81,10 @@ pub fn cache_with_message_and_reactions() -> InMemoryCache {
         mention_everyone: false,
         mention_roles: Vec::new(),
         mentions: Vec::new(),
+        nonce: None,
         pinned: false,
+        poll: None,
+        position: None,
         reactions: Vec::new(),
         reference: None,
         sticker_items: Vec::new(),
@@ -95,6 +98,8 @@ pub fn cache_with_message_and_reactions() -> InMemoryCache {
     cache.update(&MessageCreate(msg));
 
     let mut reaction = ReactionAdd(Reaction {
+        burst: false,
+        burst_colors: Vec::new(),
         channel_id: Id::new(2),
         emoji: ReactionType::Unicode {
             name: "😀".to_owned(),
@@ -217,8 +222,10 @@ pub fn guild_channel_text() -> (Id<GuildMarker>, Id<ChannelMarker>, Channel) {
     let channel_id = Id::new(2);
     let channel = Channel {
         application_id: None,
+        applied_tags: None,
         bitrate: None,
         default_auto_archive_duration: None,
+        flags: None,
         guild_id: Some(guild_id),
         icon: None,
         id: channel_id,
@@ -269,6 +276,7 @@ pub fn member(id: Id<UserMarker>, guild_id: Id<GuildMarker>) -> Member {
 pub fn role(id: Id<RoleMarker>) -> Role {
     Role {
         color: 0,
+        flags: RoleFlags::empty(),
         hoist: false,
         icon: None,
         id,
@@ -359,6 +367,7 @@ pub fn guild(id: Id<GuildMarker>, member_count: Option<u64>) -> Guild {
         emojis: Vec::new(),
         explicit_content_filter: ExplicitContentFilter::None,
         features: Vec::new(),
+        guild_scheduled_events: Vec::new(),
         icon: None,
         id,
         joined_at: None,
@@ -7,7 +7,9 @@ use std::{
     mem,
 };
 use twilight_model::{
-    channel::embed::{Embed, EmbedAuthor, EmbedField, EmbedFooter, EmbedImage, EmbedThumbnail},
+    channel::embed::{
+        Embed, EmbedAuthor, EmbedField, EmbedFooter, EmbedImage, EmbedThumbnail, EmbedType,
+    },
     util::Timestamp,
 };
 
@@ -240,7 +242,7 @@ impl EmbedBuilder {
             fields: Vec::new(),
             footer: None,
             image: None,
-            kind: String::new(),
+            kind: EmbedType::Rich,
             provider: None,
             thumbnail: None,
             timestamp: None,
@@ -462,10 +464,6 @@ impl EmbedBuilder {
             });
         }
 
-        if self.0.kind.is_empty() {
-            self.0.kind = "rich".to_string();
-        }
-
         Ok(self.0)
     }
 
@@ -748,7 +746,7 @@ mod tests {
     use static_assertions::{assert_fields, assert_impl_all, const_assert};
     use std::{error::Error, fmt::Debug};
     use twilight_model::{
-        channel::embed::{Embed, EmbedField, EmbedFooter},
+        channel::embed::{Embed, EmbedField, EmbedFooter, EmbedType},
         util::Timestamp,
     };
 
@@ -860,7 +858,7 @@ mod tests {
                 text: "Warn".to_string(),
             }),
             image: None,
-            kind: "rich".to_string(),
+            kind: EmbedType::Rich,
             provider: None,
             thumbnail: None,
             timestamp: Some(timestamp),
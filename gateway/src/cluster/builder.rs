@@ -6,6 +6,7 @@ use crate::{
 use std::{
     collections::HashMap,
     fmt::{Debug, Formatter, Result as FmtResult},
+    num::NonZeroU8,
     sync::Arc,
 };
 use twilight_gateway_queue::{LocalQueue, Queue};
@@ -170,6 +171,18 @@ impl ClusterBuilder {
         self
     }
 
+    /// Set whether the first heartbeat of each shard is sent after a random
+    /// fraction of the heartbeat interval.
+    ///
+    /// Refer to [`ShardBuilder::heartbeat_jitter`] for the default value.
+    ///
+    /// [`ShardBuilder::heartbeat_jitter`]: crate::shard::ShardBuilder::heartbeat_jitter
+    pub fn heartbeat_jitter(mut self, heartbeat_jitter: bool) -> Self {
+        self.shard = self.shard.heartbeat_jitter(heartbeat_jitter);
+
+        self
+    }
+
     /// Set the `twilight_http` Client used by the cluster and the shards it
     /// manages.
     ///
@@ -226,6 +239,18 @@ impl ClusterBuilder {
         self
     }
 
+    /// Set the number of consecutive heartbeats of a shard that may go
+    /// unacknowledged before it's reconnected.
+    ///
+    /// Refer to [`ShardBuilder::max_missed_heartbeats`] for the default value.
+    ///
+    /// [`ShardBuilder::max_missed_heartbeats`]: crate::shard::ShardBuilder::max_missed_heartbeats
+    pub fn max_missed_heartbeats(mut self, max_missed_heartbeats: NonZeroU8) -> Self {
+        self.shard = self.shard.max_missed_heartbeats(max_missed_heartbeats);
+
+        self
+    }
+
     /// Set the presence to use when identifying with the gateway.
     ///
     /// Refer to the shard's [`ShardBuilder::presence`] for more information.
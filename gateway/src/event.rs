@@ -55,6 +55,9 @@ bitflags! {
         const GUILD_STICKERS_UPDATE = 1 << 63;
         /// A guild has been updated.
         const GUILD_UPDATE = 1 << 14;
+        /// All of the guilds of a shard's ready payload have been received, or
+        /// waiting on them timed out.
+        const GUILDS_READY = 1 << 71;
         /// A guild integration was created.
         const INTEGRATION_CREATE = 1 << 60;
         /// A guild integration was deleted.
@@ -84,6 +87,10 @@ bitflags! {
         const MESSAGE_DELETE = 1 << 20;
         /// Multiple messages have been deleted in a channel.
         const MESSAGE_DELETE_BULK = 1 << 21;
+        /// User has voted for an answer of a poll.
+        const MESSAGE_POLL_VOTE_ADD = 1 << 72;
+        /// User has removed their vote for an answer of a poll.
+        const MESSAGE_POLL_VOTE_REMOVE = 1 << 73;
         /// Message in a channel has been updated.
         const MESSAGE_UPDATE = 1 << 22;
         /// User's presence details are updated.
@@ -180,6 +187,14 @@ impl EventTypeFlags {
             | EventTypeFlags::REACTION_REMOVE_EMOJI.bits(),
     );
 
+    /// All [`EventTypeFlags`] in [`Intents::DIRECT_MESSAGE_POLLS`].
+    ///
+    /// [`Intents::DIRECT_MESSAGE_POLLS`]: crate::Intents::DIRECT_MESSAGE_POLLS
+    pub const DIRECT_MESSAGE_POLLS: EventTypeFlags = EventTypeFlags::from_bits_truncate(
+        EventTypeFlags::MESSAGE_POLL_VOTE_ADD.bits()
+            | EventTypeFlags::MESSAGE_POLL_VOTE_REMOVE.bits(),
+    );
+
     /// All [`EventTypeFlags`] in [`Intents::DIRECT_MESSAGE_TYPING`].
     ///
     /// [`Intents::DIRECT_MESSAGE_TYPING`]: crate::Intents::DIRECT_MESSAGE_TYPING
@@ -248,6 +263,14 @@ impl EventTypeFlags {
             | EventTypeFlags::MESSAGE_DELETE_BULK.bits(),
     );
 
+    /// All [`EventTypeFlags`] in [`Intents::GUILD_MESSAGE_POLLS`].
+    ///
+    /// [`Intents::GUILD_MESSAGE_POLLS`]: crate::Intents::GUILD_MESSAGE_POLLS
+    pub const GUILD_MESSAGE_POLLS: EventTypeFlags = EventTypeFlags::from_bits_truncate(
+        EventTypeFlags::MESSAGE_POLL_VOTE_ADD.bits()
+            | EventTypeFlags::MESSAGE_POLL_VOTE_REMOVE.bits(),
+    );
+
     /// All [`EventTypeFlags`] in [`Intents::GUILD_MESSAGE_REACTIONS`].
     ///
     /// [`Intents::GUILD_MESSAGE_REACTIONS`]: crate::Intents::GUILD_MESSAGE_REACTIONS
@@ -323,6 +346,7 @@ impl From<EventType> for EventTypeFlags {
             }
             EventType::GuildStickersUpdate => EventTypeFlags::GUILD_STICKERS_UPDATE,
             EventType::GuildUpdate => EventTypeFlags::GUILD_UPDATE,
+            EventType::GuildsReady => EventTypeFlags::GUILDS_READY,
             EventType::IntegrationCreate => EventTypeFlags::INTEGRATION_CREATE,
             EventType::IntegrationDelete => EventTypeFlags::INTEGRATION_DELETE,
             EventType::IntegrationUpdate => EventTypeFlags::INTEGRATION_UPDATE,
@@ -336,6 +360,8 @@ impl From<EventType> for EventTypeFlags {
             EventType::MessageCreate => EventTypeFlags::MESSAGE_CREATE,
             EventType::MessageDelete => EventTypeFlags::MESSAGE_DELETE,
             EventType::MessageDeleteBulk => EventTypeFlags::MESSAGE_DELETE_BULK,
+            EventType::MessagePollVoteAdd => EventTypeFlags::MESSAGE_POLL_VOTE_ADD,
+            EventType::MessagePollVoteRemove => EventTypeFlags::MESSAGE_POLL_VOTE_REMOVE,
             EventType::MessageUpdate => EventTypeFlags::MESSAGE_UPDATE,
             EventType::PresenceUpdate => EventTypeFlags::PRESENCE_UPDATE,
             EventType::PresencesReplace => EventTypeFlags::PRESENCES_REPLACE,
@@ -27,6 +27,8 @@ mod event;
 pub use self::event::EventTypeFlags;
 pub use twilight_model::gateway::Intents;
 
+use twilight_model::id::{marker::GuildMarker, Id};
+
 #[doc(no_inline)]
 pub use self::{
     cluster::{Cluster, Config as ClusterConfig},
@@ -39,3 +41,62 @@ pub use twilight_model::gateway::event::{Event, EventType};
 
 /// Discord API version used by this crate.
 pub const API_VERSION: u8 = 10;
+
+/// Calculate the ID of the shard that receives events for a guild.
+///
+/// Discord assigns guilds to shards via `(guild_id >> 22) % shard_count`.
+/// This is useful for routing guild operations, such as requesting guild
+/// members, to the shard or process that handles the guild.
+///
+/// # Examples
+///
+/// Calculate the shard of a guild in a bot with 16 shards:
+///
+/// ```
+/// use twilight_model::id::Id;
+///
+/// let guild_id = Id::new(197_038_439_483_310_086);
+/// assert_eq!(2, twilight_gateway::shard_id_for_guild(guild_id, 16));
+/// ```
+///
+/// # Panics
+///
+/// Panics if `shard_count` is 0.
+pub const fn shard_id_for_guild(guild_id: Id<GuildMarker>, shard_count: u64) -> u64 {
+    (guild_id.get() >> 22) % shard_count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::shard_id_for_guild;
+    use twilight_model::id::Id;
+
+    #[test]
+    fn shard_id_for_guild_known() {
+        let guild_a = Id::new(197_038_439_483_310_086);
+        let guild_b = Id::new(41_771_983_423_143_937);
+        let guild_c = Id::new(81_384_788_765_712_384);
+
+        assert_eq!(0, shard_id_for_guild(guild_a, 1));
+        assert_eq!(0, shard_id_for_guild(guild_b, 1));
+        assert_eq!(0, shard_id_for_guild(guild_c, 1));
+
+        assert_eq!(0, shard_id_for_guild(guild_a, 5));
+        assert_eq!(4, shard_id_for_guild(guild_b, 5));
+        assert_eq!(3, shard_id_for_guild(guild_c, 5));
+
+        assert_eq!(2, shard_id_for_guild(guild_a, 16));
+        assert_eq!(6, shard_id_for_guild(guild_b, 16));
+        assert_eq!(2, shard_id_for_guild(guild_c, 16));
+
+        assert_eq!(70, shard_id_for_guild(guild_a, 100));
+        assert_eq!(34, shard_id_for_guild(guild_b, 100));
+        assert_eq!(98, shard_id_for_guild(guild_c, 100));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to calculate the remainder with a divisor of zero")]
+    fn shard_id_for_guild_zero_shards() {
+        shard_id_for_guild(Id::new(1), 0);
+    }
+}
@@ -3,6 +3,7 @@ use crate::EventTypeFlags;
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
+    num::NonZeroU8,
     sync::Arc,
 };
 use twilight_gateway_queue::{LocalQueue, Queue};
@@ -69,6 +70,12 @@ pub enum ShardIdErrorType {
     },
 }
 
+/// Default number of consecutive heartbeats that may go unacknowledged.
+const DEFAULT_MAX_MISSED_HEARTBEATS: NonZeroU8 = match NonZeroU8::new(1) {
+    Some(max) => max,
+    None => panic!("1 is zero"),
+};
+
 /// Builder to configure and construct a shard.
 ///
 /// Use [`ShardBuilder::new`] to start configuring a new [`Shard`].
@@ -100,10 +107,12 @@ pub enum ShardIdErrorType {
 pub struct ShardBuilder {
     event_types: EventTypeFlags,
     pub(crate) gateway_url: Option<Box<str>>,
+    heartbeat_jitter: bool,
     pub(crate) http_client: Arc<Client>,
     identify_properties: Option<IdentifyProperties>,
     intents: Intents,
     large_threshold: u64,
+    max_missed_heartbeats: NonZeroU8,
     presence: Option<UpdatePresencePayload>,
     queue: Arc<dyn Queue>,
     ratelimit_payloads: bool,
@@ -123,10 +132,12 @@ impl ShardBuilder {
         Self {
             event_types: EventTypeFlags::default(),
             gateway_url: None,
+            heartbeat_jitter: true,
             http_client: Arc::new(Client::new(token.clone())),
             identify_properties: None,
             intents,
             large_threshold: 50,
+            max_missed_heartbeats: DEFAULT_MAX_MISSED_HEARTBEATS,
             presence: None,
             queue: Arc::new(LocalQueue::new()),
             ratelimit_payloads: true,
@@ -142,10 +153,12 @@ impl ShardBuilder {
         Config {
             event_types: self.event_types,
             gateway_url: self.gateway_url.unwrap(),
+            heartbeat_jitter: self.heartbeat_jitter,
             http_client: self.http_client,
             identify_properties: self.identify_properties,
             intents: self.intents,
             large_threshold: self.large_threshold,
+            max_missed_heartbeats: self.max_missed_heartbeats,
             presence: self.presence,
             queue: self.queue,
             ratelimit_payloads: self.ratelimit_payloads,
@@ -219,6 +232,19 @@ impl ShardBuilder {
         self
     }
 
+    /// Set whether the first heartbeat is sent after a random fraction of the
+    /// heartbeat interval, as recommended by Discord.
+    ///
+    /// Jitter prevents all shards from heartbeating at the same time, which
+    /// smooths outbound traffic for large clusters.
+    ///
+    /// Defaults to being enabled.
+    pub const fn heartbeat_jitter(mut self, heartbeat_jitter: bool) -> Self {
+        self.heartbeat_jitter = heartbeat_jitter;
+
+        self
+    }
+
     /// Set the HTTP client to be used by the shard for getting gateway
     /// information.
     ///
@@ -286,6 +312,20 @@ impl ShardBuilder {
         self
     }
 
+    /// Set the number of consecutive heartbeats that may go unacknowledged
+    /// before the connection is considered dead and is reconnected.
+    ///
+    /// Discord recommends reconnecting as soon as a heartbeat isn't
+    /// acknowledged, but a higher value may be useful to tolerate flaky
+    /// networks.
+    ///
+    /// Default value is 1.
+    pub const fn max_missed_heartbeats(mut self, max_missed_heartbeats: NonZeroU8) -> Self {
+        self.max_missed_heartbeats = max_missed_heartbeats;
+
+        self
+    }
+
     /// Set the presence to use automatically when starting a new session.
     ///
     /// Default is no presence, which defaults to strictly being "online"
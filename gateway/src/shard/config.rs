@@ -1,5 +1,5 @@
 use crate::EventTypeFlags;
-use std::sync::Arc;
+use std::{num::NonZeroU8, sync::Arc};
 use twilight_gateway_queue::Queue;
 use twilight_http::Client;
 use twilight_model::gateway::{
@@ -24,10 +24,12 @@ use super::tls::TlsContainer;
 pub struct Config {
     pub(super) event_types: EventTypeFlags,
     pub(super) gateway_url: Box<str>,
+    pub(crate) heartbeat_jitter: bool,
     pub(super) http_client: Arc<Client>,
     pub(super) identify_properties: Option<IdentifyProperties>,
     pub(super) intents: Intents,
     pub(super) large_threshold: u64,
+    pub(crate) max_missed_heartbeats: NonZeroU8,
     pub(crate) presence: Option<UpdatePresencePayload>,
     pub(super) queue: Arc<dyn Queue>,
     pub(crate) ratelimit_payloads: bool,
@@ -54,6 +56,11 @@ impl Config {
         &self.gateway_url
     }
 
+    /// Whether the first heartbeat is jittered.
+    pub const fn heartbeat_jitter(&self) -> bool {
+        self.heartbeat_jitter
+    }
+
     /// Return an immutable reference to the `twilight_http` client to be used
     /// by the shard.
     pub fn http_client(&self) -> &Client {
@@ -77,6 +84,12 @@ impl Config {
         self.large_threshold
     }
 
+    /// Number of consecutive heartbeats that may go unacknowledged before the
+    /// connection is reconnected.
+    pub const fn max_missed_heartbeats(&self) -> NonZeroU8 {
+        self.max_missed_heartbeats
+    }
+
     /// Return an immutable reference to the presence to set when identifying
     /// with the gateway.
     ///
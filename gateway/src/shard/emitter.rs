@@ -1,11 +1,17 @@
-use super::json;
+use super::{
+    event::{EventSender, QueuedEvent},
+    json,
+    member_chunks::MemberChunks,
+    pending_guilds::PendingGuilds,
+};
 use crate::{Event, EventTypeFlags};
 use std::{
     error::Error,
     fmt::{Debug, Display, Formatter, Result as FmtResult},
+    sync::{atomic::AtomicUsize, Arc},
 };
-use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
-use twilight_model::gateway::event::shard::Payload;
+use tokio::sync::mpsc::{self, UnboundedReceiver};
+use twilight_model::gateway::{event::shard::Payload, payload::incoming::Ready};
 
 #[derive(Debug)]
 pub struct EmitJsonError {
@@ -64,15 +70,37 @@ pub enum EmitJsonErrorType {
 #[derive(Clone, Debug)]
 pub struct Emitter {
     event_types: EventTypeFlags,
-    tx: UnboundedSender<Event>,
+    member_chunks: Arc<MemberChunks>,
+    pending_guilds: Arc<PendingGuilds>,
+    tx: EventSender,
 }
 
 impl Emitter {
     /// Create a new emitter for events and bytes.
-    pub fn new(event_types: EventTypeFlags) -> (Self, UnboundedReceiver<Event>) {
+    pub fn new(event_types: EventTypeFlags) -> (Self, UnboundedReceiver<QueuedEvent>) {
         let (tx, rx) = mpsc::unbounded_channel();
 
-        (Self { event_types, tx }, rx)
+        (
+            Self {
+                event_types,
+                member_chunks: Arc::default(),
+                pending_guilds: Arc::default(),
+                tx: EventSender::new(tx),
+            },
+            rx,
+        )
+    }
+
+    /// Pending guild member requests whose chunks are collected by the
+    /// emitter.
+    pub fn member_chunks(&self) -> Arc<MemberChunks> {
+        Arc::clone(&self.member_chunks)
+    }
+
+    /// Counter of the number of events sent to the listener that it has yet to
+    /// receive.
+    pub fn queued_events(&self) -> Arc<AtomicUsize> {
+        self.tx.queued()
     }
 
     /// Whether the configured event types include an individual event type.
@@ -107,9 +135,30 @@ impl Emitter {
         }
     }
 
+    /// Send a [`Ready`] payload to the listener and wait for its guilds to
+    /// be received in order to emit [`Event::GuildsReady`], if the listener has
+    /// subscribed to either.
+    ///
+    /// [`Ready`]: twilight_model::gateway::payload::incoming::Ready
+    pub fn ready(&self, shard_id: u64, ready: Ready) {
+        let guilds = ready.guilds.iter().map(|guild| guild.id).collect();
+        self.event(Event::Ready(Box::new(ready)));
+
+        if self.wants(EventTypeFlags::GUILDS_READY) {
+            self.pending_guilds.wait(shard_id, guilds, self.tx.clone());
+        }
+    }
+
     /// Emit a JSON payload that hasn't been deserialized yet, but only if the
     /// listener wants the event type.
     ///
+    /// Member chunks are additionally deserialized while there are pending
+    /// guild member requests so that their members can be collected, and guild
+    /// creates and deletes while there are guilds of a [`Ready`] payload that
+    /// have yet to be received.
+    ///
+    /// [`Ready`]: twilight_model::gateway::payload::incoming::Ready
+    ///
     /// # Errors
     ///
     /// Returns a [`EmitJsonErrorType::EventTypeUnknown`] error type if the
@@ -135,7 +184,12 @@ impl Emitter {
             }
         })?;
 
-        if self.wants(flag) {
+        let wants = self.wants(flag);
+        let collect = (flag == EventTypeFlags::MEMBER_CHUNK && self.member_chunks.is_active())
+            || ((flag == EventTypeFlags::GUILD_CREATE || flag == EventTypeFlags::GUILD_DELETE)
+                && self.pending_guilds.is_active());
+
+        if wants || collect {
             let gateway_event =
                 json::parse_gateway_event(op, seq, event_type, json).map_err(|source| {
                     EmitJsonError {
@@ -143,21 +197,50 @@ impl Emitter {
                         source: Some(Box::new(source)),
                     }
                 })?;
-            self.send(Event::from(gateway_event));
+            let event = Event::from(gateway_event);
+
+            let guilds_ready = match &event {
+                Event::GuildCreate(guild) => self.pending_guilds.receive(guild.0.id, false),
+                Event::GuildDelete(guild) => {
+                    self.pending_guilds.receive(guild.id, guild.unavailable)
+                }
+                Event::MemberChunk(chunk) => {
+                    self.member_chunks.receive(chunk);
+
+                    None
+                }
+                _ => None,
+            };
+
+            if wants {
+                self.send(event);
+            }
+
+            if let Some(guilds_ready) = guilds_ready {
+                self.send(Event::GuildsReady(guilds_ready));
+            }
         }
 
         Ok(())
     }
 
     fn send(&self, event: Event) {
-        let _res = self.tx.send(event);
+        self.tx.send(event);
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Emitter;
+    use super::{json, Emitter};
     use crate::{Event, EventTypeFlags};
+    use twilight_model::gateway::{event::shard::GuildsReady, payload::incoming::Ready};
+
+    fn guild_create(id: u64) -> Vec<u8> {
+        format!(
+            r#"{{"op":0,"s":{id},"t":"GUILD_CREATE","d":{{"afk_timeout":60,"default_message_notifications":0,"explicit_content_filter":0,"features":[],"id":"{id}","mfa_level":0,"name":"guild","nsfw_level":0,"owner_id":"1","preferred_locale":"en-US","premium_progress_bar_enabled":false,"roles":[],"system_channel_flags":0,"verification_level":0}}}}"#
+        )
+        .into_bytes()
+    }
 
     #[test]
     fn bytes_send() {
@@ -178,4 +261,28 @@ mod tests {
         // now check that the event didn't send the event twice
         assert!(rx.try_recv().is_err());
     }
+
+    #[tokio::test]
+    async fn guilds_ready() {
+        let (emitter, mut rx) = Emitter::new(EventTypeFlags::GUILDS_READY);
+        let mut ready = br#"{"application":{"flags":0,"id":"100"},"guilds":[{"id":"1","unavailable":true},{"id":"2","unavailable":true}],"session_id":"session","shard":[2,3],"user":{"avatar":null,"bot":true,"discriminator":"0001","id":"3","mfa_enabled":false,"username":"twilight"},"v":8}"#.to_vec();
+        emitter.ready(2, json::from_slice::<Ready>(ready.as_mut_slice()).unwrap());
+
+        emitter
+            .json(0, Some(1), Some("GUILD_CREATE"), &mut guild_create(1))
+            .unwrap();
+        assert!(rx.try_recv().is_err());
+
+        emitter
+            .json(0, Some(2), Some("GUILD_CREATE"), &mut guild_create(2))
+            .unwrap();
+        assert_eq!(
+            Event::GuildsReady(GuildsReady {
+                shard_id: 2,
+                unavailable: Vec::new(),
+            }),
+            rx.try_recv().unwrap().event
+        );
+        assert!(rx.try_recv().is_err());
+    }
 }
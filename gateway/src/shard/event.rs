@@ -9,17 +9,112 @@
 //! the type of an event and to filter events from event streams via
 //! [`ShardBuilder::event_types`].
 //!
+//! Streams can be narrowed down to the payloads of a single event via
+//! [`Events::events_of`].
+//!
 //! [`EventType`]: ::twilight_model::gateway::event::EventType
 //! [`ShardBuilder::event_types`]: crate::shard::ShardBuilder::event_types
 
 use crate::EventTypeFlags;
-use futures_util::stream::Stream;
+use futures_util::{
+    future::FutureExt,
+    stream::{Stream, StreamExt},
+};
 use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    marker::PhantomData,
     pin::Pin,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
     task::{Context, Poll},
 };
-use tokio::sync::mpsc::UnboundedReceiver;
-use twilight_model::gateway::event::Event;
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
+use twilight_model::gateway::{
+    event::{shard::GuildsReady, Event},
+    payload::incoming::{
+        BanAdd, BanRemove, ChannelCreate, ChannelDelete, ChannelPinsUpdate, ChannelUpdate,
+        CommandPermissionsUpdate, GuildCreate, GuildDelete, GuildEmojisUpdate,
+        GuildIntegrationsUpdate, GuildScheduledEventCreate, GuildScheduledEventDelete,
+        GuildScheduledEventUpdate, GuildScheduledEventUserAdd, GuildScheduledEventUserRemove,
+        GuildStickersUpdate, GuildUpdate, IntegrationCreate, IntegrationDelete, IntegrationUpdate,
+        InteractionCreate, InviteCreate, InviteDelete, MemberAdd, MemberChunk, MemberRemove,
+        MemberUpdate, MessageCreate, MessageDelete, MessageDeleteBulk, MessagePollVoteAdd,
+        MessagePollVoteRemove, MessageUpdate, PresenceUpdate, ReactionAdd, ReactionRemove,
+        ReactionRemoveAll, ReactionRemoveEmoji, Ready, RoleCreate, RoleDelete, RoleUpdate,
+        StageInstanceCreate, StageInstanceDelete, StageInstanceUpdate, ThreadCreate, ThreadDelete,
+        ThreadListSync, ThreadMemberUpdate, ThreadMembersUpdate, ThreadUpdate, TypingStart,
+        UnavailableGuild, UserUpdate, VoiceServerUpdate, VoiceStateUpdate, WebhooksUpdate,
+    },
+};
+
+/// How a shard finished connecting.
+///
+/// Returned by [`Events::wait_for_ready`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Connected {
+    /// Shard identified and received a new session.
+    Ready(Box<Ready>),
+    /// Shard resumed its previous session.
+    Resumed,
+}
+
+/// Waiting for a shard to connect failed.
+///
+/// Returned by [`Events::wait_for_ready`].
+#[derive(Debug)]
+pub struct WaitForReadyError {
+    kind: WaitForReadyErrorType,
+}
+
+impl WaitForReadyError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &WaitForReadyErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (WaitForReadyErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, None)
+    }
+}
+
+impl Display for WaitForReadyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.kind {
+            WaitForReadyErrorType::Closed => {
+                f.write_str("event stream closed before the shard connected")
+            }
+            WaitForReadyErrorType::EventTypesFiltered => {
+                f.write_str("event stream doesn't receive ready and resumed events")
+            }
+        }
+    }
+}
+
+impl Error for WaitForReadyError {}
+
+/// Type of [`WaitForReadyError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum WaitForReadyErrorType {
+    /// Event stream closed before the shard connected.
+    Closed,
+    /// Event stream's event types don't include [`EventTypeFlags::READY`]
+    /// and [`EventTypeFlags::RESUMED`].
+    EventTypesFiltered,
+}
 
 /// A stream of events from a [`Shard`].
 ///
@@ -39,34 +134,597 @@ use twilight_model::gateway::event::Event;
 #[derive(Debug)]
 pub struct Events {
     event_types: EventTypeFlags,
-    rx: UnboundedReceiver<Event>,
+    /// Number of events sent but not yet received.
+    queued: Arc<AtomicUsize>,
+    rx: UnboundedReceiver<QueuedEvent>,
+    /// Span of the last received event.
+    #[cfg(feature = "tracing-spans")]
+    span: tracing::Span,
 }
 
 impl Events {
-    pub(super) const fn new(event_types: EventTypeFlags, rx: UnboundedReceiver<Event>) -> Self {
-        Self { event_types, rx }
+    pub(super) const fn new(
+        event_types: EventTypeFlags,
+        rx: UnboundedReceiver<QueuedEvent>,
+        queued: Arc<AtomicUsize>,
+    ) -> Self {
+        Self {
+            event_types,
+            queued,
+            rx,
+            #[cfg(feature = "tracing-spans")]
+            span: tracing::Span::none(),
+        }
     }
 
     /// Returns the event types that can be passed to this stream.
     pub const fn event_types(&self) -> EventTypeFlags {
         self.event_types
     }
+
+    /// Span that was current when the shard emitted the last event received
+    /// from this stream.
+    ///
+    /// For events emitted while processing a payload this is, or is within,
+    /// the payload's `gateway_event` span. Instrument the handling of an event
+    /// with it to correlate the handling with the payload:
+    ///
+    /// ```no_run
+    /// use futures::StreamExt;
+    /// use std::env;
+    /// use tracing::Instrument;
+    /// use twilight_gateway::{Event, Intents, Shard};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let token = env::var("DISCORD_TOKEN")?;
+    /// let (shard, mut events) = Shard::new(token, Intents::GUILD_MESSAGES).await?;
+    ///
+    /// shard.start().await?;
+    ///
+    /// while let Some(event) = events.next().await {
+    ///     tokio::spawn(
+    ///         async move {
+    ///             if let Event::MessageCreate(message) = event {
+    ///                 tracing::info!(id = %message.id, "received message");
+    ///             }
+    ///         }
+    ///         .instrument(events.span()),
+    ///     );
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// The span is disabled if no event has been received yet.
+    #[cfg(feature = "tracing-spans")]
+    pub fn span(&self) -> tracing::Span {
+        self.span.clone()
+    }
+
+    /// Wait until the shard has finished connecting.
+    ///
+    /// This consumes events from the stream: any events received before the
+    /// shard has connected are discarded.
+    ///
+    /// Resolves to [`Connected::Ready`] with the [`Ready`] payload once the
+    /// shard has identified. If the shard resumes a session instead, Discord
+    /// sends no [`Ready`] and this resolves to [`Connected::Resumed`].
+    ///
+    /// # Examples
+    ///
+    /// Start a shard and wait until it is ready before doing anything else:
+    ///
+    /// ```no_run
+    /// use std::env;
+    /// use twilight_gateway::{shard::Connected, Intents, Shard};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let token = env::var("DISCORD_TOKEN")?;
+    /// let (shard, mut events) = Shard::new(token, Intents::GUILDS).await?;
+    ///
+    /// shard.start().await?;
+    ///
+    /// if let Connected::Ready(ready) = events.wait_for_ready().await? {
+    ///     println!("ready as {}", ready.user.name);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`WaitForReadyErrorType::EventTypesFiltered`] error type if
+    /// the stream's [event types] don't include both
+    /// [`EventTypeFlags::READY`] and [`EventTypeFlags::RESUMED`].
+    ///
+    /// Returns a [`WaitForReadyErrorType::Closed`] error type if the stream
+    /// closed before the shard connected.
+    ///
+    /// [event types]: Self::event_types
+    pub async fn wait_for_ready(&mut self) -> Result<Connected, WaitForReadyError> {
+        if !self
+            .event_types
+            .contains(EventTypeFlags::READY | EventTypeFlags::RESUMED)
+        {
+            return Err(WaitForReadyError {
+                kind: WaitForReadyErrorType::EventTypesFiltered,
+            });
+        }
+
+        while let Some(event) = self.next().await {
+            match event {
+                Event::Ready(ready) => return Ok(Connected::Ready(ready)),
+                Event::Resumed => return Ok(Connected::Resumed),
+                _ => {}
+            }
+        }
+
+        Err(WaitForReadyError {
+            kind: WaitForReadyErrorType::Closed,
+        })
+    }
+
+    /// Narrow the stream down to the payloads of a single event, discarding
+    /// all other events.
+    ///
+    /// The stream's [event types] must include the event's type, or the
+    /// returned stream never yields anything.
+    ///
+    /// # Examples
+    ///
+    /// Print the content of every new message:
+    ///
+    /// ```no_run
+    /// use futures::StreamExt;
+    /// use std::env;
+    /// use twilight_gateway::{Intents, Shard};
+    /// use twilight_model::gateway::payload::incoming::MessageCreate;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let token = env::var("DISCORD_TOKEN")?;
+    /// let intents = Intents::GUILD_MESSAGES | Intents::MESSAGE_CONTENT;
+    /// let (shard, events) = Shard::new(token, intents).await?;
+    ///
+    /// shard.start().await?;
+    ///
+    /// let mut messages = events.events_of::<MessageCreate>();
+    ///
+    /// while let Some(message) = messages.next().await {
+    ///     println!("{}", message.content);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [event types]: Self::event_types
+    pub fn events_of<T: FromEvent>(self) -> EventsOf<T> {
+        EventsOf {
+            events: self,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl Drop for Events {
+    fn drop(&mut self) {
+        // Close the channel so that the shard stops queueing events, then
+        // discard the events that were never received so the shard's queued
+        // event count drops back to zero.
+        self.rx.close();
+
+        while let Some(Some(_)) = self.rx.recv().now_or_never() {
+            self.queued.fetch_sub(1, Ordering::Relaxed);
+        }
+    }
 }
 
 impl Stream for Events {
     type Item = Event;
 
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        self.rx.poll_recv(cx)
+        self.rx.poll_recv(cx).map(|queued| {
+            let queued = queued?;
+            self.queued.fetch_sub(1, Ordering::Relaxed);
+
+            #[cfg(feature = "tracing-spans")]
+            {
+                self.span = queued.span;
+            }
+
+            Some(queued.event)
+        })
+    }
+}
+
+/// Payload of a single variant of [`Event`].
+///
+/// Used by [`Events::events_of`] to pull the payload out of events.
+pub trait FromEvent: Sized {
+    /// Take the payload out of an event, returning [`None`] if the event is of
+    /// another variant.
+    fn from_event(event: Event) -> Option<Self>;
+}
+
+/// Implement [`FromEvent`] for payloads whose variant shares their name.
+macro_rules! from_event {
+    (boxed: $($variant:ident),* $(,)?) => {
+        $(
+            impl FromEvent for $variant {
+                fn from_event(event: Event) -> Option<Self> {
+                    if let Event::$variant(payload) = event {
+                        Some(*payload)
+                    } else {
+                        None
+                    }
+                }
+            }
+        )*
+    };
+    ($($variant:ident),* $(,)?) => {
+        $(
+            impl FromEvent for $variant {
+                fn from_event(event: Event) -> Option<Self> {
+                    if let Event::$variant(payload) = event {
+                        Some(payload)
+                    } else {
+                        None
+                    }
+                }
+            }
+        )*
+    };
+}
+
+from_event!(
+    boxed: ChannelCreate,
+    ChannelDelete,
+    ChannelUpdate,
+    GuildCreate,
+    GuildScheduledEventCreate,
+    GuildScheduledEventDelete,
+    GuildScheduledEventUpdate,
+    GuildUpdate,
+    IntegrationCreate,
+    IntegrationUpdate,
+    InviteCreate,
+    MemberAdd,
+    MemberUpdate,
+    MessageCreate,
+    MessageUpdate,
+    PresenceUpdate,
+    ReactionAdd,
+    ReactionRemove,
+    Ready,
+    ThreadCreate,
+    ThreadMemberUpdate,
+    ThreadUpdate,
+    TypingStart,
+    VoiceStateUpdate,
+);
+
+from_event!(
+    BanAdd,
+    BanRemove,
+    ChannelPinsUpdate,
+    CommandPermissionsUpdate,
+    GuildDelete,
+    GuildEmojisUpdate,
+    GuildIntegrationsUpdate,
+    GuildScheduledEventUserAdd,
+    GuildScheduledEventUserRemove,
+    GuildStickersUpdate,
+    GuildsReady,
+    IntegrationDelete,
+    InteractionCreate,
+    InviteDelete,
+    MemberChunk,
+    MemberRemove,
+    MessageDelete,
+    MessageDeleteBulk,
+    MessagePollVoteAdd,
+    MessagePollVoteRemove,
+    ReactionRemoveAll,
+    ReactionRemoveEmoji,
+    RoleCreate,
+    RoleDelete,
+    RoleUpdate,
+    StageInstanceCreate,
+    StageInstanceDelete,
+    StageInstanceUpdate,
+    ThreadDelete,
+    ThreadListSync,
+    ThreadMembersUpdate,
+    UnavailableGuild,
+    UserUpdate,
+    VoiceServerUpdate,
+    WebhooksUpdate,
+);
+
+/// A stream of the payloads of a single event from a [`Shard`].
+///
+/// Created via [`Events::events_of`].
+///
+/// [`Shard`]: super::Shard
+#[derive(Debug)]
+pub struct EventsOf<T> {
+    events: Events,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<T> EventsOf<T> {
+    /// Returns the event types that can be passed to the underlying stream.
+    pub const fn event_types(&self) -> EventTypeFlags {
+        self.events.event_types
+    }
+
+    /// Span that was current when the shard emitted the event of the last
+    /// payload yielded by this stream.
+    ///
+    /// Refer to [`Events::span`] for more information.
+    #[cfg(feature = "tracing-spans")]
+    pub fn span(&self) -> tracing::Span {
+        self.events.span()
+    }
+
+    /// Consume the stream, returning the underlying stream of all events.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn into_inner(self) -> Events {
+        self.events
+    }
+}
+
+impl<T: FromEvent> Stream for EventsOf<T> {
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match self.events.poll_next_unpin(cx) {
+                Poll::Ready(Some(event)) => {
+                    if let Some(payload) = T::from_event(event) {
+                        return Poll::Ready(Some(payload));
+                    }
+                }
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+/// Event sent to an [`Events`] stream.
+#[derive(Debug)]
+pub(super) struct QueuedEvent {
+    pub event: Event,
+    /// Span that was current when the event was sent.
+    #[cfg(feature = "tracing-spans")]
+    pub span: tracing::Span,
+}
+
+/// Sender of events to an [`Events`] stream, keeping count of the number of
+/// events queued in the channel.
+#[derive(Clone, Debug)]
+pub(super) struct EventSender {
+    queued: Arc<AtomicUsize>,
+    tx: UnboundedSender<QueuedEvent>,
+}
+
+impl EventSender {
+    pub fn new(tx: UnboundedSender<QueuedEvent>) -> Self {
+        Self {
+            queued: Arc::default(),
+            tx,
+        }
+    }
+
+    /// Counter of the number of events sent but not yet received.
+    pub fn queued(&self) -> Arc<AtomicUsize> {
+        Arc::clone(&self.queued)
+    }
+
+    /// Send an event, ignoring it if the stream has been dropped.
+    pub fn send(&self, event: Event) {
+        // Increment first so that the stream can't receive the event and
+        // decrement the count before it has been incremented.
+        self.queued.fetch_add(1, Ordering::Relaxed);
+
+        let queued = QueuedEvent {
+            event,
+            #[cfg(feature = "tracing-spans")]
+            span: tracing::Span::current(),
+        };
+
+        if self.tx.send(queued).is_err() {
+            self.queued.fetch_sub(1, Ordering::Relaxed);
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Events;
-    use futures_util::stream::Stream;
+    use super::{Connected, EventSender, Events, EventsOf, WaitForReadyErrorType};
+    use crate::EventTypeFlags;
+    use futures_util::stream::{Stream, StreamExt};
     use static_assertions::assert_impl_all;
-    use std::fmt::Debug;
+    use std::{fmt::Debug, sync::atomic::Ordering};
+    use tokio::sync::mpsc;
+    use twilight_model::{
+        channel::message::{Message, MessageType},
+        gateway::{
+            event::{shard::Connecting, Event},
+            payload::incoming::{MessageCreate, MessageDelete, Ready},
+        },
+        id::Id,
+        user::User,
+        util::Timestamp,
+    };
 
     assert_impl_all!(Events: Debug, Send, Stream, Sync);
+    assert_impl_all!(EventsOf<MessageCreate>: Debug, Send, Stream, Sync);
+
+    fn message(id: u64) -> Message {
+        Message {
+            activity: None,
+            application: None,
+            application_id: None,
+            attachments: Vec::new(),
+            author: User {
+                accent_color: None,
+                avatar: None,
+                banner: None,
+                bot: false,
+                discriminator: 1,
+                email: None,
+                flags: None,
+                id: Id::new(3),
+                locale: None,
+                mfa_enabled: None,
+                name: "test".to_owned(),
+                premium_type: None,
+                public_flags: None,
+                system: None,
+                verified: None,
+            },
+            channel_id: Id::new(2),
+            components: Vec::new(),
+            content: "ping".to_owned(),
+            edited_timestamp: None,
+            embeds: Vec::new(),
+            flags: None,
+            guild_id: None,
+            id: Id::new(id),
+            interaction: None,
+            kind: MessageType::Regular,
+            member: None,
+            mention_channels: Vec::new(),
+            mention_everyone: false,
+            mention_roles: Vec::new(),
+            mentions: Vec::new(),
+            nonce: None,
+            pinned: false,
+            poll: None,
+            position: None,
+            reactions: Vec::new(),
+            reference: None,
+            sticker_items: Vec::new(),
+            thread: None,
+            referenced_message: None,
+            timestamp: Timestamp::from_secs(1_632_072_645).expect("non zero"),
+            tts: false,
+            webhook_id: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn wait_for_ready() -> Result<(), serde_json::Error> {
+        let ready = serde_json::from_value::<Ready>(serde_json::json!({
+            "application": {
+                "flags": 0,
+                "id": "1",
+            },
+            "guilds": [],
+            "session_id": "session",
+            "shard": [0, 1],
+            "user": {
+                "avatar": null,
+                "bot": true,
+                "discriminator": "0001",
+                "id": "2",
+                "mfa_enabled": true,
+                "username": "bot",
+            },
+            "v": 10,
+        }))?;
+
+        let (tx, rx) = mpsc::unbounded_channel();
+        let tx = EventSender::new(tx);
+        let mut events = Events::new(EventTypeFlags::all(), rx, tx.queued());
+
+        tx.send(Event::ShardConnecting(Connecting {
+            gateway: "wss://gateway.discord.gg".to_owned(),
+            shard_id: 0,
+        }));
+        tx.send(Event::GatewayHello(41_250));
+        tx.send(Event::Ready(Box::new(ready.clone())));
+
+        assert_eq!(
+            Connected::Ready(Box::new(ready)),
+            events.wait_for_ready().await.unwrap()
+        );
+
+        tx.send(Event::Resumed);
+        assert_eq!(Connected::Resumed, events.wait_for_ready().await.unwrap());
+
+        drop(tx);
+        assert!(matches!(
+            events.wait_for_ready().await.unwrap_err().kind(),
+            WaitForReadyErrorType::Closed
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn wait_for_ready_filtered() {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let tx = EventSender::new(tx);
+        let mut events = Events::new(EventTypeFlags::READY, rx, tx.queued());
+        tx.send(Event::Resumed);
+
+        assert!(matches!(
+            events.wait_for_ready().await.unwrap_err().kind(),
+            WaitForReadyErrorType::EventTypesFiltered
+        ));
+    }
+
+    #[tokio::test]
+    async fn events_of() {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let tx = EventSender::new(tx);
+        let events = Events::new(EventTypeFlags::all(), rx, tx.queued());
+
+        tx.send(Event::GatewayHeartbeatAck);
+        tx.send(Event::MessageCreate(Box::new(MessageCreate(message(4)))));
+        tx.send(Event::MessageDelete(MessageDelete {
+            channel_id: Id::new(2),
+            guild_id: None,
+            id: Id::new(4),
+        }));
+        tx.send(Event::MessageCreate(Box::new(MessageCreate(message(5)))));
+        tx.send(Event::Resumed);
+        drop(tx);
+
+        let messages = events
+            .events_of::<MessageCreate>()
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(
+            vec![MessageCreate(message(4)), MessageCreate(message(5))],
+            messages
+        );
+    }
+
+    #[tokio::test]
+    async fn queued() {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let tx = EventSender::new(tx);
+        let queued = tx.queued();
+        let mut events = Events::new(EventTypeFlags::all(), rx, tx.queued());
+
+        for _ in 0..3 {
+            tx.send(Event::GatewayHeartbeatAck);
+        }
+
+        assert_eq!(3, queued.load(Ordering::Relaxed));
+
+        events.next().await;
+        assert_eq!(2, queued.load(Ordering::Relaxed));
+
+        // Dropping the stream discards its queued events, and events sent
+        // afterwards aren't queued.
+        drop(events);
+        assert_eq!(0, queued.load(Ordering::Relaxed));
+        tx.send(Event::GatewayHeartbeatAck);
+        assert_eq!(0, queued.load(Ordering::Relaxed));
+    }
 }
@@ -5,6 +5,7 @@ use super::{
     emitter::Emitter,
     event::Events,
     json,
+    member_chunks::MemberChunks,
     processor::{ConnectingErrorType, Latency, Session, ShardProcessor},
     raw_message::Message,
     stage::Stage,
@@ -16,16 +17,21 @@ use std::{
     borrow::Cow,
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
-    sync::{atomic::Ordering, Arc, Mutex},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
 };
 use tokio::{
     sync::{watch::Receiver as WatchReceiver, OnceCell},
     task::JoinHandle,
-    time::Instant,
+    time::{self as tokio_time, Instant},
 };
 use tokio_tungstenite::tungstenite::protocol::{
     frame::coding::CloseCode, CloseFrame as TungsteniteCloseFrame,
 };
+use twilight_model::{gateway::payload::outgoing::RequestGuildMembers, guild::Member};
 
 /// Sending a command failed.
 #[derive(Debug)]
@@ -109,6 +115,76 @@ pub enum CommandErrorType {
     SessionInactive,
 }
 
+/// Requesting guild members via [`Shard::request_guild_members`] failed.
+#[derive(Debug)]
+pub struct RequestGuildMembersError {
+    kind: RequestGuildMembersErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl RequestGuildMembersError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &RequestGuildMembersErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        RequestGuildMembersErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for RequestGuildMembersError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            RequestGuildMembersErrorType::Closed => {
+                f.write_str("the shard was shut down before all member chunks were received")
+            }
+            RequestGuildMembersErrorType::Command => {
+                f.write_str("sending the request guild members command failed")
+            }
+            RequestGuildMembersErrorType::TimedOut => {
+                f.write_str("not all member chunks were received before timing out")
+            }
+        }
+    }
+}
+
+impl Error for RequestGuildMembersError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`RequestGuildMembersError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum RequestGuildMembersErrorType {
+    /// Shard was shut down before all member chunks were received.
+    Closed,
+    /// Sending the command failed.
+    ///
+    /// The source is a [`CommandError`].
+    Command,
+    /// Not all member chunks were received before the timeout elapsed.
+    TimedOut,
+}
+
 /// Shard's session is inactive.
 ///
 /// This means that the shard has not yet been started.
@@ -403,14 +479,19 @@ pub struct ResumeSession {
 pub struct Shard {
     config: Arc<Config>,
     emitter: Mutex<Option<Emitter>>,
+    member_chunks: Arc<MemberChunks>,
     processor_handle: OnceCell<JoinHandle<()>>,
+    queued_events: Arc<AtomicUsize>,
     session: OnceCell<WatchReceiver<Arc<Session>>>,
+    token: Arc<Mutex<Box<str>>>,
 }
 
 impl Shard {
     /// Create a new unconfigured shard.
     ///
-    /// Use [`start`] to initiate the gateway session.
+    /// Use [`start`] to initiate the gateway session. The returned stream of
+    /// [`Events`] doesn't need to be polled before the shard is started; events
+    /// are buffered until it is.
     ///
     /// # Examples
     ///
@@ -452,15 +533,21 @@ impl Shard {
         let event_types = config.event_types();
 
         let (emitter, rx) = Emitter::new(event_types);
+        let member_chunks = emitter.member_chunks();
+        let queued_events = emitter.queued_events();
+        let token = Arc::new(Mutex::new(config.token().into()));
 
         let this = Self {
             config,
             emitter: Mutex::new(Some(emitter)),
+            member_chunks,
             processor_handle: OnceCell::new(),
+            queued_events: Arc::clone(&queued_events),
             session: OnceCell::new(),
+            token,
         };
 
-        (this, Events::new(event_types, rx))
+        (this, Events::new(event_types, rx, queued_events))
     }
 
     /// Create a builder to configure and construct a shard.
@@ -478,6 +565,10 @@ impl Shard {
     /// Start the shard, connecting it to the gateway and starting the process
     /// of receiving and processing events.
     ///
+    /// The stream of [`Events`] returned when the shard was created may be
+    /// polled before or after the shard is started. Events received before the
+    /// stream is first polled are buffered, not dropped.
+    ///
     /// The same shard can't be started multiple times. If you stop a shard via
     /// [`shutdown`] or [`shutdown_resumable`] you need to create a new instance
     /// of the shard.
@@ -507,7 +598,7 @@ impl Shard {
             })?;
 
         let config = Arc::clone(&self.config);
-        let (processor, wrx) = ShardProcessor::new(config, emitter)
+        let (processor, wrx) = ShardProcessor::new(config, emitter, Arc::clone(&self.token))
             .await
             .map_err(|source| {
                 let (kind, source) = source.into_parts();
@@ -538,6 +629,36 @@ impl Shard {
         Ok(())
     }
 
+    /// Set the token used to authenticate with the gateway.
+    ///
+    /// The token takes effect when the shard next identifies or resumes, such
+    /// as when it reconnects. The current connection is not affected. The
+    /// shard's [`config`] continues to return the token the shard was created
+    /// with.
+    ///
+    /// The token is prefixed with "Bot ", if it isn't already.
+    ///
+    /// [`config`]: Self::config
+    pub fn set_token(&self, mut token: String) {
+        if !token.starts_with("Bot ") {
+            token.insert_str(0, "Bot ");
+        }
+
+        *self.token.lock().expect("token poisoned") = token.into_boxed_str();
+    }
+
+    /// Number of events emitted by the shard that the [`Events`] stream has yet
+    /// to receive.
+    ///
+    /// The event channel is unbounded, so a count that keeps growing indicates
+    /// that the consumer of the stream can't keep up with the shard.
+    ///
+    /// Dropping the stream discards its queued events and resets the count to
+    /// zero, which it remains at since the shard stops emitting events.
+    pub fn queued_events(&self) -> usize {
+        self.queued_events.load(Ordering::Relaxed)
+    }
+
     /// Retrieve information about the running of the shard, such as the current
     /// connection stage.
     ///
@@ -631,6 +752,84 @@ impl Shard {
             .map_err(CommandError::from_send)
     }
 
+    /// Request members of a guild, returning the combined members of all
+    /// chunks Discord responds with.
+    ///
+    /// Discord responds to a [`RequestGuildMembers`] command with one or more
+    /// [`MemberChunk`] events. The request's nonce is overwritten with a
+    /// generated one to match these chunks to the request. Chunks are still
+    /// emitted to the shard's [`Events`] stream if it subscribed to them.
+    ///
+    /// # Examples
+    ///
+    /// Request all members of a guild, waiting at most 10 seconds:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::{env, time::Duration};
+    /// use twilight_gateway::{Intents, Shard};
+    /// use twilight_model::{gateway::payload::outgoing::RequestGuildMembers, id::Id};
+    ///
+    /// let token = env::var("DISCORD_TOKEN")?;
+    /// let (shard, _events) = Shard::new(token, Intents::GUILD_MEMBERS).await?;
+    /// shard.start().await?;
+    ///
+    /// let request = RequestGuildMembers::builder(Id::new(1)).query("", None);
+    /// let members = shard
+    ///     .request_guild_members(request, Duration::from_secs(10))
+    ///     .await?;
+    ///
+    /// println!("guild has {} members", members.len());
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RequestGuildMembersErrorType::Closed`] error type if the
+    /// shard was shut down before all chunks were received.
+    ///
+    /// Returns a [`RequestGuildMembersErrorType::Command`] error type if the
+    /// command could not be sent. See [`command`] for details.
+    ///
+    /// Returns a [`RequestGuildMembersErrorType::TimedOut`] error type if not
+    /// all chunks were received within the timeout.
+    ///
+    /// [`MemberChunk`]: twilight_model::gateway::payload::incoming::MemberChunk
+    /// [`command`]: Self::command
+    pub async fn request_guild_members(
+        &self,
+        mut request: RequestGuildMembers,
+        timeout: Duration,
+    ) -> Result<Vec<Member>, RequestGuildMembersError> {
+        let (nonce, rx) = self.member_chunks.register();
+        request.d.nonce = Some(nonce.clone());
+
+        if let Err(source) = self.command(&request).await {
+            self.member_chunks.remove(&nonce);
+
+            return Err(RequestGuildMembersError {
+                kind: RequestGuildMembersErrorType::Command,
+                source: Some(Box::new(source)),
+            });
+        }
+
+        match tokio_time::timeout(timeout, rx).await {
+            Ok(Ok(members)) => Ok(members),
+            Ok(Err(source)) => Err(RequestGuildMembersError {
+                kind: RequestGuildMembersErrorType::Closed,
+                source: Some(Box::new(source)),
+            }),
+            Err(source) => {
+                self.member_chunks.remove(&nonce);
+
+                Err(RequestGuildMembersError {
+                    kind: RequestGuildMembersErrorType::TimedOut,
+                    source: Some(Box::new(source)),
+                })
+            }
+        }
+    }
+
     /// Send a raw websocket message.
     ///
     /// # Examples
@@ -722,11 +921,17 @@ impl Shard {
     /// The shard will cleanly close the connection by sending a normal close
     /// code, causing Discord to show the bot as being offline. The session will
     /// not be resumable.
+    ///
+    /// Pending [guild member requests] fail.
+    ///
+    /// [guild member requests]: Self::request_guild_members
     pub fn shutdown(&self) {
         if let Some(processor_handle) = self.processor_handle.get() {
             processor_handle.abort();
         }
 
+        self.member_chunks.clear();
+
         if let Ok(session) = self.session() {
             // Since we're shutting down now, we don't care if it sends or not.
             let _res = session.close(Some(TungsteniteCloseFrame {
@@ -742,14 +947,18 @@ impl Shard {
     /// The shard will cleanly close the connection by sending a restart close
     /// code, causing Discord to keep the bot as showing online. The connection
     /// will be resumable by using the provided session resume information
-    /// to [`ClusterBuilder::resume_sessions`].
+    /// to [`ClusterBuilder::resume_sessions`]. Pending [guild member requests]
+    /// fail.
     ///
     /// [`ClusterBuilder::resume_sessions`]: crate::cluster::ClusterBuilder::resume_sessions
+    /// [guild member requests]: Self::request_guild_members
     pub fn shutdown_resumable(&self) -> (u64, Option<ResumeSession>) {
         if let Some(processor_handle) = self.processor_handle.get() {
             processor_handle.abort();
         }
 
+        self.member_chunks.clear();
+
         let shard_id = self.config().shard()[0];
 
         let session = if let Ok(session) = self.session() {
@@ -791,15 +1000,42 @@ impl Shard {
 #[cfg(test)]
 mod tests {
     use super::{
-        CommandError, CommandErrorType, Information, ResumeSession, SendError, SendErrorType,
+        CommandError, CommandErrorType, Information, RequestGuildMembersError,
+        RequestGuildMembersErrorType, ResumeSession, SendError, SendErrorType,
         SessionInactiveError, Shard, ShardStartError, ShardStartErrorType,
     };
+    use crate::{
+        shard::{processor::identify_payload, ShardBuilder},
+        Intents,
+    };
+    use futures_util::{FutureExt, SinkExt, StreamExt};
     use static_assertions::{assert_fields, assert_impl_all};
-    use std::{error::Error, fmt::Debug};
+    use std::{
+        error::Error,
+        fmt::Debug,
+        net::SocketAddr,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        time::Duration,
+    };
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::tungstenite::{
+        protocol::{frame::coding::CloseCode, CloseFrame},
+        Message,
+    };
+    use twilight_gateway_queue::NoOpQueue;
+    use twilight_model::{
+        gateway::{event::Event, payload::outgoing::RequestGuildMembers},
+        id::Id,
+    };
 
     assert_impl_all!(CommandErrorType: Debug, Send, Sync);
     assert_impl_all!(CommandError: Error, Send, Sync);
     assert_impl_all!(Information: Clone, Debug, Send, Sync);
+    assert_impl_all!(RequestGuildMembersErrorType: Debug, Send, Sync);
+    assert_impl_all!(RequestGuildMembersError: Error, Send, Sync);
     assert_impl_all!(ResumeSession: Clone, Debug, Send, Sync);
     assert_impl_all!(SendErrorType: Debug, Send, Sync);
     assert_impl_all!(SendError: Error, Send, Sync);
@@ -808,4 +1044,524 @@ mod tests {
     assert_impl_all!(ShardStartErrorType: Debug, Send, Sync);
     assert_impl_all!(ShardStartError: Error, Send, Sync);
     assert_impl_all!(Shard: Debug, Send, Sync);
+
+    #[tokio::test]
+    async fn set_token() {
+        let config = ShardBuilder::new("old".to_owned(), Intents::empty())
+            .gateway_url("wss://gateway.discord.gg".to_owned())
+            .into_config();
+        let (shard, _events) = Shard::new_with_config(config);
+
+        shard.set_token("new".to_owned());
+
+        let token = shard.token.lock().unwrap().to_string();
+        let identify = identify_payload(shard.config(), token);
+
+        assert_eq!("Bot new", identify.d.token);
+        assert_eq!("Bot old", shard.config().token());
+    }
+
+    #[tokio::test]
+    async fn queued_events() {
+        let config = ShardBuilder::new("token".to_owned(), Intents::empty())
+            .gateway_url("wss://gateway.discord.gg".to_owned())
+            .into_config();
+        let (shard, mut events) = Shard::new_with_config(config);
+        assert_eq!(0, shard.queued_events());
+
+        {
+            let emitter = shard.emitter.lock().unwrap();
+            let emitter = emitter.as_ref().unwrap();
+
+            for _ in 0..3 {
+                emitter.event(Event::GatewayHeartbeatAck);
+            }
+        }
+
+        assert_eq!(3, shard.queued_events());
+
+        events.next().await;
+        assert_eq!(2, shard.queued_events());
+    }
+
+    /// Encodes payloads the way the gateway does for the enabled compression
+    /// feature.
+    struct Encoder {
+        #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+        compress: flate2::Compress,
+    }
+
+    impl Encoder {
+        fn new() -> Self {
+            Self {
+                #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+                compress: flate2::Compress::new(flate2::Compression::default(), true),
+            }
+        }
+
+        fn encode(&mut self, json: &str) -> Message {
+            #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+            {
+                // Pad the payload so that it compresses to fewer bytes than
+                // it decompresses to, like real payloads do.
+                let padded = format!("{json}{:64}", "");
+                let mut output = Vec::with_capacity(padded.len());
+                self.compress
+                    .compress_vec(padded.as_bytes(), &mut output, flate2::FlushCompress::Sync)
+                    .unwrap();
+
+                Message::Binary(output)
+            }
+
+            #[cfg(not(any(feature = "zlib-stock", feature = "zlib-simd")))]
+            Message::Text(json.to_owned())
+        }
+    }
+
+    /// Start a minimal gateway that says hello and sends a ready once
+    /// identified, returning its address.
+    ///
+    /// If a close code is provided then the connection is closed with it after
+    /// sending the ready.
+    async fn mock_gateway(close_code: Option<u16>) -> Result<SocketAddr, Box<dyn Error>> {
+        const HELLO: &str = r#"{"op":10,"d":{"heartbeat_interval":41250}}"#;
+        const READY: &str = r#"{"op":0,"s":1,"t":"READY","d":{
+            "application":{"flags":0,"id":"1"},
+            "guilds":[],
+            "session_id":"session",
+            "shard":[0,1],
+            "user":{"avatar":null,"bot":true,"discriminator":"0001","id":"2","mfa_enabled":true,"username":"bot"},
+            "v":10
+        }}"#;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut socket = tokio_tungstenite::accept_async(stream).await.unwrap();
+            let mut encoder = Encoder::new();
+            socket.send(encoder.encode(HELLO)).await.unwrap();
+
+            while let Some(Ok(message)) = socket.next().await {
+                if message.to_text().unwrap_or_default().contains(r#""op":2"#) {
+                    socket.send(encoder.encode(READY)).await.unwrap();
+
+                    if let Some(code) = close_code {
+                        let frame = CloseFrame {
+                            code: CloseCode::from(code),
+                            reason: "".into(),
+                        };
+                        socket.send(Message::Close(Some(frame))).await.unwrap();
+                    }
+                }
+            }
+        });
+
+        Ok(addr)
+    }
+
+    /// Events sent between starting the shard and first polling the stream are
+    /// buffered, not dropped.
+    #[tokio::test]
+    async fn events_buffered_until_polled() -> Result<(), Box<dyn Error>> {
+        let addr = mock_gateway(None).await?;
+
+        let config = ShardBuilder::new("token".to_owned(), Intents::empty())
+            .gateway_url(format!("ws://{addr}"))
+            .queue(Arc::new(NoOpQueue))
+            .into_config();
+        let (shard, mut events) = Shard::new_with_config(config);
+        shard.start().await?;
+
+        // Don't consume the stream until the shard has received everything.
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while shard.queued_events() < 5 {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await?;
+
+        assert!(matches!(
+            events.next().await,
+            Some(Event::ShardConnecting(_))
+        ));
+        assert!(matches!(
+            events.next().await,
+            Some(Event::ShardIdentifying(_))
+        ));
+        assert!(matches!(
+            events.next().await,
+            Some(Event::GatewayHello(41_250))
+        ));
+        assert!(matches!(
+            events.next().await,
+            Some(Event::ShardConnected(_))
+        ));
+        assert!(matches!(events.next().await, Some(Event::Ready(_))));
+
+        shard.shutdown();
+
+        Ok(())
+    }
+
+    /// Handling an event inline, without polling the stream for a while,
+    /// doesn't stall heartbeats, which are sent from their own task.
+    #[tokio::test]
+    async fn heartbeats_during_inline_handling() -> Result<(), Box<dyn Error>> {
+        // Shortest interval that leaves the command ratelimiter room for
+        // identifying.
+        const HELLO: &str = r#"{"op":10,"d":{"heartbeat_interval":1100}}"#;
+        const HEARTBEAT_ACK: &str = r#"{"op":11}"#;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        let heartbeats = Arc::new(AtomicUsize::new(0));
+
+        // Gateway acknowledging every heartbeat it receives.
+        tokio::spawn({
+            let heartbeats = Arc::clone(&heartbeats);
+
+            async move {
+                let (stream, _) = listener.accept().await.unwrap();
+                let mut socket = tokio_tungstenite::accept_async(stream).await.unwrap();
+                let mut encoder = Encoder::new();
+                socket.send(encoder.encode(HELLO)).await.unwrap();
+
+                while let Some(Ok(message)) = socket.next().await {
+                    if message
+                        .to_text()
+                        .unwrap_or_default()
+                        .ends_with(r#""op":1}"#)
+                    {
+                        heartbeats.fetch_add(1, Ordering::Relaxed);
+                        socket.send(encoder.encode(HEARTBEAT_ACK)).await.unwrap();
+                    }
+                }
+            }
+        });
+
+        let config = ShardBuilder::new("token".to_owned(), Intents::empty())
+            .gateway_url(format!("ws://{addr}"))
+            .queue(Arc::new(NoOpQueue))
+            .into_config();
+        let (shard, mut events) = Shard::new_with_config(config);
+        shard.start().await?;
+
+        // Handle the first event slowly, spanning many heartbeat intervals,
+        // without polling the stream in the meantime.
+        assert!(events.next().await.is_some());
+        tokio::time::sleep(Duration::from_secs(4)).await;
+
+        let sent = heartbeats.load(Ordering::Relaxed);
+        assert!(sent >= 3, "only {sent} heartbeats were sent");
+        assert!(shard.queued_events() > 0);
+
+        // The connection was never aborted over missed heartbeats.
+        while let Some(Some(event)) = events.next().now_or_never() {
+            assert!(
+                !matches!(
+                    event,
+                    Event::ShardDisconnected(_) | Event::ShardReconnecting(_)
+                ),
+                "{event:?}"
+            );
+        }
+
+        shard.shutdown();
+
+        Ok(())
+    }
+
+    /// Being ratelimited announces the delay before resuming instead of
+    /// silently waiting.
+    #[tokio::test]
+    async fn ratelimited_resume_delay() -> Result<(), Box<dyn Error>> {
+        let addr = mock_gateway(Some(4008)).await?;
+
+        let config = ShardBuilder::new("token".to_owned(), Intents::empty())
+            .gateway_url(format!("ws://{addr}"))
+            .queue(Arc::new(NoOpQueue))
+            .into_config();
+        let (shard, mut events) = Shard::new_with_config(config);
+        shard.start().await?;
+
+        let resuming = tokio::time::timeout(Duration::from_secs(5), async {
+            loop {
+                if let Some(Event::ShardResuming(resuming)) = events.next().await {
+                    return resuming;
+                }
+            }
+        })
+        .await?;
+
+        assert_eq!(Some(60), resuming.delay);
+        assert_eq!(0, resuming.shard_id);
+
+        shard.shutdown();
+
+        Ok(())
+    }
+
+    /// Being ratelimited before receiving a ready, so without a session to
+    /// resume, waits the requested delay before reconnecting.
+    #[tokio::test]
+    async fn ratelimited_reconnect_delay() -> Result<(), Box<dyn Error>> {
+        const HELLO: &str = r#"{"op":10,"d":{"heartbeat_interval":41250}}"#;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+
+        // Gateway closing the connection as ratelimited when identified.
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut socket = tokio_tungstenite::accept_async(stream).await.unwrap();
+            socket.send(Encoder::new().encode(HELLO)).await.unwrap();
+
+            while let Some(Ok(message)) = socket.next().await {
+                if message.to_text().unwrap_or_default().contains(r#""op":2"#) {
+                    let frame = CloseFrame {
+                        code: CloseCode::from(4008),
+                        reason: "".into(),
+                    };
+                    socket.send(Message::Close(Some(frame))).await.unwrap();
+                }
+            }
+        });
+
+        let config = ShardBuilder::new("token".to_owned(), Intents::empty())
+            .gateway_url(format!("ws://{addr}"))
+            .queue(Arc::new(NoOpQueue))
+            .into_config();
+        let (shard, mut events) = Shard::new_with_config(config);
+        shard.start().await?;
+
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while !matches!(
+                events.next().await,
+                Some(Event::ShardDisconnected(_)) | None
+            ) {}
+        })
+        .await?;
+
+        // Skip ahead through the delay rather than waiting it out. The
+        // processor may have started waiting just before time was paused.
+        tokio::time::pause();
+        let disconnected = tokio::time::Instant::now();
+
+        loop {
+            if let Some(Event::ShardReconnecting(_)) = events.next().await {
+                break;
+            }
+        }
+
+        assert!(disconnected.elapsed() >= Duration::from_secs(59));
+
+        shard.shutdown();
+
+        Ok(())
+    }
+
+    /// Guild member requests time out if not all chunks are received, and fail
+    /// if the shard shuts down first.
+    #[tokio::test]
+    async fn request_guild_members() -> Result<(), Box<dyn Error>> {
+        let addr = mock_gateway(None).await?;
+
+        let config = ShardBuilder::new("token".to_owned(), Intents::GUILD_MEMBERS)
+            .gateway_url(format!("ws://{addr}"))
+            .queue(Arc::new(NoOpQueue))
+            .into_config();
+        let (shard, mut events) = Shard::new_with_config(config);
+        shard.start().await?;
+
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while !matches!(events.next().await, Some(Event::Ready(_)) | None) {}
+        })
+        .await?;
+
+        let request = || RequestGuildMembers::builder(Id::new(1)).query("", None);
+        let error = shard
+            .request_guild_members(request(), Duration::from_millis(10))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            RequestGuildMembersErrorType::TimedOut
+        ));
+        assert!(!shard.member_chunks.is_active());
+
+        let shard = Arc::new(shard);
+        let pending = tokio::spawn({
+            let shard = Arc::clone(&shard);
+
+            async move {
+                shard
+                    .request_guild_members(request(), Duration::from_secs(60))
+                    .await
+            }
+        });
+
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while !shard.member_chunks.is_active() {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await?;
+        shard.shutdown();
+
+        let error = pending.await?.unwrap_err();
+        assert!(matches!(error.kind(), RequestGuildMembersErrorType::Closed));
+
+        Ok(())
+    }
+
+    /// Records the name and fields of created spans.
+    #[cfg(feature = "tracing-spans")]
+    #[derive(Clone, Default)]
+    struct SpanRecorder {
+        /// Spans that have been entered but not yet exited.
+        entered: Arc<std::sync::Mutex<Vec<tracing::span::Id>>>,
+        spans: Arc<std::sync::Mutex<Vec<RecordedSpan>>>,
+    }
+
+    #[cfg(feature = "tracing-spans")]
+    struct RecordedSpan {
+        fields: std::collections::HashMap<&'static str, String>,
+        metadata: &'static tracing::Metadata<'static>,
+        parent: Option<tracing::span::Id>,
+    }
+
+    #[cfg(feature = "tracing-spans")]
+    impl RecordedSpan {
+        fn field(&self, name: &str) -> Option<&str> {
+            self.fields.get(name).map(String::as_str)
+        }
+    }
+
+    #[cfg(feature = "tracing-spans")]
+    impl tracing::field::Visit for RecordedSpan {
+        fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+            self.fields.insert(field.name(), value.to_owned());
+        }
+
+        fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn Debug) {
+            self.fields.insert(field.name(), format!("{value:?}"));
+        }
+    }
+
+    #[cfg(feature = "tracing-spans")]
+    fn span_index(span: &tracing::span::Id) -> usize {
+        usize::try_from(span.into_u64()).unwrap() - 1
+    }
+
+    #[cfg(feature = "tracing-spans")]
+    impl tracing::Subscriber for SpanRecorder {
+        fn enabled(&self, _: &tracing::Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+            let parent = if span.is_contextual() {
+                self.entered.lock().unwrap().last().cloned()
+            } else {
+                span.parent().cloned()
+            };
+            let mut recorded = RecordedSpan {
+                fields: std::collections::HashMap::new(),
+                metadata: span.metadata(),
+                parent,
+            };
+            span.record(&mut recorded);
+
+            let mut spans = self.spans.lock().unwrap();
+            spans.push(recorded);
+
+            tracing::span::Id::from_u64(spans.len() as u64)
+        }
+
+        fn record(&self, span: &tracing::span::Id, values: &tracing::span::Record<'_>) {
+            values.record(&mut self.spans.lock().unwrap()[span_index(span)]);
+        }
+
+        fn record_follows_from(&self, _: &tracing::span::Id, _: &tracing::span::Id) {}
+
+        fn event(&self, _: &tracing::Event<'_>) {}
+
+        fn enter(&self, span: &tracing::span::Id) {
+            self.entered.lock().unwrap().push(span.clone());
+        }
+
+        fn exit(&self, _: &tracing::span::Id) {
+            self.entered.lock().unwrap().pop();
+        }
+
+        fn current_span(&self) -> tracing_core::span::Current {
+            match self.entered.lock().unwrap().last() {
+                Some(span) => {
+                    let metadata = self.spans.lock().unwrap()[span_index(span)].metadata;
+
+                    tracing_core::span::Current::new(span.clone(), metadata)
+                }
+                None => tracing_core::span::Current::none(),
+            }
+        }
+    }
+
+    /// Received payloads are processed within a span carrying the shard and
+    /// the payload's opcode and event type, which is handed to the consumer
+    /// along with the events emitted while processing the payload.
+    #[cfg(feature = "tracing-spans")]
+    #[tokio::test]
+    async fn event_spans() -> Result<(), Box<dyn Error>> {
+        let recorder = SpanRecorder::default();
+        let _guard = tracing::subscriber::set_default(recorder.clone());
+
+        let addr = mock_gateway(None).await?;
+        let config = ShardBuilder::new("token".to_owned(), Intents::empty())
+            .gateway_url(format!("ws://{addr}"))
+            .queue(Arc::new(NoOpQueue))
+            .into_config();
+        let (shard, mut events) = Shard::new_with_config(config);
+        shard.start().await?;
+
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while !matches!(events.next().await, Some(Event::Ready(_)) | None) {}
+        })
+        .await?;
+        let ready_span = events.span().id().expect("ready event span");
+
+        shard.shutdown();
+
+        let spans = recorder.spans.lock().unwrap();
+        let span = |op: &str| {
+            spans
+                .iter()
+                .find(|span| {
+                    span.metadata.name() == "gateway_event" && span.field("op") == Some(op)
+                })
+                .expect("span for opcode")
+        };
+
+        let hello = span("10");
+        assert_eq!(Some("0"), hello.field("shard_id"));
+        assert_eq!(Some("1"), hello.field("shard_total"));
+        assert_eq!(None, hello.field("event_type"));
+
+        let ready = span("0");
+        assert_eq!(Some("0"), ready.field("shard_id"));
+        assert_eq!(Some("1"), ready.field("shard_total"));
+        assert_eq!(Some("READY"), ready.field("event_type"));
+
+        // The ready event's span is the ready payload's span, or within it.
+        let mut span = &spans[span_index(&ready_span)];
+
+        while span.metadata.name() != "gateway_event" {
+            span = &spans[span_index(span.parent.as_ref().expect("parent span"))];
+        }
+
+        assert!(std::ptr::eq(ready, span));
+
+        Ok(())
+    }
 }
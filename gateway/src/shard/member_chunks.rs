@@ -0,0 +1,203 @@
+//! Collect the member chunks of guild member requests made via
+//! [`Shard::request_guild_members`].
+//!
+//! [`Shard::request_guild_members`]: super::Shard::request_guild_members
+
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+};
+use tokio::sync::oneshot::{self, Receiver, Sender};
+use twilight_model::{gateway::payload::incoming::MemberChunk, guild::Member};
+
+/// Members received so far for a request.
+#[derive(Debug)]
+struct Pending {
+    /// Number of chunks received.
+    chunks_received: u32,
+    /// Members of the received chunks.
+    members: Vec<Member>,
+    /// Sender to notify once all chunks have been received.
+    tx: Sender<Vec<Member>>,
+}
+
+/// Pending guild member requests, keyed by their nonce.
+#[derive(Debug, Default)]
+pub struct MemberChunks {
+    /// Whether there are any pending requests.
+    ///
+    /// Allows the emitter to cheaply check whether it needs to deserialize
+    /// member chunks that the listener didn't subscribe to.
+    active: AtomicBool,
+    pending: Mutex<HashMap<String, Pending>>,
+}
+
+impl MemberChunks {
+    /// Whether there are any pending requests.
+    pub fn is_active(&self) -> bool {
+        self.active.load(Ordering::Relaxed)
+    }
+
+    /// Register a new request, returning its generated nonce and a receiver
+    /// resolving to the combined members of all of its chunks.
+    pub fn register(&self) -> (String, Receiver<Vec<Member>>) {
+        let (tx, rx) = oneshot::channel();
+        let mut pending = self.pending.lock().expect("member chunks poisoned");
+
+        let nonce = loop {
+            let nonce = format!("{:016x}", rand::random::<u64>());
+
+            if !pending.contains_key(&nonce) {
+                break nonce;
+            }
+        };
+
+        pending.insert(
+            nonce.clone(),
+            Pending {
+                chunks_received: 0,
+                members: Vec::new(),
+                tx,
+            },
+        );
+        self.active.store(true, Ordering::Relaxed);
+
+        (nonce, rx)
+    }
+
+    /// Remove all requests, such as when the shard shuts down.
+    ///
+    /// The receivers of the requests resolve with an error.
+    pub fn clear(&self) {
+        self.pending.lock().expect("member chunks poisoned").clear();
+        self.active.store(false, Ordering::Relaxed);
+    }
+
+    /// Remove a request, such as one that timed out.
+    pub fn remove(&self, nonce: &str) {
+        let mut pending = self.pending.lock().expect("member chunks poisoned");
+        pending.remove(nonce);
+        self.active.store(!pending.is_empty(), Ordering::Relaxed);
+    }
+
+    /// Add a received chunk to its request, completing the request if it was
+    /// the last chunk.
+    ///
+    /// Chunks without a nonce or with a nonce of another request are ignored.
+    pub fn receive(&self, chunk: &MemberChunk) {
+        let nonce = match chunk.nonce.as_deref() {
+            Some(nonce) => nonce,
+            None => return,
+        };
+
+        let mut pending = self.pending.lock().expect("member chunks poisoned");
+
+        let request = match pending.get_mut(nonce) {
+            Some(request) => request,
+            None => return,
+        };
+
+        request.chunks_received += 1;
+        request.members.extend(chunk.members.iter().cloned());
+
+        if request.chunks_received >= chunk.chunk_count {
+            if let Some(request) = pending.remove(nonce) {
+                // The receiver may have been dropped by a timed out request.
+                let _res = request.tx.send(request.members);
+            }
+
+            self.active.store(!pending.is_empty(), Ordering::Relaxed);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MemberChunks;
+    use std::str::FromStr;
+    use twilight_model::{
+        gateway::payload::incoming::MemberChunk, guild::Member, id::Id, user::User, util::Timestamp,
+    };
+
+    fn member(id: u64) -> Member {
+        Member {
+            avatar: None,
+            communication_disabled_until: None,
+            deaf: false,
+            guild_id: Id::new(1),
+            joined_at: Timestamp::from_str("2021-08-03T16:08:36.325000+00:00")
+                .expect("valid timestamp"),
+            mute: false,
+            nick: None,
+            pending: false,
+            premium_since: None,
+            roles: Vec::new(),
+            user: User {
+                accent_color: None,
+                avatar: None,
+                banner: None,
+                bot: false,
+                discriminator: 1,
+                email: None,
+                flags: None,
+                id: Id::new(id),
+                locale: None,
+                mfa_enabled: None,
+                name: "test".to_owned(),
+                premium_type: None,
+                public_flags: None,
+                system: None,
+                verified: None,
+            },
+        }
+    }
+
+    fn chunk(nonce: &str, chunk_index: u32, members: Vec<Member>) -> MemberChunk {
+        MemberChunk {
+            chunk_count: 2,
+            chunk_index,
+            guild_id: Id::new(1),
+            members,
+            nonce: Some(nonce.to_owned()),
+            not_found: Vec::new(),
+            presences: Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn two_chunks_complete() {
+        let chunks = MemberChunks::default();
+        let (nonce, mut rx) = chunks.register();
+        assert!(chunks.is_active());
+
+        chunks.receive(&chunk(&nonce, 0, vec![member(2), member(3)]));
+        assert!(rx.try_recv().is_err());
+
+        // Chunks of other requests don't count towards this one.
+        chunks.receive(&chunk("other", 1, vec![member(4)]));
+        assert!(rx.try_recv().is_err());
+
+        chunks.receive(&chunk(&nonce, 1, vec![member(5)]));
+        let ids = rx
+            .await
+            .expect("all chunks received")
+            .iter()
+            .map(|member| member.user.id.get())
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![2, 3, 5], ids);
+        assert!(!chunks.is_active());
+    }
+
+    #[test]
+    fn remove() {
+        let chunks = MemberChunks::default();
+        let (nonce, _rx) = chunks.register();
+        chunks.remove(&nonce);
+
+        assert!(!chunks.is_active());
+    }
+}
@@ -12,6 +12,45 @@
 //! Shards are configurable through the [`ShardBuilder`], which provides a clean
 //! interface for correctly configuring a shard.
 //!
+//! # Processing Events
+//!
+//! Events don't need to be handled in spawned tasks. The shard's connection
+//! and heartbeats are maintained on tasks of their own and events are queued
+//! in the unbounded [`Events`] stream until they're received, so awaiting
+//! each event's handler inline doesn't stall the connection. This processes
+//! events in the order they were received, at the cost of a slow handler
+//! delaying the handling of later events:
+//!
+//! ```no_run
+//! # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use futures_util::StreamExt;
+//! use std::env;
+//! use twilight_gateway::{Event, Intents, Shard};
+//!
+//! let intents = Intents::GUILD_MESSAGES | Intents::MESSAGE_CONTENT;
+//! let token = env::var("DISCORD_TOKEN")?;
+//!
+//! let (shard, mut events) = Shard::new(token, intents).await?;
+//! shard.start().await?;
+//!
+//! while let Some(event) = events.next().await {
+//!     handle_event(event).await;
+//! }
+//!
+//! async fn handle_event(event: Event) {
+//!     if let Event::MessageCreate(message) = event {
+//!         println!("Message: {}", message.content);
+//!     }
+//! }
+//! # Ok(()) }
+//! ```
+//!
+//! Spawn a task per event instead when handlers shouldn't delay each other,
+//! such as when one needs to wait on an HTTP request.
+//!
+//! [`Shard::queued_events`] can be used to monitor how far behind the
+//! handling of events is.
+//!
 //! # Member Chunking
 //!
 //! Requesting chunks of a guild's members may be done via [`Shard::command`]
@@ -62,6 +101,8 @@ mod emitter;
 mod event;
 mod r#impl;
 mod json;
+mod member_chunks;
+mod pending_guilds;
 mod processor;
 #[cfg(any(
     feature = "native",
@@ -74,10 +115,11 @@ pub use self::{
     builder::{ShardBuilder, ShardIdError, ShardIdErrorType},
     command::Command,
     config::Config,
-    event::Events,
+    event::{Connected, Events, EventsOf, FromEvent, WaitForReadyError, WaitForReadyErrorType},
     processor::heartbeat::Latency,
     r#impl::{
-        CommandError, CommandErrorType, Information, ResumeSession, SendError, SendErrorType,
+        CommandError, CommandErrorType, Information, RequestGuildMembersError,
+        RequestGuildMembersErrorType, ResumeSession, SendError, SendErrorType,
         SessionInactiveError, Shard, ShardStartError, ShardStartErrorType,
     },
     stage::Stage,
@@ -0,0 +1,282 @@
+//! Track the guilds of a [`Ready`] payload that have yet to be received, in
+//! order to emit [`Event::GuildsReady`] once all of them have been.
+//!
+//! [`Ready`]: twilight_model::gateway::payload::incoming::Ready
+
+use super::event::EventSender;
+use crate::Event;
+use std::{
+    collections::HashSet,
+    mem,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+use tokio::{task::JoinHandle, time};
+use twilight_model::{
+    gateway::event::shard::GuildsReady,
+    id::{marker::GuildMarker, Id},
+};
+
+/// Duration to wait for the guilds of a [`Ready`] payload before emitting
+/// [`Event::GuildsReady`] with the guilds that haven't been received.
+///
+/// [`Ready`]: twilight_model::gateway::payload::incoming::Ready
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Guilds that have yet to be received.
+#[derive(Debug, Default)]
+struct State {
+    /// Incremented for every [`Ready`] payload so that a timeout of a
+    /// previous session doesn't apply to the current one.
+    ///
+    /// [`Ready`]: twilight_model::gateway::payload::incoming::Ready
+    generation: u64,
+    /// Guilds that have yet to be received.
+    guilds: HashSet<Id<GuildMarker>>,
+    shard_id: u64,
+    /// Handle of the task emitting the event once timed out.
+    timeout: Option<JoinHandle<()>>,
+    /// Guilds that were received as unavailable.
+    unavailable: Vec<Id<GuildMarker>>,
+}
+
+impl State {
+    /// Take the current guilds into an event, aborting the timeout.
+    fn finish(&mut self) -> GuildsReady {
+        if let Some(handle) = self.timeout.take() {
+            handle.abort();
+        }
+
+        let mut unavailable = mem::take(&mut self.unavailable);
+        unavailable.extend(self.guilds.drain());
+
+        GuildsReady {
+            shard_id: self.shard_id,
+            unavailable,
+        }
+    }
+}
+
+/// Guilds of the latest [`Ready`] payload that have yet to be received.
+///
+/// [`Ready`]: twilight_model::gateway::payload::incoming::Ready
+#[derive(Debug)]
+pub struct PendingGuilds {
+    /// Whether there are any guilds that have yet to be received.
+    ///
+    /// Allows the emitter to cheaply check whether it needs to deserialize
+    /// guild events that the listener didn't subscribe to.
+    active: AtomicBool,
+    state: Mutex<State>,
+    timeout: Duration,
+}
+
+impl PendingGuilds {
+    /// Create a new tracker emitting the event after a custom timeout.
+    pub fn with_timeout(timeout: Duration) -> Self {
+        Self {
+            active: AtomicBool::new(false),
+            state: Mutex::default(),
+            timeout,
+        }
+    }
+
+    /// Whether there are any guilds that have yet to be received.
+    pub fn is_active(&self) -> bool {
+        self.active.load(Ordering::Relaxed)
+    }
+
+    /// Start waiting for the guilds of a new [`Ready`] payload, replacing the
+    /// guilds of any previous payload.
+    ///
+    /// The event is sent immediately if there are no guilds, or once the
+    /// [timeout] has elapsed if not all guilds have been received by then.
+    ///
+    /// [`Ready`]: twilight_model::gateway::payload::incoming::Ready
+    /// [timeout]: TIMEOUT
+    pub fn wait(
+        self: &Arc<Self>,
+        shard_id: u64,
+        guilds: HashSet<Id<GuildMarker>>,
+        tx: EventSender,
+    ) {
+        let mut state = self.state.lock().expect("pending guilds poisoned");
+
+        if let Some(handle) = state.timeout.take() {
+            handle.abort();
+        }
+
+        state.generation = state.generation.wrapping_add(1);
+        state.guilds = guilds;
+        state.shard_id = shard_id;
+        state.unavailable.clear();
+
+        if state.guilds.is_empty() {
+            // A previous wait may have been interrupted by this ready.
+            self.active.store(false, Ordering::Relaxed);
+            tx.send(Event::GuildsReady(state.finish()));
+
+            return;
+        }
+
+        // Only hold a weak reference so that dropping the emitter drops the
+        // tracker, which aborts the task and closes the channel.
+        let generation = state.generation;
+        let pending = Arc::downgrade(self);
+        let timeout = self.timeout;
+
+        state.timeout = Some(tokio::spawn(async move {
+            time::sleep(timeout).await;
+
+            if let Some(event) = pending
+                .upgrade()
+                .and_then(|pending| pending.time_out(generation))
+            {
+                tx.send(Event::GuildsReady(event));
+            }
+        }));
+        self.active.store(true, Ordering::Relaxed);
+    }
+
+    /// Mark a guild as received, returning the event if it was the last one.
+    ///
+    /// Guilds not in the latest [`Ready`] payload are ignored.
+    ///
+    /// [`Ready`]: twilight_model::gateway::payload::incoming::Ready
+    pub fn receive(&self, guild_id: Id<GuildMarker>, unavailable: bool) -> Option<GuildsReady> {
+        let mut state = self.state.lock().expect("pending guilds poisoned");
+
+        if !state.guilds.remove(&guild_id) {
+            return None;
+        }
+
+        if unavailable {
+            state.unavailable.push(guild_id);
+        }
+
+        if !state.guilds.is_empty() {
+            return None;
+        }
+
+        self.active.store(false, Ordering::Relaxed);
+
+        Some(state.finish())
+    }
+
+    /// Take the event if guilds of the given generation are still pending.
+    fn time_out(&self, generation: u64) -> Option<GuildsReady> {
+        let mut state = self.state.lock().expect("pending guilds poisoned");
+
+        if state.generation != generation || state.guilds.is_empty() {
+            return None;
+        }
+
+        // Don't abort the task calling this.
+        state.timeout.take();
+        self.active.store(false, Ordering::Relaxed);
+
+        Some(state.finish())
+    }
+}
+
+impl Default for PendingGuilds {
+    fn default() -> Self {
+        Self::with_timeout(TIMEOUT)
+    }
+}
+
+impl Drop for PendingGuilds {
+    fn drop(&mut self) {
+        if let Ok(state) = self.state.get_mut() {
+            if let Some(handle) = state.timeout.take() {
+                handle.abort();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EventSender, PendingGuilds};
+    use crate::Event;
+    use std::{collections::HashSet, sync::Arc, time::Duration};
+    use tokio::sync::mpsc;
+    use twilight_model::{gateway::event::shard::GuildsReady, id::Id};
+
+    #[tokio::test]
+    async fn all_received() {
+        let pending = Arc::new(PendingGuilds::default());
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        pending.wait(
+            3,
+            [Id::new(1), Id::new(2)].into_iter().collect(),
+            EventSender::new(tx),
+        );
+        assert!(pending.is_active());
+
+        assert!(pending.receive(Id::new(1), false).is_none());
+        // Guilds not in the ready aren't counted.
+        assert!(pending.receive(Id::new(4), false).is_none());
+
+        assert_eq!(
+            Some(GuildsReady {
+                shard_id: 3,
+                unavailable: Vec::from([Id::new(2)]),
+            }),
+            pending.receive(Id::new(2), true)
+        );
+        assert!(!pending.is_active());
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn no_guilds() {
+        let pending = Arc::new(PendingGuilds::default());
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        pending.wait(0, HashSet::default(), EventSender::new(tx));
+
+        assert!(!pending.is_active());
+        assert!(matches!(
+            rx.try_recv().map(|queued| queued.event),
+            Ok(Event::GuildsReady(GuildsReady { shard_id: 0, unavailable })) if unavailable.is_empty()
+        ));
+    }
+
+    #[tokio::test]
+    async fn no_guilds_after_pending() {
+        let pending = Arc::new(PendingGuilds::default());
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let tx = EventSender::new(tx);
+        pending.wait(0, [Id::new(1)].into_iter().collect(), tx.clone());
+        assert!(pending.is_active());
+
+        pending.wait(0, HashSet::default(), tx);
+        assert!(!pending.is_active());
+        assert!(matches!(
+            rx.try_recv().map(|queued| queued.event),
+            Ok(Event::GuildsReady(GuildsReady { shard_id: 0, unavailable })) if unavailable.is_empty()
+        ));
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn timed_out() {
+        let pending = Arc::new(PendingGuilds::with_timeout(Duration::from_millis(10)));
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        pending.wait(
+            0,
+            [Id::new(1), Id::new(2)].into_iter().collect(),
+            EventSender::new(tx),
+        );
+        assert!(pending.receive(Id::new(1), false).is_none());
+
+        assert!(matches!(
+            rx.recv().await.map(|queued| queued.event),
+            Some(Event::GuildsReady(GuildsReady { unavailable, .. })) if unavailable == [Id::new(2)]
+        ));
+        assert!(!pending.is_active());
+    }
+}
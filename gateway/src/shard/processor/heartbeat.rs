@@ -5,13 +5,14 @@ use super::{
 use serde::{Deserialize, Serialize};
 use std::{
     collections::VecDeque,
+    num::NonZeroU8,
     sync::{
         atomic::{AtomicU32, AtomicU64, Ordering},
         Arc, Mutex,
     },
     time::{Duration, Instant},
 };
-use tokio::sync::mpsc::UnboundedSender;
+use tokio::sync::{mpsc::UnboundedSender, Notify};
 use tokio_tungstenite::tungstenite::Message as TungsteniteMessage;
 use twilight_model::gateway::payload::outgoing::Heartbeat;
 
@@ -173,23 +174,47 @@ impl Default for Heartbeats {
     }
 }
 
+/// Calculate how long to wait before sending the first heartbeat.
+///
+/// Discord recommends waiting `heartbeat_interval * jitter`, where jitter is a
+/// random value between 0 and 1, so that shards don't all heartbeat at once.
+fn first_heartbeat_delay(interval: Duration, jitter: bool) -> Duration {
+    if jitter {
+        interval.mul_f64(rand::random::<f64>())
+    } else {
+        interval
+    }
+}
+
 pub struct Heartbeater {
+    /// Notifier to abort the socket of the connection.
+    abort: Arc<Notify>,
     heartbeats: Arc<Heartbeats>,
     interval: u64,
+    jitter: bool,
+    /// Number of consecutive unacknowledged heartbeats after which the
+    /// connection is aborted.
+    max_missed: NonZeroU8,
     seq: Arc<AtomicU64>,
     tx: UnboundedSender<TungsteniteMessage>,
 }
 
 impl Heartbeater {
     pub fn new(
+        abort: Arc<Notify>,
         heartbeats: Arc<Heartbeats>,
         interval: u64,
+        jitter: bool,
+        max_missed: NonZeroU8,
         seq: Arc<AtomicU64>,
         tx: UnboundedSender<TungsteniteMessage>,
     ) -> Self {
         Self {
+            abort,
             heartbeats,
             interval,
+            jitter,
+            max_missed,
             seq,
             tx,
         }
@@ -207,25 +232,35 @@ impl Heartbeater {
     // programmatic error.
     async fn try_run(self) -> Result<(), SessionSendError> {
         let duration = Duration::from_millis(self.interval);
+        let mut delay = first_heartbeat_delay(duration, self.jitter);
+        let max_missed = self.max_missed.get();
 
-        let mut last = true;
+        // Number of consecutive heartbeats that weren't acknowledged.
+        let mut missed = 0;
+        let mut sent = false;
 
         loop {
-            tokio::time::sleep(duration).await;
+            tokio::time::sleep(delay).await;
+            delay = duration;
 
-            // Check if a heartbeat acknowledgement was received.
-            //
-            // If so, then check if one was received last time.
+            // Check if the last heartbeat was acknowledged.
             //
-            // - if so, then mark that we didn't get one this time
-            // - if not, then end the heartbeater because something is off
-            // (connecting closed?)
-            if self.heartbeats.last_acked() {
-                last = true;
-            } else if last {
-                last = false;
+            // If it wasn't for the configured number of heartbeats in a row
+            // then the connection is likely dead ("zombied"). A close frame
+            // would never be acknowledged over a dead connection, so abort the
+            // socket to make the shard reconnect right away.
+            if !sent || self.heartbeats.last_acked() {
+                missed = 0;
             } else {
-                return Ok(());
+                missed += 1;
+
+                if missed >= max_missed {
+                    tracing::warn!(missed, "heartbeats not acknowledged; aborting connection");
+
+                    self.abort.notify_one();
+
+                    return Ok(());
+                }
             }
 
             let seq = self.seq.load(Ordering::Acquire);
@@ -237,6 +272,11 @@ impl Heartbeater {
 
             tracing::debug!(seq, "sending heartbeat");
 
+            // Mark the heartbeat as sent first, so that an acknowledgement
+            // received right after sending isn't cleared.
+            self.heartbeats.send();
+            sent = true;
+
             self.tx
                 .send(TungsteniteMessage::Binary(bytes))
                 .map_err(|source| SessionSendError {
@@ -245,17 +285,132 @@ impl Heartbeater {
                 })?;
 
             tracing::debug!(seq, "sent heartbeat");
-
-            self.heartbeats.send();
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Latency;
+    use super::{first_heartbeat_delay, Heartbeater, Heartbeats, Latency};
+    use futures_util::FutureExt;
     use static_assertions::assert_impl_all;
-    use std::fmt::Debug;
+    use std::{
+        fmt::Debug,
+        num::NonZeroU8,
+        sync::{atomic::AtomicU64, Arc},
+        time::Duration,
+    };
+    use tokio::sync::{mpsc, Notify};
+    use tokio_tungstenite::tungstenite::Message;
 
     assert_impl_all!(Latency: Clone, Debug, Send, Sync);
+
+    #[test]
+    fn first_heartbeat_jitter() {
+        let interval = Duration::from_millis(41_250);
+
+        for _ in 0..100 {
+            assert!(first_heartbeat_delay(interval, true) <= interval);
+        }
+
+        assert_eq!(interval, first_heartbeat_delay(interval, false));
+    }
+
+    /// Run a heartbeater whose heartbeats are never acknowledged, returning
+    /// the number of heartbeats sent before the connection was aborted.
+    async fn heartbeats_until_abort(max_missed: u8) -> usize {
+        let abort = Arc::new(Notify::new());
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let heartbeater = Heartbeater::new(
+            Arc::clone(&abort),
+            Arc::new(Heartbeats::default()),
+            5,
+            false,
+            NonZeroU8::new(max_missed).unwrap(),
+            Arc::new(AtomicU64::new(0)),
+            tx,
+        );
+        tokio::spawn(heartbeater.run()).await.unwrap();
+
+        // The heartbeater ends after aborting the connection, which is
+        // notified without closing it.
+        abort.notified().await;
+
+        let mut heartbeats = 0;
+
+        while let Some(message) = rx.recv().await {
+            assert!(matches!(message, Message::Binary(_)), "{message:?}");
+            heartbeats += 1;
+        }
+
+        heartbeats
+    }
+
+    #[tokio::test]
+    async fn max_missed_heartbeats() {
+        tokio::time::pause();
+
+        assert_eq!(1, heartbeats_until_abort(1).await);
+        assert_eq!(3, heartbeats_until_abort(3).await);
+    }
+
+    /// Test that an acknowledgement received right after a heartbeat is sent
+    /// isn't discarded.
+    #[tokio::test]
+    async fn fast_acknowledgement() {
+        tokio::time::pause();
+
+        let abort = Arc::new(Notify::new());
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let heartbeats = Arc::new(Heartbeats::default());
+        let heartbeater = Heartbeater::new(
+            Arc::clone(&abort),
+            Arc::clone(&heartbeats),
+            5,
+            false,
+            NonZeroU8::new(1).unwrap(),
+            Arc::new(AtomicU64::new(0)),
+            tx,
+        );
+        let mut handle = tokio::spawn(heartbeater.run());
+
+        for _ in 0..5 {
+            let message = rx.recv().await.expect("heartbeater ended");
+            assert!(matches!(message, Message::Binary(_)));
+
+            heartbeats.receive();
+        }
+
+        assert!((&mut handle).now_or_never().is_none());
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn acknowledged_heartbeats_reset_missed() {
+        tokio::time::pause();
+
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let heartbeats = Arc::new(Heartbeats::default());
+        let heartbeater = Heartbeater::new(
+            Arc::new(Notify::new()),
+            Arc::clone(&heartbeats),
+            5,
+            false,
+            NonZeroU8::new(2).unwrap(),
+            Arc::new(AtomicU64::new(0)),
+            tx,
+        );
+        tokio::spawn(heartbeater.run());
+
+        // Acknowledge every other heartbeat: one missed heartbeat at a time is
+        // tolerated.
+        for index in 0..6 {
+            let message = rx.recv().await.expect("heartbeater ended");
+            assert!(matches!(message, Message::Binary(_)));
+
+            if index % 2 == 1 {
+                heartbeats.receive();
+            }
+        }
+    }
 }
@@ -16,7 +16,7 @@ use std::{
     error::Error,
     fmt::{Debug, Display, Formatter, Result as FmtResult},
     str,
-    sync::{atomic::Ordering, Arc},
+    sync::{atomic::Ordering, Arc, Mutex},
     time::Duration,
 };
 use tokio::sync::{
@@ -310,6 +310,7 @@ pub struct ShardProcessor {
     compression: Compression,
     url: Box<str>,
     resume: Option<(u64, Box<str>)>,
+    token: Arc<Mutex<Box<str>>>,
     wtx: WatchSender<Arc<Session>>,
 }
 
@@ -317,6 +318,7 @@ impl ShardProcessor {
     pub async fn new(
         config: Arc<Config>,
         emitter: Emitter,
+        token: Arc<Mutex<Box<str>>>,
     ) -> Result<(Self, WatchReceiver<Arc<Session>>), ConnectingError> {
         //if we got resume info we don't need to wait
         let shard_id = config.shard();
@@ -360,9 +362,10 @@ impl ShardProcessor {
         )
         .await?;
         let (forwarder, rx, tx) = SocketForwarder::new(stream);
+        let abort = forwarder.abort();
         tokio::spawn(forwarder.run());
 
-        let session = Arc::new(Session::new(tx, config.ratelimit_payloads));
+        let session = Arc::new(Session::new(tx, abort, config.ratelimit_payloads));
 
         if resumable {
             session.set_id(config.session_id.clone().unwrap());
@@ -381,6 +384,7 @@ impl ShardProcessor {
             session,
             url: url.into_boxed_str(),
             resume: None,
+            token,
             wtx,
         };
 
@@ -415,7 +419,23 @@ impl ShardProcessor {
                 continue;
             }
 
-            if let Err(source) = self.process().await {
+            #[cfg(feature = "tracing-spans")]
+            let processed = {
+                let span = tracing::info_span!(
+                    "gateway_event",
+                    shard_id = self.config.shard()[0],
+                    shard_total = self.config.shard()[1],
+                    op = tracing::field::Empty,
+                    event_type = tracing::field::Empty,
+                );
+
+                tracing::Instrument::instrument(self.process(), span).await
+            };
+
+            #[cfg(not(feature = "tracing-spans"))]
+            let processed = self.process().await;
+
+            if let Err(source) = processed {
                 if matches!(&source.kind, ProcessErrorType::EventTypeUnknown { .. }) {
                     tracing::debug!(
                         shard_id = self.config.shard()[0],
@@ -482,6 +502,16 @@ impl ShardProcessor {
                     });
                 };
 
+            #[cfg(feature = "tracing-spans")]
+            {
+                let span = tracing::Span::current();
+                span.record("op", op);
+
+                if let Some(event_type) = event_type.as_deref() {
+                    span.record("event_type", event_type);
+                }
+            }
+
             // We can do a few little optimization tricks here. For the
             // "heartbeat ack" and "reconnect" opcodes we can construct
             // the gateway events without needing to go through a serde
@@ -545,7 +575,7 @@ impl ShardProcessor {
                 })?;
 
                 self.process_ready(&ready.d);
-                emitter.event(Event::Ready(Box::new(ready.d)));
+                emitter.ready(self.config.shard()[0], ready.d);
 
                 return Ok(());
             }
@@ -655,14 +685,17 @@ impl ShardProcessor {
 
             tracing::debug!("resuming with sequence {seq}, session id {id}");
 
-            let payload = Resume::new(seq, id.clone().into_string(), self.config.token());
+            let payload = Resume::new(seq, id.clone().into_string(), self.token());
 
             // Set id so it is correct for next resume.
             self.session.set_id(id);
 
             if interval > 0 {
                 self.session.set_heartbeat_interval(interval);
-                self.session.start_heartbeater();
+                self.session.start_heartbeater(
+                    self.config.heartbeat_jitter(),
+                    self.config.max_missed_heartbeats(),
+                );
             }
 
             self.send(payload).await.map_err(|source| ProcessError {
@@ -674,7 +707,10 @@ impl ShardProcessor {
 
             if interval > 0 {
                 self.session.set_heartbeat_interval(interval);
-                self.session.start_heartbeater();
+                self.session.start_heartbeater(
+                    self.config.heartbeat_jitter(),
+                    self.config.max_missed_heartbeats(),
+                );
             }
 
             self.identify().await.map_err(|source| ProcessError {
@@ -848,7 +884,7 @@ impl ShardProcessor {
                     return Err(ReceivingEventError {
                         kind: ReceivingEventErrorType::AuthorizationInvalid {
                             shard_id: self.config.shard()[0],
-                            token: self.config.token().to_owned(),
+                            token: self.token(),
                         },
                         source: None,
                     });
@@ -894,6 +930,12 @@ impl ShardProcessor {
                 }
                 _ => {}
             }
+
+            if let Some(delay) = reconnect_delay(close_frame) {
+                self.resume_after(Some(delay)).await;
+
+                return Ok(());
+            }
         }
 
         self.resume().await;
@@ -963,25 +1005,19 @@ impl ShardProcessor {
         Ok(stream)
     }
 
+    /// Current token of the shard, which may have been rotated via
+    /// [`Shard::set_token`].
+    ///
+    /// [`Shard::set_token`]: super::super::Shard::set_token
+    fn token(&self) -> String {
+        self.token.lock().expect("token poisoned").to_string()
+    }
+
     /// Identifies with the gateway to create a new session.
     async fn identify(&mut self) -> Result<(), SessionSendError> {
         self.session.set_stage(Stage::Identifying);
 
-        let properties = self
-            .config
-            .identify_properties()
-            .cloned()
-            .unwrap_or_else(default_identify_properties);
-
-        let identify = Identify::new(IdentifyInfo {
-            compress: false,
-            large_threshold: self.config.large_threshold(),
-            intents: self.config.intents(),
-            properties,
-            shard: Some(self.config.shard()),
-            presence: self.config.presence().cloned(),
-            token: self.config.token().to_owned(),
-        });
+        let identify = identify_payload(&self.config, self.token());
         self.emitter.event(Event::ShardIdentifying(Identifying {
             shard_id: self.config.shard()[0],
             shard_total: self.config.shard()[1],
@@ -992,19 +1028,27 @@ impl ShardProcessor {
 
     /// Perform a full reconnect to the gateway, instantiating a new session.
     async fn reconnect(&mut self) {
+        self.reconnect_after(None).await;
+    }
+
+    /// Instantiate a new connection, waiting for an optional delay requested
+    /// by Discord instead of the usual wait before the first attempt.
+    async fn reconnect_after(&mut self, mut delay: Option<Duration>) {
         tracing::info!("reconnection started");
 
         let mut wait = Duration::from_secs(1);
 
         loop {
+            let current_wait = delay.take().unwrap_or(wait);
+
             tracing::debug!(
                 shard_id = self.config.shard()[0],
                 shard_total = self.config.shard()[1],
-                wait_in_seconds = wait.as_secs(),
+                wait_in_seconds = current_wait.as_secs(),
                 "waiting before attempting a reconnect",
             );
 
-            tokio::time::sleep(wait).await;
+            tokio::time::sleep(current_wait).await;
 
             // Await allowance when doing a full reconnect.
             self.config.queue.request(self.config.shard()).await;
@@ -1050,6 +1094,12 @@ impl ShardProcessor {
     /// Resume a session if possible, defaulting to instantiating a new
     /// connection.
     async fn resume(&mut self) {
+        self.resume_after(None).await;
+    }
+
+    /// Resume a session if possible after an optional delay, defaulting to
+    /// instantiating a new connection.
+    async fn resume_after(&mut self, delay: Option<Duration>) {
         tracing::debug!("resuming shard {:?}", self.config.shard());
 
         self.session.set_stage(Stage::Resuming);
@@ -1062,13 +1112,13 @@ impl ShardProcessor {
         } else {
             tracing::info!("session id unavailable, reconnecting");
 
-            self.reconnect().await;
+            self.reconnect_after(delay).await;
             return;
         };
 
         self.resume = Some((seq, id));
 
-        if let Err(source) = self.try_resume().await {
+        if let Err(source) = self.try_resume(delay).await {
             tracing::warn!(
                 seq = seq,
                 session_id = ?self.session.id(),
@@ -1080,13 +1130,28 @@ impl ShardProcessor {
         }
     }
 
-    /// Attempt to resume a session.
-    async fn try_resume(&mut self) -> Result<(), ConnectingError> {
+    /// Attempt to resume a session after an optional delay.
+    ///
+    /// The delay is announced in the [`Resuming`] event before waiting. The
+    /// wait is cancelled when the shard is shut down, as that aborts the
+    /// processor.
+    async fn try_resume(&mut self, delay: Option<Duration>) -> Result<(), ConnectingError> {
         self.emitter.event(Event::ShardResuming(Resuming {
+            delay: delay.map(|delay| delay.as_secs()),
             seq: self.session.seq(),
             shard_id: self.config.shard()[0],
         }));
 
+        if let Some(delay) = delay {
+            tracing::debug!(
+                shard_id = self.config.shard()[0],
+                wait_in_seconds = delay.as_secs(),
+                "close code requires waiting before resuming",
+            );
+
+            tokio::time::sleep(delay).await;
+        }
+
         let stream = Self::connect(
             &self.url,
             #[cfg(any(
@@ -1109,11 +1174,12 @@ impl ShardProcessor {
     /// new websocket connection.
     fn set_session(&mut self, stream: ShardStream, stage: Stage) {
         let (forwarder, rx, tx) = SocketForwarder::new(stream);
+        let abort = forwarder.abort();
 
         tokio::spawn(forwarder.run());
 
         self.rx = rx;
-        self.session = Arc::new(Session::new(tx, self.config.ratelimit_payloads));
+        self.session = Arc::new(Session::new(tx, abort, self.config.ratelimit_payloads));
 
         if let Err(source) = self.wtx.send(Arc::clone(&self.session)) {
             tracing::error!("failed to broadcast new session: {source:?}");
@@ -1132,6 +1198,24 @@ impl ShardProcessor {
     }
 }
 
+/// Identify payload for a new session.
+pub fn identify_payload(config: &Config, token: String) -> Identify {
+    let properties = config
+        .identify_properties()
+        .cloned()
+        .unwrap_or_else(default_identify_properties);
+
+    Identify::new(IdentifyInfo {
+        compress: false,
+        large_threshold: config.large_threshold(),
+        intents: config.intents(),
+        properties,
+        shard: Some(config.shard()),
+        presence: config.presence().cloned(),
+        token,
+    })
+}
+
 /// Default identify properties to use when the user has not customized it via
 /// [`ShardBuilder::identify_properties`].
 ///
@@ -1139,3 +1223,44 @@ impl ShardProcessor {
 fn default_identify_properties() -> IdentifyProperties {
     IdentifyProperties::new("twilight.rs", "twilight.rs", OS)
 }
+
+/// Delay to wait before reconnecting implied by a close frame, if any.
+///
+/// Discord closes the connection with a code of 4008 when the shard sends too
+/// many payloads. Since the gateway ratelimits payloads over a window of 60
+/// seconds, reconnecting before the window resets only risks being ratelimited
+/// again.
+const fn reconnect_delay(close_frame: &CloseFrame<'_>) -> Option<Duration> {
+    match close_frame.code {
+        CloseCode::Library(4008) => Some(Duration::from_secs(60)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::reconnect_delay;
+    use std::{borrow::Cow, time::Duration};
+    use tokio_tungstenite::tungstenite::protocol::{frame::coding::CloseCode, CloseFrame};
+
+    #[test]
+    fn reconnect_delay_close_code() {
+        let ratelimited = CloseFrame {
+            code: CloseCode::Library(4008),
+            reason: Cow::Borrowed("You are being rate limited."),
+        };
+        assert_eq!(Some(Duration::from_secs(60)), reconnect_delay(&ratelimited));
+
+        let session_timed_out = CloseFrame {
+            code: CloseCode::Library(4009),
+            reason: Cow::Borrowed("Session timed out."),
+        };
+        assert!(reconnect_delay(&session_timed_out).is_none());
+
+        let normal = CloseFrame {
+            code: CloseCode::Normal,
+            reason: Cow::Borrowed(""),
+        };
+        assert!(reconnect_delay(&normal).is_none());
+    }
+}
@@ -10,3 +10,6 @@ pub use self::{
     r#impl::{ConnectingError, ConnectingErrorType, ShardProcessor},
     session::Session,
 };
+
+#[cfg(test)]
+pub use self::r#impl::identify_payload;
@@ -7,6 +7,7 @@ use serde::ser::Serialize;
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
+    num::NonZeroU8,
     sync::{
         atomic::{AtomicU64, AtomicU8, Ordering},
         Arc, Mutex as MutexSync,
@@ -16,7 +17,7 @@ use std::{
 use tokio::{
     sync::{
         mpsc::{error::SendError, UnboundedSender},
-        OnceCell,
+        Notify, OnceCell,
     },
     task::JoinHandle,
 };
@@ -65,6 +66,8 @@ pub enum SessionSendErrorType {
 
 #[derive(Debug)]
 pub struct Session {
+    /// Notifier to drop the socket without closing it.
+    pub abort: Arc<Notify>,
     pub heartbeater_handle: MutexSync<Option<JoinHandle<()>>>,
     pub heartbeats: Arc<Heartbeats>,
     pub heartbeat_interval: AtomicU64,
@@ -76,8 +79,13 @@ pub struct Session {
 }
 
 impl Session {
-    pub fn new(tx: UnboundedSender<TungsteniteMessage>, ratelimit_payloads: bool) -> Self {
+    pub fn new(
+        tx: UnboundedSender<TungsteniteMessage>,
+        abort: Arc<Notify>,
+        ratelimit_payloads: bool,
+    ) -> Self {
         let session = Self {
+            abort,
             heartbeater_handle: MutexSync::new(None),
             heartbeats: Arc::new(Heartbeats::default()),
             heartbeat_interval: AtomicU64::new(0),
@@ -204,12 +212,21 @@ impl Session {
         }
     }
 
-    pub fn start_heartbeater(&self) {
+    pub fn start_heartbeater(&self, jitter: bool, max_missed: NonZeroU8) {
         let interval = self.heartbeat_interval();
         let seq = Arc::clone(&self.seq);
         let heartbeats = Arc::clone(&self.heartbeats);
 
-        let heartbeater = Heartbeater::new(heartbeats, interval, seq, self.tx.clone()).run();
+        let heartbeater = Heartbeater::new(
+            Arc::clone(&self.abort),
+            heartbeats,
+            interval,
+            jitter,
+            max_missed,
+            seq,
+            self.tx.clone(),
+        )
+        .run();
         let handle = tokio::spawn(heartbeater);
 
         if let Some(old) = self
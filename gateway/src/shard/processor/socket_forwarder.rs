@@ -4,14 +4,20 @@ use futures_util::{
     sink::SinkExt,
     stream::StreamExt,
 };
-use std::time::Duration;
+use std::{sync::Arc, time::Duration};
 use tokio::{
-    sync::mpsc::{self, UnboundedReceiver, UnboundedSender},
+    sync::{
+        mpsc::{self, UnboundedReceiver, UnboundedSender},
+        Notify,
+    },
     time::timeout,
 };
 use tokio_tungstenite::tungstenite::Message;
 
 pub struct SocketForwarder {
+    /// Notifier to drop the socket without closing it, for connections that
+    /// are dead.
+    abort: Arc<Notify>,
     rx: UnboundedReceiver<Message>,
     pub stream: ShardStream,
     tx: UnboundedSender<Message>,
@@ -28,6 +34,7 @@ impl SocketForwarder {
 
         (
             Self {
+                abort: Arc::new(Notify::new()),
                 rx: from_user,
                 stream,
                 tx: to_user,
@@ -37,16 +44,36 @@ impl SocketForwarder {
         )
     }
 
+    /// Notifier to drop the socket without closing it.
+    pub fn abort(&self) -> Arc<Notify> {
+        Arc::clone(&self.abort)
+    }
+
     pub async fn run(mut self) {
         tracing::debug!("starting driving loop");
 
         loop {
             tokio::pin! {
+                let abort = self.abort.notified();
                 let rx = self.rx.recv();
                 let tx = self.stream.next();
             }
 
-            match timeout(Self::TIMEOUT, future::select(rx, tx)).await {
+            let next = timeout(Self::TIMEOUT, future::select(rx, tx));
+            tokio::pin!(next);
+
+            // Dropping the socket without closing it, as the connection is
+            // dead.
+            let next = match future::select(abort, next).await {
+                Either::Left(_) => {
+                    tracing::debug!("aborting socket");
+
+                    break;
+                }
+                Either::Right((next, _)) => next,
+            };
+
+            match next {
                 // `rx` future finished first.
                 Ok(Either::Left((maybe_msg, _))) => {
                     if let Some(msg) = maybe_msg {
@@ -123,6 +123,8 @@ pub enum Path {
     ApplicationGuildCommand(u64),
     /// Operating on a specific command in a guild.
     ApplicationGuildCommandId(u64),
+    /// Operating on the current application.
+    ApplicationsMe,
     /// Operating on a channel.
     ChannelsId(u64),
     /// Operating on a channel's invites.
@@ -148,6 +150,10 @@ pub enum Path {
     ChannelsIdPins(u64),
     /// Operating on a channel's individual pinned message.
     ChannelsIdPinsMessageId(u64),
+    /// Operating on the voters of an individual channel's poll's answer.
+    ChannelsIdPollsIdAnswersId(u64),
+    /// Operating on the expiry of an individual channel's poll.
+    ChannelsIdPollsIdExpire(u64),
     /// Operating on a group DM's recipients.
     ChannelsIdRecipients(u64),
     /// Operating on a thread's members.
@@ -176,6 +182,8 @@ pub enum Path {
     GuildsIdAuditLogs(u64),
     /// Operating on specific member's ban from one of the user's guilds.
     GuildsIdBansUserId(u64),
+    /// Operating on bans of multiple members from one of the user's guilds.
+    GuildsIdBulkBan(u64),
     /// Operating on one of the user's guilds' channels.
     GuildsIdChannels(u64),
     /// Operating on one of the user's guilds' widget.
@@ -202,6 +210,10 @@ pub enum Path {
     GuildsIdMembersMeNick(u64),
     /// Operating on one of the user's guilds' members by searching.
     GuildsIdMembersSearch(u64),
+    /// Operating on one of the user's guilds' MFA level.
+    GuildsIdMfa(u64),
+    /// Operating on one of the user's guilds' onboarding.
+    GuildsIdOnboarding(u64),
     /// Operating on one of the user's guilds' by previewing it.
     GuildsIdPreview(u64),
     /// Operating on one of the user's guilds' by pruning members.
@@ -318,6 +330,7 @@ impl FromStr for Path {
             | ["applications", id, "guilds", _, "commands", _, "permissions"] => {
                 ApplicationGuildCommandId(parse_id(id)?)
             }
+            ["applications", "@me"] => ApplicationsMe,
             ["channels", id] => ChannelsId(parse_id(id)?),
             ["channels", id, "followers"] => ChannelsIdFollowers(parse_id(id)?),
             ["channels", id, "invites"] => ChannelsIdInvites(parse_id(id)?),
@@ -350,6 +363,8 @@ impl FromStr for Path {
             ["channels", id, "permissions", _] => ChannelsIdPermissionsOverwriteId(parse_id(id)?),
             ["channels", id, "pins"] => ChannelsIdPins(parse_id(id)?),
             ["channels", id, "pins", _] => ChannelsIdPinsMessageId(parse_id(id)?),
+            ["channels", id, "polls", _, "answers", _] => ChannelsIdPollsIdAnswersId(parse_id(id)?),
+            ["channels", id, "polls", _, "expire"] => ChannelsIdPollsIdExpire(parse_id(id)?),
             ["channels", id, "recipients"] | ["channels", id, "recipients", _] => {
                 ChannelsIdRecipients(parse_id(id)?)
             }
@@ -367,6 +382,7 @@ impl FromStr for Path {
             ["guilds", id, "audit-logs"] => GuildsIdAuditLogs(parse_id(id)?),
             ["guilds", id, "bans"] => GuildsIdBans(parse_id(id)?),
             ["guilds", id, "bans", _] => GuildsIdBansUserId(parse_id(id)?),
+            ["guilds", id, "bulk-ban"] => GuildsIdBulkBan(parse_id(id)?),
             ["guilds", id, "channels"] => GuildsIdChannels(parse_id(id)?),
             ["guilds", id, "widget" | "widget.json"] => GuildsIdWidget(parse_id(id)?),
             ["guilds", id, "emojis"] => GuildsIdEmojis(parse_id(id)?),
@@ -380,6 +396,8 @@ impl FromStr for Path {
             ["guilds", id, "members", _] => GuildsIdMembersId(parse_id(id)?),
             ["guilds", id, "members", _, "roles", _] => GuildsIdMembersIdRolesId(parse_id(id)?),
             ["guilds", id, "members", "@me", "nick"] => GuildsIdMembersMeNick(parse_id(id)?),
+            ["guilds", id, "mfa"] => GuildsIdMfa(parse_id(id)?),
+            ["guilds", id, "onboarding"] => GuildsIdOnboarding(parse_id(id)?),
             ["guilds", id, "preview"] => GuildsIdPreview(parse_id(id)?),
             ["guilds", id, "prune"] => GuildsIdPrune(parse_id(id)?),
             ["guilds", id, "regions"] => GuildsIdRegions(parse_id(id)?),
@@ -473,6 +491,7 @@ mod tests {
         assert_eq!(Path::ChannelsId(123), Path::from_str("/channels/123")?);
         assert_eq!(Path::WebhooksId(123), Path::from_str("/webhooks/123")?);
         assert_eq!(Path::InvitesCode, Path::from_str("/invites/abc")?);
+        assert_eq!(Path::ApplicationsMe, Path::from_str("/applications/@me")?);
 
         Ok(())
     }
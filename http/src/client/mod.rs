@@ -12,8 +12,8 @@ use crate::{
         channel::{
             invite::{CreateInvite, DeleteInvite, GetChannelInvites, GetInvite},
             message::{
-                CreateMessage, CrosspostMessage, DeleteMessage, DeleteMessages, GetChannelMessages,
-                GetMessage, UpdateMessage,
+                CreateMessage, CrosspostMessage, DeleteMessage, DeleteMessages, EndPoll,
+                GetChannelMessages, GetMessage, GetPollAnswerVoters, UpdateMessage,
             },
             reaction::{
                 delete_reaction::TargetUser, CreateReaction, DeleteAllReaction, DeleteAllReactions,
@@ -33,11 +33,12 @@ use crate::{
                 GetChannelWebhooks, GetWebhook, GetWebhookMessage, UpdateWebhook,
                 UpdateWebhookMessage, UpdateWebhookWithToken,
             },
-            CreatePin, CreateTypingTrigger, DeleteChannel, DeleteChannelPermission, DeletePin,
-            FollowNewsChannel, GetChannel, GetPins, UpdateChannel, UpdateChannelPermission,
+            AddGroupDmRecipient, CreatePin, CreateTypingTrigger, DeleteChannel,
+            DeleteChannelPermission, DeletePin, FollowNewsChannel, GetChannel, GetPins,
+            RemoveGroupDmRecipient, UpdateChannel, UpdateChannelPermission,
         },
         guild::{
-            ban::{CreateBan, DeleteBan, GetBan, GetBans},
+            ban::{CreateBan, CreateBulkBan, DeleteBan, GetBan, GetBans},
             create_guild::CreateGuildError,
             emoji::{CreateEmoji, DeleteEmoji, GetEmoji, GetEmojis, UpdateEmoji},
             integration::{DeleteGuildIntegration, GetGuildIntegrations},
@@ -51,12 +52,16 @@ use crate::{
                 UpdateGuildSticker,
             },
             update_guild_channel_positions::Position,
-            user::{UpdateCurrentUserVoiceState, UpdateUserVoiceState},
+            user::{
+                GetCurrentUserVoiceState, GetUserVoiceState, UpdateCurrentUserVoiceState,
+                UpdateUserVoiceState,
+            },
             CreateGuild, CreateGuildChannel, CreateGuildPrune, DeleteGuild, GetActiveThreads,
-            GetAuditLog, GetGuild, GetGuildChannels, GetGuildInvites, GetGuildPreview,
-            GetGuildPruneCount, GetGuildVanityUrl, GetGuildVoiceRegions, GetGuildWebhooks,
-            GetGuildWelcomeScreen, GetGuildWidget, UpdateCurrentMember, UpdateGuild,
-            UpdateGuildChannelPositions, UpdateGuildWelcomeScreen, UpdateGuildWidget,
+            GetAuditLog, GetGuild, GetGuildChannels, GetGuildInvites, GetGuildOnboarding,
+            GetGuildPreview, GetGuildPruneCount, GetGuildVanityUrl, GetGuildVoiceRegions,
+            GetGuildWebhooks, GetGuildWelcomeScreen, GetGuildWidget, UpdateCurrentMember,
+            UpdateGuild, UpdateGuildChannelPositions, UpdateGuildMfaLevel, UpdateGuildOnboarding,
+            UpdateGuildWelcomeScreen, UpdateGuildWidget,
         },
         scheduled_event::{
             CreateGuildScheduledEvent, DeleteGuildScheduledEvent, GetGuildScheduledEvent,
@@ -73,6 +78,7 @@ use crate::{
             UpdateCurrentUser,
         },
         GetGateway, GetUserApplicationInfo, GetVoiceRegions, Method, Request,
+        UpdateCurrentApplication,
     },
     response::ResponseFuture,
     API_VERSION,
@@ -94,6 +100,7 @@ use tokio::time;
 use twilight_http_ratelimiting::Ratelimiter;
 use twilight_model::{
     channel::{message::allowed_mentions::AllowedMentions, ChannelType},
+    guild::MfaLevel,
     http::permission_overwrite::PermissionOverwrite,
     id::{
         marker::{
@@ -338,8 +345,8 @@ impl Client {
         GetBan::new(self, guild_id, user_id)
     }
 
-    /// Bans a user from a guild, optionally with the number of days' worth of
-    /// messages to delete and the reason.
+    /// Bans a user from a guild, optionally with the duration of messages to
+    /// delete and the reason.
     ///
     /// # Examples
     ///
@@ -348,7 +355,7 @@ impl Client {
     ///
     /// ```no_run
     /// # use twilight_http::{request::AuditLogReason, Client};
-    /// use twilight_model::id::Id;
+    /// use twilight_model::{http::ban::DeleteMessageDuration, id::Id};
     /// #
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -357,7 +364,7 @@ impl Client {
     /// let guild_id = Id::new(100);
     /// let user_id = Id::new(200);
     /// client.create_ban(guild_id, user_id)
-    ///     .delete_message_days(1)?
+    ///     .delete_message_duration(DeleteMessageDuration::from_days(1)?)
     ///     .reason("memes")?
     ///     .exec()
     ///     .await?;
@@ -371,6 +378,49 @@ impl Client {
         CreateBan::new(self, guild_id, user_id)
     }
 
+    /// Ban up to 200 users from a guild.
+    ///
+    /// Responds with the IDs of the users that were banned and the IDs of the
+    /// users that couldn't be banned.
+    ///
+    /// # Examples
+    ///
+    /// Ban users `200` and `300` from guild `100`, deleting 1 hour's worth of
+    /// messages, for the reason `"raid"`:
+    ///
+    /// ```no_run
+    /// # use twilight_http::{request::AuditLogReason, Client};
+    /// # use twilight_model::{http::ban::DeleteMessageDuration, id::Id};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::new("my token".to_owned());
+    /// #
+    /// let guild_id = Id::new(100);
+    /// let user_ids = [Id::new(200), Id::new(300)];
+    /// client
+    ///     .bulk_ban(guild_id, &user_ids)?
+    ///     .delete_message_duration(DeleteMessageDuration::from_seconds(3_600)?)
+    ///     .reason("raid")?
+    ///     .exec()
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`BulkBanUserIds`] if there are no user IDs or
+    /// more than 200.
+    ///
+    /// [`BulkBanUserIds`]: twilight_validate::request::ValidationErrorType::BulkBanUserIds
+    pub const fn bulk_ban<'a>(
+        &'a self,
+        guild_id: Id<GuildMarker>,
+        user_ids: &'a [Id<UserMarker>],
+    ) -> Result<CreateBulkBan<'a>, ValidationError> {
+        CreateBulkBan::new(self, guild_id, user_ids)
+    }
+
     /// Remove a ban from a user in a guild.
     ///
     /// # Examples
@@ -567,11 +617,27 @@ impl Client {
         GetCurrentUserGuildMember::new(self, guild_id)
     }
 
+    /// Get the current user's voice state in a guild.
+    pub const fn current_user_voice_state(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> GetCurrentUserVoiceState<'_> {
+        GetCurrentUserVoiceState::new(self, guild_id)
+    }
+
     /// Get information about the current bot application.
     pub const fn current_user_application(&self) -> GetUserApplicationInfo<'_> {
         GetUserApplicationInfo::new(self)
     }
 
+    /// Update the current bot application.
+    ///
+    /// All parameters are optional. Refer to [`UpdateCurrentApplication`]
+    /// for the fields that may be updated.
+    pub const fn update_current_application(&self) -> UpdateCurrentApplication<'_> {
+        UpdateCurrentApplication::new(self)
+    }
+
     /// Update the current user.
     ///
     /// All parameters are optional. If the username is changed, it may cause the discriminator to
@@ -584,16 +650,35 @@ impl Client {
     ///
     /// All parameters are optional.
     ///
-    /// # Caveats
+    /// # Examples
     ///
-    /// - `channel_id` must currently point to a stage channel.
-    /// - Current user must have already joined `channel_id`.
+    /// Request to speak in the stage channel the current user is connected to:
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::time::{SystemTime, UNIX_EPOCH};
+    /// use twilight_http::Client;
+    /// use twilight_model::{id::Id, util::Timestamp};
+    ///
+    /// let client = Client::new("my token".to_owned());
+    ///
+    /// let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    /// let timestamp = Timestamp::from_secs(now.try_into()?)?;
+    ///
+    /// client
+    ///     .update_current_user_voice_state(Id::new(1))
+    ///     .channel_id(Id::new(2))
+    ///     .request_to_speak_timestamp(Some(timestamp))
+    ///     .exec()
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
     pub const fn update_current_user_voice_state(
         &self,
         guild_id: Id<GuildMarker>,
-        channel_id: Id<ChannelMarker>,
     ) -> UpdateCurrentUserVoiceState<'_> {
-        UpdateCurrentUserVoiceState::new(self, guild_id, channel_id)
+        UpdateCurrentUserVoiceState::new(self, guild_id)
     }
 
     /// Get the current user's connections.
@@ -1102,7 +1187,12 @@ impl Client {
         CreateGuildPrune::new(self, guild_id)
     }
 
-    /// Get a guild's vanity url, if there is one.
+    /// Get a guild's vanity url and how many times it has been used.
+    ///
+    /// If the guild doesn't have a vanity url, the returned [`VanityUrl`]'s
+    /// code is [`None`].
+    ///
+    /// [`VanityUrl`]: twilight_model::guild::VanityUrl
     pub const fn guild_vanity_url(&self, guild_id: Id<GuildMarker>) -> GetGuildVanityUrl<'_> {
         GetGuildVanityUrl::new(self, guild_id)
     }
@@ -1114,6 +1204,55 @@ impl Client {
         GetGuildVoiceRegions::new(self, guild_id)
     }
 
+    /// Update the multi-factor authentication level of a guild.
+    ///
+    /// Requires guild ownership. The response contains the new level.
+    ///
+    /// # Examples
+    ///
+    /// Require moderators to have two-factor authentication enabled:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use twilight_http::Client;
+    /// use twilight_model::{guild::MfaLevel, id::Id};
+    ///
+    /// let client = Client::new("my token".to_owned());
+    ///
+    /// let guild_id = Id::new(1);
+    /// let response = client
+    ///     .update_guild_mfa_level(guild_id, MfaLevel::Elevated)
+    ///     .exec()
+    ///     .await?;
+    /// let level = response.model().await?.level;
+    /// # Ok(()) }
+    /// ```
+    pub const fn update_guild_mfa_level(
+        &self,
+        guild_id: Id<GuildMarker>,
+        level: MfaLevel,
+    ) -> UpdateGuildMfaLevel<'_> {
+        UpdateGuildMfaLevel::new(self, guild_id, level)
+    }
+
+    /// Get the onboarding flow of a guild.
+    pub const fn guild_onboarding(&self, guild_id: Id<GuildMarker>) -> GetGuildOnboarding<'_> {
+        GetGuildOnboarding::new(self, guild_id)
+    }
+
+    /// Update the onboarding flow of a guild.
+    ///
+    /// Requires the [`MANAGE_GUILD`] and [`MANAGE_ROLES`] permissions.
+    ///
+    /// [`MANAGE_GUILD`]: twilight_model::guild::Permissions::MANAGE_GUILD
+    /// [`MANAGE_ROLES`]: twilight_model::guild::Permissions::MANAGE_ROLES
+    pub const fn update_guild_onboarding(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> UpdateGuildOnboarding<'_> {
+        UpdateGuildOnboarding::new(self, guild_id)
+    }
+
     /// Get the webhooks of a guild.
     pub const fn guild_webhooks(&self, guild_id: Id<GuildMarker>) -> GetGuildWebhooks<'_> {
         GetGuildWebhooks::new(self, guild_id)
@@ -1338,6 +1477,30 @@ impl Client {
         CrosspostMessage::new(self, channel_id, message_id)
     }
 
+    /// Get a list of users that voted for an answer of a message's poll.
+    ///
+    /// This endpoint is limited to 100 users maximum, so if an answer has more
+    /// than 100 voters, requests must be chained until all voters are retrieved.
+    pub const fn poll_answer_voters(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        message_id: Id<MessageMarker>,
+        answer_id: u8,
+    ) -> GetPollAnswerVoters<'_> {
+        GetPollAnswerVoters::new(self, channel_id, message_id, answer_id)
+    }
+
+    /// Immediately end the poll of a message.
+    ///
+    /// Only polls created by the current user can be ended.
+    pub const fn end_poll(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        message_id: Id<MessageMarker>,
+    ) -> EndPoll<'_> {
+        EndPoll::new(self, channel_id, message_id)
+    }
+
     /// Get the pins of a channel.
     pub const fn pins(&self, channel_id: Id<ChannelMarker>) -> GetPins<'_> {
         GetPins::new(self, channel_id)
@@ -1464,6 +1627,29 @@ impl Client {
         CreatePrivateChannel::new(self, recipient_id)
     }
 
+    /// Add a user to a group DM.
+    ///
+    /// Requires an access token for the user with the `gdm.join` scope.
+    /// The nickname is the user's nickname within the group DM.
+    pub const fn add_group_dm_recipient<'a>(
+        &'a self,
+        channel_id: Id<ChannelMarker>,
+        user_id: Id<UserMarker>,
+        access_token: &'a str,
+        nick: &'a str,
+    ) -> AddGroupDmRecipient<'a> {
+        AddGroupDmRecipient::new(self, channel_id, user_id, access_token, nick)
+    }
+
+    /// Remove a user from a group DM.
+    pub const fn remove_group_dm_recipient(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        user_id: Id<UserMarker>,
+    ) -> RemoveGroupDmRecipient<'_> {
+        RemoveGroupDmRecipient::new(self, channel_id, user_id)
+    }
+
     /// Get the roles of a guild.
     pub const fn roles(&self, guild_id: Id<GuildMarker>) -> GetGuildRoles<'_> {
         GetGuildRoles::new(self, guild_id)
@@ -1514,6 +1700,9 @@ impl Client {
     /// Modify the position of the roles.
     ///
     /// The minimum amount of roles to modify, is a swap between two roles.
+    ///
+    /// Responds with all of the guild's roles, including their updated
+    /// positions.
     pub const fn update_role_positions<'a>(
         &'a self,
         guild_id: Id<GuildMarker>,
@@ -1836,6 +2025,15 @@ impl Client {
         GetUser::new(self, user_id)
     }
 
+    /// Get a user's voice state in a guild.
+    pub const fn user_voice_state(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+    ) -> GetUserVoiceState<'_> {
+        GetUserVoiceState::new(self, guild_id, user_id)
+    }
+
     /// Update another user's voice state.
     ///
     /// # Caveats
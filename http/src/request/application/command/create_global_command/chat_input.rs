@@ -1,16 +1,20 @@
-use super::super::CommandBorrowed;
+use super::super::{CommandBorrowed, CommandContexts};
 use crate::{
     client::Client,
-    error::Error as HttpError,
+    error::{Error as HttpError, ErrorType},
     request::{Request, RequestBuilder, TryIntoRequest},
     response::ResponseFuture,
     routing::Route,
 };
 use std::collections::HashMap;
 use twilight_model::{
-    application::command::{Command, CommandOption, CommandType},
+    application::{
+        command::{Command, CommandOption, CommandType},
+        interaction::InteractionContextType,
+    },
     guild::Permissions,
     id::{marker::ApplicationMarker, Id},
+    oauth::ApplicationIntegrationType,
 };
 use twilight_validate::command::{
     chat_input_name as validate_chat_input_name, description as validate_description,
@@ -28,6 +32,7 @@ use twilight_validate::command::{
 #[must_use = "requests must be configured and executed"]
 pub struct CreateGlobalChatInputCommand<'a> {
     application_id: Id<ApplicationMarker>,
+    contexts: CommandContexts<'a>,
     default_member_permissions: Option<Permissions>,
     dm_permission: Option<bool>,
     description: &'a str,
@@ -51,6 +56,7 @@ impl<'a> CreateGlobalChatInputCommand<'a> {
 
         Ok(Self {
             application_id,
+            contexts: CommandContexts::create(),
             default_member_permissions: None,
             dm_permission: None,
             description,
@@ -84,6 +90,31 @@ impl<'a> CreateGlobalChatInputCommand<'a> {
         Ok(self)
     }
 
+    /// Set the contexts in which the command can be invoked.
+    ///
+    /// Defaults to [`None`]. The contexts must be allowed by the integration
+    /// types, which default to [`GuildInstall`], otherwise building the request
+    /// fails with an error of type [`BuildingRequest`]; refer to
+    /// [`contexts_integration_types`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the contexts are invalid. Refer to [`contexts`] for
+    /// the error types.
+    ///
+    /// [`BuildingRequest`]: crate::error::ErrorType::BuildingRequest
+    /// [`GuildInstall`]: ApplicationIntegrationType::GuildInstall
+    /// [`contexts`]: twilight_validate::command::contexts
+    /// [`contexts_integration_types`]: twilight_validate::command::contexts_integration_types
+    pub fn contexts(
+        mut self,
+        contexts: &'a [InteractionContextType],
+    ) -> Result<Self, CommandValidationError> {
+        self.contexts.set_contexts(contexts)?;
+
+        Ok(self)
+    }
+
     /// Default permissions required for a member to run the command.
     ///
     /// Defaults to [`None`].
@@ -118,6 +149,25 @@ impl<'a> CreateGlobalChatInputCommand<'a> {
         Ok(self)
     }
 
+    /// Set the installation contexts where the command is available.
+    ///
+    /// Defaults to [`None`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the integration types are invalid. Refer to
+    /// [`integration_types`] for the error types.
+    ///
+    /// [`integration_types`]: twilight_validate::command::integration_types
+    pub fn integration_types(
+        mut self,
+        integration_types: &'a [ApplicationIntegrationType],
+    ) -> Result<Self, CommandValidationError> {
+        self.contexts.set_integration_types(integration_types)?;
+
+        Ok(self)
+    }
+
     /// Set the localization dictionary for the command name.
     ///
     /// Defaults to [`None`].
@@ -149,15 +199,22 @@ impl<'a> CreateGlobalChatInputCommand<'a> {
 
 impl TryIntoRequest for CreateGlobalChatInputCommand<'_> {
     fn try_into_request(self) -> Result<Request, HttpError> {
+        self.contexts.validate().map_err(|source| HttpError {
+            kind: ErrorType::BuildingRequest,
+            source: Some(Box::new(source)),
+        })?;
+
         Request::builder(&Route::CreateGlobalCommand {
             application_id: self.application_id.get(),
         })
         .json(&CommandBorrowed {
             application_id: Some(self.application_id),
+            contexts: self.contexts.contexts,
             default_member_permissions: self.default_member_permissions,
             dm_permission: self.dm_permission,
             description: Some(self.description),
             description_localizations: self.description_localizations,
+            integration_types: self.contexts.integration_types,
             kind: CommandType::ChatInput,
             name: self.name,
             name_localizations: self.name_localizations,
@@ -1,16 +1,20 @@
-use super::super::CommandBorrowed;
+use super::super::{CommandBorrowed, CommandContexts};
 use crate::{
     client::Client,
-    error::Error,
+    error::{Error, ErrorType},
     request::{Request, RequestBuilder, TryIntoRequest},
     response::ResponseFuture,
     routing::Route,
 };
 use std::collections::HashMap;
 use twilight_model::{
-    application::command::{Command, CommandType},
+    application::{
+        command::{Command, CommandType},
+        interaction::InteractionContextType,
+    },
     guild::Permissions,
     id::{marker::ApplicationMarker, Id},
+    oauth::ApplicationIntegrationType,
 };
 use twilight_validate::command::{name as validate_name, CommandValidationError};
 
@@ -24,6 +28,7 @@ use twilight_validate::command::{name as validate_name, CommandValidationError};
 #[must_use = "requests must be configured and executed"]
 pub struct CreateGlobalUserCommand<'a> {
     application_id: Id<ApplicationMarker>,
+    contexts: CommandContexts<'a>,
     default_member_permissions: Option<Permissions>,
     dm_permission: Option<bool>,
     http: &'a Client,
@@ -41,6 +46,7 @@ impl<'a> CreateGlobalUserCommand<'a> {
 
         Ok(Self {
             application_id,
+            contexts: CommandContexts::create(),
             default_member_permissions: None,
             dm_permission: None,
             http,
@@ -49,6 +55,31 @@ impl<'a> CreateGlobalUserCommand<'a> {
         })
     }
 
+    /// Set the contexts in which the command can be invoked.
+    ///
+    /// Defaults to [`None`]. The contexts must be allowed by the integration
+    /// types, which default to [`GuildInstall`], otherwise building the request
+    /// fails with an error of type [`BuildingRequest`]; refer to
+    /// [`contexts_integration_types`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the contexts are invalid. Refer to [`contexts`] for
+    /// the error types.
+    ///
+    /// [`BuildingRequest`]: crate::error::ErrorType::BuildingRequest
+    /// [`GuildInstall`]: ApplicationIntegrationType::GuildInstall
+    /// [`contexts`]: twilight_validate::command::contexts
+    /// [`contexts_integration_types`]: twilight_validate::command::contexts_integration_types
+    pub fn contexts(
+        mut self,
+        contexts: &'a [InteractionContextType],
+    ) -> Result<Self, CommandValidationError> {
+        self.contexts.set_contexts(contexts)?;
+
+        Ok(self)
+    }
+
     /// Default permissions required for a member to run the command.
     ///
     /// Defaults to [`None`].
@@ -67,6 +98,25 @@ impl<'a> CreateGlobalUserCommand<'a> {
         self
     }
 
+    /// Set the installation contexts where the command is available.
+    ///
+    /// Defaults to [`None`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the integration types are invalid. Refer to
+    /// [`integration_types`] for the error types.
+    ///
+    /// [`integration_types`]: twilight_validate::command::integration_types
+    pub fn integration_types(
+        mut self,
+        integration_types: &'a [ApplicationIntegrationType],
+    ) -> Result<Self, CommandValidationError> {
+        self.contexts.set_integration_types(integration_types)?;
+
+        Ok(self)
+    }
+
     /// Set the localization dictionary for the command name.
     ///
     /// Defaults to [`None`].
@@ -98,15 +148,22 @@ impl<'a> CreateGlobalUserCommand<'a> {
 
 impl TryIntoRequest for CreateGlobalUserCommand<'_> {
     fn try_into_request(self) -> Result<Request, Error> {
+        self.contexts.validate().map_err(|source| Error {
+            kind: ErrorType::BuildingRequest,
+            source: Some(Box::new(source)),
+        })?;
+
         Request::builder(&Route::CreateGlobalCommand {
             application_id: self.application_id.get(),
         })
         .json(&CommandBorrowed {
             application_id: Some(self.application_id),
+            contexts: self.contexts.contexts,
             default_member_permissions: self.default_member_permissions,
             dm_permission: self.dm_permission,
             description: None,
             description_localizations: None,
+            integration_types: self.contexts.integration_types,
             kind: CommandType::User,
             name: self.name,
             name_localizations: self.name_localizations,
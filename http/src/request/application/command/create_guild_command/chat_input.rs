@@ -150,10 +150,12 @@ impl TryIntoRequest for CreateGuildChatInputCommand<'_> {
         })
         .json(&CommandBorrowed {
             application_id: Some(self.application_id),
+            contexts: None,
             default_member_permissions: self.default_member_permissions,
             dm_permission: None,
             description: Some(self.description),
             description_localizations: self.description_localizations,
+            integration_types: None,
             kind: CommandType::ChatInput,
             name: self.name,
             name_localizations: self.name_localizations,
@@ -99,10 +99,12 @@ impl TryIntoRequest for CreateGuildMessageCommand<'_> {
         })
         .json(&CommandBorrowed {
             application_id: Some(self.application_id),
+            contexts: None,
             default_member_permissions: self.default_member_permissions,
             dm_permission: None,
             description: None,
             description_localizations: None,
+            integration_types: None,
             kind: CommandType::Message,
             name: self.name,
             name_localizations: self.name_localizations,
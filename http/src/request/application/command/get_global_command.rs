@@ -14,6 +14,13 @@ use twilight_model::{
 };
 
 /// Retrieve a global command for an application.
+///
+/// # Errors
+///
+/// Returns an error of type [`Response`] with a status code of 404 if the
+/// command doesn't exist.
+///
+/// [`Response`]: crate::error::ErrorType::Response
 #[must_use = "requests must be configured and executed"]
 pub struct GetGlobalCommand<'a> {
     application_id: Id<ApplicationMarker>,
@@ -55,3 +62,70 @@ impl TryIntoRequest for GetGlobalCommand<'_> {
         }))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::GetGlobalCommand;
+    use crate::{
+        client::Client,
+        error::ErrorType,
+        request::{Request, TryIntoRequest},
+        response::Response,
+        routing::Route,
+    };
+    use hyper::{Body, Response as HyperResponse};
+    use std::error::Error;
+    use twilight_model::{application::command::Command, id::Id};
+
+    #[test]
+    fn request() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("foo".to_owned());
+        let actual = GetGlobalCommand::new(&client, Id::new(1), Id::new(2)).try_into_request()?;
+        let expected = Request::from_route(&Route::GetGlobalCommand {
+            application_id: 1,
+            command_id: 2,
+        });
+
+        assert_eq!(expected.path, actual.path);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn response() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let body = r#"{
+            "application_id": "1",
+            "default_member_permissions": null,
+            "description": "Ping the bot",
+            "dm_permission": null,
+            "id": "2",
+            "name": "ping",
+            "type": 1,
+            "version": "4"
+        }"#;
+
+        let response = Response::<Command>::new(HyperResponse::new(Body::from(body)));
+        let command = response.model().await?;
+
+        assert_eq!(Some(Id::new(2)), command.id);
+        assert_eq!("ping", command.name);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn response_unknown_command() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let client = super::super::tests::unknown_command_client().await?;
+        let error = GetGlobalCommand::new(&client, Id::new(1), Id::new(2))
+            .exec()
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            error.kind(),
+            ErrorType::Response { status, .. } if status.get() == 404
+        ));
+
+        Ok(())
+    }
+}
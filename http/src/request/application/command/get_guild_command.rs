@@ -13,7 +13,14 @@ use twilight_model::{
     },
 };
 
-/// Retrieve a global command for an application.
+/// Retrieve a guild command for an application.
+///
+/// # Errors
+///
+/// Returns an error of type [`Response`] with a status code of 404 if the
+/// command doesn't exist in the guild.
+///
+/// [`Response`]: crate::error::ErrorType::Response
 #[must_use = "requests must be configured and executed"]
 pub struct GetGuildCommand<'a> {
     application_id: Id<ApplicationMarker>,
@@ -59,3 +66,74 @@ impl TryIntoRequest for GetGuildCommand<'_> {
         }))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::GetGuildCommand;
+    use crate::{
+        client::Client,
+        error::ErrorType,
+        request::{Request, TryIntoRequest},
+        response::Response,
+        routing::Route,
+    };
+    use hyper::{Body, Response as HyperResponse};
+    use std::error::Error;
+    use twilight_model::{application::command::Command, id::Id};
+
+    #[test]
+    fn request() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("foo".to_owned());
+        let actual =
+            GetGuildCommand::new(&client, Id::new(1), Id::new(2), Id::new(3)).try_into_request()?;
+        let expected = Request::from_route(&Route::GetGuildCommand {
+            application_id: 1,
+            command_id: 3,
+            guild_id: 2,
+        });
+
+        assert_eq!(expected.path, actual.path);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn response() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let body = r#"{
+            "application_id": "1",
+            "default_member_permissions": null,
+            "description": "Ping the bot",
+            "dm_permission": null,
+            "guild_id": "2",
+            "id": "3",
+            "name": "ping",
+            "type": 1,
+            "version": "4"
+        }"#;
+
+        let response = Response::<Command>::new(HyperResponse::new(Body::from(body)));
+        let command = response.model().await?;
+
+        assert_eq!(Some(Id::new(3)), command.id);
+        assert_eq!(Some(Id::new(2)), command.guild_id);
+        assert_eq!("ping", command.name);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn response_unknown_command() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let client = super::super::tests::unknown_command_client().await?;
+        let error = GetGuildCommand::new(&client, Id::new(1), Id::new(2), Id::new(3))
+            .exec()
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            error.kind(),
+            ErrorType::Response { status, .. } if status.get() == 404
+        ));
+
+        Ok(())
+    }
+}
@@ -29,10 +29,88 @@ pub use self::{
 use serde::Serialize;
 use std::collections::HashMap;
 use twilight_model::{
-    application::command::{CommandOption, CommandType},
+    application::{
+        command::{CommandOption, CommandType},
+        interaction::InteractionContextType,
+    },
     guild::Permissions,
     id::{marker::ApplicationMarker, Id},
+    oauth::ApplicationIntegrationType,
 };
+use twilight_validate::command::{
+    contexts as validate_contexts,
+    contexts_integration_types as validate_contexts_integration_types,
+    integration_types as validate_integration_types, CommandValidationError,
+};
+
+/// Contexts and integration types of a global command, which are validated
+/// against each other.
+struct CommandContexts<'a> {
+    contexts: Option<&'a [InteractionContextType]>,
+    integration_types: Option<&'a [ApplicationIntegrationType]>,
+    /// Whether unset integration types are Discord's default rather than the
+    /// command's existing ones.
+    default_integration_types: bool,
+}
+
+impl<'a> CommandContexts<'a> {
+    /// Contexts of a command being created, whose integration types default
+    /// to Discord's default.
+    const fn create() -> Self {
+        Self {
+            contexts: None,
+            default_integration_types: true,
+            integration_types: None,
+        }
+    }
+
+    /// Contexts of a command being updated, whose integration types may
+    /// already be set.
+    const fn update() -> Self {
+        Self {
+            contexts: None,
+            default_integration_types: false,
+            integration_types: None,
+        }
+    }
+
+    fn set_contexts(
+        &mut self,
+        contexts: &'a [InteractionContextType],
+    ) -> Result<(), CommandValidationError> {
+        validate_contexts(contexts)?;
+
+        self.contexts = Some(contexts);
+
+        Ok(())
+    }
+
+    fn set_integration_types(
+        &mut self,
+        integration_types: &'a [ApplicationIntegrationType],
+    ) -> Result<(), CommandValidationError> {
+        validate_integration_types(integration_types)?;
+
+        self.integration_types = Some(integration_types);
+
+        Ok(())
+    }
+
+    /// Validate the contexts against the integration types once both are
+    /// final, so that they may be set in any order.
+    fn validate(&self) -> Result<(), CommandValidationError> {
+        let contexts = match self.contexts {
+            Some(contexts) => contexts,
+            None => return Ok(()),
+        };
+
+        if !self.default_integration_types && self.integration_types.is_none() {
+            return Ok(());
+        }
+
+        validate_contexts_integration_types(contexts, self.integration_types)
+    }
+}
 
 /// Version of [`Command`] but with borrowed fields.
 ///
@@ -42,6 +120,8 @@ struct CommandBorrowed<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub application_id: Option<Id<ApplicationMarker>>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub contexts: Option<&'a [InteractionContextType]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub default_member_permissions: Option<Permissions>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub dm_permission: Option<bool>,
@@ -49,6 +129,8 @@ struct CommandBorrowed<'a> {
     pub description: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description_localizations: Option<&'a HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub integration_types: Option<&'a [ApplicationIntegrationType]>,
     #[serde(rename = "type")]
     pub kind: CommandType,
     pub name: &'a str,
@@ -60,13 +142,104 @@ struct CommandBorrowed<'a> {
 
 #[cfg(test)]
 mod tests {
-    use super::CommandBorrowed;
-    use std::collections::HashMap;
+    use super::{CommandBorrowed, CommandContexts};
+    use crate::Client;
+    use std::{collections::HashMap, error::Error as StdError, io::Error as IoError};
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
     use twilight_model::{
-        application::command::{BaseCommandOptionData, Command, CommandOption, CommandType},
+        application::{
+            command::{BaseCommandOptionData, Command, CommandOption, CommandType},
+            interaction::InteractionContextType,
+        },
         guild::Permissions,
         id::Id,
+        oauth::ApplicationIntegrationType,
     };
+    use twilight_validate::command::CommandValidationErrorType;
+
+    /// Create a client whose first request is answered with a 404 for an
+    /// unknown command by a local server.
+    pub(super) async fn unknown_command_client() -> Result<Client, IoError> {
+        const RESPONSE: &str = "HTTP/1.1 404 Not Found\r\n\
+            content-type: application/json\r\n\
+            content-length: 54\r\n\r\n\
+            {\"code\":10063,\"message\":\"Unknown application command\"}";
+
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let address = listener.local_addr()?;
+
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await?;
+            let mut request = Vec::new();
+            let mut buf = [0; 1024];
+
+            // Wait for the end of the request's headers; it has no body.
+            while !request.ends_with(b"\r\n\r\n") {
+                let read = stream.read(&mut buf).await?;
+
+                if read == 0 {
+                    break;
+                }
+
+                request.extend_from_slice(&buf[..read]);
+            }
+
+            stream.write_all(RESPONSE.as_bytes()).await?;
+
+            Ok::<_, IoError>(())
+        });
+
+        Ok(Client::builder()
+            .proxy(address.to_string(), true)
+            .ratelimiter(None)
+            .build())
+    }
+
+    /// Test that contexts and integration types may be set in any order, and
+    /// that created commands are only installable to guilds unless specified
+    /// otherwise, while updated commands may already be installable to users.
+    #[test]
+    fn contexts_integration_types() -> Result<(), Box<dyn StdError>> {
+        const CONTEXTS: &[InteractionContextType] = &[InteractionContextType::PrivateChannel];
+        const GUILD_INSTALL: &[ApplicationIntegrationType] =
+            &[ApplicationIntegrationType::GuildInstall];
+        const USER_INSTALL: &[ApplicationIntegrationType] =
+            &[ApplicationIntegrationType::UserInstall];
+
+        let mut contexts = CommandContexts::create();
+        contexts.set_contexts(CONTEXTS)?;
+        contexts.set_integration_types(USER_INSTALL)?;
+        contexts.validate()?;
+
+        let mut contexts = CommandContexts::create();
+        contexts.set_integration_types(USER_INSTALL)?;
+        contexts.set_contexts(CONTEXTS)?;
+        contexts.validate()?;
+
+        let mut contexts = CommandContexts::create();
+        contexts.set_contexts(CONTEXTS)?;
+        assert_private_channel_invalid(&contexts);
+        contexts.set_integration_types(GUILD_INSTALL)?;
+        assert_private_channel_invalid(&contexts);
+
+        let mut contexts = CommandContexts::update();
+        contexts.set_contexts(CONTEXTS)?;
+        contexts.validate()?;
+        contexts.set_integration_types(GUILD_INSTALL)?;
+        assert_private_channel_invalid(&contexts);
+
+        Ok(())
+    }
+
+    fn assert_private_channel_invalid(contexts: &CommandContexts<'_>) {
+        assert!(matches!(
+            contexts.validate().unwrap_err().kind(),
+            CommandValidationErrorType::PrivateChannelContextInvalid
+        ));
+    }
 
     /// Test to convert a `Command` to a `CommandBorrowed`.
     ///
@@ -77,6 +250,7 @@ mod tests {
     fn command_borrowed_from_command() {
         let command = Command {
             application_id: Some(Id::new(1)),
+            contexts: None,
             default_member_permissions: Some(Permissions::ADMINISTRATOR),
             dm_permission: Some(true),
             description: "command description".to_owned(),
@@ -86,6 +260,7 @@ mod tests {
             )])),
             guild_id: Some(Id::new(2)),
             id: Some(Id::new(3)),
+            integration_types: None,
             kind: CommandType::ChatInput,
             name: "command name".to_owned(),
             name_localizations: Some(HashMap::from([(
@@ -104,10 +279,12 @@ mod tests {
 
         let _ = CommandBorrowed {
             application_id: command.application_id,
+            contexts: command.contexts.as_deref(),
             default_member_permissions: command.default_member_permissions,
             dm_permission: command.dm_permission,
             description: Some(&command.description),
             description_localizations: command.description_localizations.as_ref(),
+            integration_types: command.integration_types.as_deref(),
             kind: CommandType::ChatInput,
             name: &command.name,
             name_localizations: command.name_localizations.as_ref(),
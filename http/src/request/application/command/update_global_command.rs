@@ -1,24 +1,34 @@
+use super::CommandContexts;
 use crate::{
     client::Client,
-    error::Error,
+    error::{Error, ErrorType},
     request::{Request, RequestBuilder, TryIntoRequest},
     response::ResponseFuture,
     routing::Route,
 };
 use serde::Serialize;
 use twilight_model::{
-    application::command::{Command, CommandOption},
+    application::{
+        command::{Command, CommandOption},
+        interaction::InteractionContextType,
+    },
     id::{
         marker::{ApplicationMarker, CommandMarker},
         Id,
     },
+    oauth::ApplicationIntegrationType,
 };
+use twilight_validate::command::CommandValidationError;
 
 #[derive(Serialize)]
 struct UpdateGlobalCommandFields<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    contexts: Option<&'a [InteractionContextType]>,
     #[serde(skip_serializing_if = "Option::is_none")]
     description: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    integration_types: Option<&'a [ApplicationIntegrationType]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     name: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     options: Option<&'a [CommandOption]>,
@@ -32,6 +42,7 @@ struct UpdateGlobalCommandFields<'a> {
 /// [Discord Docs/Edit Global Application Command]: https://discord.com/developers/docs/interactions/application-commands#edit-global-application-command
 #[must_use = "requests must be configured and executed"]
 pub struct UpdateGlobalCommand<'a> {
+    contexts: CommandContexts<'a>,
     fields: UpdateGlobalCommandFields<'a>,
     command_id: Id<CommandMarker>,
     application_id: Id<ApplicationMarker>,
@@ -47,8 +58,11 @@ impl<'a> UpdateGlobalCommand<'a> {
         Self {
             application_id,
             command_id,
+            contexts: CommandContexts::update(),
             fields: UpdateGlobalCommandFields {
+                contexts: None,
                 description: None,
+                integration_types: None,
                 name: None,
                 options: None,
             },
@@ -77,6 +91,46 @@ impl<'a> UpdateGlobalCommand<'a> {
         self
     }
 
+    /// Edit the contexts in which the command can be invoked.
+    ///
+    /// If the integration types are edited as well, the contexts must be
+    /// allowed by them, otherwise building the request fails with an error of
+    /// type [`BuildingRequest`]; refer to [`contexts_integration_types`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the contexts are invalid. Refer to [`contexts`] for
+    /// the error types.
+    ///
+    /// [`BuildingRequest`]: crate::error::ErrorType::BuildingRequest
+    /// [`contexts`]: twilight_validate::command::contexts
+    /// [`contexts_integration_types`]: twilight_validate::command::contexts_integration_types
+    pub fn contexts(
+        mut self,
+        contexts: &'a [InteractionContextType],
+    ) -> Result<Self, CommandValidationError> {
+        self.contexts.set_contexts(contexts)?;
+
+        Ok(self)
+    }
+
+    /// Edit the installation contexts where the command is available.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the integration types are invalid. Refer to
+    /// [`integration_types`] for the error types.
+    ///
+    /// [`integration_types`]: twilight_validate::command::integration_types
+    pub fn integration_types(
+        mut self,
+        integration_types: &'a [ApplicationIntegrationType],
+    ) -> Result<Self, CommandValidationError> {
+        self.contexts.set_integration_types(integration_types)?;
+
+        Ok(self)
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -91,7 +145,15 @@ impl<'a> UpdateGlobalCommand<'a> {
 }
 
 impl TryIntoRequest for UpdateGlobalCommand<'_> {
-    fn try_into_request(self) -> Result<Request, Error> {
+    fn try_into_request(mut self) -> Result<Request, Error> {
+        self.contexts.validate().map_err(|source| Error {
+            kind: ErrorType::BuildingRequest,
+            source: Some(Box::new(source)),
+        })?;
+
+        self.fields.contexts = self.contexts.contexts;
+        self.fields.integration_types = self.contexts.integration_types;
+
         Request::builder(&Route::UpdateGlobalCommand {
             application_id: self.application_id.get(),
             command_id: self.command_id.get(),
@@ -100,3 +162,68 @@ impl TryIntoRequest for UpdateGlobalCommand<'_> {
         .map(RequestBuilder::build)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::UpdateGlobalCommand;
+    use crate::{
+        client::Client,
+        error::ErrorType,
+        request::{Request, TryIntoRequest},
+        routing::Route,
+    };
+    use std::error::Error;
+    use twilight_model::{
+        application::interaction::InteractionContextType, id::Id, oauth::ApplicationIntegrationType,
+    };
+    use twilight_validate::command::{CommandValidationError, CommandValidationErrorType};
+
+    #[test]
+    fn integration_types_and_contexts() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("token".to_owned());
+        let actual = UpdateGlobalCommand::new(&client, Id::new(1), Id::new(2))
+            .integration_types(&[
+                ApplicationIntegrationType::GuildInstall,
+                ApplicationIntegrationType::UserInstall,
+            ])?
+            .contexts(&[
+                InteractionContextType::Guild,
+                InteractionContextType::BotDm,
+                InteractionContextType::PrivateChannel,
+            ])?
+            .try_into_request()?;
+        let expected = Request::from_route(&Route::UpdateGlobalCommand {
+            application_id: 1,
+            command_id: 2,
+        });
+
+        assert_eq!(expected.path, actual.path);
+        assert_eq!(
+            Some(br#"{"contexts":[0,1,2],"integration_types":[0,1]}"#.as_slice()),
+            actual.body()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn private_channel_requires_user_install() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("token".to_owned());
+        let error = UpdateGlobalCommand::new(&client, Id::new(1), Id::new(2))
+            .contexts(&[InteractionContextType::PrivateChannel])?
+            .integration_types(&[ApplicationIntegrationType::GuildInstall])?
+            .try_into_request()
+            .unwrap_err();
+
+        assert!(matches!(error.kind(), ErrorType::BuildingRequest));
+        assert!(matches!(
+            error
+                .into_source()
+                .and_then(|source| source.downcast::<CommandValidationError>().ok())
+                .map(|source| source.into_parts().0),
+            Some(CommandValidationErrorType::PrivateChannelContextInvalid)
+        ));
+
+        Ok(())
+    }
+}
@@ -1,16 +1,27 @@
 use crate::{
     client::Client,
-    error::Error as HttpError,
+    error::{Error as HttpError, ErrorType},
     request::{attachment::AttachmentManager, Request, TryIntoRequest},
     response::{marker::EmptyBody, ResponseFuture},
     routing::Route,
 };
 use twilight_model::{
-    http::interaction::InteractionResponse,
+    http::interaction::{InteractionResponse, InteractionResponseType},
     id::{marker::InteractionMarker, Id},
 };
+use twilight_validate::component::modal_components;
 
 /// Respond to an interaction, by its ID and token.
+///
+/// # Errors
+///
+/// Returns an error of type [`BuildingRequest`] if the response is a
+/// [`Modal`] with invalid components. Refer to
+/// [`twilight_validate::component::modal_components`] for a list of errors
+/// that may occur.
+///
+/// [`BuildingRequest`]: ErrorType::BuildingRequest
+/// [`Modal`]: InteractionResponseType::Modal
 #[must_use = "requests must be configured and executed"]
 pub struct CreateResponse<'a> {
     interaction_id: Id<InteractionMarker>,
@@ -49,6 +60,20 @@ impl<'a> CreateResponse<'a> {
 
 impl TryIntoRequest for CreateResponse<'_> {
     fn try_into_request(self) -> Result<Request, HttpError> {
+        if self.response.kind == InteractionResponseType::Modal {
+            if let Some(components) = self
+                .response
+                .data
+                .as_ref()
+                .and_then(|data| data.components.as_ref())
+            {
+                modal_components(components).map_err(|source| HttpError {
+                    kind: ErrorType::BuildingRequest,
+                    source: Some(Box::new(source)),
+                })?;
+            }
+        }
+
         let mut request = Request::builder(&Route::InteractionCallback {
             interaction_id: self.interaction_id.get(),
             interaction_token: self.interaction_token,
@@ -83,11 +108,18 @@ impl TryIntoRequest for CreateResponse<'_> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{client::Client, request::TryIntoRequest};
+    use crate::{client::Client, error::ErrorType, request::TryIntoRequest};
     use std::error::Error;
     use twilight_http_ratelimiting::Path;
     use twilight_model::{
-        http::interaction::{InteractionResponse, InteractionResponseType},
+        application::component::{
+            button::ButtonStyle,
+            text_input::{TextInput, TextInputStyle},
+            ActionRow, Button, Component,
+        },
+        http::interaction::{
+            InteractionResponse, InteractionResponseData, InteractionResponseType,
+        },
         id::Id,
     };
 
@@ -117,4 +149,69 @@ mod tests {
 
         Ok(())
     }
+
+    fn modal(components: Vec<Component>) -> InteractionResponse {
+        InteractionResponse {
+            kind: InteractionResponseType::Modal,
+            data: Some(InteractionResponseData {
+                components: Some(Vec::from([Component::ActionRow(ActionRow { components })])),
+                custom_id: Some("feedback".to_owned()),
+                title: Some("Feedback".to_owned()),
+                ..InteractionResponseData::default()
+            }),
+        }
+    }
+
+    #[test]
+    fn modal_callback() -> Result<(), Box<dyn Error>> {
+        let client = Client::new(String::new());
+        let response = modal(Vec::from([Component::TextInput(TextInput {
+            custom_id: "comment".to_owned(),
+            label: "Comment".to_owned(),
+            max_length: None,
+            min_length: None,
+            placeholder: None,
+            required: Some(true),
+            style: TextInputStyle::Paragraph,
+            value: None,
+        })]));
+
+        let req = client
+            .interaction(Id::new(1))
+            .create_response(Id::new(2), "foo", &response)
+            .try_into_request()?;
+
+        assert_eq!(
+            Some(
+                br#"{"type":9,"data":{"components":[{"type":1,"components":[{"type":4,"custom_id":"comment","label":"Comment","required":true,"style":2}]}],"custom_id":"feedback","title":"Feedback"}}"#
+                    .as_slice()
+            ),
+            req.body()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn modal_callback_invalid_component() {
+        let client = Client::new(String::new());
+        let response = modal(Vec::from([Component::Button(Button {
+            custom_id: Some("submit".to_owned()),
+            disabled: false,
+            emoji: None,
+            label: Some("Submit".to_owned()),
+            style: ButtonStyle::Primary,
+            url: None,
+        })]));
+
+        let result = client
+            .interaction(Id::new(1))
+            .create_response(Id::new(2), "foo", &response)
+            .try_into_request();
+
+        assert!(matches!(
+            result.map_err(|error| error.into_parts().0),
+            Err(ErrorType::BuildingRequest)
+        ));
+    }
 }
@@ -75,13 +75,17 @@ impl TryIntoRequest for DeleteResponse<'_> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{client::Client, request::TryIntoRequest};
+    use crate::{
+        client::Client,
+        request::{Request, TryIntoRequest},
+        routing::Route,
+    };
     use std::error::Error;
     use twilight_http_ratelimiting::Path;
     use twilight_model::id::Id;
 
     #[test]
-    fn delete_followup_message() -> Result<(), Box<dyn Error>> {
+    fn delete_response() -> Result<(), Box<dyn Error>> {
         let application_id = Id::new(1);
         let token = "foo".to_owned();
 
@@ -97,6 +101,14 @@ mod tests {
             req.ratelimit_path()
         );
 
+        let expected = Request::from_route(&Route::DeleteInteractionOriginal {
+            application_id: application_id.get(),
+            interaction_token: "foo",
+        });
+
+        assert_eq!(expected.path, req.path);
+        assert_eq!(expected.method, req.method);
+
         Ok(())
     }
 }
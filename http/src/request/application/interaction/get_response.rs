@@ -78,13 +78,17 @@ impl TryIntoRequest for GetResponse<'_> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{client::Client, request::TryIntoRequest};
+    use crate::{
+        client::Client,
+        request::{Request, TryIntoRequest},
+        routing::Route,
+    };
     use std::error::Error;
     use twilight_http_ratelimiting::Path;
     use twilight_model::id::Id;
 
     #[test]
-    fn delete_followup_message() -> Result<(), Box<dyn Error>> {
+    fn get_response() -> Result<(), Box<dyn Error>> {
         let application_id = Id::new(1);
         let token = "foo".to_owned();
 
@@ -100,6 +104,14 @@ mod tests {
             req.ratelimit_path()
         );
 
+        let expected = Request::from_route(&Route::GetInteractionOriginal {
+            application_id: application_id.get(),
+            interaction_token: "foo",
+        });
+
+        assert_eq!(expected.path, req.path);
+        assert_eq!(expected.method, req.method);
+
         Ok(())
     }
 }
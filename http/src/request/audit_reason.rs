@@ -17,7 +17,7 @@ mod private {
             UpdateChannelPermission,
         },
         guild::{
-            ban::{CreateBan, DeleteBan},
+            ban::{CreateBan, CreateBulkBan, DeleteBan},
             emoji::{CreateEmoji, DeleteEmoji, UpdateEmoji},
             integration::DeleteGuildIntegration,
             member::{AddRoleToMember, RemoveMember, RemoveRoleFromMember, UpdateGuildMember},
@@ -38,6 +38,7 @@ mod private {
 
     impl Sealed for AddRoleToMember<'_> {}
     impl Sealed for CreateBan<'_> {}
+    impl Sealed for CreateBulkBan<'_> {}
     impl Sealed for CreateEmoji<'_> {}
     impl Sealed for CreateGuildChannel<'_> {}
     impl Sealed for CreateGuildExternalScheduledEvent<'_> {}
@@ -90,7 +91,7 @@ mod tests {
             UpdateChannelPermission,
         },
         guild::{
-            ban::{CreateBan, DeleteBan},
+            ban::{CreateBan, CreateBulkBan, DeleteBan},
             emoji::{CreateEmoji, DeleteEmoji, UpdateEmoji},
             integration::DeleteGuildIntegration,
             member::{AddRoleToMember, RemoveMember, RemoveRoleFromMember, UpdateGuildMember},
@@ -106,6 +107,7 @@ mod tests {
 
     assert_impl_all!(AddRoleToMember<'_>: AuditLogReason<'static>);
     assert_impl_all!(CreateBan<'_>: AuditLogReason<'static>);
+    assert_impl_all!(CreateBulkBan<'_>: AuditLogReason<'static>);
     assert_impl_all!(CreateEmoji<'_>: AuditLogReason<'static>);
     assert_impl_all!(CreateGuildChannel<'_>: AuditLogReason<'static>);
     assert_impl_all!(CreateGuildPrune<'_>: AuditLogReason<'static>);
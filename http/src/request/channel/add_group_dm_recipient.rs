@@ -0,0 +1,106 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Request, TryIntoRequest},
+    response::{marker::EmptyBody, ResponseFuture},
+    routing::Route,
+};
+use serde::Serialize;
+use twilight_model::id::{
+    marker::{ChannelMarker, UserMarker},
+    Id,
+};
+
+#[derive(Serialize)]
+struct AddGroupDmRecipientFields<'a> {
+    access_token: &'a str,
+    nick: &'a str,
+}
+
+/// Add a user to a group DM.
+///
+/// An access token for the user with the `gdm.join` scope is required, which
+/// must have been granted to the application making the request. See
+/// [Discord Docs/Group DM Add Recipient].
+///
+/// [Discord Docs/Group DM Add Recipient]: https://discord.com/developers/docs/resources/channel#group-dm-add-recipient
+#[must_use = "requests must be configured and executed"]
+pub struct AddGroupDmRecipient<'a> {
+    channel_id: Id<ChannelMarker>,
+    fields: AddGroupDmRecipientFields<'a>,
+    http: &'a Client,
+    user_id: Id<UserMarker>,
+}
+
+impl<'a> AddGroupDmRecipient<'a> {
+    pub(crate) const fn new(
+        http: &'a Client,
+        channel_id: Id<ChannelMarker>,
+        user_id: Id<UserMarker>,
+        access_token: &'a str,
+        nick: &'a str,
+    ) -> Self {
+        Self {
+            channel_id,
+            fields: AddGroupDmRecipientFields { access_token, nick },
+            http,
+            user_id,
+        }
+    }
+
+    /// Execute the request, returning a future resolving to a [`Response`].
+    ///
+    /// [`Response`]: crate::response::Response
+    pub fn exec(self) -> ResponseFuture<EmptyBody> {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl TryIntoRequest for AddGroupDmRecipient<'_> {
+    fn try_into_request(self) -> Result<Request, Error> {
+        let mut request = Request::builder(&Route::AddGroupDmRecipient {
+            channel_id: self.channel_id.get(),
+            user_id: self.user_id.get(),
+        });
+
+        request = request.json(&self.fields)?;
+
+        Ok(request.build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AddGroupDmRecipient;
+    use crate::{
+        client::Client,
+        request::{Request, TryIntoRequest},
+        routing::Route,
+    };
+    use std::error::Error;
+    use twilight_model::id::Id;
+
+    #[test]
+    fn request() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("foo".to_owned());
+        let actual = AddGroupDmRecipient::new(&client, Id::new(1), Id::new(2), "token", "nick")
+            .try_into_request()?;
+        let expected = Request::from_route(&Route::AddGroupDmRecipient {
+            channel_id: 1,
+            user_id: 2,
+        });
+
+        assert_eq!(expected.path, actual.path);
+        assert_eq!(
+            Some(br#"{"access_token":"token","nick":"nick"}"#.to_vec()),
+            actual.body
+        );
+
+        Ok(())
+    }
+}
@@ -47,3 +47,26 @@ impl TryIntoRequest for GetChannelInvites<'_> {
         }))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::GetChannelInvites;
+    use crate::{
+        client::Client,
+        request::{Request, TryIntoRequest},
+        routing::Route,
+    };
+    use std::error::Error;
+    use twilight_model::id::Id;
+
+    #[test]
+    fn request() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("foo".to_owned());
+        let actual = GetChannelInvites::new(&client, Id::new(1)).try_into_request()?;
+        let expected = Request::from_route(&Route::GetChannelInvites { channel_id: 1 });
+
+        assert_eq!(expected.path, actual.path);
+
+        Ok(())
+    }
+}
@@ -5,9 +5,13 @@ use crate::{
     response::ResponseFuture,
     routing::Route,
 };
-use twilight_model::invite::Invite;
+use twilight_model::{
+    id::{marker::ScheduledEventMarker, Id},
+    invite::Invite,
+};
 
 struct GetInviteFields {
+    guild_scheduled_event_id: Option<Id<ScheduledEventMarker>>,
     with_counts: bool,
     with_expiration: bool,
 }
@@ -16,7 +20,8 @@ struct GetInviteFields {
 ///
 /// If [`with_counts`] is called, the returned invite will contain approximate
 /// member counts. If [`with_expiration`] is called, it will contain the
-/// expiration date.
+/// expiration date. If [`guild_scheduled_event_id`] is called, it will contain
+/// the guild scheduled event.
 ///
 /// # Examples
 ///
@@ -35,6 +40,7 @@ struct GetInviteFields {
 /// # Ok(()) }
 /// ```
 ///
+/// [`guild_scheduled_event_id`]: Self::guild_scheduled_event_id
 /// [`with_counts`]: Self::with_counts
 /// [`with_expiration`]: Self::with_expiration
 #[must_use = "requests must be configured and executed"]
@@ -49,6 +55,7 @@ impl<'a> GetInvite<'a> {
         Self {
             code,
             fields: GetInviteFields {
+                guild_scheduled_event_id: None,
                 with_counts: false,
                 with_expiration: false,
             },
@@ -56,6 +63,17 @@ impl<'a> GetInvite<'a> {
         }
     }
 
+    /// Include the guild scheduled event with the given ID in the returned
+    /// invite.
+    pub const fn guild_scheduled_event_id(
+        mut self,
+        guild_scheduled_event_id: Id<ScheduledEventMarker>,
+    ) -> Self {
+        self.fields.guild_scheduled_event_id = Some(guild_scheduled_event_id);
+
+        self
+    }
+
     /// Whether the invite returned should contain approximate member counts.
     pub const fn with_counts(mut self) -> Self {
         self.fields.with_counts = true;
@@ -87,8 +105,47 @@ impl TryIntoRequest for GetInvite<'_> {
     fn try_into_request(self) -> Result<Request, Error> {
         Ok(Request::from_route(&Route::GetInviteWithExpiration {
             code: self.code,
+            guild_scheduled_event_id: self.fields.guild_scheduled_event_id.map(Id::get),
             with_counts: self.fields.with_counts,
             with_expiration: self.fields.with_expiration,
         }))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::GetInvite;
+    use crate::{
+        client::Client,
+        request::{Request, TryIntoRequest},
+        routing::Route,
+    };
+    use std::error::Error;
+    use twilight_model::id::Id;
+
+    #[test]
+    fn guild_scheduled_event_id() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("foo".to_owned());
+
+        let actual = GetInvite::new(&client, "code")
+            .guild_scheduled_event_id(Id::new(1))
+            .with_counts()
+            .with_expiration()
+            .try_into_request()?;
+
+        let expected = Request::from_route(&Route::GetInviteWithExpiration {
+            code: "code",
+            guild_scheduled_event_id: Some(1),
+            with_counts: true,
+            with_expiration: true,
+        });
+
+        assert_eq!(expected.path, actual.path);
+        assert_eq!(
+            "invites/code?guild_scheduled_event_id=1&with-counts=true&with-expiration=true",
+            actual.path()
+        );
+
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
     client::Client,
-    error::Error as HttpError,
+    error::{Error as HttpError, ErrorType},
     request::{
         attachment::{AttachmentManager, PartialAttachment},
         Nullable, Request, TryIntoRequest,
@@ -13,10 +13,10 @@ use twilight_model::{
     application::component::Component,
     channel::{
         embed::Embed,
-        message::{AllowedMentions, MessageFlags, MessageReference},
+        message::{AllowedMentions, MessageFlags, MessageNonce, MessageReference},
         Message,
     },
-    http::attachment::Attachment,
+    http::{attachment::Attachment, poll::PollCreateRequest},
     id::{
         marker::{ChannelMarker, MessageMarker, StickerMarker},
         Id,
@@ -24,7 +24,8 @@ use twilight_model::{
 };
 use twilight_validate::message::{
     attachment_filename as validate_attachment_filename, components as validate_components,
-    content as validate_content, embeds as validate_embeds, sticker_ids as validate_sticker_ids,
+    content as validate_content, embeds as validate_embeds, nonce as validate_nonce,
+    not_empty as validate_not_empty, poll as validate_poll, sticker_ids as validate_sticker_ids,
     MessageValidationError,
 };
 
@@ -45,10 +46,12 @@ pub(crate) struct CreateMessageFields<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     message_reference: Option<MessageReference>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    nonce: Option<u64>,
+    nonce: Option<MessageNonce>,
     #[serde(skip_serializing_if = "Option::is_none")]
     payload_json: Option<&'a [u8]>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    poll: Option<&'a PollCreateRequest>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     sticker_ids: Option<&'a [Id<StickerMarker>]>,
     #[serde(skip_serializing_if = "Option::is_none")]
     tts: Option<bool>,
@@ -56,8 +59,13 @@ pub(crate) struct CreateMessageFields<'a> {
 
 /// Send a message to a channel.
 ///
-/// The message must include at least one of [`attachments`], [`content`],
-/// [`embeds`], or [`sticker_ids`].
+/// The message must include at least one of [`attachments`], [`components`],
+/// [`content`], [`embeds`], [`poll`], or [`sticker_ids`].
+///
+/// # Errors
+///
+/// Returns an error of type [`BuildingRequest`] if the message is empty.
+/// Messages with a [`payload_json`] aren't checked.
 ///
 /// # Example
 ///
@@ -78,9 +86,13 @@ pub(crate) struct CreateMessageFields<'a> {
 /// # Ok(()) }
 /// ```
 ///
+/// [`BuildingRequest`]: ErrorType::BuildingRequest
 /// [`attachments`]: Self::attachments
+/// [`components`]: Self::components
 /// [`content`]: Self::content
 /// [`embeds`]: Self::embeds
+/// [`payload_json`]: Self::payload_json
+/// [`poll`]: Self::poll
 /// [`sticker_ids`]: Self::sticker_ids
 #[must_use = "requests must be configured and executed"]
 pub struct CreateMessage<'a> {
@@ -104,6 +116,7 @@ impl<'a> CreateMessage<'a> {
                 message_reference: None,
                 nonce: None,
                 payload_json: None,
+                poll: None,
                 allowed_mentions: None,
                 sticker_ids: None,
                 tts: None,
@@ -250,10 +263,26 @@ impl<'a> CreateMessage<'a> {
     }
 
     /// Attach a nonce to the message, for optimistic message sending.
-    pub const fn nonce(mut self, nonce: u64) -> Self {
+    ///
+    /// The nonce is either an integer or a string, and is echoed back in the
+    /// type it was sent as in the [`Message::nonce`] of the created message.
+    /// String nonces must be at most [`MESSAGE_NONCE_LENGTH_MAX`] characters
+    /// long.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`NonceInvalid`] if a string nonce is too
+    /// long.
+    ///
+    /// [`MESSAGE_NONCE_LENGTH_MAX`]: twilight_validate::message::MESSAGE_NONCE_LENGTH_MAX
+    /// [`NonceInvalid`]: twilight_validate::message::MessageValidationErrorType::NonceInvalid
+    pub fn nonce(mut self, nonce: impl Into<MessageNonce>) -> Result<Self, MessageValidationError> {
+        let nonce = nonce.into();
+        validate_nonce(&nonce)?;
+
         self.fields.nonce = Some(nonce);
 
-        self
+        Ok(self)
     }
 
     /// JSON encoded body of any additional request fields.
@@ -274,6 +303,34 @@ impl<'a> CreateMessage<'a> {
         self
     }
 
+    /// Attach a poll to the message.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`PollAnswerCount`] if the poll has less than
+    /// 1 or more than 10 answers.
+    ///
+    /// Returns an error of type [`PollAnswerInvalid`] if an answer's text is
+    /// empty or longer than 55 characters.
+    ///
+    /// Returns an error of type [`PollDurationInvalid`] if the duration is
+    /// longer than 32 days.
+    ///
+    /// Returns an error of type [`PollQuestionInvalid`] if the question's text
+    /// is missing, empty, or longer than 300 characters.
+    ///
+    /// [`PollAnswerCount`]: twilight_validate::message::MessageValidationErrorType::PollAnswerCount
+    /// [`PollAnswerInvalid`]: twilight_validate::message::MessageValidationErrorType::PollAnswerInvalid
+    /// [`PollDurationInvalid`]: twilight_validate::message::MessageValidationErrorType::PollDurationInvalid
+    /// [`PollQuestionInvalid`]: twilight_validate::message::MessageValidationErrorType::PollQuestionInvalid
+    pub fn poll(mut self, poll: &'a PollCreateRequest) -> Result<Self, MessageValidationError> {
+        validate_poll(poll)?;
+
+        self.fields.poll = Some(poll);
+
+        Ok(self)
+    }
+
     /// Specify the ID of another message to create a reply to.
     pub const fn reply(mut self, other: Id<MessageMarker>) -> Self {
         let channel_id = self.channel_id;
@@ -340,6 +397,20 @@ impl<'a> CreateMessage<'a> {
 
 impl TryIntoRequest for CreateMessage<'_> {
     fn try_into_request(mut self) -> Result<Request, HttpError> {
+        if self.fields.payload_json.is_none() && self.fields.poll.is_none() {
+            validate_not_empty(
+                self.fields.content.unwrap_or_default(),
+                self.fields.embeds.unwrap_or_default(),
+                self.fields.sticker_ids.unwrap_or_default(),
+                self.fields.components.unwrap_or_default(),
+                !self.attachment_manager.is_empty(),
+            )
+            .map_err(|source| HttpError {
+                kind: ErrorType::BuildingRequest,
+                source: Some(Box::new(source)),
+            })?;
+        }
+
         let mut request = Request::builder(&Route::CreateMessage {
             channel_id: self.channel_id.get(),
         });
@@ -374,3 +445,157 @@ impl TryIntoRequest for CreateMessage<'_> {
         Ok(request.build())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::CreateMessage;
+    use crate::{
+        client::Client,
+        error::ErrorType,
+        request::{Request, TryIntoRequest},
+        routing::Route,
+    };
+    use std::error::Error;
+    use twilight_model::{
+        http::poll::{PollCreateAnswer, PollCreateRequest},
+        id::Id,
+        poll::PollMedia,
+    };
+
+    #[test]
+    fn nonce() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("token".to_owned());
+
+        let integer = CreateMessage::new(&client, Id::new(1))
+            .content("hi")?
+            .nonce(123)?
+            .try_into_request()?;
+        assert_eq!(
+            Some(br#"{"content":"hi","nonce":123}"#.as_slice()),
+            integer.body()
+        );
+
+        let string = CreateMessage::new(&client, Id::new(1))
+            .content("hi")?
+            .nonce("123")?
+            .try_into_request()?;
+        assert_eq!(
+            Some(br#"{"content":"hi","nonce":"123"}"#.as_slice()),
+            string.body()
+        );
+
+        assert!(CreateMessage::new(&client, Id::new(1))
+            .nonce("a".repeat(26))
+            .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn poll() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("token".to_owned());
+        let poll = PollCreateRequest {
+            allow_multiselect: false,
+            answers: vec![PollCreateAnswer {
+                poll_media: PollMedia {
+                    emoji: None,
+                    text: Some("yes".to_owned()),
+                },
+            }],
+            duration: Some(24),
+            layout_type: None,
+            question: PollMedia {
+                emoji: None,
+                text: Some("twilight?".to_owned()),
+            },
+        };
+
+        let actual = CreateMessage::new(&client, Id::new(1))
+            .poll(&poll)?
+            .try_into_request()?;
+
+        let expected = Request::from_route(&Route::CreateMessage { channel_id: 1 });
+
+        assert_eq!(expected.path, actual.path);
+        assert_eq!(expected.method, actual.method);
+        assert_eq!(
+            Some(
+                br#"{"poll":{"allow_multiselect":false,"answers":[{"poll_media":{"text":"yes"}}],"duration":24,"question":{"text":"twilight?"}}}"#
+                    .as_slice()
+            ),
+            actual.body()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn poll_too_many_answers() {
+        let client = Client::new("token".to_owned());
+        let answer = PollCreateAnswer {
+            poll_media: PollMedia {
+                emoji: None,
+                text: Some("answer".to_owned()),
+            },
+        };
+        let poll = PollCreateRequest {
+            allow_multiselect: true,
+            answers: vec![answer; 11],
+            duration: None,
+            layout_type: None,
+            question: PollMedia {
+                emoji: None,
+                text: Some("question".to_owned()),
+            },
+        };
+
+        assert!(CreateMessage::new(&client, Id::new(1)).poll(&poll).is_err());
+    }
+
+    #[test]
+    fn sticker_ids() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("token".to_owned());
+        let sticker_ids = [Id::new(2), Id::new(3)];
+
+        let actual = CreateMessage::new(&client, Id::new(1))
+            .sticker_ids(&sticker_ids)?
+            .try_into_request()?;
+
+        let expected = Request::from_route(&Route::CreateMessage { channel_id: 1 });
+
+        assert_eq!(expected.path, actual.path);
+        assert_eq!(expected.method, actual.method);
+        assert_eq!(
+            Some(br#"{"sticker_ids":["2","3"]}"#.as_slice()),
+            actual.body()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn too_many_sticker_ids() {
+        let client = Client::new("token".to_owned());
+        let sticker_ids = [Id::new(2), Id::new(3), Id::new(4), Id::new(5)];
+
+        assert!(CreateMessage::new(&client, Id::new(1))
+            .sticker_ids(&sticker_ids)
+            .is_err());
+    }
+
+    #[test]
+    fn empty() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("token".to_owned());
+
+        let result = CreateMessage::new(&client, Id::new(1))
+            .content("")?
+            .try_into_request();
+
+        assert!(matches!(
+            result.map_err(|error| error.into_parts().0),
+            Err(ErrorType::BuildingRequest)
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Request, TryIntoRequest},
+    response::ResponseFuture,
+    routing::Route,
+};
+use twilight_model::{
+    channel::Message,
+    id::{
+        marker::{ChannelMarker, MessageMarker},
+        Id,
+    },
+};
+
+/// Immediately end the poll of a message.
+///
+/// Only polls created by the current user can be ended.
+#[must_use = "requests must be configured and executed"]
+pub struct EndPoll<'a> {
+    channel_id: Id<ChannelMarker>,
+    http: &'a Client,
+    message_id: Id<MessageMarker>,
+}
+
+impl<'a> EndPoll<'a> {
+    pub(crate) const fn new(
+        http: &'a Client,
+        channel_id: Id<ChannelMarker>,
+        message_id: Id<MessageMarker>,
+    ) -> Self {
+        Self {
+            channel_id,
+            http,
+            message_id,
+        }
+    }
+
+    /// Execute the request, returning a future resolving to a [`Response`].
+    ///
+    /// [`Response`]: crate::response::Response
+    pub fn exec(self) -> ResponseFuture<Message> {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl TryIntoRequest for EndPoll<'_> {
+    fn try_into_request(self) -> Result<Request, Error> {
+        Ok(Request::from_route(&Route::EndPoll {
+            channel_id: self.channel_id.get(),
+            message_id: self.message_id.get(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EndPoll;
+    use crate::{
+        client::Client,
+        request::{Request, TryIntoRequest},
+        routing::Route,
+    };
+    use std::error::Error;
+    use twilight_model::id::Id;
+
+    #[test]
+    fn request() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("token".to_owned());
+        let actual = EndPoll::new(&client, Id::new(1), Id::new(2)).try_into_request()?;
+        let expected = Request::from_route(&Route::EndPoll {
+            channel_id: 1,
+            message_id: 2,
+        });
+
+        assert_eq!(expected.path, actual.path);
+        assert_eq!(expected.method, actual.method);
+
+        Ok(())
+    }
+}
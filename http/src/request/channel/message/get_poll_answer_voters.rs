@@ -0,0 +1,196 @@
+use crate::{
+    client::Client,
+    error::Error as HttpError,
+    request::{Request, TryIntoRequest},
+    response::ResponseFuture,
+    routing::Route,
+};
+use twilight_model::{
+    id::{
+        marker::{ChannelMarker, MessageMarker, UserMarker},
+        Id,
+    },
+    poll::PollAnswerVoters,
+};
+use twilight_validate::request::{
+    get_poll_answer_voters_limit as validate_get_poll_answer_voters_limit, ValidationError,
+};
+
+struct GetPollAnswerVotersFields {
+    after: Option<Id<UserMarker>>,
+    limit: Option<u16>,
+}
+
+/// Get the users who voted for an answer of a poll.
+///
+/// This endpoint is limited to 100 users maximum, so if an answer has more
+/// than 100 voters, requests must be chained until all voters are retrieved.
+///
+/// # Examples
+///
+/// Get all voters of an answer:
+///
+/// ```no_run
+/// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use twilight_http::Client;
+/// use twilight_model::{id::Id, user::User};
+///
+/// let client = Client::new("my token".to_owned());
+///
+/// let channel_id = Id::new(1);
+/// let message_id = Id::new(2);
+/// let mut voters: Vec<User> = Vec::new();
+///
+/// loop {
+///     let mut request = client
+///         .poll_answer_voters(channel_id, message_id, 1)
+///         .limit(100)?;
+///
+///     if let Some(last) = voters.last() {
+///         request = request.after(last.id);
+///     }
+///
+///     let users = request.exec().await?.model().await?.users;
+///     let done = users.len() < 100;
+///     voters.extend(users);
+///
+///     if done {
+///         break;
+///     }
+/// }
+/// # Ok(()) }
+/// ```
+#[must_use = "requests must be configured and executed"]
+pub struct GetPollAnswerVoters<'a> {
+    answer_id: u8,
+    channel_id: Id<ChannelMarker>,
+    fields: GetPollAnswerVotersFields,
+    http: &'a Client,
+    message_id: Id<MessageMarker>,
+}
+
+impl<'a> GetPollAnswerVoters<'a> {
+    pub(crate) const fn new(
+        http: &'a Client,
+        channel_id: Id<ChannelMarker>,
+        message_id: Id<MessageMarker>,
+        answer_id: u8,
+    ) -> Self {
+        Self {
+            answer_id,
+            channel_id,
+            fields: GetPollAnswerVotersFields {
+                after: None,
+                limit: None,
+            },
+            http,
+            message_id,
+        }
+    }
+
+    /// Get users after this id.
+    pub const fn after(mut self, after: Id<UserMarker>) -> Self {
+        self.fields.after = Some(after);
+
+        self
+    }
+
+    /// Set the maximum number of users to retrieve.
+    ///
+    /// The minimum is 1 and the maximum is 100. If no limit is specified,
+    /// Discord sets the default to 25.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`GetPollAnswerVoters`] if the amount is less
+    /// than 1 or greater than 100.
+    ///
+    /// [`GetPollAnswerVoters`]: twilight_validate::request::ValidationErrorType::GetPollAnswerVoters
+    pub const fn limit(mut self, limit: u16) -> Result<Self, ValidationError> {
+        if let Err(source) = validate_get_poll_answer_voters_limit(limit) {
+            return Err(source);
+        }
+
+        self.fields.limit = Some(limit);
+
+        Ok(self)
+    }
+
+    /// Execute the request, returning a future resolving to a [`Response`].
+    ///
+    /// [`Response`]: crate::response::Response
+    pub fn exec(self) -> ResponseFuture<PollAnswerVoters> {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl TryIntoRequest for GetPollAnswerVoters<'_> {
+    fn try_into_request(self) -> Result<Request, HttpError> {
+        Ok(Request::from_route(&Route::GetPollAnswerVoters {
+            after: self.fields.after.map(Id::get),
+            answer_id: self.answer_id,
+            channel_id: self.channel_id.get(),
+            limit: self.fields.limit,
+            message_id: self.message_id.get(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GetPollAnswerVoters;
+    use crate::{
+        client::Client,
+        request::{Request, TryIntoRequest},
+        routing::Route,
+    };
+    use std::error::Error;
+    use twilight_model::id::Id;
+
+    #[test]
+    fn request() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("token".to_owned());
+        let actual =
+            GetPollAnswerVoters::new(&client, Id::new(1), Id::new(2), 3).try_into_request()?;
+        let expected = Request::from_route(&Route::GetPollAnswerVoters {
+            after: None,
+            answer_id: 3,
+            channel_id: 1,
+            limit: None,
+            message_id: 2,
+        });
+
+        assert_eq!(expected.path, actual.path);
+        assert_eq!(expected.method, actual.method);
+
+        Ok(())
+    }
+
+    #[test]
+    fn paginated() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("token".to_owned());
+        let actual = GetPollAnswerVoters::new(&client, Id::new(1), Id::new(2), 3)
+            .after(Id::new(4))
+            .limit(100)?
+            .try_into_request()?;
+        let expected = Request::from_route(&Route::GetPollAnswerVoters {
+            after: Some(4),
+            answer_id: 3,
+            channel_id: 1,
+            limit: Some(100),
+            message_id: 2,
+        });
+
+        assert_eq!(expected.path, actual.path);
+        assert!(GetPollAnswerVoters::new(&client, Id::new(1), Id::new(2), 3)
+            .limit(101)
+            .is_err());
+
+        Ok(())
+    }
+}
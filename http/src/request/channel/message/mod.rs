@@ -4,14 +4,16 @@ pub mod update_message;
 mod crosspost_message;
 mod delete_message;
 mod delete_messages;
+mod end_poll;
 mod get_channel_messages;
 mod get_channel_messages_configured;
 mod get_message;
+mod get_poll_answer_voters;
 
 pub use self::{
     create_message::CreateMessage, crosspost_message::CrosspostMessage,
-    delete_message::DeleteMessage, delete_messages::DeleteMessages,
+    delete_message::DeleteMessage, delete_messages::DeleteMessages, end_poll::EndPoll,
     get_channel_messages::GetChannelMessages,
     get_channel_messages_configured::GetChannelMessagesConfigured, get_message::GetMessage,
-    update_message::UpdateMessage,
+    get_poll_answer_voters::GetPollAnswerVoters, update_message::UpdateMessage,
 };
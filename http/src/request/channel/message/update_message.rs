@@ -373,4 +373,34 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn keep_attachment_and_add_file() -> Result<(), Box<dyn Error>> {
+        const CHANNEL_ID: Id<ChannelMarker> = Id::new(1);
+        const MESSAGE_ID: Id<MessageMarker> = Id::new(2);
+
+        let client = Client::new("token".into());
+        let attachments = [Attachment::from_bytes(
+            "new.png".to_owned(),
+            b"file".to_vec(),
+            1,
+        )];
+
+        let actual = UpdateMessage::new(&client, CHANNEL_ID, MESSAGE_ID)
+            .keep_attachment_ids(&[Id::new(3)])
+            .attachments(&attachments)?
+            .try_into_request()?;
+
+        assert!(actual.body().is_none());
+
+        let body = actual.form.expect("attachments use a form").build();
+        let contains = |needle: &[u8]| body.windows(needle.len()).any(|window| window == needle);
+
+        assert!(contains(
+            br#"{"attachments":[{"filename":"new.png","id":1},{"id":3}]}"#
+        ));
+        assert!(contains(br#"name="files[1]"; filename="new.png""#));
+
+        Ok(())
+    }
 }
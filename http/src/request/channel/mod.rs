@@ -5,6 +5,7 @@ pub mod stage;
 pub mod thread;
 pub mod webhook;
 
+mod add_group_dm_recipient;
 mod create_pin;
 mod create_typing_trigger;
 mod delete_channel;
@@ -14,13 +15,16 @@ mod delete_pin;
 mod follow_news_channel;
 mod get_channel;
 mod get_pins;
+mod remove_group_dm_recipient;
 mod update_channel;
 mod update_channel_permission;
 
 pub use self::{
-    create_pin::CreatePin, create_typing_trigger::CreateTypingTrigger,
-    delete_channel::DeleteChannel, delete_channel_permission::DeleteChannelPermission,
+    add_group_dm_recipient::AddGroupDmRecipient, create_pin::CreatePin,
+    create_typing_trigger::CreateTypingTrigger, delete_channel::DeleteChannel,
+    delete_channel_permission::DeleteChannelPermission,
     delete_channel_permission_configured::DeleteChannelPermissionConfigured, delete_pin::DeletePin,
     follow_news_channel::FollowNewsChannel, get_channel::GetChannel, get_pins::GetPins,
-    update_channel::UpdateChannel, update_channel_permission::UpdateChannelPermission,
+    remove_group_dm_recipient::RemoveGroupDmRecipient, update_channel::UpdateChannel,
+    update_channel_permission::UpdateChannelPermission,
 };
@@ -0,0 +1,87 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Request, TryIntoRequest},
+    response::{marker::EmptyBody, ResponseFuture},
+    routing::Route,
+};
+use twilight_model::id::{
+    marker::{ChannelMarker, UserMarker},
+    Id,
+};
+
+/// Remove a user from a group DM.
+///
+/// See [Discord Docs/Group DM Remove Recipient].
+///
+/// [Discord Docs/Group DM Remove Recipient]: https://discord.com/developers/docs/resources/channel#group-dm-remove-recipient
+#[must_use = "requests must be configured and executed"]
+pub struct RemoveGroupDmRecipient<'a> {
+    channel_id: Id<ChannelMarker>,
+    http: &'a Client,
+    user_id: Id<UserMarker>,
+}
+
+impl<'a> RemoveGroupDmRecipient<'a> {
+    pub(crate) const fn new(
+        http: &'a Client,
+        channel_id: Id<ChannelMarker>,
+        user_id: Id<UserMarker>,
+    ) -> Self {
+        Self {
+            channel_id,
+            http,
+            user_id,
+        }
+    }
+
+    /// Execute the request, returning a future resolving to a [`Response`].
+    ///
+    /// [`Response`]: crate::response::Response
+    pub fn exec(self) -> ResponseFuture<EmptyBody> {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl TryIntoRequest for RemoveGroupDmRecipient<'_> {
+    fn try_into_request(self) -> Result<Request, Error> {
+        Ok(Request::from_route(&Route::RemoveGroupDmRecipient {
+            channel_id: self.channel_id.get(),
+            user_id: self.user_id.get(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RemoveGroupDmRecipient;
+    use crate::{
+        client::Client,
+        request::{Method, Request, TryIntoRequest},
+        routing::Route,
+    };
+    use std::error::Error;
+    use twilight_model::id::Id;
+
+    #[test]
+    fn request() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("foo".to_owned());
+        let actual =
+            RemoveGroupDmRecipient::new(&client, Id::new(1), Id::new(2)).try_into_request()?;
+        let expected = Request::from_route(&Route::RemoveGroupDmRecipient {
+            channel_id: 1,
+            user_id: 2,
+        });
+
+        assert_eq!(expected.path, actual.path);
+        assert_eq!(Method::Delete, actual.method);
+        assert!(actual.body.is_none());
+
+        Ok(())
+    }
+}
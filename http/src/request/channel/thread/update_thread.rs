@@ -7,24 +7,31 @@ use crate::{
 };
 use serde::Serialize;
 use twilight_model::{
-    channel::{thread::AutoArchiveDuration, Channel},
-    id::{marker::ChannelMarker, Id},
+    channel::{thread::AutoArchiveDuration, Channel, ChannelFlags},
+    id::{
+        marker::{ChannelMarker, ForumTagMarker},
+        Id,
+    },
 };
 use twilight_validate::{
     channel::{
-        name as validate_name, rate_limit_per_user as validate_rate_limit_per_user,
-        ChannelValidationError,
+        applied_tags as validate_applied_tags, name as validate_name,
+        rate_limit_per_user as validate_rate_limit_per_user, ChannelValidationError,
     },
     request::{audit_reason as validate_audit_reason, ValidationError},
 };
 
 #[derive(Serialize)]
 struct UpdateThreadFields<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    applied_tags: Option<&'a [Id<ForumTagMarker>]>,
     #[serde(skip_serializing_if = "Option::is_none")]
     archived: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     auto_archive_duration: Option<AutoArchiveDuration>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    flags: Option<ChannelFlags>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     invitable: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     locked: Option<bool>,
@@ -51,8 +58,10 @@ impl<'a> UpdateThread<'a> {
         Self {
             channel_id,
             fields: UpdateThreadFields {
+                applied_tags: None,
                 archived: None,
                 auto_archive_duration: None,
+                flags: None,
                 invitable: None,
                 locked: None,
                 name: None,
@@ -63,6 +72,30 @@ impl<'a> UpdateThread<'a> {
         }
     }
 
+    /// Set the tags applied to a thread in a forum channel.
+    ///
+    /// At most 5 tags may be applied. Only threads in forum channels have
+    /// tags; Discord rejects this for other threads.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`AppliedTagsInvalid`] if more than 5 tags are
+    /// provided.
+    ///
+    /// [`AppliedTagsInvalid`]: twilight_validate::channel::ChannelValidationErrorType::AppliedTagsInvalid
+    pub const fn applied_tags(
+        mut self,
+        applied_tags: &'a [Id<ForumTagMarker>],
+    ) -> Result<Self, ChannelValidationError> {
+        if let Err(source) = validate_applied_tags(applied_tags) {
+            return Err(source);
+        }
+
+        self.fields.applied_tags = Some(applied_tags);
+
+        Ok(self)
+    }
+
     /// Set whether the thread is archived.
     ///
     /// Requires that the user have [`SEND_MESSAGES`] in the thread. However, if
@@ -93,6 +126,19 @@ impl<'a> UpdateThread<'a> {
         self
     }
 
+    /// Set the flags of the thread.
+    ///
+    /// Setting [`ChannelFlags::PINNED`] pins a thread in a forum channel, and
+    /// removing it unpins the thread. Only threads in forum channels can be
+    /// pinned, and doing so requires [`MANAGE_THREADS`].
+    ///
+    /// [`MANAGE_THREADS`]: twilight_model::guild::Permissions::MANAGE_THREADS
+    pub const fn flags(mut self, flags: ChannelFlags) -> Self {
+        self.fields.flags = Some(flags);
+
+        self
+    }
+
     /// Whether non-moderators can add other non-moderators to a thread.
     pub const fn invitable(mut self, invitable: bool) -> Self {
         self.fields.invitable = Some(invitable);
@@ -202,7 +248,7 @@ mod tests {
         Client,
     };
     use std::error::Error;
-    use twilight_model::id::Id;
+    use twilight_model::{channel::ChannelFlags, id::Id};
 
     #[test]
     fn request() -> Result<(), Box<dyn Error>> {
@@ -217,8 +263,10 @@ mod tests {
             channel_id: channel_id.get(),
         })
         .json(&UpdateThreadFields {
+            applied_tags: None,
             archived: None,
             auto_archive_duration: None,
+            flags: None,
             invitable: None,
             locked: None,
             name: None,
@@ -232,4 +280,62 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn pin_forum_post() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("token".to_string());
+        let channel_id = Id::new(123);
+
+        let actual = UpdateThread::new(&client, channel_id)
+            .flags(ChannelFlags::PINNED)
+            .try_into_request()?;
+
+        assert_eq!(Some(br#"{"flags":2}"#.as_slice()), actual.body());
+
+        let actual = UpdateThread::new(&client, channel_id)
+            .flags(ChannelFlags::empty())
+            .try_into_request()?;
+
+        assert_eq!(Some(br#"{"flags":0}"#.as_slice()), actual.body());
+
+        Ok(())
+    }
+
+    #[test]
+    fn applied_tags() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("token".to_string());
+        let channel_id = Id::new(123);
+        let tags = [Id::new(1), Id::new(2)];
+
+        let actual = UpdateThread::new(&client, channel_id)
+            .applied_tags(&tags)?
+            .try_into_request()?;
+
+        let expected = Request::builder(&Route::UpdateChannel {
+            channel_id: channel_id.get(),
+        })
+        .json(&UpdateThreadFields {
+            applied_tags: Some(&tags),
+            archived: None,
+            auto_archive_duration: None,
+            flags: None,
+            invitable: None,
+            locked: None,
+            name: None,
+            rate_limit_per_user: None,
+        })?
+        .build();
+
+        assert_eq!(expected.body(), actual.body());
+        assert_eq!(
+            Some(br#"{"applied_tags":["1","2"]}"#.as_slice()),
+            actual.body()
+        );
+
+        assert!(UpdateThread::new(&client, channel_id)
+            .applied_tags(&[Id::new(1); 6])
+            .is_err());
+
+        Ok(())
+    }
 }
@@ -10,6 +10,10 @@ use twilight_model::gateway::connection_info::ConnectionInfo;
 /// Get information about the gateway, optionally with additional information detailing the
 /// number of shards to use and sessions remaining.
 ///
+/// Without [`authed`], the client's authorization token isn't sent and only the
+/// URL is returned. [`authed`] additionally reports the session start limit,
+/// but doesn't consume any of it.
+///
 /// # Examples
 ///
 /// Get the gateway connection URL without bot information:
@@ -41,6 +45,8 @@ use twilight_model::gateway::connection_info::ConnectionInfo;
 /// println!("Recommended shards to use: {}", info.shards);
 /// # Ok(()) }
 /// ```
+///
+/// [`authed`]: Self::authed
 #[must_use = "requests must be configured and executed"]
 pub struct GetGateway<'a> {
     http: &'a Client,
@@ -74,6 +80,47 @@ impl<'a> GetGateway<'a> {
 
 impl TryIntoRequest for GetGateway<'_> {
     fn try_into_request(self) -> Result<Request, Error> {
-        Ok(Request::from_route(&Route::GetGateway))
+        Ok(Request::builder(&Route::GetGateway)
+            .use_authorization_token(false)
+            .build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{client::Client, request::TryIntoRequest, response::Response};
+    use hyper::{Body, Response as HyperResponse};
+    use std::error::Error;
+    use twilight_http_ratelimiting::{Method, Path};
+    use twilight_model::gateway::connection_info::ConnectionInfo;
+
+    #[test]
+    fn request() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("token".to_owned());
+        let request = client.gateway().try_into_request()?;
+
+        assert!(request.body().is_none());
+        assert_eq!(Method::Get, request.method());
+        assert_eq!("gateway", request.path());
+        assert_eq!(&Path::Gateway, request.ratelimit_path());
+        assert!(!request.use_authorization_token());
+
+        let request = client.gateway().authed().try_into_request()?;
+        assert_eq!("gateway/bot", request.path());
+        assert!(request.use_authorization_token());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn response() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let body = r#"{"url": "wss://gateway.discord.gg"}"#;
+
+        let response = Response::<ConnectionInfo>::new(HyperResponse::new(Body::from(body)));
+        let info = response.model().await?;
+
+        assert_eq!("wss://gateway.discord.gg", info.url);
+
+        Ok(())
     }
 }
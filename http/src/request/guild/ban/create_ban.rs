@@ -5,9 +5,13 @@ use crate::{
     response::{marker::EmptyBody, ResponseFuture},
     routing::Route,
 };
-use twilight_model::id::{
-    marker::{GuildMarker, UserMarker},
-    Id,
+use serde::Serialize;
+use twilight_model::{
+    http::ban::DeleteMessageDuration,
+    id::{
+        marker::{GuildMarker, UserMarker},
+        Id,
+    },
 };
 use twilight_validate::request::{
     audit_reason as validate_audit_reason,
@@ -15,13 +19,14 @@ use twilight_validate::request::{
     ValidationError,
 };
 
-struct CreateBanFields<'a> {
-    delete_message_days: Option<u16>,
-    reason: Option<&'a str>,
+#[derive(Serialize)]
+struct CreateBanFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    delete_message_seconds: Option<DeleteMessageDuration>,
 }
 
-/// Bans a user from a guild, optionally with the number of days' worth of
-/// messages to delete and the reason.
+/// Bans a user from a guild, optionally with the duration of messages to
+/// delete and the reason.
 ///
 /// # Examples
 ///
@@ -30,7 +35,7 @@ struct CreateBanFields<'a> {
 ///
 /// ```no_run
 /// use twilight_http::{request::AuditLogReason, Client};
-/// use twilight_model::id::Id;
+/// use twilight_model::{http::ban::DeleteMessageDuration, id::Id};
 ///
 /// # #[tokio::main]
 /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -39,7 +44,7 @@ struct CreateBanFields<'a> {
 /// let guild_id = Id::new(100);
 /// let user_id = Id::new(200);
 /// client.create_ban(guild_id, user_id)
-///     .delete_message_days(1)?
+///     .delete_message_duration(DeleteMessageDuration::from_days(1)?)
 ///     .reason("memes")?
 ///     .exec()
 ///     .await?;
@@ -47,9 +52,10 @@ struct CreateBanFields<'a> {
 /// ```
 #[must_use = "requests must be configured and executed"]
 pub struct CreateBan<'a> {
-    fields: CreateBanFields<'a>,
+    fields: CreateBanFields,
     guild_id: Id<GuildMarker>,
     http: &'a Client,
+    reason: Option<&'a str>,
     user_id: Id<UserMarker>,
 }
 
@@ -61,11 +67,11 @@ impl<'a> CreateBan<'a> {
     ) -> Self {
         Self {
             fields: CreateBanFields {
-                delete_message_days: None,
-                reason: None,
+                delete_message_seconds: None,
             },
             guild_id,
             http,
+            reason: None,
             user_id,
         }
     }
@@ -80,16 +86,27 @@ impl<'a> CreateBan<'a> {
     /// number of days is greater than 7.
     ///
     /// [`CreateGuildBanDeleteMessageDays`]: twilight_validate::request::ValidationErrorType::CreateGuildBanDeleteMessageDays
+    #[deprecated(since = "0.11.1", note = "use `delete_message_duration` instead")]
     pub const fn delete_message_days(mut self, days: u16) -> Result<Self, ValidationError> {
         if let Err(source) = validate_create_guild_ban_delete_message_days(days) {
             return Err(source);
         }
 
-        self.fields.delete_message_days = Some(days);
+        // Validated days are always a valid duration.
+        if let Ok(duration) = DeleteMessageDuration::from_days(days) {
+            self.fields.delete_message_seconds = Some(duration);
+        }
 
         Ok(self)
     }
 
+    /// Set the duration of messages to delete.
+    pub const fn delete_message_duration(mut self, duration: DeleteMessageDuration) -> Self {
+        self.fields.delete_message_seconds = Some(duration);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -107,7 +124,7 @@ impl<'a> AuditLogReason<'a> for CreateBan<'a> {
     fn reason(mut self, reason: &'a str) -> Result<Self, ValidationError> {
         validate_audit_reason(reason)?;
 
-        self.fields.reason.replace(reason);
+        self.reason.replace(reason);
 
         Ok(self)
     }
@@ -116,12 +133,15 @@ impl<'a> AuditLogReason<'a> for CreateBan<'a> {
 impl TryIntoRequest for CreateBan<'_> {
     fn try_into_request(self) -> Result<Request, HttpError> {
         let mut request = Request::builder(&Route::CreateBan {
-            delete_message_days: self.fields.delete_message_days,
             guild_id: self.guild_id.get(),
             user_id: self.user_id.get(),
         });
 
-        if let Some(reason) = self.fields.reason.as_ref() {
+        if self.fields.delete_message_seconds.is_some() {
+            request = request.json(&self.fields)?;
+        }
+
+        if let Some(reason) = self.reason.as_ref() {
             let header = request::audit_header(reason)?;
 
             request = request.headers(header);
@@ -140,9 +160,12 @@ mod tests {
     use hyper::header::HeaderValue;
     use std::error::Error;
     use twilight_http_ratelimiting::Method;
-    use twilight_model::id::{
-        marker::{GuildMarker, UserMarker},
-        Id,
+    use twilight_model::{
+        http::ban::DeleteMessageDuration,
+        id::{
+            marker::{GuildMarker, UserMarker},
+            Id,
+        },
     };
 
     #[test]
@@ -170,4 +193,23 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn delete_message_duration() -> Result<(), Box<dyn Error>> {
+        const GUILD_ID: Id<GuildMarker> = Id::new(1);
+        const USER_ID: Id<UserMarker> = Id::new(2);
+
+        let client = Client::new(String::new());
+        let request = client
+            .create_ban(GUILD_ID, USER_ID)
+            .delete_message_duration(DeleteMessageDuration::from_days(1)?)
+            .try_into_request()?;
+
+        assert_eq!(
+            Some(br#"{"delete_message_seconds":86400}"#.as_slice()),
+            request.body()
+        );
+
+        Ok(())
+    }
 }
@@ -0,0 +1,206 @@
+use crate::{
+    client::Client,
+    error::Error as HttpError,
+    request::{self, AuditLogReason, Request, TryIntoRequest},
+    response::ResponseFuture,
+    routing::Route,
+};
+use serde::Serialize;
+use twilight_model::{
+    guild::BulkBan,
+    http::ban::DeleteMessageDuration,
+    id::{
+        marker::{GuildMarker, UserMarker},
+        Id,
+    },
+};
+use twilight_validate::request::{
+    audit_reason as validate_audit_reason, bulk_ban_user_ids as validate_bulk_ban_user_ids,
+    ValidationError,
+};
+
+#[derive(Serialize)]
+struct CreateBulkBanFields<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    delete_message_seconds: Option<DeleteMessageDuration>,
+    user_ids: &'a [Id<UserMarker>],
+}
+
+/// Ban up to 200 users from a guild, optionally with the duration of messages
+/// to delete and the reason.
+///
+/// Responds with the IDs of the users that were and weren't banned.
+///
+/// # Examples
+///
+/// Ban users `200` and `300` from guild `100`, deleting 1 day's worth of
+/// messages, for the reason `"raid"`:
+///
+/// ```no_run
+/// use twilight_http::{request::AuditLogReason, Client};
+/// use twilight_model::{http::ban::DeleteMessageDuration, id::Id};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new("my token".to_owned());
+///
+/// let guild_id = Id::new(100);
+/// let user_ids = [Id::new(200), Id::new(300)];
+/// let response = client
+///     .bulk_ban(guild_id, &user_ids)?
+///     .delete_message_duration(DeleteMessageDuration::from_days(1)?)
+///     .reason("raid")?
+///     .exec()
+///     .await?;
+/// let bulk_ban = response.model().await?;
+///
+/// for user_id in bulk_ban.failed_users {
+///     println!("couldn't ban {user_id}");
+/// }
+/// # Ok(()) }
+/// ```
+#[must_use = "requests must be configured and executed"]
+pub struct CreateBulkBan<'a> {
+    fields: CreateBulkBanFields<'a>,
+    guild_id: Id<GuildMarker>,
+    http: &'a Client,
+    reason: Option<&'a str>,
+}
+
+impl<'a> CreateBulkBan<'a> {
+    pub(crate) const fn new(
+        http: &'a Client,
+        guild_id: Id<GuildMarker>,
+        user_ids: &'a [Id<UserMarker>],
+    ) -> Result<Self, ValidationError> {
+        if let Err(source) = validate_bulk_ban_user_ids(user_ids) {
+            return Err(source);
+        }
+
+        Ok(Self {
+            fields: CreateBulkBanFields {
+                delete_message_seconds: None,
+                user_ids,
+            },
+            guild_id,
+            http,
+            reason: None,
+        })
+    }
+
+    /// Set the duration of messages to delete.
+    pub const fn delete_message_duration(mut self, duration: DeleteMessageDuration) -> Self {
+        self.fields.delete_message_seconds = Some(duration);
+
+        self
+    }
+
+    /// Execute the request, returning a future resolving to a [`Response`].
+    ///
+    /// [`Response`]: crate::response::Response
+    pub fn exec(self) -> ResponseFuture<BulkBan> {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl<'a> AuditLogReason<'a> for CreateBulkBan<'a> {
+    fn reason(mut self, reason: &'a str) -> Result<Self, ValidationError> {
+        validate_audit_reason(reason)?;
+
+        self.reason.replace(reason);
+
+        Ok(self)
+    }
+}
+
+impl TryIntoRequest for CreateBulkBan<'_> {
+    fn try_into_request(self) -> Result<Request, HttpError> {
+        let mut request = Request::builder(&Route::CreateBulkBan {
+            guild_id: self.guild_id.get(),
+        });
+
+        request = request.json(&self.fields)?;
+
+        if let Some(reason) = self.reason.as_ref() {
+            let header = request::audit_header(reason)?;
+
+            request = request.headers(header);
+        }
+
+        Ok(request.build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        client::Client,
+        request::{AuditLogReason, Request, TryIntoRequest, REASON_HEADER_NAME},
+        routing::Route,
+    };
+    use hyper::header::HeaderValue;
+    use std::error::Error;
+    use twilight_model::{
+        guild::BulkBan,
+        http::ban::DeleteMessageDuration,
+        id::{marker::GuildMarker, Id},
+    };
+
+    const GUILD_ID: Id<GuildMarker> = Id::new(1);
+
+    #[test]
+    fn request() -> Result<(), Box<dyn Error>> {
+        const REASON: &str = "raid";
+
+        let client = Client::new(String::new());
+        let user_ids = [Id::new(2), Id::new(3)];
+        let actual = client
+            .bulk_ban(GUILD_ID, &user_ids)?
+            .delete_message_duration(DeleteMessageDuration::from_seconds(3_600)?)
+            .reason(REASON)?
+            .try_into_request()?;
+        let expected = Request::from_route(&Route::CreateBulkBan {
+            guild_id: GUILD_ID.get(),
+        });
+
+        assert_eq!(expected.path, actual.path);
+        assert_eq!(expected.method, actual.method);
+        assert_eq!(
+            Some(br#"{"delete_message_seconds":3600,"user_ids":["2","3"]}"#.as_slice()),
+            actual.body()
+        );
+
+        let header = HeaderValue::from_static(REASON);
+        assert!(matches!(
+            actual.headers(),
+            Some(map)
+            if map.len() == 1 && map.get(REASON_HEADER_NAME) == Some(&header)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn user_ids_count() {
+        let client = Client::new(String::new());
+
+        assert!(client.bulk_ban(GUILD_ID, &[]).is_err());
+        assert!(client.bulk_ban(GUILD_ID, &[Id::new(2); 200]).is_ok());
+        assert!(client.bulk_ban(GUILD_ID, &[Id::new(2); 201]).is_err());
+    }
+
+    #[test]
+    fn response() -> Result<(), Box<dyn Error>> {
+        let bulk_ban =
+            serde_json::from_str::<BulkBan>(r#"{"banned_users":["2","3"],"failed_users":["4"]}"#)?;
+
+        assert_eq!(vec![Id::new(2), Id::new(3)], bulk_ban.banned_users);
+        assert_eq!(vec![Id::new(4)], bulk_ban.failed_users);
+
+        Ok(())
+    }
+}
@@ -1,6 +1,10 @@
 mod create_ban;
+mod create_bulk_ban;
 mod delete_ban;
 mod get_ban;
 mod get_bans;
 
-pub use self::{create_ban::CreateBan, delete_ban::DeleteBan, get_ban::GetBan, get_bans::GetBans};
+pub use self::{
+    create_ban::CreateBan, create_bulk_ban::CreateBulkBan, delete_ban::DeleteBan, get_ban::GetBan,
+    get_bans::GetBans,
+};
@@ -0,0 +1,69 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Request, TryIntoRequest},
+    response::ResponseFuture,
+    routing::Route,
+};
+use twilight_model::{
+    guild::onboarding::Onboarding,
+    id::{marker::GuildMarker, Id},
+};
+
+/// Get the onboarding flow of a guild.
+#[must_use = "requests must be configured and executed"]
+pub struct GetGuildOnboarding<'a> {
+    guild_id: Id<GuildMarker>,
+    http: &'a Client,
+}
+
+impl<'a> GetGuildOnboarding<'a> {
+    pub(crate) const fn new(http: &'a Client, guild_id: Id<GuildMarker>) -> Self {
+        Self { guild_id, http }
+    }
+
+    /// Execute the request, returning a future resolving to a [`Response`].
+    ///
+    /// [`Response`]: crate::response::Response
+    pub fn exec(self) -> ResponseFuture<Onboarding> {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl TryIntoRequest for GetGuildOnboarding<'_> {
+    fn try_into_request(self) -> Result<Request, Error> {
+        Ok(Request::from_route(&Route::GetGuildOnboarding {
+            guild_id: self.guild_id.get(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GetGuildOnboarding;
+    use crate::{
+        client::Client,
+        request::{Request, TryIntoRequest},
+        routing::Route,
+    };
+    use std::error::Error;
+    use twilight_model::id::Id;
+
+    #[test]
+    fn request() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("foo".to_owned());
+        let actual = GetGuildOnboarding::new(&client, Id::new(1)).try_into_request()?;
+        let expected = Request::from_route(&Route::GetGuildOnboarding { guild_id: 1 });
+
+        assert_eq!(expected.path, actual.path);
+        assert_eq!(expected.method, actual.method);
+        assert!(actual.body.is_none());
+
+        Ok(())
+    }
+}
@@ -10,7 +10,10 @@ use twilight_model::{
     id::{marker::GuildMarker, Id},
 };
 
-/// Get a guild's vanity url, if there is one.
+/// Get a guild's vanity url and how many times it has been used.
+///
+/// If the guild doesn't have a vanity url, the returned [`VanityUrl`]'s code is
+/// [`None`].
 #[must_use = "requests must be configured and executed"]
 pub struct GetGuildVanityUrl<'a> {
     guild_id: Id<GuildMarker>,
@@ -42,3 +45,38 @@ impl TryIntoRequest for GetGuildVanityUrl<'_> {
         }))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::GetGuildVanityUrl;
+    use crate::{
+        client::Client,
+        request::{Request, TryIntoRequest},
+        routing::Route,
+    };
+    use std::error::Error;
+    use twilight_model::{guild::VanityUrl, id::Id};
+
+    #[test]
+    fn request() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("foo".to_owned());
+        let actual = GetGuildVanityUrl::new(&client, Id::new(1)).try_into_request()?;
+        let expected = Request::from_route(&Route::GetGuildVanityUrl { guild_id: 1 });
+
+        assert_eq!(expected.path, actual.path);
+
+        Ok(())
+    }
+
+    #[test]
+    fn response() -> Result<(), Box<dyn Error>> {
+        let present = serde_json::from_str::<VanityUrl>(r#"{"code":"twilight","uses":5}"#)?;
+        assert_eq!(Some("twilight"), present.code.as_deref());
+        assert_eq!(5, present.uses);
+
+        let absent = serde_json::from_str::<VanityUrl>(r#"{"code":null,"uses":0}"#)?;
+        assert!(absent.code.is_none());
+
+        Ok(())
+    }
+}
@@ -42,3 +42,74 @@ impl TryIntoRequest for GetGuildWebhooks<'_> {
         }))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        client::Client,
+        request::TryIntoRequest,
+        response::{marker::ListBody, Response},
+    };
+    use hyper::{Body, Response as HyperResponse};
+    use std::error::Error;
+    use twilight_http_ratelimiting::{Method, Path};
+    use twilight_model::{
+        channel::{Webhook, WebhookType},
+        id::{marker::GuildMarker, Id},
+    };
+
+    const GUILD_ID: Id<GuildMarker> = Id::new(1);
+
+    #[test]
+    fn request() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("token".to_owned());
+        let request = client.guild_webhooks(GUILD_ID).try_into_request()?;
+
+        assert!(request.body().is_none());
+        assert_eq!(Method::Get, request.method());
+        assert_eq!("guilds/1/webhooks", request.path());
+        assert_eq!(
+            &Path::GuildsIdWebhooks(GUILD_ID.get()),
+            request.ratelimit_path()
+        );
+        assert!(request.use_authorization_token());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn response() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let body = r#"[{
+            "avatar": null,
+            "channel_id": "2",
+            "guild_id": "1",
+            "id": "3",
+            "name": "webhook",
+            "token": "token",
+            "type": 1
+        }]"#;
+
+        let response = Response::<ListBody<Webhook>>::new(HyperResponse::new(Body::from(body)));
+        let webhooks = response.models().await?;
+
+        assert_eq!(
+            Vec::from([Webhook {
+                application_id: None,
+                avatar: None,
+                channel_id: Id::new(2),
+                guild_id: Some(GUILD_ID),
+                id: Id::new(3),
+                kind: WebhookType::Incoming,
+                name: Some("webhook".to_owned()),
+                source_channel: None,
+                source_guild: None,
+                token: Some("token".to_owned()),
+                url: None,
+                user: None,
+            }]),
+            webhooks
+        );
+
+        Ok(())
+    }
+}
@@ -70,7 +70,7 @@ impl<'a> GetGuildMembers<'a> {
 
     /// Sets the number of members to retrieve per request.
     ///
-    /// The limit must be greater than 0 and less than 1000.
+    /// The limit must be greater than 0 and at most 1000.
     ///
     /// # Errors
     ///
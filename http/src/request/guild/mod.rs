@@ -16,6 +16,7 @@ mod get_audit_log;
 mod get_guild;
 mod get_guild_channels;
 mod get_guild_invites;
+mod get_guild_onboarding;
 mod get_guild_preview;
 mod get_guild_prune_count;
 mod get_guild_vanity_url;
@@ -25,6 +26,8 @@ mod get_guild_welcome_screen;
 mod get_guild_widget;
 mod update_current_member;
 mod update_guild;
+mod update_guild_mfa_level;
+mod update_guild_onboarding;
 mod update_guild_welcome_screen;
 mod update_guild_widget;
 
@@ -33,10 +36,12 @@ pub use self::{
     create_guild_prune::CreateGuildPrune, delete_guild::DeleteGuild,
     get_active_threads::GetActiveThreads, get_audit_log::GetAuditLog, get_guild::GetGuild,
     get_guild_channels::GetGuildChannels, get_guild_invites::GetGuildInvites,
-    get_guild_preview::GetGuildPreview, get_guild_prune_count::GetGuildPruneCount,
-    get_guild_vanity_url::GetGuildVanityUrl, get_guild_voice_regions::GetGuildVoiceRegions,
-    get_guild_webhooks::GetGuildWebhooks, get_guild_welcome_screen::GetGuildWelcomeScreen,
-    get_guild_widget::GetGuildWidget, update_current_member::UpdateCurrentMember,
-    update_guild::UpdateGuild, update_guild_channel_positions::UpdateGuildChannelPositions,
+    get_guild_onboarding::GetGuildOnboarding, get_guild_preview::GetGuildPreview,
+    get_guild_prune_count::GetGuildPruneCount, get_guild_vanity_url::GetGuildVanityUrl,
+    get_guild_voice_regions::GetGuildVoiceRegions, get_guild_webhooks::GetGuildWebhooks,
+    get_guild_welcome_screen::GetGuildWelcomeScreen, get_guild_widget::GetGuildWidget,
+    update_current_member::UpdateCurrentMember, update_guild::UpdateGuild,
+    update_guild_channel_positions::UpdateGuildChannelPositions,
+    update_guild_mfa_level::UpdateGuildMfaLevel, update_guild_onboarding::UpdateGuildOnboarding,
     update_guild_welcome_screen::UpdateGuildWelcomeScreen, update_guild_widget::UpdateGuildWidget,
 };
@@ -5,6 +5,7 @@ use crate::{
     response::{marker::ListBody, ResponseFuture},
     routing::Route,
 };
+use serde::Serialize;
 use twilight_model::{
     guild::Role,
     id::{
@@ -13,9 +14,17 @@ use twilight_model::{
     },
 };
 
+#[derive(Serialize)]
+struct RolePosition {
+    id: Id<RoleMarker>,
+    position: u64,
+}
+
 /// Modify the position of the roles.
 ///
 /// The minimum amount of roles to modify, is a swap between two roles.
+///
+/// Responds with all of the guild's roles, including their updated positions.
 #[must_use = "requests must be configured and executed"]
 pub struct UpdateRolePositions<'a> {
     guild_id: Id<GuildMarker>,
@@ -55,8 +64,44 @@ impl TryIntoRequest for UpdateRolePositions<'_> {
             guild_id: self.guild_id.get(),
         });
 
-        request = request.json(&self.roles)?;
+        let positions = self
+            .roles
+            .iter()
+            .map(|&(id, position)| RolePosition { id, position })
+            .collect::<Vec<_>>();
+
+        request = request.json(&positions)?;
 
         Ok(request.build())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::UpdateRolePositions;
+    use crate::{
+        client::Client,
+        request::{Request, TryIntoRequest},
+        routing::Route,
+    };
+    use std::error::Error;
+    use twilight_model::id::Id;
+
+    #[test]
+    fn request() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("foo".to_owned());
+        let positions = [(Id::new(2), 1), (Id::new(3), 2)];
+        let actual =
+            UpdateRolePositions::new(&client, Id::new(1), &positions).try_into_request()?;
+        let expected = Request::from_route(&Route::UpdateRolePositions { guild_id: 1 });
+
+        assert_eq!(expected.path, actual.path);
+        assert_eq!(expected.method, actual.method);
+        assert_eq!(
+            Some(br#"[{"id":"2","position":1},{"id":"3","position":2}]"#.to_vec()),
+            actual.body
+        );
+
+        Ok(())
+    }
+}
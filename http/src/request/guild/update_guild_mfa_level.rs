@@ -0,0 +1,87 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Request, TryIntoRequest},
+    response::ResponseFuture,
+    routing::Route,
+};
+use serde::Serialize;
+use twilight_model::{
+    guild::{GuildMfa, MfaLevel},
+    id::{marker::GuildMarker, Id},
+};
+
+#[derive(Serialize)]
+struct UpdateGuildMfaLevelFields {
+    level: MfaLevel,
+}
+
+/// Update the multi-factor authentication level of a guild.
+///
+/// Requires guild ownership.
+#[must_use = "requests must be configured and executed"]
+pub struct UpdateGuildMfaLevel<'a> {
+    fields: UpdateGuildMfaLevelFields,
+    guild_id: Id<GuildMarker>,
+    http: &'a Client,
+}
+
+impl<'a> UpdateGuildMfaLevel<'a> {
+    pub(crate) const fn new(http: &'a Client, guild_id: Id<GuildMarker>, level: MfaLevel) -> Self {
+        Self {
+            fields: UpdateGuildMfaLevelFields { level },
+            guild_id,
+            http,
+        }
+    }
+
+    /// Execute the request, returning a future resolving to a [`Response`].
+    ///
+    /// [`Response`]: crate::response::Response
+    pub fn exec(self) -> ResponseFuture<GuildMfa> {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl TryIntoRequest for UpdateGuildMfaLevel<'_> {
+    fn try_into_request(self) -> Result<Request, Error> {
+        let mut request = Request::builder(&Route::UpdateGuildMfaLevel {
+            guild_id: self.guild_id.get(),
+        });
+
+        request = request.json(&self.fields)?;
+
+        Ok(request.build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UpdateGuildMfaLevel;
+    use crate::{
+        client::Client,
+        request::{Request, TryIntoRequest},
+        routing::Route,
+    };
+    use std::error::Error;
+    use twilight_model::{guild::MfaLevel, id::Id};
+
+    #[test]
+    fn request() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("foo".to_owned());
+        let actual =
+            UpdateGuildMfaLevel::new(&client, Id::new(1), MfaLevel::Elevated).try_into_request()?;
+        let expected = Request::from_route(&Route::UpdateGuildMfaLevel { guild_id: 1 });
+
+        assert_eq!(expected.path, actual.path);
+        assert_eq!(expected.method, actual.method);
+        assert_eq!(Some(br#"{"level":1}"#.as_slice()), actual.body());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,222 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Request, TryIntoRequest},
+    response::ResponseFuture,
+    routing::Route,
+};
+use serde::Serialize;
+use twilight_model::{
+    guild::onboarding::{Onboarding, OnboardingMode},
+    http::onboarding::OnboardingPromptRequest,
+    id::{
+        marker::{ChannelMarker, GuildMarker},
+        Id,
+    },
+};
+use twilight_validate::request::{
+    onboarding_prompt_options as validate_onboarding_prompt_options, ValidationError,
+};
+
+#[derive(Serialize)]
+struct UpdateGuildOnboardingFields<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_channel_ids: Option<&'a [Id<ChannelMarker>]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mode: Option<OnboardingMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    prompts: Option<&'a [OnboardingPromptRequest]>,
+}
+
+/// Update the onboarding flow of a guild.
+///
+/// Requires the [`MANAGE_GUILD`] and [`MANAGE_ROLES`] permissions.
+///
+/// [`MANAGE_GUILD`]: twilight_model::guild::Permissions::MANAGE_GUILD
+/// [`MANAGE_ROLES`]: twilight_model::guild::Permissions::MANAGE_ROLES
+#[must_use = "requests must be configured and executed"]
+pub struct UpdateGuildOnboarding<'a> {
+    fields: UpdateGuildOnboardingFields<'a>,
+    guild_id: Id<GuildMarker>,
+    http: &'a Client,
+}
+
+impl<'a> UpdateGuildOnboarding<'a> {
+    pub(crate) const fn new(http: &'a Client, guild_id: Id<GuildMarker>) -> Self {
+        Self {
+            fields: UpdateGuildOnboardingFields {
+                default_channel_ids: None,
+                enabled: None,
+                mode: None,
+                prompts: None,
+            },
+            guild_id,
+            http,
+        }
+    }
+
+    /// Set the channels that members are opted into by default.
+    pub const fn default_channel_ids(
+        mut self,
+        default_channel_ids: &'a [Id<ChannelMarker>],
+    ) -> Self {
+        self.fields.default_channel_ids = Some(default_channel_ids);
+
+        self
+    }
+
+    /// Set whether onboarding is enabled.
+    pub const fn enabled(mut self, enabled: bool) -> Self {
+        self.fields.enabled = Some(enabled);
+
+        self
+    }
+
+    /// Set the criteria used to determine whether onboarding is satisfied.
+    pub const fn mode(mut self, mode: OnboardingMode) -> Self {
+        self.fields.mode = Some(mode);
+
+        self
+    }
+
+    /// Set the prompts shown during onboarding.
+    ///
+    /// Existing prompts may be converted into [`OnboardingPromptRequest`]s to
+    /// keep them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`OnboardingPromptOptions`] if a prompt has
+    /// no options or more than 50.
+    ///
+    /// [`OnboardingPromptOptions`]: twilight_validate::request::ValidationErrorType::OnboardingPromptOptions
+    pub fn prompts(
+        mut self,
+        prompts: &'a [OnboardingPromptRequest],
+    ) -> Result<Self, ValidationError> {
+        for prompt in prompts {
+            validate_onboarding_prompt_options(&prompt.options)?;
+        }
+
+        self.fields.prompts = Some(prompts);
+
+        Ok(self)
+    }
+
+    /// Execute the request, returning a future resolving to a [`Response`].
+    ///
+    /// [`Response`]: crate::response::Response
+    pub fn exec(self) -> ResponseFuture<Onboarding> {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl TryIntoRequest for UpdateGuildOnboarding<'_> {
+    fn try_into_request(self) -> Result<Request, Error> {
+        let mut request = Request::builder(&Route::UpdateGuildOnboarding {
+            guild_id: self.guild_id.get(),
+        });
+
+        request = request.json(&self.fields)?;
+
+        Ok(request.build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UpdateGuildOnboarding;
+    use crate::{
+        client::Client,
+        request::{Request, TryIntoRequest},
+        routing::Route,
+    };
+    use std::error::Error;
+    use twilight_model::{
+        guild::onboarding::{OnboardingMode, OnboardingPromptType},
+        http::onboarding::{OnboardingPromptOptionRequest, OnboardingPromptRequest},
+        id::Id,
+    };
+    use twilight_validate::request::ValidationErrorType;
+
+    fn prompt(options: Vec<OnboardingPromptOptionRequest>) -> OnboardingPromptRequest {
+        OnboardingPromptRequest {
+            id: Id::new(3),
+            in_onboarding: true,
+            kind: OnboardingPromptType::MultipleChoice,
+            options,
+            required: false,
+            single_select: false,
+            title: "prompt".to_owned(),
+        }
+    }
+
+    fn option() -> OnboardingPromptOptionRequest {
+        OnboardingPromptOptionRequest {
+            channel_ids: Vec::new(),
+            description: None,
+            emoji_animated: None,
+            emoji_id: Some(Id::new(6)),
+            emoji_name: Some("twilight".to_owned()),
+            id: Id::new(4),
+            role_ids: vec![Id::new(5)],
+            title: "option".to_owned(),
+        }
+    }
+
+    #[test]
+    fn request() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("foo".to_owned());
+        let default_channel_ids = [Id::new(2)];
+        let prompts = [prompt(vec![option()])];
+
+        let actual = UpdateGuildOnboarding::new(&client, Id::new(1))
+            .default_channel_ids(&default_channel_ids)
+            .enabled(true)
+            .mode(OnboardingMode::Default)
+            .prompts(&prompts)?
+            .try_into_request()?;
+        let expected = Request::from_route(&Route::UpdateGuildOnboarding { guild_id: 1 });
+
+        assert_eq!(expected.path, actual.path);
+        assert_eq!(expected.method, actual.method);
+        assert_eq!(
+            Some(
+                br#"{"default_channel_ids":["2"],"enabled":true,"mode":0,"prompts":[{"id":"3","in_onboarding":true,"type":0,"options":[{"channel_ids":[],"emoji_id":"6","emoji_name":"twilight","id":"4","role_ids":["5"],"title":"option"}],"required":false,"single_select":false,"title":"prompt"}]}"#
+                    .to_vec()
+            ),
+            actual.body
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn empty() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("foo".to_owned());
+        let actual = UpdateGuildOnboarding::new(&client, Id::new(1)).try_into_request()?;
+
+        assert_eq!(Some(b"{}".to_vec()), actual.body);
+
+        Ok(())
+    }
+
+    #[test]
+    fn prompt_options() {
+        let client = Client::new("foo".to_owned());
+        let prompts = [prompt(vec![option()]), prompt(Vec::new())];
+        let result = UpdateGuildOnboarding::new(&client, Id::new(1)).prompts(&prompts);
+
+        assert!(matches!(
+            result.map(|_| ()).unwrap_err().kind(),
+            ValidationErrorType::OnboardingPromptOptions { len: 0 }
+        ));
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Request, TryIntoRequest},
+    response::ResponseFuture,
+    routing::Route,
+};
+use twilight_model::{
+    id::{
+        marker::{GuildMarker, UserMarker},
+        Id,
+    },
+    voice::VoiceState,
+};
+
+/// Get a user's voice state.
+#[must_use = "requests must be configured and executed"]
+pub struct GetUserVoiceState<'a> {
+    guild_id: Id<GuildMarker>,
+    http: &'a Client,
+    user_id: Id<UserMarker>,
+}
+
+impl<'a> GetUserVoiceState<'a> {
+    pub(crate) const fn new(
+        http: &'a Client,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+    ) -> Self {
+        Self {
+            guild_id,
+            http,
+            user_id,
+        }
+    }
+
+    /// Execute the request, returning a future resolving to a [`Response`].
+    ///
+    /// [`Response`]: crate::response::Response
+    pub fn exec(self) -> ResponseFuture<VoiceState> {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl TryIntoRequest for GetUserVoiceState<'_> {
+    fn try_into_request(self) -> Result<Request, Error> {
+        Ok(Request::from_route(&Route::GetUserVoiceState {
+            guild_id: self.guild_id.get(),
+            user_id: self.user_id.get(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GetUserVoiceState;
+    use crate::{
+        client::Client,
+        request::{Request, TryIntoRequest},
+        routing::Route,
+    };
+    use std::error::Error;
+    use twilight_model::id::Id;
+
+    #[test]
+    fn request() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("foo".to_owned());
+        let guild_id = Id::new(1);
+        let user_id = Id::new(2);
+
+        let actual = GetUserVoiceState::new(&client, guild_id, user_id).try_into_request()?;
+        let expected = Request::from_route(&Route::GetUserVoiceState {
+            guild_id: guild_id.get(),
+            user_id: user_id.get(),
+        });
+
+        assert_eq!(expected.path(), actual.path());
+        assert_eq!(expected.method(), actual.method());
+        assert_eq!("guilds/1/voice-states/2", actual.path());
+
+        Ok(())
+    }
+}
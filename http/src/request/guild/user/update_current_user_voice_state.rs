@@ -6,37 +6,37 @@ use crate::{
     routing::Route,
 };
 use serde::Serialize;
-use twilight_model::id::{
-    marker::{ChannelMarker, GuildMarker},
-    Id,
+use twilight_model::{
+    id::{
+        marker::{ChannelMarker, GuildMarker},
+        Id,
+    },
+    util::Timestamp,
 };
 
 #[derive(Serialize)]
-struct UpdateCurrentUserVoiceStateFields<'a> {
-    channel_id: Id<ChannelMarker>,
+struct UpdateCurrentUserVoiceStateFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    channel_id: Option<Id<ChannelMarker>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     suppress: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    request_to_speak_timestamp: Option<Nullable<&'a str>>,
+    request_to_speak_timestamp: Option<Nullable<Timestamp>>,
 }
 
 /// Update the current user's voice state.
 #[must_use = "requests must be configured and executed"]
 pub struct UpdateCurrentUserVoiceState<'a> {
-    fields: UpdateCurrentUserVoiceStateFields<'a>,
+    fields: UpdateCurrentUserVoiceStateFields,
     guild_id: Id<GuildMarker>,
     http: &'a Client,
 }
 
 impl<'a> UpdateCurrentUserVoiceState<'a> {
-    pub(crate) const fn new(
-        http: &'a Client,
-        guild_id: Id<GuildMarker>,
-        channel_id: Id<ChannelMarker>,
-    ) -> Self {
+    pub(crate) const fn new(http: &'a Client, guild_id: Id<GuildMarker>) -> Self {
         Self {
             fields: UpdateCurrentUserVoiceStateFields {
-                channel_id,
+                channel_id: None,
                 suppress: None,
                 request_to_speak_timestamp: None,
             },
@@ -45,33 +45,44 @@ impl<'a> UpdateCurrentUserVoiceState<'a> {
         }
     }
 
+    /// Specify the ID of the stage channel which the user is currently
+    /// connected to.
+    ///
+    /// # Caveats
+    ///
+    /// - `channel_id` must currently point to a stage channel.
+    /// - Current user must have already joined `channel_id`.
+    pub const fn channel_id(mut self, channel_id: Id<ChannelMarker>) -> Self {
+        self.fields.channel_id = Some(channel_id);
+
+        self
+    }
+
     /// Set the user's request to speak.
     ///
-    /// Set to an empty string to remove an already-present request.
+    /// Set to [`None`] to remove an already-present request.
     ///
     /// # Caveats
     ///
     /// - You are able to set `request_to_speak_timestamp` to any present or
     /// future time.
-    pub const fn request_to_speak_timestamp(mut self, request_to_speak_timestamp: &'a str) -> Self {
-        if request_to_speak_timestamp.is_empty() {
-            self.fields.request_to_speak_timestamp = Some(Nullable(None));
-        } else {
-            self.fields.request_to_speak_timestamp =
-                Some(Nullable(Some(request_to_speak_timestamp)));
-        }
+    pub const fn request_to_speak_timestamp(
+        mut self,
+        request_to_speak_timestamp: Option<Timestamp>,
+    ) -> Self {
+        self.fields.request_to_speak_timestamp = Some(Nullable(request_to_speak_timestamp));
 
         self
     }
 
-    /// Toggle the user's suppress state.
+    /// Set whether the user is suppressed.
     ///
     /// # Caveats
     ///
     /// - You must have the `MUTE_MEMBERS` permission to unsuppress yourself.
     /// You can always suppress yourself.
-    pub const fn suppress(mut self) -> Self {
-        self.fields.suppress = Some(true);
+    pub const fn suppress(mut self, suppress: bool) -> Self {
+        self.fields.suppress = Some(suppress);
 
         self
     }
@@ -100,3 +111,85 @@ impl TryIntoRequest for UpdateCurrentUserVoiceState<'_> {
         Ok(request.build())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{UpdateCurrentUserVoiceState, UpdateCurrentUserVoiceStateFields};
+    use crate::{
+        client::Client,
+        request::{Nullable, Request, TryIntoRequest},
+        routing::Route,
+    };
+    use std::error::Error;
+    use twilight_model::{
+        id::{marker::GuildMarker, Id},
+        util::Timestamp,
+    };
+
+    const GUILD_ID: Id<GuildMarker> = Id::new(1);
+
+    #[test]
+    fn suppress() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("foo".to_owned());
+        let channel_id = Id::new(2);
+
+        let actual = UpdateCurrentUserVoiceState::new(&client, GUILD_ID)
+            .channel_id(channel_id)
+            .suppress(false)
+            .try_into_request()?;
+
+        let body = UpdateCurrentUserVoiceStateFields {
+            channel_id: Some(channel_id),
+            suppress: Some(false),
+            request_to_speak_timestamp: None,
+        };
+        let expected = Request::builder(&Route::UpdateCurrentUserVoiceState {
+            guild_id: GUILD_ID.get(),
+        })
+        .json(&body)?
+        .build();
+
+        assert_eq!(expected.body, actual.body);
+        assert_eq!(expected.path, actual.path);
+        assert_eq!(
+            Some(br#"{"channel_id":"2","suppress":false}"#.as_slice()),
+            actual.body()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn request_to_speak_timestamp() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("foo".to_owned());
+        let timestamp = Timestamp::from_secs(1_580_608_922)?;
+
+        let actual = UpdateCurrentUserVoiceState::new(&client, GUILD_ID)
+            .request_to_speak_timestamp(Some(timestamp))
+            .try_into_request()?;
+
+        let body = UpdateCurrentUserVoiceStateFields {
+            channel_id: None,
+            suppress: None,
+            request_to_speak_timestamp: Some(Nullable(Some(timestamp))),
+        };
+        let expected = Request::builder(&Route::UpdateCurrentUserVoiceState {
+            guild_id: GUILD_ID.get(),
+        })
+        .json(&body)?
+        .build();
+
+        assert_eq!(expected.body, actual.body);
+
+        let actual = UpdateCurrentUserVoiceState::new(&client, GUILD_ID)
+            .request_to_speak_timestamp(None)
+            .try_into_request()?;
+
+        assert_eq!(
+            Some(br#"{"request_to_speak_timestamp":null}"#.as_slice()),
+            actual.body()
+        );
+
+        Ok(())
+    }
+}
@@ -45,7 +45,9 @@ impl<'a> UpdateUserVoiceState<'a> {
         }
     }
 
-    /// Toggle the user's suppress state.
+    /// Set whether the user is suppressed.
+    ///
+    /// Unsuppressing a user invites them to speak in the stage channel.
     ///
     /// # Caveats
     ///
@@ -57,8 +59,8 @@ impl<'a> UpdateUserVoiceState<'a> {
     /// removed.
     ///
     /// [`MUTE_MEMBERS`]: twilight_model::guild::Permissions::MUTE_MEMBERS
-    pub const fn suppress(mut self) -> Self {
-        self.fields.suppress = Some(true);
+    pub const fn suppress(mut self, suppress: bool) -> Self {
+        self.fields.suppress = Some(suppress);
 
         self
     }
@@ -88,3 +90,43 @@ impl TryIntoRequest for UpdateUserVoiceState<'_> {
         Ok(request.build())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{UpdateUserVoiceState, UpdateUserVoiceStateFields};
+    use crate::{
+        client::Client,
+        request::{Request, TryIntoRequest},
+        routing::Route,
+    };
+    use std::error::Error;
+    use twilight_model::id::Id;
+
+    #[test]
+    fn suppress() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("foo".to_owned());
+        let guild_id = Id::new(1);
+        let user_id = Id::new(2);
+        let channel_id = Id::new(3);
+
+        let actual = UpdateUserVoiceState::new(&client, guild_id, user_id, channel_id)
+            .suppress(false)
+            .try_into_request()?;
+
+        let body = UpdateUserVoiceStateFields {
+            channel_id,
+            suppress: Some(false),
+        };
+        let expected = Request::builder(&Route::UpdateUserVoiceState {
+            guild_id: guild_id.get(),
+            user_id: user_id.get(),
+        })
+        .json(&body)?
+        .build();
+
+        assert_eq!(expected.body, actual.body);
+        assert_eq!("guilds/1/voice-states/2", actual.path());
+
+        Ok(())
+    }
+}
@@ -15,6 +15,7 @@ mod get_user_application;
 mod get_voice_regions;
 mod multipart;
 mod try_into_request;
+mod update_current_application;
 
 pub use self::{
     audit_reason::AuditLogReason,
@@ -25,6 +26,7 @@ pub use self::{
     get_voice_regions::GetVoiceRegions,
     multipart::Form,
     try_into_request::TryIntoRequest,
+    update_current_application::UpdateCurrentApplication,
 };
 pub use twilight_http_ratelimiting::request::Method;
 
@@ -130,3 +130,55 @@ impl TryIntoRequest for GetGuildScheduledEventUsers<'_> {
         }))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{client::Client, request::TryIntoRequest};
+    use std::error::Error;
+    use twilight_http_ratelimiting::{Method, Path};
+    use twilight_model::id::{
+        marker::{GuildMarker, ScheduledEventMarker},
+        Id,
+    };
+
+    const GUILD_ID: Id<GuildMarker> = Id::new(1);
+    const SCHEDULED_EVENT_ID: Id<ScheduledEventMarker> = Id::new(2);
+
+    #[test]
+    fn request() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("token".to_owned());
+        let request = client
+            .guild_scheduled_event_users(GUILD_ID, SCHEDULED_EVENT_ID)
+            .after(Id::new(3))
+            .limit(50)?
+            .with_member(true)
+            .try_into_request()?;
+
+        assert!(request.body().is_none());
+        assert_eq!(Method::Get, request.method());
+        assert_eq!(
+            "guilds/1/scheduled-events/2/users?after=3&limit=50&with_member=true",
+            request.path()
+        );
+        assert_eq!(
+            &Path::GuildsIdScheduledEventsIdUsers(GUILD_ID.get()),
+            request.ratelimit_path()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn limit() {
+        let client = Client::new("token".to_owned());
+
+        assert!(client
+            .guild_scheduled_event_users(GUILD_ID, SCHEDULED_EVENT_ID)
+            .limit(0)
+            .is_err());
+        assert!(client
+            .guild_scheduled_event_users(GUILD_ID, SCHEDULED_EVENT_ID)
+            .limit(101)
+            .is_err());
+    }
+}
@@ -8,8 +8,10 @@ use crate::{
 use serde::Deserialize;
 use twilight_model::channel::message::sticker::StickerPack;
 
+/// Response body of [`GetNitroStickerPacks`].
 #[derive(Deserialize)]
 pub struct StickerPackListing {
+    /// List of sticker packs.
     pub sticker_packs: Vec<StickerPack>,
 }
 
@@ -57,3 +59,26 @@ impl TryIntoRequest for GetNitroStickerPacks<'_> {
         Ok(Request::from_route(&Route::GetNitroStickerPacks))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::GetNitroStickerPacks;
+    use crate::{
+        client::Client,
+        request::{Request, TryIntoRequest},
+        routing::Route,
+    };
+    use std::error::Error;
+
+    #[test]
+    fn request() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("foo".to_owned());
+        let actual = GetNitroStickerPacks::new(&client).try_into_request()?;
+        let expected = Request::from_route(&Route::GetNitroStickerPacks);
+
+        assert_eq!(expected.path, actual.path);
+        assert_eq!("sticker-packs", actual.path);
+
+        Ok(())
+    }
+}
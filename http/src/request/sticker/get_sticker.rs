@@ -57,3 +57,27 @@ impl TryIntoRequest for GetSticker<'_> {
         }))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::GetSticker;
+    use crate::{
+        client::Client,
+        request::{Request, TryIntoRequest},
+        routing::Route,
+    };
+    use std::error::Error;
+    use twilight_model::id::Id;
+
+    #[test]
+    fn request() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("foo".to_owned());
+        let actual = GetSticker::new(&client, Id::new(1)).try_into_request()?;
+        let expected = Request::from_route(&Route::GetSticker { sticker_id: 1 });
+
+        assert_eq!(expected.path, actual.path);
+        assert_eq!("stickers/1", actual.path);
+
+        Ok(())
+    }
+}
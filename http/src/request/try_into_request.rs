@@ -22,8 +22,9 @@ mod private {
         channel::{
             invite::{CreateInvite, DeleteInvite, GetChannelInvites, GetInvite},
             message::{
-                CreateMessage, CrosspostMessage, DeleteMessage, DeleteMessages, GetChannelMessages,
-                GetChannelMessagesConfigured, GetMessage, UpdateMessage,
+                CreateMessage, CrosspostMessage, DeleteMessage, DeleteMessages, EndPoll,
+                GetChannelMessages, GetChannelMessagesConfigured, GetMessage, GetPollAnswerVoters,
+                UpdateMessage,
             },
             reaction::{
                 CreateReaction, DeleteAllReaction, DeleteAllReactions, DeleteReaction, GetReactions,
@@ -42,12 +43,13 @@ mod private {
                 ExecuteWebhookAndWait, GetChannelWebhooks, GetWebhook, GetWebhookMessage,
                 UpdateWebhook, UpdateWebhookMessage, UpdateWebhookWithToken,
             },
-            CreatePin, CreateTypingTrigger, DeleteChannel, DeleteChannelPermission,
-            DeleteChannelPermissionConfigured, DeletePin, FollowNewsChannel, GetChannel, GetPins,
-            UpdateChannel, UpdateChannelPermission,
+            AddGroupDmRecipient, CreatePin, CreateTypingTrigger, DeleteChannel,
+            DeleteChannelPermission, DeleteChannelPermissionConfigured, DeletePin,
+            FollowNewsChannel, GetChannel, GetPins, RemoveGroupDmRecipient, UpdateChannel,
+            UpdateChannelPermission,
         },
         guild::{
-            ban::{CreateBan, DeleteBan, GetBan, GetBans},
+            ban::{CreateBan, CreateBulkBan, DeleteBan, GetBan, GetBans},
             emoji::{CreateEmoji, DeleteEmoji, GetEmoji, GetEmojis, UpdateEmoji},
             integration::{DeleteGuildIntegration, GetGuildIntegrations},
             member::{
@@ -59,12 +61,16 @@ mod private {
                 CreateGuildSticker, DeleteGuildSticker, GetGuildSticker, GetGuildStickers,
                 UpdateGuildSticker,
             },
-            user::{UpdateCurrentUserVoiceState, UpdateUserVoiceState},
+            user::{
+                GetCurrentUserVoiceState, GetUserVoiceState, UpdateCurrentUserVoiceState,
+                UpdateUserVoiceState,
+            },
             CreateGuild, CreateGuildChannel, CreateGuildPrune, DeleteGuild, GetActiveThreads,
-            GetAuditLog, GetGuild, GetGuildChannels, GetGuildInvites, GetGuildPreview,
-            GetGuildPruneCount, GetGuildVanityUrl, GetGuildVoiceRegions, GetGuildWebhooks,
-            GetGuildWelcomeScreen, GetGuildWidget, UpdateCurrentMember, UpdateGuild,
-            UpdateGuildChannelPositions, UpdateGuildWelcomeScreen, UpdateGuildWidget,
+            GetAuditLog, GetGuild, GetGuildChannels, GetGuildInvites, GetGuildOnboarding,
+            GetGuildPreview, GetGuildPruneCount, GetGuildVanityUrl, GetGuildVoiceRegions,
+            GetGuildWebhooks, GetGuildWelcomeScreen, GetGuildWidget, UpdateCurrentMember,
+            UpdateGuild, UpdateGuildChannelPositions, UpdateGuildMfaLevel, UpdateGuildOnboarding,
+            UpdateGuildWelcomeScreen, UpdateGuildWidget,
         },
         scheduled_event::{
             CreateGuildExternalScheduledEvent, CreateGuildScheduledEvent,
@@ -83,14 +89,17 @@ mod private {
             UpdateCurrentUser,
         },
         GetGateway, GetGatewayAuthed, GetUserApplicationInfo, GetVoiceRegions,
+        UpdateCurrentApplication,
     };
 
     pub trait Sealed {}
 
+    impl Sealed for AddGroupDmRecipient<'_> {}
     impl Sealed for AddGuildMember<'_> {}
     impl Sealed for AddRoleToMember<'_> {}
     impl Sealed for AddThreadMember<'_> {}
     impl Sealed for CreateBan<'_> {}
+    impl Sealed for CreateBulkBan<'_> {}
     impl Sealed for CreateEmoji<'_> {}
     impl Sealed for CreateFollowup<'_> {}
     impl Sealed for CreateGlobalChatInputCommand<'_> {}
@@ -149,6 +158,7 @@ mod private {
     impl Sealed for DeleteTemplate<'_> {}
     impl Sealed for DeleteWebhook<'_> {}
     impl Sealed for DeleteWebhookMessage<'_> {}
+    impl Sealed for EndPoll<'_> {}
     impl Sealed for ExecuteWebhook<'_> {}
     impl Sealed for ExecuteWebhookAndWait<'_> {}
     impl Sealed for FollowNewsChannel<'_> {}
@@ -165,6 +175,7 @@ mod private {
     impl Sealed for GetCurrentUser<'_> {}
     impl Sealed for GetCurrentUserConnections<'_> {}
     impl Sealed for GetCurrentUserGuildMember<'_> {}
+    impl Sealed for GetCurrentUserVoiceState<'_> {}
     impl Sealed for GetCurrentUserGuilds<'_> {}
     impl Sealed for GetEmoji<'_> {}
     impl Sealed for GetEmojis<'_> {}
@@ -180,6 +191,7 @@ mod private {
     impl Sealed for GetGuildCommands<'_> {}
     impl Sealed for GetGuildIntegrations<'_> {}
     impl Sealed for GetGuildInvites<'_> {}
+    impl Sealed for GetGuildOnboarding<'_> {}
     impl Sealed for GetGuildMembers<'_> {}
     impl Sealed for GetGuildPreview<'_> {}
     impl Sealed for GetGuildPruneCount<'_> {}
@@ -200,6 +212,7 @@ mod private {
     impl Sealed for GetMessage<'_> {}
     impl Sealed for GetNitroStickerPacks<'_> {}
     impl Sealed for GetPins<'_> {}
+    impl Sealed for GetPollAnswerVoters<'_> {}
     impl Sealed for GetPrivateArchivedThreads<'_> {}
     impl Sealed for GetPublicArchivedThreads<'_> {}
     impl Sealed for GetReactions<'_> {}
@@ -211,13 +224,16 @@ mod private {
     impl Sealed for GetThreadMember<'_> {}
     impl Sealed for GetThreadMembers<'_> {}
     impl Sealed for GetUser<'_> {}
+    impl Sealed for GetUserVoiceState<'_> {}
     impl Sealed for GetUserApplicationInfo<'_> {}
+    impl Sealed for UpdateCurrentApplication<'_> {}
     impl Sealed for GetVoiceRegions<'_> {}
     impl Sealed for GetWebhook<'_> {}
     impl Sealed for GetWebhookMessage<'_> {}
     impl Sealed for JoinThread<'_> {}
     impl Sealed for LeaveGuild<'_> {}
     impl Sealed for LeaveThread<'_> {}
+    impl Sealed for RemoveGroupDmRecipient<'_> {}
     impl Sealed for RemoveMember<'_> {}
     impl Sealed for RemoveRoleFromMember<'_> {}
     impl Sealed for RemoveThreadMember<'_> {}
@@ -238,6 +254,8 @@ mod private {
     impl Sealed for UpdateGuildChannelPositions<'_> {}
     impl Sealed for UpdateGuildCommand<'_> {}
     impl Sealed for UpdateGuildMember<'_> {}
+    impl Sealed for UpdateGuildMfaLevel<'_> {}
+    impl Sealed for UpdateGuildOnboarding<'_> {}
     impl Sealed for UpdateGuildScheduledEvent<'_> {}
     impl Sealed for UpdateGuildSticker<'_> {}
     impl Sealed for UpdateGuildWelcomeScreen<'_> {}
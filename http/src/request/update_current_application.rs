@@ -0,0 +1,212 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{self, Nullable, Request, TryIntoRequest},
+    response::ResponseFuture,
+    routing::Route,
+};
+use serde::Serialize;
+use twilight_model::oauth::{Application, ApplicationFlags};
+use twilight_validate::request::{application_tags as validate_application_tags, ValidationError};
+
+#[derive(Serialize)]
+struct UpdateCurrentApplicationFields<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    flags: Option<ApplicationFlags>,
+    #[serde(
+        serialize_with = "request::serialize_optional_nullable_image",
+        skip_serializing_if = "Option::is_none"
+    )]
+    icon: Option<Nullable<&'a [u8]>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    interactions_endpoint_url: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tags: Option<&'a [&'a str]>,
+}
+
+/// Update the current application.
+///
+/// All parameters are optional.
+///
+/// # Examples
+///
+/// ```no_run
+/// use twilight_http::Client;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new("my token".to_owned());
+///
+/// let application = client
+///     .update_current_application()
+///     .description("a pretty cool application")
+///     .tags(&["moderation", "utility"])?
+///     .exec()
+///     .await?;
+/// # Ok(()) }
+/// ```
+#[must_use = "requests must be configured and executed"]
+pub struct UpdateCurrentApplication<'a> {
+    fields: UpdateCurrentApplicationFields<'a>,
+    http: &'a Client,
+}
+
+impl<'a> UpdateCurrentApplication<'a> {
+    pub(crate) const fn new(http: &'a Client) -> Self {
+        Self {
+            fields: UpdateCurrentApplicationFields {
+                description: None,
+                flags: None,
+                icon: None,
+                interactions_endpoint_url: None,
+                tags: None,
+            },
+            http,
+        }
+    }
+
+    /// Set the description of the application.
+    pub const fn description(mut self, description: &'a str) -> Self {
+        self.fields.description = Some(description);
+
+        self
+    }
+
+    /// Set the flags of the application.
+    ///
+    /// Only limited intent flags, such as
+    /// [`ApplicationFlags::GATEWAY_MESSAGE_CONTENT_LIMITED`], may be updated.
+    pub const fn flags(mut self, flags: ApplicationFlags) -> Self {
+        self.fields.flags = Some(flags);
+
+        self
+    }
+
+    /// Set the icon of the application.
+    ///
+    /// This must be a Data URI, in the form of
+    /// `data:image/{type};base64,{data}` where `{type}` is the image MIME type
+    /// and `{data}` is the base64-encoded image. See [Discord Docs/Image Data].
+    ///
+    /// Pass [`None`] to remove the icon.
+    ///
+    /// [Discord Docs/Image Data]: https://discord.com/developers/docs/reference#image-data
+    pub const fn icon(mut self, icon: Option<&'a [u8]>) -> Self {
+        self.fields.icon = Some(Nullable(icon));
+
+        self
+    }
+
+    /// Set the URL which receives interactions over HTTP.
+    pub const fn interactions_endpoint_url(mut self, interactions_endpoint_url: &'a str) -> Self {
+        self.fields.interactions_endpoint_url = Some(interactions_endpoint_url);
+
+        self
+    }
+
+    /// Set the tags describing the content and functionality of the
+    /// application.
+    ///
+    /// There can be at most 5 tags, each at most 20 characters long.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ApplicationTags`] if there are too many
+    /// tags.
+    ///
+    /// Returns an error of type [`ApplicationTag`] if a tag is too long.
+    ///
+    /// [`ApplicationTag`]: twilight_validate::request::ValidationErrorType::ApplicationTag
+    /// [`ApplicationTags`]: twilight_validate::request::ValidationErrorType::ApplicationTags
+    pub fn tags(mut self, tags: &'a [&'a str]) -> Result<Self, ValidationError> {
+        validate_application_tags(tags)?;
+
+        self.fields.tags = Some(tags);
+
+        Ok(self)
+    }
+
+    /// Execute the request, returning a future resolving to a [`Response`].
+    ///
+    /// [`Response`]: crate::response::Response
+    pub fn exec(self) -> ResponseFuture<Application> {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl TryIntoRequest for UpdateCurrentApplication<'_> {
+    fn try_into_request(self) -> Result<Request, Error> {
+        let mut request = Request::builder(&Route::UpdateCurrentApplication);
+
+        request = request.json(&self.fields)?;
+
+        Ok(request.build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{UpdateCurrentApplication, UpdateCurrentApplicationFields};
+    use crate::{
+        client::Client,
+        request::{Nullable, Request, TryIntoRequest},
+        routing::Route,
+    };
+    use std::error::Error;
+    use twilight_model::oauth::ApplicationFlags;
+
+    #[test]
+    fn update_current_application() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("foo".to_owned());
+        let tags = ["moderation", "utility"];
+
+        let actual = UpdateCurrentApplication::new(&client)
+            .description("a description")
+            .flags(ApplicationFlags::GATEWAY_MESSAGE_CONTENT_LIMITED)
+            .icon(None)
+            .interactions_endpoint_url("https://example.com/interactions")
+            .tags(&tags)?
+            .try_into_request()?;
+
+        let expected = Request::builder(&Route::UpdateCurrentApplication)
+            .json(&UpdateCurrentApplicationFields {
+                description: Some("a description"),
+                flags: Some(ApplicationFlags::GATEWAY_MESSAGE_CONTENT_LIMITED),
+                icon: Some(Nullable(None)),
+                interactions_endpoint_url: Some("https://example.com/interactions"),
+                tags: Some(&tags),
+            })?
+            .build();
+
+        assert_eq!(expected.body, actual.body);
+        assert_eq!(expected.path, actual.path);
+        assert_eq!(
+            Some(
+                br#"{"description":"a description","flags":524288,"icon":null,"interactions_endpoint_url":"https://example.com/interactions","tags":["moderation","utility"]}"#
+                    .as_slice()
+            ),
+            actual.body()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn tags_invalid() {
+        let client = Client::new("foo".to_owned());
+
+        assert!(UpdateCurrentApplication::new(&client)
+            .tags(&["a", "b", "c", "d", "e", "f"])
+            .is_err());
+        assert!(UpdateCurrentApplication::new(&client)
+            .tags(&["this tag is too long to be valid"])
+            .is_err());
+    }
+}
@@ -8,6 +8,13 @@ use twilight_model::id::{marker::RoleMarker, Id};
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 #[non_exhaustive]
 pub enum Route<'a> {
+    /// Route information to add a user to a group DM.
+    AddGroupDmRecipient {
+        /// ID of the group DM channel.
+        channel_id: u64,
+        /// ID of the user.
+        user_id: u64,
+    },
     /// Route information to add a user to a guild.
     AddGuildMember { guild_id: u64, user_id: u64 },
     /// Route information to add a role to guild member.
@@ -28,14 +35,16 @@ pub enum Route<'a> {
     },
     /// Route information to create a ban on a user in a guild.
     CreateBan {
-        /// The number of days' worth of the user's messages to delete in the
-        /// guild's channels.
-        delete_message_days: Option<u16>,
         /// The ID of the guild.
         guild_id: u64,
         /// The ID of the user.
         user_id: u64,
     },
+    /// Route information to ban multiple users from a guild.
+    CreateBulkBan {
+        /// The ID of the guild.
+        guild_id: u64,
+    },
     /// Route information to create a channel in a guild.
     CreateChannel {
         /// The ID of the guild.
@@ -321,6 +330,13 @@ pub enum Route<'a> {
         /// The ID of the webhook.
         webhook_id: u64,
     },
+    /// Route information to immediately end a poll.
+    EndPoll {
+        /// The ID of the channel.
+        channel_id: u64,
+        /// The ID of the message.
+        message_id: u64,
+    },
     /// Route information to execute a webhook by ID and token.
     ExecuteWebhook {
         /// ID of the thread channel, if there is one.
@@ -416,6 +432,11 @@ pub enum Route<'a> {
         /// ID of the guild.
         guild_id: u64,
     },
+    /// Route information to get the current user's voice state.
+    GetCurrentUserVoiceState {
+        /// ID of the guild.
+        guild_id: u64,
+    },
     /// Route information to get an emoji by ID within a guild.
     GetEmoji {
         /// The ID of the emoji.
@@ -516,6 +537,11 @@ pub enum Route<'a> {
         /// Whether to get the members' presences.
         presences: Option<bool>,
     },
+    /// Route information to get a guild's onboarding.
+    GetGuildOnboarding {
+        /// The ID of the guild.
+        guild_id: u64,
+    },
     /// Route information to get a guild's preview.
     GetGuildPreview {
         /// The ID of the guild.
@@ -630,6 +656,8 @@ pub enum Route<'a> {
     GetInviteWithExpiration {
         /// The unique invite code.
         code: &'a str,
+        /// ID of the guild scheduled event to include with the invite.
+        guild_scheduled_event_id: Option<u64>,
         /// Whether to retrieve statistics about the invite.
         with_counts: bool,
         /// Whether to retrieve the expiration date of the invite.
@@ -665,6 +693,19 @@ pub enum Route<'a> {
     /// Route information to get a list of sticker packs available to Nitro
     /// subscribers.
     GetNitroStickerPacks,
+    /// Route information to get the users who voted for an answer of a poll.
+    GetPollAnswerVoters {
+        /// The minimum ID of users to get.
+        after: Option<u64>,
+        /// The ID of the answer.
+        answer_id: u8,
+        /// The ID of the channel.
+        channel_id: u64,
+        /// The maximum number of users to retrieve.
+        limit: Option<u16>,
+        /// The ID of the message.
+        message_id: u64,
+    },
     /// Route information to get a channel's pins.
     GetPins {
         /// The ID of the channel.
@@ -752,6 +793,13 @@ pub enum Route<'a> {
     GetUserConnections,
     /// Route information to get the current user's private channels and groups.
     GetUserPrivateChannels,
+    /// Route information to get a user's voice state.
+    GetUserVoiceState {
+        /// ID of the guild.
+        guild_id: u64,
+        /// ID of the user.
+        user_id: u64,
+    },
     /// Route information to get a list of the voice regions.
     GetVoiceRegions,
     /// Route information to get a webhook by ID, optionally with a token if the
@@ -802,6 +850,13 @@ pub enum Route<'a> {
         /// The ID of the message.
         message_id: u64,
     },
+    /// Route information to remove a user from a group DM.
+    RemoveGroupDmRecipient {
+        /// ID of the group DM channel.
+        channel_id: u64,
+        /// ID of the user.
+        user_id: u64,
+    },
     /// Route information to remove a member from a guild.
     RemoveMember {
         /// The ID of the guild.
@@ -881,6 +936,8 @@ pub enum Route<'a> {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to update the current application.
+    UpdateCurrentApplication,
     /// Route information to update the current member.
     UpdateCurrentMember {
         /// ID of the guild.
@@ -938,6 +995,16 @@ pub enum Route<'a> {
         /// The ID of the integration.
         integration_id: u64,
     },
+    /// Route information to update a guild's MFA level.
+    UpdateGuildMfaLevel {
+        /// ID of the guild.
+        guild_id: u64,
+    },
+    /// Route information to update a guild's onboarding.
+    UpdateGuildOnboarding {
+        /// ID of the guild.
+        guild_id: u64,
+    },
     /// Route information to update a scheduled event in a guild.
     UpdateGuildScheduledEvent {
         /// ID of the guild.
@@ -1085,6 +1152,7 @@ impl<'a> Route<'a> {
             | Self::DeleteWebhook { .. }
             | Self::LeaveGuild { .. }
             | Self::LeaveThread { .. }
+            | Self::RemoveGroupDmRecipient { .. }
             | Self::RemoveMember { .. }
             | Self::RemoveMemberRole { .. }
             | Self::RemoveThreadMember { .. }
@@ -1103,6 +1171,7 @@ impl<'a> Route<'a> {
             | Self::GetCurrentUserApplicationInfo
             | Self::GetCurrentUser
             | Self::GetCurrentUserGuildMember { .. }
+            | Self::GetCurrentUserVoiceState { .. }
             | Self::GetEmoji { .. }
             | Self::GetEmojis { .. }
             | Self::GetGateway
@@ -1116,6 +1185,7 @@ impl<'a> Route<'a> {
             | Self::GetGuildIntegrations { .. }
             | Self::GetGuildInvites { .. }
             | Self::GetGuildMembers { .. }
+            | Self::GetGuildOnboarding { .. }
             | Self::GetGuildPreview { .. }
             | Self::GetGuildPruneCount { .. }
             | Self::GetGuildRoles { .. }
@@ -1138,6 +1208,7 @@ impl<'a> Route<'a> {
             | Self::GetMessages { .. }
             | Self::GetNitroStickerPacks { .. }
             | Self::GetPins { .. }
+            | Self::GetPollAnswerVoters { .. }
             | Self::GetJoinedPrivateArchivedThreads { .. }
             | Self::GetPrivateArchivedThreads { .. }
             | Self::GetPublicArchivedThreads { .. }
@@ -1151,11 +1222,13 @@ impl<'a> Route<'a> {
             | Self::GetUserConnections
             | Self::GetUserPrivateChannels
             | Self::GetUser { .. }
+            | Self::GetUserVoiceState { .. }
             | Self::GetVoiceRegions
             | Self::GetWebhook { .. }
             | Self::GetWebhookMessage { .. }
             | Self::SearchGuildMembers { .. } => Method::Get,
             Self::UpdateChannel { .. }
+            | Self::UpdateCurrentApplication
             | Self::UpdateCurrentMember { .. }
             | Self::UpdateCurrentUser
             | Self::UpdateCurrentUserVoiceState { .. }
@@ -1180,7 +1253,8 @@ impl<'a> Route<'a> {
             | Self::UpdateUserVoiceState { .. }
             | Self::UpdateWebhookMessage { .. }
             | Self::UpdateWebhook { .. } => Method::Patch,
-            Self::CreateChannel { .. }
+            Self::CreateBulkBan { .. }
+            | Self::CreateChannel { .. }
             | Self::CreateGlobalCommand { .. }
             | Self::CreateGuildCommand { .. }
             | Self::CreateEmoji { .. }
@@ -1202,11 +1276,14 @@ impl<'a> Route<'a> {
             | Self::CreateWebhook { .. }
             | Self::CrosspostMessage { .. }
             | Self::DeleteMessages { .. }
+            | Self::EndPoll { .. }
             | Self::ExecuteWebhook { .. }
             | Self::FollowNewsChannel { .. }
             | Self::InteractionCallback { .. }
-            | Self::SyncGuildIntegration { .. } => Method::Post,
-            Self::AddGuildMember { .. }
+            | Self::SyncGuildIntegration { .. }
+            | Self::UpdateGuildMfaLevel { .. } => Method::Post,
+            Self::AddGroupDmRecipient { .. }
+            | Self::AddGuildMember { .. }
             | Self::AddMemberRole { .. }
             | Self::AddThreadMember { .. }
             | Self::CreateBan { .. }
@@ -1217,6 +1294,7 @@ impl<'a> Route<'a> {
             | Self::SetGuildCommands { .. }
             | Self::SyncTemplate { .. }
             | Self::UpdateCommandPermissions { .. }
+            | Self::UpdateGuildOnboarding { .. }
             | Self::UpdatePermissionOverwrite { .. } => Method::Put,
         }
     }
@@ -1254,6 +1332,10 @@ impl<'a> Route<'a> {
     #[allow(clippy::too_many_lines)]
     pub fn to_path(&self) -> Path {
         match *self {
+            Self::AddGroupDmRecipient { channel_id, .. }
+            | Self::RemoveGroupDmRecipient { channel_id, .. } => {
+                Path::ChannelsIdRecipients(channel_id)
+            }
             Self::AddGuildMember { guild_id, .. }
             | Self::GetMember { guild_id, .. }
             | Self::RemoveMember { guild_id, .. }
@@ -1272,6 +1354,7 @@ impl<'a> Route<'a> {
             Self::CreateBan { guild_id, .. } | Self::DeleteBan { guild_id, .. } => {
                 Path::GuildsIdBansUserId(guild_id)
             }
+            Self::CreateBulkBan { guild_id } => Path::GuildsIdBulkBan(guild_id),
             Self::CreateChannel { guild_id } => Path::GuildsIdChannels(guild_id),
             Self::CreateEmoji { guild_id } | Self::GetEmojis { guild_id } => {
                 Path::GuildsIdEmojis(guild_id)
@@ -1432,6 +1515,7 @@ impl<'a> Route<'a> {
             Self::DeleteWebhook { webhook_id, .. }
             | Self::GetWebhook { webhook_id, .. }
             | Self::UpdateWebhook { webhook_id, .. } => (Path::WebhooksId(webhook_id)),
+            Self::EndPoll { channel_id, .. } => Path::ChannelsIdPollsIdExpire(channel_id),
             Self::FollowNewsChannel { channel_id } => Path::ChannelsIdFollowers(channel_id),
             Self::GetJoinedPrivateArchivedThreads { channel_id, .. }
             | Self::GetPrivateArchivedThreads { channel_id, .. }
@@ -1456,6 +1540,7 @@ impl<'a> Route<'a> {
                 Path::ApplicationGuildCommandId(application_id)
             }
             Self::GetCurrentUserApplicationInfo => Path::OauthApplicationsMe,
+            Self::UpdateCurrentApplication => Path::ApplicationsMe,
             Self::GetCurrentUser | Self::GetUser { .. } | Self::UpdateCurrentUser => Path::UsersId,
             Self::GetCurrentUserGuildMember { .. } => Path::UsersIdGuildsIdMember,
             Self::GetEmoji { guild_id, .. } | Self::UpdateEmoji { guild_id, .. } => {
@@ -1485,6 +1570,10 @@ impl<'a> Route<'a> {
             Self::GetGuildScheduledEventUsers { guild_id, .. } => {
                 Path::GuildsIdScheduledEventsIdUsers(guild_id)
             }
+            Self::GetGuildOnboarding { guild_id } | Self::UpdateGuildOnboarding { guild_id } => {
+                Path::GuildsIdOnboarding(guild_id)
+            }
+            Self::UpdateGuildMfaLevel { guild_id } => Path::GuildsIdMfa(guild_id),
             Self::GetGuildPreview { guild_id } => Path::GuildsIdPreview(guild_id),
             Self::GetGuildVanityUrl { guild_id } => Path::GuildsIdVanityUrl(guild_id),
             Self::GetGuildVoiceRegions { guild_id } => Path::GuildsIdRegions(guild_id),
@@ -1499,6 +1588,9 @@ impl<'a> Route<'a> {
             Self::GetPins { channel_id } | Self::PinMessage { channel_id, .. } => {
                 Path::ChannelsIdPins(channel_id)
             }
+            Self::GetPollAnswerVoters { channel_id, .. } => {
+                Path::ChannelsIdPollsIdAnswersId(channel_id)
+            }
             Self::GetSticker { .. } => Path::Stickers,
             Self::GetUserConnections => Path::UsersIdConnections,
             Self::GetVoiceRegions => Path::VoiceRegions,
@@ -1511,7 +1603,9 @@ impl<'a> Route<'a> {
                 Path::GuildsIdIntegrationsIdSync(guild_id)
             }
             Self::UnpinMessage { channel_id, .. } => Path::ChannelsIdPinsMessageId(channel_id),
-            Self::UpdateCurrentUserVoiceState { guild_id }
+            Self::GetCurrentUserVoiceState { guild_id }
+            | Self::GetUserVoiceState { guild_id, .. }
+            | Self::UpdateCurrentUserVoiceState { guild_id }
             | Self::UpdateUserVoiceState { guild_id, .. } => Path::GuildsIdVoiceStates(guild_id),
             Self::UpdateMessage { channel_id, .. } => {
                 Path::ChannelsIdMessagesId(Method::Patch, channel_id)
@@ -1565,6 +1659,20 @@ impl Display for Route<'_> {
     #[allow(clippy::too_many_lines)]
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self {
+            Route::AddGroupDmRecipient {
+                channel_id,
+                user_id,
+            }
+            | Route::RemoveGroupDmRecipient {
+                channel_id,
+                user_id,
+            } => {
+                f.write_str("channels/")?;
+                Display::fmt(channel_id, f)?;
+                f.write_str("/recipients/")?;
+
+                Display::fmt(user_id, f)
+            }
             Route::AddGuildMember { guild_id, user_id }
             | Route::GetMember { guild_id, user_id }
             | Route::RemoveMember { guild_id, user_id }
@@ -1611,23 +1719,11 @@ impl Display for Route<'_> {
 
                 Display::fmt(user_id, f)
             }
-            Route::CreateBan {
-                guild_id,
-                delete_message_days,
-                user_id,
-            } => {
+            Route::CreateBulkBan { guild_id } => {
                 f.write_str("guilds/")?;
                 Display::fmt(guild_id, f)?;
-                f.write_str("/bans/")?;
-                Display::fmt(user_id, f)?;
-                f.write_str("?")?;
 
-                if let Some(delete_message_days) = delete_message_days {
-                    f.write_str("delete_message_days=")?;
-                    Display::fmt(delete_message_days, f)?;
-                }
-
-                Ok(())
+                f.write_str("/bulk-ban")
             }
             Route::CreateChannel { guild_id }
             | Route::GetChannels { guild_id }
@@ -1850,7 +1946,9 @@ impl Display for Route<'_> {
 
                 f.write_str("/crosspost")
             }
-            Route::DeleteBan { guild_id, user_id } | Route::GetBan { guild_id, user_id } => {
+            Route::CreateBan { guild_id, user_id }
+            | Route::DeleteBan { guild_id, user_id }
+            | Route::GetBan { guild_id, user_id } => {
                 f.write_str("guilds/")?;
                 Display::fmt(guild_id, f)?;
                 f.write_str("/bans/")?;
@@ -2118,6 +2216,17 @@ impl Display for Route<'_> {
 
                 Ok(())
             }
+            Route::EndPoll {
+                channel_id,
+                message_id,
+            } => {
+                f.write_str("channels/")?;
+                Display::fmt(channel_id, f)?;
+                f.write_str("/polls/")?;
+                Display::fmt(message_id, f)?;
+
+                f.write_str("/expire")
+            }
             Route::ExecuteWebhook {
                 thread_id,
                 token,
@@ -2242,6 +2351,7 @@ impl Display for Route<'_> {
                 f.write_str("/permissions")
             }
             Route::GetCurrentUserApplicationInfo => f.write_str("oauth2/applications/@me"),
+            Route::UpdateCurrentApplication => f.write_str("applications/@me"),
             Route::GetCurrentUser | Route::UpdateCurrentUser => f.write_str("users/@me"),
             Route::GetCurrentUserGuildMember { guild_id } => {
                 f.write_str("users/@me/guilds/")?;
@@ -2307,6 +2417,18 @@ impl Display for Route<'_> {
 
                 Ok(())
             }
+            Route::GetGuildOnboarding { guild_id } | Route::UpdateGuildOnboarding { guild_id } => {
+                f.write_str("guilds/")?;
+                Display::fmt(guild_id, f)?;
+
+                f.write_str("/onboarding")
+            }
+            Route::UpdateGuildMfaLevel { guild_id } => {
+                f.write_str("guilds/")?;
+                Display::fmt(guild_id, f)?;
+
+                f.write_str("/mfa")
+            }
             Route::GetGuildPreview { guild_id } => {
                 f.write_str("guilds/")?;
                 Display::fmt(guild_id, f)?;
@@ -2496,6 +2618,7 @@ impl Display for Route<'_> {
             }
             Route::GetInviteWithExpiration {
                 code,
+                guild_scheduled_event_id,
                 with_counts,
                 with_expiration,
             } => {
@@ -2503,8 +2626,21 @@ impl Display for Route<'_> {
                 f.write_str(code)?;
                 f.write_str("?")?;
 
+                if let Some(guild_scheduled_event_id) = guild_scheduled_event_id {
+                    f.write_str("guild_scheduled_event_id=")?;
+                    Display::fmt(guild_scheduled_event_id, f)?;
+
+                    if *with_counts || *with_expiration {
+                        f.write_str("&")?;
+                    }
+                }
+
                 if *with_counts {
                     f.write_str("with-counts=true")?;
+
+                    if *with_expiration {
+                        f.write_str("&")?;
+                    }
                 }
 
                 if *with_expiration {
@@ -2553,6 +2689,33 @@ impl Display for Route<'_> {
 
                 f.write_str("/pins")
             }
+            Route::GetPollAnswerVoters {
+                after,
+                answer_id,
+                channel_id,
+                limit,
+                message_id,
+            } => {
+                f.write_str("channels/")?;
+                Display::fmt(channel_id, f)?;
+                f.write_str("/polls/")?;
+                Display::fmt(message_id, f)?;
+                f.write_str("/answers/")?;
+                Display::fmt(answer_id, f)?;
+                f.write_str("?")?;
+
+                if let Some(after) = after {
+                    f.write_str("after=")?;
+                    Display::fmt(after, f)?;
+                }
+
+                if let Some(limit) = limit {
+                    f.write_str("&limit=")?;
+                    Display::fmt(limit, f)?;
+                }
+
+                Ok(())
+            }
             Route::GetJoinedPrivateArchivedThreads {
                 before,
                 channel_id,
@@ -2731,7 +2894,8 @@ impl Display for Route<'_> {
 
                 f.write_str("/members/@me")
             }
-            Route::UpdateCurrentUserVoiceState { guild_id } => {
+            Route::GetCurrentUserVoiceState { guild_id }
+            | Route::UpdateCurrentUserVoiceState { guild_id } => {
                 f.write_str("guilds/")?;
                 Display::fmt(guild_id, f)?;
 
@@ -2757,7 +2921,8 @@ impl Display for Route<'_> {
 
                 f.write_str("/members/@me/nick")
             }
-            Route::UpdateUserVoiceState { guild_id, user_id } => {
+            Route::GetUserVoiceState { guild_id, user_id }
+            | Route::UpdateUserVoiceState { guild_id, user_id } => {
                 f.write_str("guilds/")?;
                 Display::fmt(guild_id, f)?;
                 f.write_str("/voice-states/")?;
@@ -2862,6 +3027,30 @@ mod tests {
         assert_eq!("webhooks/3/token/messages/1?thread_id=2", route.to_string())
     }
 
+    #[test]
+    fn add_group_dm_recipient() {
+        let route = Route::AddGroupDmRecipient {
+            channel_id: CHANNEL_ID,
+            user_id: USER_ID,
+        };
+        assert_eq!(
+            route.to_string(),
+            format!("channels/{CHANNEL_ID}/recipients/{USER_ID}")
+        );
+    }
+
+    #[test]
+    fn remove_group_dm_recipient() {
+        let route = Route::RemoveGroupDmRecipient {
+            channel_id: CHANNEL_ID,
+            user_id: USER_ID,
+        };
+        assert_eq!(
+            route.to_string(),
+            format!("channels/{CHANNEL_ID}/recipients/{USER_ID}")
+        );
+    }
+
     #[test]
     fn add_guild_member() {
         let route = Route::AddGuildMember {
@@ -3309,6 +3498,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn end_poll() {
+        let route = Route::EndPoll {
+            channel_id: CHANNEL_ID,
+            message_id: MESSAGE_ID,
+        };
+        assert_eq!(
+            route.to_string(),
+            format!("channels/{CHANNEL_ID}/polls/{MESSAGE_ID}/expire")
+        );
+    }
+
     #[test]
     fn delete_ban() {
         let route = Route::DeleteBan {
@@ -3871,6 +4072,12 @@ mod tests {
         assert_eq!(route.to_string(), "oauth2/applications/@me");
     }
 
+    #[test]
+    fn update_current_application() {
+        let route = Route::UpdateCurrentApplication;
+        assert_eq!(route.to_string(), "applications/@me");
+    }
+
     #[test]
     fn get_current_user() {
         let route = Route::GetCurrentUser;
@@ -3886,6 +4093,15 @@ mod tests {
         )
     }
 
+    #[test]
+    fn get_current_user_voice_state() {
+        let route = Route::GetCurrentUserVoiceState { guild_id: GUILD_ID };
+        assert_eq!(
+            route.to_string(),
+            format!("guilds/{GUILD_ID}/voice-states/@me")
+        );
+    }
+
     #[test]
     fn update_current_user() {
         let route = Route::UpdateCurrentUser;
@@ -3916,6 +4132,24 @@ mod tests {
         assert_eq!(route.to_string(), format!("guilds/{GUILD_ID}/invites"));
     }
 
+    #[test]
+    fn get_guild_onboarding() {
+        let route = Route::GetGuildOnboarding { guild_id: GUILD_ID };
+        assert_eq!(route.to_string(), format!("guilds/{GUILD_ID}/onboarding"));
+    }
+
+    #[test]
+    fn update_guild_mfa_level() {
+        let route = Route::UpdateGuildMfaLevel { guild_id: GUILD_ID };
+        assert_eq!(route.to_string(), format!("guilds/{GUILD_ID}/mfa"));
+    }
+
+    #[test]
+    fn update_guild_onboarding() {
+        let route = Route::UpdateGuildOnboarding { guild_id: GUILD_ID };
+        assert_eq!(route.to_string(), format!("guilds/{GUILD_ID}/onboarding"));
+    }
+
     #[test]
     fn get_guild_preview() {
         let route = Route::GetGuildPreview { guild_id: GUILD_ID };
@@ -4021,6 +4255,21 @@ mod tests {
         assert_eq!(route.to_string(), format!("channels/{CHANNEL_ID}/pins"));
     }
 
+    #[test]
+    fn get_poll_answer_voters() {
+        let route = Route::GetPollAnswerVoters {
+            after: Some(USER_ID),
+            answer_id: 1,
+            channel_id: CHANNEL_ID,
+            limit: Some(10),
+            message_id: MESSAGE_ID,
+        };
+        assert_eq!(
+            route.to_string(),
+            format!("channels/{CHANNEL_ID}/polls/{MESSAGE_ID}/answers/1?after={USER_ID}&limit=10")
+        );
+    }
+
     #[test]
     fn get_sticker() {
         let route = Route::GetSticker {
@@ -4052,6 +4301,18 @@ mod tests {
         assert_eq!(route.to_string(), format!("users/{USER_ID}"));
     }
 
+    #[test]
+    fn get_user_voice_state() {
+        let route = Route::GetUserVoiceState {
+            guild_id: GUILD_ID,
+            user_id: USER_ID,
+        };
+        assert_eq!(
+            route.to_string(),
+            format!("guilds/{GUILD_ID}/voice-states/{USER_ID}")
+        );
+    }
+
     #[test]
     fn get_voice_regions() {
         let route = Route::GetVoiceRegions;
@@ -4172,25 +4433,20 @@ mod tests {
 
     #[test]
     fn create_ban() {
-        let mut route = Route::CreateBan {
+        let route = Route::CreateBan {
             guild_id: GUILD_ID,
-            delete_message_days: None,
             user_id: USER_ID,
         };
         assert_eq!(
             route.to_string(),
-            format!("guilds/{GUILD_ID}/bans/{USER_ID}?")
+            format!("guilds/{GUILD_ID}/bans/{USER_ID}")
         );
+    }
 
-        route = Route::CreateBan {
-            guild_id: GUILD_ID,
-            delete_message_days: Some(3),
-            user_id: USER_ID,
-        };
-        assert_eq!(
-            route.to_string(),
-            format!("guilds/{GUILD_ID}/bans/{USER_ID}?delete_message_days=3")
-        );
+    #[test]
+    fn create_bulk_ban() {
+        let route = Route::CreateBulkBan { guild_id: GUILD_ID };
+        assert_eq!(route.to_string(), format!("guilds/{GUILD_ID}/bulk-ban"));
     }
 
     #[test]
@@ -4294,6 +4550,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn get_invite_with_expiration() {
+        let route = Route::GetInviteWithExpiration {
+            code: CODE,
+            guild_scheduled_event_id: None,
+            with_counts: true,
+            with_expiration: true,
+        };
+
+        assert_eq!(
+            route.to_string(),
+            format!("invites/{CODE}?with-counts=true&with-expiration=true")
+        );
+
+        let route = Route::GetInviteWithExpiration {
+            code: CODE,
+            guild_scheduled_event_id: Some(SCHEDULED_EVENT_ID),
+            with_counts: false,
+            with_expiration: true,
+        };
+
+        assert_eq!(
+            route.to_string(),
+            format!(
+                "invites/{CODE}?guild_scheduled_event_id={SCHEDULED_EVENT_ID}&with-expiration=true"
+            )
+        );
+    }
+
     #[test]
     fn get_guild_scheduled_events() {
         let route = Route::GetGuildScheduledEvents {
@@ -15,11 +15,13 @@ pub use self::{
 };
 
 use crate::{
+    application::interaction::InteractionContextType,
     guild::Permissions,
     id::{
         marker::{ApplicationMarker, CommandMarker, CommandVersionMarker, GuildMarker},
         Id,
     },
+    oauth::ApplicationIntegrationType,
 };
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -39,6 +41,12 @@ use std::collections::HashMap;
 pub struct Command {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub application_id: Option<Id<ApplicationMarker>>,
+    /// Contexts in which the command can be invoked.
+    ///
+    /// This is only relevant for globally-scoped commands. By default, commands
+    /// can be invoked in all contexts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contexts: Option<Vec<InteractionContextType>>,
     /// Default permissions required for a member to run the command.
     ///
     /// Setting this [`Permissions::empty()`] will prohibit anyone from running
@@ -69,6 +77,12 @@ pub struct Command {
     pub guild_id: Option<Id<GuildMarker>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<Id<CommandMarker>>,
+    /// Installation contexts where the command is available.
+    ///
+    /// This is only relevant for globally-scoped commands. By default, commands
+    /// are available in the application's configured installation contexts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_types: Option<Vec<ApplicationIntegrationType>>,
     #[serde(rename = "type")]
     pub kind: CommandType,
     pub name: String,
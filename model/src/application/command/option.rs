@@ -809,6 +809,7 @@ mod tests {
     fn test_command_option_full() {
         let value = Command {
             application_id: Some(Id::new(100)),
+            contexts: None,
             default_member_permissions: Some(Permissions::ADMINISTRATOR),
             dm_permission: Some(false),
             description: "this command is a test".into(),
@@ -818,6 +819,7 @@ mod tests {
             )])),
             guild_id: Some(Id::new(300)),
             id: Some(Id::new(200)),
+            integration_types: None,
             kind: CommandType::ChatInput,
             name: "test command".into(),
             name_localizations: Some(HashMap::from([("en-US".into(), "test command".into())])),
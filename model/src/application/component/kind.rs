@@ -19,7 +19,7 @@ pub enum ComponentType {
     /// [`Button`]: super::Button
     Button = 2,
 
-    /// Component is an [`SelectMenu`].
+    /// Component is a [`SelectMenu`] of developer defined text options.
     ///
     /// [`SelectMenu`]: super::SelectMenu
     SelectMenu = 3,
@@ -28,6 +28,26 @@ pub enum ComponentType {
     ///
     /// [`TextInput`]: super::TextInput
     TextInput = 4,
+
+    /// Component is a [`SelectMenu`] of users.
+    ///
+    /// [`SelectMenu`]: super::SelectMenu
+    UserSelectMenu = 5,
+
+    /// Component is a [`SelectMenu`] of roles.
+    ///
+    /// [`SelectMenu`]: super::SelectMenu
+    RoleSelectMenu = 6,
+
+    /// Component is a [`SelectMenu`] of users and roles.
+    ///
+    /// [`SelectMenu`]: super::SelectMenu
+    MentionableSelectMenu = 7,
+
+    /// Component is a [`SelectMenu`] of channels.
+    ///
+    /// [`SelectMenu`]: super::SelectMenu
+    ChannelSelectMenu = 8,
 }
 
 impl ComponentType {
@@ -52,6 +72,10 @@ impl ComponentType {
             Self::Button => "Button",
             Self::SelectMenu => "SelectMenu",
             Self::TextInput => "TextInput",
+            Self::UserSelectMenu => "UserSelectMenu",
+            Self::RoleSelectMenu => "RoleSelectMenu",
+            Self::MentionableSelectMenu => "MentionableSelectMenu",
+            Self::ChannelSelectMenu => "ChannelSelectMenu",
         }
     }
 }
@@ -85,12 +109,22 @@ mod tests {
     const_assert_eq!(1, ComponentType::ActionRow as u8);
     const_assert_eq!(2, ComponentType::Button as u8);
     const_assert_eq!(3, ComponentType::SelectMenu as u8);
+    const_assert_eq!(4, ComponentType::TextInput as u8);
+    const_assert_eq!(5, ComponentType::UserSelectMenu as u8);
+    const_assert_eq!(6, ComponentType::RoleSelectMenu as u8);
+    const_assert_eq!(7, ComponentType::MentionableSelectMenu as u8);
+    const_assert_eq!(8, ComponentType::ChannelSelectMenu as u8);
 
     #[test]
     fn variants() {
         serde_test::assert_tokens(&ComponentType::ActionRow, &[Token::U8(1)]);
         serde_test::assert_tokens(&ComponentType::Button, &[Token::U8(2)]);
         serde_test::assert_tokens(&ComponentType::SelectMenu, &[Token::U8(3)]);
+        serde_test::assert_tokens(&ComponentType::TextInput, &[Token::U8(4)]);
+        serde_test::assert_tokens(&ComponentType::UserSelectMenu, &[Token::U8(5)]);
+        serde_test::assert_tokens(&ComponentType::RoleSelectMenu, &[Token::U8(6)]);
+        serde_test::assert_tokens(&ComponentType::MentionableSelectMenu, &[Token::U8(7)]);
+        serde_test::assert_tokens(&ComponentType::ChannelSelectMenu, &[Token::U8(8)]);
     }
 
     #[test]
@@ -98,5 +132,13 @@ mod tests {
         assert_eq!("ActionRow", ComponentType::ActionRow.name());
         assert_eq!("Button", ComponentType::Button.name());
         assert_eq!("SelectMenu", ComponentType::SelectMenu.name());
+        assert_eq!("TextInput", ComponentType::TextInput.name());
+        assert_eq!("UserSelectMenu", ComponentType::UserSelectMenu.name());
+        assert_eq!("RoleSelectMenu", ComponentType::RoleSelectMenu.name());
+        assert_eq!(
+            "MentionableSelectMenu",
+            ComponentType::MentionableSelectMenu.name()
+        );
+        assert_eq!("ChannelSelectMenu", ComponentType::ChannelSelectMenu.name());
     }
 }
@@ -20,7 +20,10 @@ pub use self::{
     text_input::TextInput,
 };
 
-use crate::{application::component::select_menu::SelectMenuOption, channel::ReactionType};
+use crate::{
+    application::component::select_menu::{SelectDefaultValue, SelectMenuOption, SelectMenuType},
+    channel::{ChannelType, ReactionType},
+};
 use serde::{
     de::{Deserializer, Error as DeError, IgnoredAny, MapAccess, Visitor},
     ser::SerializeStruct,
@@ -67,12 +70,23 @@ impl Component {
         match self {
             Self::ActionRow(_) => ComponentType::ActionRow,
             Self::Button(_) => ComponentType::Button,
-            Self::SelectMenu(_) => ComponentType::SelectMenu,
+            Self::SelectMenu(select_menu) => select_menu_kind(select_menu.kind),
             Self::TextInput(_) => ComponentType::TextInput,
         }
     }
 }
 
+/// Component type of a select menu of the given type.
+const fn select_menu_kind(kind: SelectMenuType) -> ComponentType {
+    match kind {
+        SelectMenuType::Channel => ComponentType::ChannelSelectMenu,
+        SelectMenuType::Mentionable => ComponentType::MentionableSelectMenu,
+        SelectMenuType::Role => ComponentType::RoleSelectMenu,
+        SelectMenuType::Text => ComponentType::SelectMenu,
+        SelectMenuType::User => ComponentType::UserSelectMenu,
+    }
+}
+
 impl From<ActionRow> for Component {
     fn from(action_row: ActionRow) -> Self {
         Self::ActionRow(action_row)
@@ -106,8 +120,10 @@ impl<'de> Deserialize<'de> for Component {
 #[derive(Debug, Deserialize)]
 #[serde(field_identifier, rename_all = "snake_case")]
 enum Field {
+    ChannelTypes,
     Components,
     CustomId,
+    DefaultValues,
     Disabled,
     Emoji,
     Label,
@@ -138,7 +154,6 @@ impl<'de> Visitor<'de> for ComponentVisitor {
         // Required fields.
         let mut components: Option<Vec<Component>> = None;
         let mut kind: Option<ComponentType> = None;
-        let mut options: Option<Vec<SelectMenuOption>> = None;
         let mut style: Option<Value> = None;
 
         // Liminal fields.
@@ -146,12 +161,15 @@ impl<'de> Visitor<'de> for ComponentVisitor {
         let mut label: Option<Option<String>> = None;
 
         // Optional fields.
+        let mut channel_types: Option<Option<Vec<ChannelType>>> = None;
+        let mut default_values: Option<Option<Vec<SelectDefaultValue>>> = None;
         let mut disabled: Option<bool> = None;
         let mut emoji: Option<Option<ReactionType>> = None;
         let mut max_length: Option<Option<u16>> = None;
         let mut max_values: Option<Option<u8>> = None;
         let mut min_length: Option<Option<u16>> = None;
         let mut min_values: Option<Option<u8>> = None;
+        let mut options: Option<Option<Vec<SelectMenuOption>>> = None;
         let mut placeholder: Option<Option<String>> = None;
         let mut required: Option<Option<bool>> = None;
         let mut url: Option<Option<String>> = None;
@@ -182,6 +200,13 @@ impl<'de> Visitor<'de> for ComponentVisitor {
             };
 
             match key {
+                Field::ChannelTypes => {
+                    if channel_types.is_some() {
+                        return Err(DeError::duplicate_field("channel_types"));
+                    }
+
+                    channel_types = Some(map.next_value()?);
+                }
                 Field::Components => {
                     if components.is_some() {
                         return Err(DeError::duplicate_field("components"));
@@ -196,6 +221,13 @@ impl<'de> Visitor<'de> for ComponentVisitor {
 
                     custom_id = Some(map.next_value()?);
                 }
+                Field::DefaultValues => {
+                    if default_values.is_some() {
+                        return Err(DeError::duplicate_field("default_values"));
+                    }
+
+                    default_values = Some(map.next_value()?);
+                }
                 Field::Disabled => {
                     if disabled.is_some() {
                         return Err(DeError::duplicate_field("disabled"));
@@ -298,8 +330,10 @@ impl<'de> Visitor<'de> for ComponentVisitor {
         }
 
         tracing::trace!(
+            ?channel_types,
             ?components,
             ?custom_id,
+            ?default_values,
             ?disabled,
             ?emoji,
             ?label,
@@ -358,25 +392,46 @@ impl<'de> Visitor<'de> for ComponentVisitor {
             }
             // Required fields:
             // - custom_id
-            // - options
+            // - options (if this is a text select menu)
             //
             // Optional fields:
+            // - channel_types
+            // - default_values
             // - disabled
             // - max_values
             // - min_values
             // - placeholder
-            ComponentType::SelectMenu => {
+            ComponentType::SelectMenu
+            | ComponentType::UserSelectMenu
+            | ComponentType::RoleSelectMenu
+            | ComponentType::MentionableSelectMenu
+            | ComponentType::ChannelSelectMenu => {
                 let custom_id = custom_id
                     .flatten()
                     .ok_or_else(|| DeError::missing_field("custom_id"))?
                     .deserialize_into()
                     .map_err(DeserializerError::into_error)?;
 
-                let options = options.ok_or_else(|| DeError::missing_field("options"))?;
+                let kind = match kind {
+                    ComponentType::ChannelSelectMenu => SelectMenuType::Channel,
+                    ComponentType::MentionableSelectMenu => SelectMenuType::Mentionable,
+                    ComponentType::RoleSelectMenu => SelectMenuType::Role,
+                    ComponentType::UserSelectMenu => SelectMenuType::User,
+                    _ => SelectMenuType::Text,
+                };
+
+                let options = options.flatten();
+
+                if kind == SelectMenuType::Text && options.is_none() {
+                    return Err(DeError::missing_field("options"));
+                }
 
                 Self::Value::SelectMenu(SelectMenu {
+                    channel_types: channel_types.unwrap_or_default(),
                     custom_id,
+                    default_values: default_values.unwrap_or_default(),
                     disabled: disabled.unwrap_or_default(),
+                    kind,
                     max_values: max_values.unwrap_or_default(),
                     min_values: min_values.unwrap_or_default(),
                     options,
@@ -451,18 +506,23 @@ impl Serialize for Component {
             }
             // Required fields:
             // - custom_id
-            // - options
             // - type
             //
             // Optional fields:
+            // - channel_types
+            // - default_values
             // - disabled
             // - max_values
             // - min_values
+            // - options
             // - placeholder
             Component::SelectMenu(select_menu) => {
-                3 + usize::from(select_menu.disabled)
+                2 + usize::from(select_menu.channel_types.is_some())
+                    + usize::from(select_menu.default_values.is_some())
+                    + usize::from(select_menu.disabled)
                     + usize::from(select_menu.max_values.is_some())
                     + usize::from(select_menu.min_values.is_some())
+                    + usize::from(select_menu.options.is_some())
                     + usize::from(select_menu.placeholder.is_some())
             }
             // Required fields:
@@ -520,12 +580,20 @@ impl Serialize for Component {
                 }
             }
             Component::SelectMenu(select_menu) => {
-                state.serialize_field("type", &ComponentType::SelectMenu)?;
+                state.serialize_field("type", &select_menu_kind(select_menu.kind))?;
+
+                if select_menu.channel_types.is_some() {
+                    state.serialize_field("channel_types", &select_menu.channel_types)?;
+                }
 
                 // Due to `custom_id` being required in some variants and
                 // optional in others, serialize as an Option.
                 state.serialize_field("custom_id", &Some(&select_menu.custom_id))?;
 
+                if select_menu.default_values.is_some() {
+                    state.serialize_field("default_values", &select_menu.default_values)?;
+                }
+
                 state.serialize_field("disabled", &select_menu.disabled)?;
 
                 if select_menu.max_values.is_some() {
@@ -536,7 +604,9 @@ impl Serialize for Component {
                     state.serialize_field("min_values", &select_menu.min_values)?;
                 }
 
-                state.serialize_field("options", &select_menu.options)?;
+                if select_menu.options.is_some() {
+                    state.serialize_field("options", &select_menu.options)?;
+                }
 
                 if select_menu.placeholder.is_some() {
                     state.serialize_field("placeholder", &select_menu.placeholder)?;
@@ -584,8 +654,11 @@ mod tests {
     #![allow(clippy::non_ascii_literal)]
 
     use super::*;
-    use crate::application::component::{
-        button::ButtonStyle, select_menu::SelectMenuOption, text_input::TextInputStyle,
+    use crate::{
+        application::component::{
+            button::ButtonStyle, select_menu::SelectMenuOption, text_input::TextInputStyle,
+        },
+        id::Id,
     };
     use serde_test::Token;
     use static_assertions::assert_impl_all;
@@ -611,17 +684,20 @@ mod tests {
                     url: None,
                 }),
                 Component::SelectMenu(SelectMenu {
+                    channel_types: None,
                     custom_id: "test custom id 2".into(),
+                    default_values: None,
                     disabled: false,
+                    kind: SelectMenuType::Text,
                     max_values: Some(25),
                     min_values: Some(5),
-                    options: Vec::from([SelectMenuOption {
+                    options: Some(Vec::from([SelectMenuOption {
                         label: "test option label".into(),
                         value: "test option value".into(),
                         description: Some("test description".into()),
                         emoji: None,
                         default: false,
-                    }]),
+                    }])),
                     placeholder: Some("test placeholder".into()),
                 }),
             ]),
@@ -673,6 +749,7 @@ mod tests {
                 Token::Some,
                 Token::U8(5),
                 Token::Str("options"),
+                Token::Some,
                 Token::Seq { len: Some(1) },
                 Token::Struct {
                     name: "SelectMenuOption",
@@ -796,6 +873,105 @@ mod tests {
         );
     }
 
+    #[test]
+    fn select_menu_channel() {
+        let value = Component::SelectMenu(SelectMenu {
+            channel_types: Some(Vec::from([ChannelType::GuildText, ChannelType::GuildVoice])),
+            custom_id: "channels".to_owned(),
+            default_values: None,
+            disabled: false,
+            kind: SelectMenuType::Channel,
+            max_values: Some(2),
+            min_values: None,
+            options: None,
+            placeholder: None,
+        });
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Component",
+                    len: 4,
+                },
+                Token::String("type"),
+                Token::U8(ComponentType::ChannelSelectMenu as u8),
+                Token::String("channel_types"),
+                Token::Some,
+                Token::Seq { len: Some(2) },
+                Token::U8(ChannelType::GuildText as u8),
+                Token::U8(ChannelType::GuildVoice as u8),
+                Token::SeqEnd,
+                Token::String("custom_id"),
+                Token::Some,
+                Token::String("channels"),
+                Token::String("disabled"),
+                Token::Bool(false),
+                Token::String("max_values"),
+                Token::Some,
+                Token::U8(2),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn select_menu_user() {
+        let value = Component::SelectMenu(SelectMenu {
+            channel_types: None,
+            custom_id: "users".to_owned(),
+            default_values: Some(Vec::from([SelectDefaultValue::User { id: Id::new(3) }])),
+            disabled: false,
+            kind: SelectMenuType::User,
+            max_values: None,
+            min_values: None,
+            options: None,
+            placeholder: Some("Pick a user".to_owned()),
+        });
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Component",
+                    len: 4,
+                },
+                Token::String("type"),
+                Token::U8(ComponentType::UserSelectMenu as u8),
+                Token::String("custom_id"),
+                Token::Some,
+                Token::String("users"),
+                Token::String("default_values"),
+                Token::Some,
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "SelectDefaultValue",
+                    len: 2,
+                },
+                Token::String("type"),
+                Token::String("user"),
+                Token::String("id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::String("3"),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::String("disabled"),
+                Token::Bool(false),
+                Token::String("placeholder"),
+                Token::Some,
+                Token::String("Pick a user"),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn select_menu_text_missing_options() {
+        let result = serde_json::from_str::<Component>(r#"{"type":3,"custom_id":"text"}"#);
+
+        assert!(result.is_err());
+    }
+
     #[test]
     fn text_input() {
         let value = Component::TextInput(TextInput {
@@ -845,4 +1021,51 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn text_input_paragraph_action_row() {
+        let value = Component::ActionRow(ActionRow {
+            components: Vec::from([Component::TextInput(TextInput {
+                custom_id: "feedback".to_owned(),
+                label: "Feedback".to_owned(),
+                max_length: None,
+                min_length: None,
+                placeholder: None,
+                required: None,
+                style: TextInputStyle::Paragraph,
+                value: None,
+            })]),
+        });
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Component",
+                    len: 2,
+                },
+                Token::String("type"),
+                Token::U8(ComponentType::ActionRow as u8),
+                Token::String("components"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "Component",
+                    len: 4,
+                },
+                Token::String("type"),
+                Token::U8(ComponentType::TextInput as u8),
+                Token::String("custom_id"),
+                Token::Some,
+                Token::String("feedback"),
+                Token::String("label"),
+                Token::Some,
+                Token::String("Feedback"),
+                Token::String("style"),
+                Token::U8(TextInputStyle::Paragraph as u8),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
 }
@@ -1,4 +1,10 @@
-use crate::channel::ReactionType;
+use crate::{
+    channel::{ChannelType, ReactionType},
+    id::{
+        marker::{ChannelMarker, RoleMarker, UserMarker},
+        Id,
+    },
+};
 use serde::{Deserialize, Serialize};
 
 /// Dropdown-style interactive components that render on messages.
@@ -8,22 +14,85 @@ use serde::{Deserialize, Serialize};
 /// [Discord Docs/Message Components]: https://discord.com/developers/docs/interactions/message-components#select-menus
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct SelectMenu {
+    /// Types of channels that may be chosen.
+    ///
+    /// Only applies to [`SelectMenuType::Channel`] select menus.
+    pub channel_types: Option<Vec<ChannelType>>,
     /// Developer defined identifier.
     pub custom_id: String,
+    /// Users, roles, or channels that are selected by default.
+    ///
+    /// Only applies to auto-populated select menus, meaning any type other
+    /// than [`SelectMenuType::Text`]. The number of default values must be
+    /// within [`min_values`] and [`max_values`].
+    ///
+    /// [`max_values`]: Self::max_values
+    /// [`min_values`]: Self::min_values
+    pub default_values: Option<Vec<SelectDefaultValue>>,
     /// Whether the select menu is disabled.
     ///
     /// Defaults to `false`.
     pub disabled: bool,
+    /// Type of select menu.
+    pub kind: SelectMenuType,
     /// Maximum number of options that may be chosen.
     pub max_values: Option<u8>,
     /// Minimum number of options that must be chosen.
     pub min_values: Option<u8>,
     /// List of available choices.
-    pub options: Vec<SelectMenuOption>,
+    ///
+    /// Required for and only applies to [`SelectMenuType::Text`] select
+    /// menus.
+    pub options: Option<Vec<SelectMenuOption>>,
     /// Custom placeholder text if no option is selected.
     pub placeholder: Option<String>,
 }
 
+/// Type of a [`SelectMenu`].
+///
+/// Text select menus have developer defined [`options`], while the other types
+/// are auto-populated by Discord with users, roles, or channels.
+///
+/// [`options`]: SelectMenu::options
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SelectMenuType {
+    /// Select menu of channels.
+    Channel,
+    /// Select menu of users and roles.
+    Mentionable,
+    /// Select menu of roles.
+    Role,
+    /// Select menu of developer defined text options.
+    Text,
+    /// Select menu of users.
+    User,
+}
+
+/// Default selected value of an auto-populated [`SelectMenu`].
+///
+/// Refer to [Discord Docs/Message Components] for additional information.
+///
+/// [Discord Docs/Message Components]: https://discord.com/developers/docs/interactions/message-components#select-menu-object-select-default-value-structure
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum SelectDefaultValue {
+    /// Channel selected by default.
+    Channel {
+        /// ID of the channel.
+        id: Id<ChannelMarker>,
+    },
+    /// Role selected by default.
+    Role {
+        /// ID of the role.
+        id: Id<RoleMarker>,
+    },
+    /// User selected by default.
+    User {
+        /// ID of the user.
+        id: Id<UserMarker>,
+    },
+}
+
 /// Dropdown options that are part of [`SelectMenu`].
 ///
 /// Refer to [Discord Docs/Message Components] for additional information.
@@ -48,12 +117,16 @@ pub struct SelectMenuOption {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use serde_test::Token;
     use static_assertions::{assert_fields, assert_impl_all};
     use std::{fmt::Debug, hash::Hash};
 
     assert_fields!(
-        SelectMenu: custom_id,
+        SelectMenu: channel_types,
+        custom_id,
+        default_values,
         disabled,
+        kind,
         max_values,
         min_values,
         options,
@@ -73,4 +146,47 @@ mod tests {
         Sync
     );
     assert_fields!(SelectMenuOption: default, description, emoji, label, value);
+
+    assert_impl_all!(
+        SelectMenuType: Clone,
+        Copy,
+        Debug,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Sync
+    );
+
+    assert_impl_all!(
+        SelectDefaultValue: Clone,
+        Copy,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    #[test]
+    fn select_default_value() {
+        serde_test::assert_tokens(
+            &SelectDefaultValue::Role { id: Id::new(5) },
+            &[
+                Token::Struct {
+                    name: "SelectDefaultValue",
+                    len: 2,
+                },
+                Token::Str("type"),
+                Token::Str("role"),
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("5"),
+                Token::StructEnd,
+            ],
+        );
+    }
 }
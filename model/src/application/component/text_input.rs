@@ -54,6 +54,7 @@ mod tests {
         placeholder,
         min_length,
         max_length,
+        required,
         value
     );
     assert_impl_all!(TextInput: Clone, Debug, Eq, Hash, PartialEq, Send, Sync);
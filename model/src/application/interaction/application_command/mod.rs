@@ -9,8 +9,10 @@ pub use self::{
 };
 
 use crate::{
-    application::interaction::InteractionType,
-    guild::PartialMember,
+    application::interaction::{
+        AuthorizingIntegrationOwners, InteractionContextType, InteractionType,
+    },
+    guild::{PartialMember, Permissions},
     id::{
         marker::{ApplicationMarker, ChannelMarker, GuildMarker, InteractionMarker, UserMarker},
         Id,
@@ -26,10 +28,20 @@ use serde::Serialize;
 #[derive(Clone, Debug, Eq, PartialEq, Serialize)]
 #[serde(rename(serialize = "Interaction"))]
 pub struct ApplicationCommand {
+    /// Permissions the application has in the channel the interaction was
+    /// invoked in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_permissions: Option<Permissions>,
     /// ID of the associated application.
     pub application_id: Id<ApplicationMarker>,
+    /// Installations of the application that authorized the interaction.
+    #[serde(skip_serializing_if = "AuthorizingIntegrationOwners::is_empty")]
+    pub authorizing_integration_owners: AuthorizingIntegrationOwners,
     /// ID of the channel the interaction was invoked in.
     pub channel_id: Id<ChannelMarker>,
+    /// Context in which the interaction was invoked.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<InteractionContextType>,
     /// Data from the invoked command.
     pub data: CommandData,
     /// ID of the guild the interaction was invoked in.
@@ -91,7 +103,9 @@ impl ApplicationCommand {
 
 #[cfg(test)]
 mod tests {
-    use super::{ApplicationCommand, CommandData, CommandOptionValue};
+    use super::{
+        ApplicationCommand, AuthorizingIntegrationOwners, CommandData, CommandOptionValue,
+    };
     use crate::{
         application::{
             command::CommandType,
@@ -115,8 +129,11 @@ mod tests {
         let joined_at = Timestamp::from_str("2020-02-02T02:02:02.020000+00:00")?;
 
         let in_guild = ApplicationCommand {
+            app_permissions: None,
             application_id: Id::<ApplicationMarker>::new(1),
+            authorizing_integration_owners: AuthorizingIntegrationOwners::default(),
             channel_id: Id::<ChannelMarker>::new(1),
+            context: None,
             data: CommandData {
                 guild_id: None,
                 id: Id::new(3),
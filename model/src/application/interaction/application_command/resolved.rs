@@ -70,7 +70,7 @@ mod tests {
             },
             Attachment, ChannelType, Message,
         },
-        guild::{PartialMember, Permissions, Role},
+        guild::{PartialMember, Permissions, Role, RoleFlags},
         id::Id,
         test::image_hash,
         user::{PremiumType, User, UserFlags},
@@ -178,7 +178,10 @@ mod tests {
                     mention_everyone: false,
                     mention_roles: Vec::new(),
                     mentions: Vec::new(),
+                    nonce: None,
                     pinned: false,
+                    poll: None,
+                    position: None,
                     reactions: Vec::new(),
                     reference: None,
                     sticker_items: vec![MessageSticker {
@@ -198,6 +201,7 @@ mod tests {
                 Id::new(400),
                 Role {
                     color: 0,
+                    flags: RoleFlags::empty(),
                     hoist: true,
                     icon: None,
                     id: Id::new(400),
@@ -434,10 +438,12 @@ mod tests {
                 Token::Str("400"),
                 Token::Struct {
                     name: "Role",
-                    len: 8,
+                    len: 9,
                 },
                 Token::Str("color"),
                 Token::U32(0),
+                Token::Str("flags"),
+                Token::U64(0),
                 Token::Str("hoist"),
                 Token::Bool(true),
                 Token::Str("id"),
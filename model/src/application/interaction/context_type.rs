@@ -0,0 +1,50 @@
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Context in which an [`Interaction`] was invoked.
+///
+/// See [Discord Docs/Interaction Context Types].
+///
+/// [`Interaction`]: super::Interaction
+/// [Discord Docs/Interaction Context Types]: https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-interaction-context-types
+#[derive(Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, Serialize_repr)]
+#[repr(u8)]
+pub enum InteractionContextType {
+    /// Interaction was invoked in a guild.
+    Guild = 0,
+    /// Interaction was invoked in a DM with the application's bot user.
+    BotDm = 1,
+    /// Interaction was invoked in a DM or group DM other than the one with
+    /// the application's bot user.
+    ///
+    /// Only possible for applications installed to a user.
+    PrivateChannel = 2,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InteractionContextType;
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(
+        InteractionContextType: Clone,
+        Copy,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Serialize,
+        Send,
+        Sync
+    );
+
+    #[test]
+    fn variants() {
+        serde_test::assert_tokens(&InteractionContextType::Guild, &[Token::U8(0)]);
+        serde_test::assert_tokens(&InteractionContextType::BotDm, &[Token::U8(1)]);
+        serde_test::assert_tokens(&InteractionContextType::PrivateChannel, &[Token::U8(2)]);
+    }
+}
@@ -0,0 +1,188 @@
+use crate::id::{
+    marker::{GuildMarker, UserMarker},
+    Id,
+};
+use serde::{
+    de::{Deserializer, Error as DeError, Unexpected, Visitor},
+    ser::Serializer,
+    Deserialize, Serialize,
+};
+use std::fmt::{Formatter, Result as FmtResult};
+
+/// Installations of the application that authorized an [`Interaction`].
+///
+/// Keyed by the installation context: a guild installation authorizes an
+/// interaction when the application was installed to the guild, and a user
+/// installation when it was installed to the invoking user.
+///
+/// See [Discord Docs/Authorizing Integration Owners Object].
+///
+/// [`Interaction`]: super::Interaction
+/// [Discord Docs/Authorizing Integration Owners Object]: https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-authorizing-integration-owners-object
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct AuthorizingIntegrationOwners {
+    /// Guild installation that authorized the interaction.
+    #[serde(default, rename = "0", skip_serializing_if = "Option::is_none")]
+    pub guild: Option<AuthorizingGuild>,
+    /// ID of the user whose installation authorized the interaction.
+    #[serde(default, rename = "1", skip_serializing_if = "Option::is_none")]
+    pub user: Option<Id<UserMarker>>,
+}
+
+impl AuthorizingIntegrationOwners {
+    /// Whether no installation authorized the interaction.
+    pub const fn is_empty(&self) -> bool {
+        self.guild.is_none() && self.user.is_none()
+    }
+}
+
+/// Guild installation that authorized an interaction.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum AuthorizingGuild {
+    /// Interaction was invoked in a DM with the application's bot user.
+    ///
+    /// Serialized by Discord as an ID of `0`.
+    BotDm,
+    /// Interaction was invoked in the guild with the ID.
+    Guild(Id<GuildMarker>),
+}
+
+impl AuthorizingGuild {
+    /// ID of the guild, if the interaction was invoked in one.
+    pub const fn guild_id(self) -> Option<Id<GuildMarker>> {
+        match self {
+            Self::BotDm => None,
+            Self::Guild(guild_id) => Some(guild_id),
+        }
+    }
+}
+
+struct AuthorizingGuildVisitor;
+
+impl<'de> Visitor<'de> for AuthorizingGuildVisitor {
+    type Value = AuthorizingGuild;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("a guild id or 0")
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_u64<E: DeError>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(Id::new_checked(value).map_or(AuthorizingGuild::BotDm, AuthorizingGuild::Guild))
+    }
+
+    fn visit_str<E: DeError>(self, value: &str) -> Result<Self::Value, E> {
+        let value = value
+            .parse()
+            .map_err(|_| DeError::invalid_value(Unexpected::Str(value), &"a guild id or 0"))?;
+
+        self.visit_u64(value)
+    }
+}
+
+impl<'de> Deserialize<'de> for AuthorizingGuild {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(AuthorizingGuildVisitor)
+    }
+}
+
+impl Serialize for AuthorizingGuild {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::BotDm => serializer.serialize_str("0"),
+            Self::Guild(guild_id) => guild_id.serialize(serializer),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AuthorizingGuild, AuthorizingIntegrationOwners};
+    use crate::id::Id;
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_fields!(AuthorizingIntegrationOwners: guild, user);
+    assert_impl_all!(
+        AuthorizingIntegrationOwners: Clone,
+        Debug,
+        Default,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Serialize,
+        Send,
+        Sync
+    );
+    assert_impl_all!(
+        AuthorizingGuild: Clone,
+        Copy,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Serialize,
+        Send,
+        Sync
+    );
+
+    #[test]
+    fn guild_and_user() {
+        let value = AuthorizingIntegrationOwners {
+            guild: Some(AuthorizingGuild::Guild(Id::new(1))),
+            user: Some(Id::new(2)),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "AuthorizingIntegrationOwners",
+                    len: 2,
+                },
+                Token::Str("0"),
+                Token::Some,
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::Str("1"),
+                Token::Some,
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("2"),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn bot_dm() {
+        let value = AuthorizingIntegrationOwners {
+            guild: Some(AuthorizingGuild::BotDm),
+            user: None,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "AuthorizingIntegrationOwners",
+                    len: 1,
+                },
+                Token::Str("0"),
+                Token::Some,
+                Token::Str("0"),
+                Token::StructEnd,
+            ],
+        );
+        assert_eq!(None, AuthorizingGuild::BotDm.guild_id());
+    }
+}
@@ -2,10 +2,10 @@ mod data;
 
 pub use self::data::MessageComponentInteractionData;
 
-use super::InteractionType;
+use super::{AuthorizingIntegrationOwners, InteractionContextType, InteractionType};
 use crate::{
     channel::Message,
-    guild::PartialMember,
+    guild::{PartialMember, Permissions},
     id::{
         marker::{ApplicationMarker, ChannelMarker, GuildMarker, InteractionMarker, UserMarker},
         Id,
@@ -20,10 +20,20 @@ use serde::Serialize;
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize)]
 #[serde(rename(serialize = "Interaction"))]
 pub struct MessageComponentInteraction {
+    /// Permissions the application has in the channel the interaction was
+    /// invoked in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_permissions: Option<Permissions>,
     /// ID of the associated application.
     pub application_id: Id<ApplicationMarker>,
+    /// Installations of the application that authorized the interaction.
+    #[serde(skip_serializing_if = "AuthorizingIntegrationOwners::is_empty")]
+    pub authorizing_integration_owners: AuthorizingIntegrationOwners,
     /// ID of the channel the interaction was invoked in.
     pub channel_id: Id<ChannelMarker>,
+    /// Context in which the interaction was invoked.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<InteractionContextType>,
     /// Data from the invoked command.
     pub data: MessageComponentInteractionData,
     /// ID of the guild the interaction was invoked in.
@@ -89,7 +99,9 @@ impl MessageComponentInteraction {
 
 #[cfg(test)]
 mod tests {
-    use super::{MessageComponentInteraction, MessageComponentInteractionData};
+    use super::{
+        AuthorizingIntegrationOwners, MessageComponentInteraction, MessageComponentInteractionData,
+    };
     use crate::{
         application::{
             component::ComponentType,
@@ -105,7 +117,8 @@ mod tests {
     use std::{fmt::Debug, hash::Hash, str::FromStr};
 
     assert_fields!(
-        MessageComponentInteraction: application_id,
+        MessageComponentInteraction: app_permissions,
+        application_id,
         channel_id,
         data,
         guild_id,
@@ -134,8 +147,11 @@ mod tests {
         let timestamp = Timestamp::from_str("2020-02-02T02:02:02.020000+00:00")?;
 
         let in_guild = MessageComponentInteraction {
+            app_permissions: None,
             application_id: Id::new(1),
+            authorizing_integration_owners: AuthorizingIntegrationOwners::default(),
             channel_id: Id::new(2),
+            context: None,
             data: MessageComponentInteractionData {
                 custom_id: "foo".to_owned(),
                 component_type: ComponentType::Button,
@@ -179,7 +195,10 @@ mod tests {
                 mention_everyone: false,
                 mention_roles: Vec::new(),
                 mentions: Vec::new(),
+                nonce: None,
                 pinned: false,
+                poll: None,
+                position: None,
                 reactions: Vec::new(),
                 reference: None,
                 referenced_message: None,
@@ -5,6 +5,8 @@ pub mod application_command_autocomplete;
 pub mod message_component;
 pub mod modal;
 
+mod context_type;
+mod integration_owners;
 mod interaction_type;
 mod ping;
 
@@ -12,12 +14,16 @@ use self::modal::ModalSubmitInteraction;
 pub use self::{
     application_command::ApplicationCommand,
     application_command_autocomplete::ApplicationCommandAutocomplete,
-    interaction_type::InteractionType, message_component::MessageComponentInteraction, ping::Ping,
+    context_type::InteractionContextType,
+    integration_owners::{AuthorizingGuild, AuthorizingIntegrationOwners},
+    interaction_type::InteractionType,
+    message_component::MessageComponentInteraction,
+    ping::Ping,
 };
 
 use crate::{
     channel::Message,
-    guild::PartialMember,
+    guild::{PartialMember, Permissions},
     id::{
         marker::{ApplicationMarker, ChannelMarker, GuildMarker, InteractionMarker, UserMarker},
         Id,
@@ -54,6 +60,22 @@ pub enum Interaction {
 }
 
 impl Interaction {
+    /// Permissions the application has in the channel the interaction was
+    /// invoked in.
+    ///
+    /// Not present for [`Ping`] interactions.
+    ///
+    /// [`Ping`]: Self::Ping
+    pub const fn app_permissions(&self) -> Option<Permissions> {
+        match self {
+            Self::Ping(_) => None,
+            Self::ApplicationCommand(command) => command.app_permissions,
+            Self::ApplicationCommandAutocomplete(command) => command.app_permissions,
+            Self::MessageComponent(component) => component.app_permissions,
+            Self::ModalSubmit(modal) => modal.app_permissions,
+        }
+    }
+
     /// Id of the associated application.
     pub const fn application_id(&self) -> Id<ApplicationMarker> {
         match self {
@@ -65,6 +87,38 @@ impl Interaction {
         }
     }
 
+    /// Installations of the application that authorized the interaction.
+    ///
+    /// Not present for [`Ping`] interactions.
+    ///
+    /// [`Ping`]: Self::Ping
+    pub const fn authorizing_integration_owners(&self) -> Option<&AuthorizingIntegrationOwners> {
+        match self {
+            Self::Ping(_) => None,
+            Self::ApplicationCommand(command) => Some(&command.authorizing_integration_owners),
+            Self::ApplicationCommandAutocomplete(command) => {
+                Some(&command.authorizing_integration_owners)
+            }
+            Self::MessageComponent(component) => Some(&component.authorizing_integration_owners),
+            Self::ModalSubmit(modal) => Some(&modal.authorizing_integration_owners),
+        }
+    }
+
+    /// Context in which the interaction was invoked.
+    ///
+    /// Not present for [`Ping`] interactions.
+    ///
+    /// [`Ping`]: Self::Ping
+    pub const fn context(&self) -> Option<InteractionContextType> {
+        match self {
+            Self::Ping(_) => None,
+            Self::ApplicationCommand(command) => command.context,
+            Self::ApplicationCommandAutocomplete(command) => command.context,
+            Self::MessageComponent(component) => component.context,
+            Self::ModalSubmit(modal) => modal.context,
+        }
+    }
+
     /// ID of the guild the interaction was invoked in.
     pub const fn guild_id(&self) -> Option<Id<GuildMarker>> {
         match self {
@@ -135,8 +189,11 @@ const fn author_id(user: Option<&User>, member: Option<&PartialMember>) -> Optio
 #[derive(Debug, Deserialize)]
 #[serde(field_identifier, rename_all = "snake_case")]
 enum InteractionField {
+    AppPermissions,
     ApplicationId,
+    AuthorizingIntegrationOwners,
     ChannelId,
+    Context,
     Data,
     GuildId,
     GuildLocale,
@@ -160,8 +217,11 @@ impl<'de> Visitor<'de> for InteractionVisitor {
 
     #[allow(clippy::too_many_lines)]
     fn visit_map<V: MapAccess<'de>>(self, mut map: V) -> Result<Self::Value, V::Error> {
+        let mut app_permissions: Option<Option<Permissions>> = None;
         let mut application_id: Option<Id<ApplicationMarker>> = None;
+        let mut authorizing_integration_owners: Option<AuthorizingIntegrationOwners> = None;
         let mut channel_id: Option<Id<ChannelMarker>> = None;
+        let mut context: Option<Option<InteractionContextType>> = None;
         let mut data: Option<Value> = None;
         let mut guild_id: Option<Option<Id<GuildMarker>>> = None;
         let mut guild_locale: Option<Option<String>> = None;
@@ -198,6 +258,13 @@ impl<'de> Visitor<'de> for InteractionVisitor {
             };
 
             match key {
+                InteractionField::AppPermissions => {
+                    if app_permissions.is_some() {
+                        return Err(DeError::duplicate_field("app_permissions"));
+                    }
+
+                    app_permissions = Some(map.next_value()?);
+                }
                 InteractionField::ApplicationId => {
                     if application_id.is_some() {
                         return Err(DeError::duplicate_field("application_id"));
@@ -205,6 +272,13 @@ impl<'de> Visitor<'de> for InteractionVisitor {
 
                     application_id = Some(map.next_value()?);
                 }
+                InteractionField::AuthorizingIntegrationOwners => {
+                    if authorizing_integration_owners.is_some() {
+                        return Err(DeError::duplicate_field("authorizing_integration_owners"));
+                    }
+
+                    authorizing_integration_owners = Some(map.next_value()?);
+                }
                 InteractionField::ChannelId => {
                     if channel_id.is_some() {
                         return Err(DeError::duplicate_field("channel_id"));
@@ -212,6 +286,13 @@ impl<'de> Visitor<'de> for InteractionVisitor {
 
                     channel_id = Some(map.next_value()?);
                 }
+                InteractionField::Context => {
+                    if context.is_some() {
+                        return Err(DeError::duplicate_field("context"));
+                    }
+
+                    context = Some(map.next_value()?);
+                }
                 InteractionField::Data => {
                     if data.is_some() {
                         return Err(DeError::duplicate_field("data"));
@@ -290,6 +371,9 @@ impl<'de> Visitor<'de> for InteractionVisitor {
         let id = id.ok_or_else(|| DeError::missing_field("id"))?;
         let token = token.ok_or_else(|| DeError::missing_field("token"))?;
         let kind = kind.ok_or_else(|| DeError::missing_field("kind"))?;
+        let app_permissions = app_permissions.unwrap_or_default();
+        let authorizing_integration_owners = authorizing_integration_owners.unwrap_or_default();
+        let context = context.unwrap_or_default();
 
         tracing::trace!(
             %application_id,
@@ -326,8 +410,11 @@ impl<'de> Visitor<'de> for InteractionVisitor {
                 tracing::trace!(%channel_id, "handling application command");
 
                 let command = Box::new(ApplicationCommand {
+                    app_permissions,
                     application_id,
+                    authorizing_integration_owners,
                     channel_id,
+                    context,
                     data,
                     guild_id,
                     guild_locale,
@@ -357,8 +444,11 @@ impl<'de> Visitor<'de> for InteractionVisitor {
                 tracing::trace!(%channel_id, "handling application command autocomplete");
 
                 let command = Box::new(ApplicationCommandAutocomplete {
+                    app_permissions,
                     application_id,
+                    authorizing_integration_owners,
                     channel_id,
+                    context,
                     data,
                     guild_id,
                     guild_locale,
@@ -389,8 +479,11 @@ impl<'de> Visitor<'de> for InteractionVisitor {
                 let user = user.unwrap_or_default();
 
                 Self::Value::MessageComponent(Box::new(MessageComponentInteraction {
+                    app_permissions,
                     application_id,
+                    authorizing_integration_owners,
                     channel_id,
+                    context,
                     data,
                     guild_id,
                     guild_locale,
@@ -417,8 +510,11 @@ impl<'de> Visitor<'de> for InteractionVisitor {
                 let user = user.unwrap_or_default();
 
                 Self::Value::ModalSubmit(Box::new(ModalSubmitInteraction {
+                    app_permissions,
                     application_id,
+                    authorizing_integration_owners,
                     channel_id,
+                    context,
                     data,
                     guild_id,
                     guild_locale,
@@ -440,12 +536,14 @@ mod tests {
     use crate::{
         application::{
             command::{CommandOptionType, CommandType},
+            component::ComponentType,
             interaction::{
                 application_command::{
                     ApplicationCommand, CommandData, CommandDataOption,
                     CommandInteractionDataResolved, CommandOptionValue, InteractionMember,
                 },
-                Interaction, InteractionType,
+                AuthorizingGuild, AuthorizingIntegrationOwners, Interaction,
+                InteractionContextType, InteractionType,
             },
         },
         guild::{PartialMember, Permissions},
@@ -483,8 +581,11 @@ mod tests {
         let joined_at = Timestamp::from_str("2020-01-01T00:00:00.000000+00:00")?;
 
         let value = Interaction::ApplicationCommand(Box::new(ApplicationCommand {
+            app_permissions: None,
             application_id: Id::new(100),
+            authorizing_integration_owners: AuthorizingIntegrationOwners::default(),
             channel_id: Id::new(200),
+            context: None,
             data: CommandData {
                 guild_id: None,
                 id: Id::new(300),
@@ -742,4 +843,251 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn app_permissions() -> Result<(), serde_json::Error> {
+        let json = r#"{
+            "app_permissions": "2048",
+            "application_id": "1",
+            "channel_id": "2",
+            "data": {
+                "id": "3",
+                "name": "ping",
+                "type": 1
+            },
+            "guild_id": "4",
+            "id": "5",
+            "locale": "en-US",
+            "token": "interaction token",
+            "type": 2,
+            "version": 1
+        }"#;
+
+        let interaction = serde_json::from_str::<Interaction>(json)?;
+        assert_eq!(
+            Some(Permissions::SEND_MESSAGES),
+            interaction.app_permissions()
+        );
+
+        let value = serde_json::to_value(&interaction)?;
+        assert_eq!(Some("2048"), value["app_permissions"].as_str());
+
+        Ok(())
+    }
+
+    #[test]
+    fn user_installed_private_channel() -> Result<(), serde_json::Error> {
+        let json = r#"{
+            "application_id": "1",
+            "authorizing_integration_owners": {
+                "1": "6"
+            },
+            "channel_id": "2",
+            "context": 2,
+            "data": {
+                "id": "3",
+                "name": "ping",
+                "type": 1
+            },
+            "id": "5",
+            "locale": "en-US",
+            "token": "interaction token",
+            "type": 2,
+            "user": {
+                "avatar": null,
+                "discriminator": "4444",
+                "id": "6",
+                "username": "twilight"
+            },
+            "version": 1
+        }"#;
+
+        let interaction = serde_json::from_str::<Interaction>(json)?;
+        assert_eq!(
+            Some(InteractionContextType::PrivateChannel),
+            interaction.context()
+        );
+        assert_eq!(
+            Some(&AuthorizingIntegrationOwners {
+                guild: None,
+                user: Some(Id::new(6)),
+            }),
+            interaction.authorizing_integration_owners()
+        );
+        assert_eq!(None, interaction.guild_id());
+
+        let value = serde_json::to_value(&interaction)?;
+        assert_eq!(Some(2), value["context"].as_u64());
+        assert_eq!(
+            Some("6"),
+            value["authorizing_integration_owners"]["1"].as_str()
+        );
+        assert!(value["authorizing_integration_owners"].get("0").is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn guild_installed_bot_dm() -> Result<(), serde_json::Error> {
+        let json = r#"{
+            "application_id": "1",
+            "authorizing_integration_owners": {
+                "0": "0"
+            },
+            "channel_id": "2",
+            "context": 1,
+            "data": {
+                "id": "3",
+                "name": "ping",
+                "type": 1
+            },
+            "id": "5",
+            "locale": "en-US",
+            "token": "interaction token",
+            "type": 2,
+            "version": 1
+        }"#;
+
+        let interaction = serde_json::from_str::<Interaction>(json)?;
+        assert_eq!(Some(InteractionContextType::BotDm), interaction.context());
+        assert_eq!(
+            Some(AuthorizingGuild::BotDm),
+            interaction
+                .authorizing_integration_owners()
+                .and_then(|owners| owners.guild)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn button_click() -> Result<(), serde_json::Error> {
+        let json = r#"{
+            "application_id": "1",
+            "channel_id": "2",
+            "data": {
+                "component_type": 2,
+                "custom_id": "click"
+            },
+            "guild_id": "3",
+            "id": "4",
+            "locale": "en-US",
+            "member": {
+                "deaf": false,
+                "joined_at": "2020-02-02T02:02:02.020000+00:00",
+                "mute": false,
+                "roles": [],
+                "user": {
+                    "discriminator": "4444",
+                    "id": "5",
+                    "username": "twilight"
+                }
+            },
+            "message": {
+                "attachments": [],
+                "author": {
+                    "discriminator": "4444",
+                    "id": "1",
+                    "username": "twilight"
+                },
+                "channel_id": "2",
+                "components": [],
+                "content": "press the button",
+                "edited_timestamp": null,
+                "embeds": [],
+                "id": "6",
+                "mention_everyone": false,
+                "mention_roles": [],
+                "mentions": [],
+                "pinned": false,
+                "timestamp": "2020-02-02T02:02:02.020000+00:00",
+                "tts": false,
+                "type": 0
+            },
+            "token": "interaction token",
+            "type": 3,
+            "version": 1
+        }"#;
+
+        let interaction = serde_json::from_str::<Interaction>(json)?;
+        assert_eq!(InteractionType::MessageComponent, interaction.kind());
+
+        let component = match interaction {
+            Interaction::MessageComponent(component) => component,
+            other => panic!("expected message component, got {other:?}"),
+        };
+
+        assert_eq!("click", component.data.custom_id);
+        assert_eq!(ComponentType::Button, component.data.component_type);
+        assert!(component.data.values.is_empty());
+        assert_eq!(Some(Id::new(5)), component.author_id());
+        assert_eq!(Id::new(6), component.message.id);
+
+        Ok(())
+    }
+
+    #[test]
+    fn modal_submit() -> Result<(), serde_json::Error> {
+        let json = r#"{
+            "application_id": "1",
+            "channel_id": "2",
+            "data": {
+                "components": [
+                    {
+                        "components": [
+                            {
+                                "custom_id": "name",
+                                "type": 4,
+                                "value": "twilight"
+                            }
+                        ],
+                        "type": 1
+                    }
+                ],
+                "custom_id": "form"
+            },
+            "id": "4",
+            "locale": "en-US",
+            "token": "interaction token",
+            "type": 5,
+            "user": {
+                "discriminator": "4444",
+                "id": "5",
+                "username": "twilight"
+            },
+            "version": 1
+        }"#;
+
+        let interaction = serde_json::from_str::<Interaction>(json)?;
+        assert_eq!(InteractionType::ModalSubmit, interaction.kind());
+
+        let modal = match interaction {
+            Interaction::ModalSubmit(modal) => modal,
+            other => panic!("expected modal submit, got {other:?}"),
+        };
+
+        assert_eq!("form", modal.data.custom_id);
+        assert_eq!(1, modal.data.components.len());
+
+        let input = &modal.data.components[0].components[0];
+        assert_eq!("name", input.custom_id);
+        assert_eq!(ComponentType::TextInput, input.kind);
+        assert_eq!("twilight", input.value);
+        assert!(modal.is_dm());
+
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_type() {
+        let json = r#"{
+            "application_id": "1",
+            "id": "4",
+            "token": "interaction token",
+            "type": 250
+        }"#;
+
+        assert!(serde_json::from_str::<Interaction>(json).is_err());
+        assert!(InteractionType::try_from(250).is_err());
+    }
 }
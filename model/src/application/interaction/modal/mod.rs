@@ -5,9 +5,11 @@ pub use self::data::{
 };
 
 use crate::{
-    application::interaction::InteractionType,
+    application::interaction::{
+        AuthorizingIntegrationOwners, InteractionContextType, InteractionType,
+    },
     channel::Message,
-    guild::PartialMember,
+    guild::{PartialMember, Permissions},
     id::{
         marker::{ApplicationMarker, ChannelMarker, GuildMarker, InteractionMarker, UserMarker},
         Id,
@@ -22,10 +24,20 @@ use serde::Serialize;
 #[derive(Clone, Debug, Eq, PartialEq, Serialize)]
 #[serde(rename(serialize = "Interaction"))]
 pub struct ModalSubmitInteraction {
+    /// Permissions the application has in the channel the interaction was
+    /// invoked in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_permissions: Option<Permissions>,
     /// ID of the associated application.
     pub application_id: Id<ApplicationMarker>,
+    /// Installations of the application that authorized the interaction.
+    #[serde(skip_serializing_if = "AuthorizingIntegrationOwners::is_empty")]
+    pub authorizing_integration_owners: AuthorizingIntegrationOwners,
     /// ID of the channel the interaction was invoked in.
     pub channel_id: Id<ChannelMarker>,
+    /// Context in which the interaction was invoked.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<InteractionContextType>,
     /// Data from the submitted modal.
     pub data: ModalInteractionData,
     /// ID of the guild the interaction was invoked in.
@@ -93,8 +105,8 @@ impl ModalSubmitInteraction {
 #[cfg(test)]
 mod tests {
     use super::{
-        ModalInteractionData, ModalInteractionDataActionRow, ModalInteractionDataComponent,
-        ModalSubmitInteraction,
+        AuthorizingIntegrationOwners, ModalInteractionData, ModalInteractionDataActionRow,
+        ModalInteractionDataComponent, ModalSubmitInteraction,
     };
     use crate::{
         application::{
@@ -115,7 +127,8 @@ mod tests {
     use std::{fmt::Debug, str::FromStr};
 
     assert_fields!(
-        ModalSubmitInteraction: application_id,
+        ModalSubmitInteraction: app_permissions,
+        application_id,
         channel_id,
         data,
         guild_id,
@@ -142,8 +155,11 @@ mod tests {
         let joined_at = Timestamp::from_str("2020-02-02T02:02:02.020000+00:00")?;
 
         let in_guild = ModalSubmitInteraction {
+            app_permissions: None,
             application_id: Id::<ApplicationMarker>::new(1),
+            authorizing_integration_owners: AuthorizingIntegrationOwners::default(),
             channel_id: Id::<ChannelMarker>::new(1),
+            context: None,
             data: ModalInteractionData {
                 custom_id: "the-id".to_owned(),
                 components: Vec::from([ModalInteractionDataActionRow {
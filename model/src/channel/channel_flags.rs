@@ -0,0 +1,56 @@
+use bitflags::bitflags;
+use serde::{
+    de::{Deserialize, Deserializer},
+    ser::{Serialize, Serializer},
+};
+
+bitflags! {
+    pub struct ChannelFlags: u64 {
+        /// Channel is a thread pinned in a forum channel.
+        const PINNED = 1 << 1;
+        /// Channel is a forum channel that requires a tag to be applied when
+        /// creating a thread.
+        const REQUIRE_TAG = 1 << 4;
+    }
+}
+
+impl<'de> Deserialize<'de> for ChannelFlags {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Build the flags from the raw bits instead of truncating them, so
+        // that flags unknown to the library aren't lost when updating a
+        // channel.
+        Ok(Self {
+            bits: u64::deserialize(deserializer)?,
+        })
+    }
+}
+
+impl Serialize for ChannelFlags {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u64(self.bits())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ChannelFlags;
+    use serde_test::Token;
+
+    #[test]
+    fn variants() {
+        serde_test::assert_tokens(&ChannelFlags::PINNED, &[Token::U64(1 << 1)]);
+        serde_test::assert_tokens(&ChannelFlags::REQUIRE_TAG, &[Token::U64(1 << 4)]);
+    }
+
+    #[test]
+    fn unknown_bits() {
+        let value = serde_json::from_str::<ChannelFlags>("3").unwrap();
+
+        assert!(value.contains(ChannelFlags::PINNED));
+        assert_eq!(3, value.bits());
+        assert_eq!("3", serde_json::to_string(&value).unwrap());
+    }
+}
@@ -0,0 +1,127 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Type of an [`Embed`].
+///
+/// Embeds sent by bots and webhooks are always [`Rich`]. The other types are
+/// generated by Discord, such as for the link previews of URLs in messages.
+///
+/// Refer to [Discord Docs/Embed Types] for additional information.
+///
+/// [`Embed`]: super::Embed
+/// [`Rich`]: Self::Rich
+/// [Discord Docs/Embed Types]: https://discord.com/developers/docs/resources/channel#embed-object-embed-types
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum EmbedType {
+    /// Article embed.
+    Article,
+    /// Embed of an auto moderation alert.
+    AutoModerationMessage,
+    /// Animated gif image embed rendered as a video.
+    Gifv,
+    /// Image embed.
+    Image,
+    /// Link embed.
+    Link,
+    /// Generic embed rendered from its attributes.
+    Rich,
+    /// Video embed.
+    Video,
+    /// Embed type unknown to the library, with the name sent by Discord.
+    ///
+    /// The name is kept so that the embed type serializes back unchanged.
+    Unknown(String),
+}
+
+impl EmbedType {
+    /// Name of the embed type as sent by Discord.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_model::channel::embed::EmbedType;
+    ///
+    /// assert_eq!("gifv", EmbedType::Gifv.name());
+    /// ```
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Article => "article",
+            Self::AutoModerationMessage => "auto_moderation_message",
+            Self::Gifv => "gifv",
+            Self::Image => "image",
+            Self::Link => "link",
+            Self::Rich => "rich",
+            Self::Video => "video",
+            Self::Unknown(name) => name,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for EmbedType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+
+        Ok(match name.as_str() {
+            "article" => Self::Article,
+            "auto_moderation_message" => Self::AutoModerationMessage,
+            "gifv" => Self::Gifv,
+            "image" => Self::Image,
+            "link" => Self::Link,
+            "rich" => Self::Rich,
+            "video" => Self::Video,
+            _ => Self::Unknown(name),
+        })
+    }
+}
+
+impl Serialize for EmbedType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EmbedType;
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(
+        EmbedType: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    #[test]
+    fn variants() {
+        const MAP: &[(EmbedType, &str)] = &[
+            (EmbedType::Article, "article"),
+            (EmbedType::AutoModerationMessage, "auto_moderation_message"),
+            (EmbedType::Gifv, "gifv"),
+            (EmbedType::Image, "image"),
+            (EmbedType::Link, "link"),
+            (EmbedType::Rich, "rich"),
+            (EmbedType::Video, "video"),
+        ];
+
+        for (kind, name) in MAP {
+            serde_test::assert_tokens(kind, &[Token::Str(name)]);
+            assert_eq!(*name, kind.name());
+        }
+    }
+
+    #[test]
+    fn unknown() {
+        let value = EmbedType::Unknown("poll_result".to_owned());
+
+        serde_test::assert_tokens(&value, &[Token::Str("poll_result")]);
+        assert_eq!("poll_result", value.name());
+    }
+}
@@ -2,13 +2,14 @@ mod author;
 mod field;
 mod footer;
 mod image;
+mod kind;
 mod provider;
 mod thumbnail;
 mod video;
 
 pub use self::{
     author::EmbedAuthor, field::EmbedField, footer::EmbedFooter, image::EmbedImage,
-    provider::EmbedProvider, thumbnail::EmbedThumbnail, video::EmbedVideo,
+    kind::EmbedType, provider::EmbedProvider, thumbnail::EmbedThumbnail, video::EmbedVideo,
 };
 
 use crate::util::Timestamp;
@@ -29,7 +30,7 @@ pub struct Embed {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub image: Option<EmbedImage>,
     #[serde(rename = "type")]
-    pub kind: String,
+    pub kind: EmbedType,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub provider: Option<EmbedProvider>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -48,7 +49,7 @@ pub struct Embed {
 mod tests {
     use super::{
         Embed, EmbedAuthor, EmbedField, EmbedFooter, EmbedImage, EmbedProvider, EmbedThumbnail,
-        EmbedVideo,
+        EmbedType, EmbedVideo,
     };
     use crate::util::datetime::{Timestamp, TimestampParseError};
     use serde_test::Token;
@@ -65,7 +66,7 @@ mod tests {
             fields: Vec::new(),
             footer: None,
             image: None,
-            kind: "rich".to_owned(),
+            kind: EmbedType::Rich,
             provider: None,
             thumbnail: None,
             timestamp: Some(timestamp),
@@ -135,7 +136,7 @@ mod tests {
                 url: "https://example.com/1.png".to_owned(),
                 width: Some(2560),
             }),
-            kind: "rich".to_owned(),
+            kind: EmbedType::Rich,
             provider: Some(EmbedProvider {
                 name: Some("Example".to_owned()),
                 url: Some("https://example.com".to_owned()),
@@ -302,4 +303,130 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn embed_video() {
+        let value = Embed {
+            author: None,
+            color: None,
+            description: None,
+            fields: Vec::new(),
+            footer: None,
+            image: None,
+            kind: EmbedType::Video,
+            provider: Some(EmbedProvider {
+                name: Some("YouTube".to_owned()),
+                url: Some("https://www.youtube.com".to_owned()),
+            }),
+            thumbnail: None,
+            timestamp: None,
+            title: Some("a video".to_owned()),
+            url: Some("https://www.youtube.com/watch?v=1".to_owned()),
+            video: Some(EmbedVideo {
+                height: Some(720),
+                proxy_url: None,
+                url: Some("https://www.youtube.com/embed/1".to_owned()),
+                width: Some(1280),
+            }),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Embed",
+                    len: 5,
+                },
+                Token::Str("type"),
+                Token::Str("video"),
+                Token::Str("provider"),
+                Token::Some,
+                Token::Struct {
+                    name: "EmbedProvider",
+                    len: 2,
+                },
+                Token::Str("name"),
+                Token::Some,
+                Token::Str("YouTube"),
+                Token::Str("url"),
+                Token::Some,
+                Token::Str("https://www.youtube.com"),
+                Token::StructEnd,
+                Token::Str("title"),
+                Token::Some,
+                Token::Str("a video"),
+                Token::Str("url"),
+                Token::Some,
+                Token::Str("https://www.youtube.com/watch?v=1"),
+                Token::Str("video"),
+                Token::Some,
+                Token::Struct {
+                    name: "EmbedVideo",
+                    len: 3,
+                },
+                Token::Str("height"),
+                Token::Some,
+                Token::U64(720),
+                Token::Str("url"),
+                Token::Some,
+                Token::Str("https://www.youtube.com/embed/1"),
+                Token::Str("width"),
+                Token::Some,
+                Token::U64(1280),
+                Token::StructEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn embed_link() {
+        let value = Embed {
+            author: None,
+            color: None,
+            description: Some("a description".to_owned()),
+            fields: Vec::new(),
+            footer: None,
+            image: None,
+            kind: EmbedType::Link,
+            provider: Some(EmbedProvider {
+                name: Some("Example".to_owned()),
+                url: None,
+            }),
+            thumbnail: None,
+            timestamp: None,
+            title: None,
+            url: Some("https://example.com".to_owned()),
+            video: None,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Embed",
+                    len: 4,
+                },
+                Token::Str("description"),
+                Token::Some,
+                Token::Str("a description"),
+                Token::Str("type"),
+                Token::Str("link"),
+                Token::Str("provider"),
+                Token::Some,
+                Token::Struct {
+                    name: "EmbedProvider",
+                    len: 1,
+                },
+                Token::Str("name"),
+                Token::Some,
+                Token::Str("Example"),
+                Token::StructEnd,
+                Token::Str("url"),
+                Token::Some,
+                Token::Str("https://example.com"),
+                Token::StructEnd,
+            ],
+        );
+    }
 }
@@ -1,10 +1,16 @@
 use super::MessageActivityType;
 use serde::{Deserialize, Serialize};
 
+/// Activity associated with a message, such as a game invite or a Spotify
+/// listen along.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct MessageActivity {
+    /// Type of activity.
     #[serde(rename = "type")]
     pub kind: MessageActivityType,
+    /// ID of the party from a [`Presence`]'s activity.
+    ///
+    /// [`Presence`]: crate::gateway::presence::Presence
     #[serde(skip_serializing_if = "Option::is_none")]
     pub party_id: Option<String>,
 }
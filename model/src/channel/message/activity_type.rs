@@ -1,11 +1,18 @@
 use serde_repr::{Deserialize_repr, Serialize_repr};
 
+/// Type of a [`MessageActivity`].
+///
+/// [`MessageActivity`]: super::MessageActivity
 #[derive(Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, Serialize_repr)]
 #[repr(u8)]
 pub enum MessageActivityType {
+    /// Invite to join a game.
     Join = 1,
+    /// Invite to spectate a game.
     Spectate = 2,
+    /// Invite to listen along, such as on Spotify.
     Listen = 3,
+    /// Request to join a game.
     JoinRequest = 5,
 }
 
@@ -1,5 +1,5 @@
 use crate::{
-    channel::message::allowed_mentions::{AllowedMentions, ParseTypes},
+    channel::message::allowed_mentions::{AllowedMentions, AllowedMentionsType},
     id::{
         marker::{RoleMarker, UserMarker},
         Id,
@@ -23,7 +23,7 @@ impl AllowedMentionsBuilder {
 
     /// Allow parsing of `@everyone`.
     pub fn everyone(mut self) -> Self {
-        self.0.parse.push(ParseTypes::Everyone);
+        self.push_parse(AllowedMentionsType::Everyone);
 
         self
     }
@@ -37,7 +37,7 @@ impl AllowedMentionsBuilder {
 
     /// Allow parsing of all roles.
     pub fn roles(mut self) -> Self {
-        self.0.parse.push(ParseTypes::Roles);
+        self.push_parse(AllowedMentionsType::Roles);
 
         self
     }
@@ -56,7 +56,7 @@ impl AllowedMentionsBuilder {
 
     /// Allow parsing of all users.
     pub fn users(mut self) -> Self {
-        self.0.parse.push(ParseTypes::Users);
+        self.push_parse(AllowedMentionsType::Users);
 
         self
     }
@@ -73,6 +73,13 @@ impl AllowedMentionsBuilder {
         self
     }
 
+    /// Add a type of mention to parse if it isn't already present.
+    fn push_parse(&mut self, kind: AllowedMentionsType) {
+        if !self.0.parse.contains(&kind) {
+            self.0.parse.push(kind);
+        }
+    }
+
     /// Build the [`AllowedMentions`] struct.
     ///
     /// Note: This clears possible validation errors by removing data.  For
@@ -85,7 +92,7 @@ impl AllowedMentionsBuilder {
                 .0
                 .parse
                 .into_iter()
-                .filter(|t| *t != ParseTypes::Users)
+                .filter(|t| *t != AllowedMentionsType::Users)
                 .collect();
         }
 
@@ -94,7 +101,7 @@ impl AllowedMentionsBuilder {
                 .0
                 .parse
                 .into_iter()
-                .filter(|t| *t != ParseTypes::Roles)
+                .filter(|t| *t != AllowedMentionsType::Roles)
                 .collect();
         }
 
@@ -105,7 +112,7 @@ impl AllowedMentionsBuilder {
 #[cfg(test)]
 mod tests {
     use super::{
-        super::{AllowedMentions, ParseTypes},
+        super::{AllowedMentions, AllowedMentionsType},
         AllowedMentionsBuilder,
     };
     use crate::id::Id;
@@ -134,7 +141,11 @@ mod tests {
         assert_eq!(
             value,
             AllowedMentions {
-                parse: vec![ParseTypes::Everyone, ParseTypes::Users, ParseTypes::Roles],
+                parse: vec![
+                    AllowedMentionsType::Everyone,
+                    AllowedMentionsType::Users,
+                    AllowedMentionsType::Roles
+                ],
                 users: vec![],
                 roles: vec![],
                 replied_user: true
@@ -142,6 +153,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn duplicates() {
+        let value = AllowedMentionsBuilder::new()
+            .everyone()
+            .users()
+            .everyone()
+            .users()
+            .build();
+
+        assert_eq!(
+            value.parse,
+            [AllowedMentionsType::Everyone, AllowedMentionsType::Users]
+        );
+    }
+
     #[test]
     fn validation() {
         let value = AllowedMentionsBuilder::new()
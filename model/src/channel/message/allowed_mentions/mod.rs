@@ -11,21 +11,34 @@ mod builder;
 
 pub use self::builder::AllowedMentionsBuilder;
 
-/// Parse types.
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+/// Type of mention to parse from a message's content.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 #[non_exhaustive]
 #[serde(rename_all = "lowercase")]
-pub enum ParseTypes {
+pub enum AllowedMentionsType {
+    /// `@everyone` and `@here` mentions.
     Everyone,
+    /// Role mentions.
     Roles,
+    /// User mentions.
     Users,
 }
 
+#[deprecated(since = "0.11.1", note = "use `AllowedMentionsType` instead")]
+pub type ParseTypes = AllowedMentionsType;
+
 /// Allowed mentions structure.
 #[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct AllowedMentions {
+    /// Types of mentions to parse from the content.
+    ///
+    /// Each type may only be present once. An empty list suppresses all
+    /// mentions not otherwise listed in [`users`] or [`roles`].
+    ///
+    /// [`roles`]: Self::roles
+    /// [`users`]: Self::users
     #[serde(default)]
-    pub parse: Vec<ParseTypes>,
+    pub parse: Vec<AllowedMentionsType>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub users: Vec<Id<UserMarker>>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
@@ -42,10 +55,35 @@ impl AllowedMentions {
 
 #[cfg(test)]
 mod tests {
-    use super::{AllowedMentions, ParseTypes};
+    use super::{AllowedMentions, AllowedMentionsType};
     use crate::id::Id;
     use serde_test::Token;
 
+    #[test]
+    fn allowed_mentions_type() {
+        serde_test::assert_tokens(
+            &AllowedMentionsType::Everyone,
+            &[Token::UnitVariant {
+                name: "AllowedMentionsType",
+                variant: "everyone",
+            }],
+        );
+        serde_test::assert_tokens(
+            &AllowedMentionsType::Roles,
+            &[Token::UnitVariant {
+                name: "AllowedMentionsType",
+                variant: "roles",
+            }],
+        );
+        serde_test::assert_tokens(
+            &AllowedMentionsType::Users,
+            &[Token::UnitVariant {
+                name: "AllowedMentionsType",
+                variant: "users",
+            }],
+        );
+    }
+
     #[test]
     fn minimal() {
         let value = AllowedMentions {
@@ -73,7 +111,7 @@ mod tests {
     #[test]
     fn full() {
         let value = AllowedMentions {
-            parse: vec![ParseTypes::Everyone],
+            parse: vec![AllowedMentionsType::Everyone],
             users: vec![Id::new(100)],
             roles: vec![Id::new(200)],
             replied_user: true,
@@ -89,7 +127,7 @@ mod tests {
                 Token::Str("parse"),
                 Token::Seq { len: Some(1) },
                 Token::UnitVariant {
-                    name: "ParseTypes",
+                    name: "AllowedMentionsType",
                     variant: "everyone",
                 },
                 Token::SeqEnd,
@@ -8,14 +8,23 @@ mod flags;
 mod interaction;
 mod kind;
 mod mention;
+mod nonce;
 mod reaction;
 mod reference;
 
 pub use self::{
-    activity::MessageActivity, activity_type::MessageActivityType,
-    allowed_mentions::AllowedMentions, application::MessageApplication, flags::MessageFlags,
-    interaction::MessageInteraction, kind::MessageType, mention::Mention,
-    reaction::MessageReaction, reference::MessageReference, sticker::Sticker,
+    activity::MessageActivity,
+    activity_type::MessageActivityType,
+    allowed_mentions::AllowedMentions,
+    application::MessageApplication,
+    flags::MessageFlags,
+    interaction::MessageInteraction,
+    kind::MessageType,
+    mention::Mention,
+    nonce::MessageNonce,
+    reaction::{MessageReaction, ReactionCountDetails},
+    reference::MessageReference,
+    sticker::Sticker,
 };
 
 use self::sticker::MessageSticker;
@@ -29,6 +38,7 @@ use crate::{
         },
         Id,
     },
+    poll::Poll,
     user::User,
     util::Timestamp,
 };
@@ -117,7 +127,20 @@ pub struct Message {
     pub mention_everyone: bool,
     pub mention_roles: Vec<Id<RoleMarker>>,
     pub mentions: Vec<Mention>,
+    /// Nonce the message was sent with, used to verify that it was sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<MessageNonce>,
     pub pinned: bool,
+    /// Poll attached to the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poll: Option<Poll>,
+    /// Approximate position of the message in a thread.
+    ///
+    /// Messages sent in threads have increasing positions, starting with `0`
+    /// for the first message after the thread's starter message. The position
+    /// may be unavailable for messages sent before April 2022.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<u64>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub reactions: Vec<MessageReaction>,
     /// Reference data sent with crossposted messages and replies.
@@ -133,6 +156,7 @@ pub struct Message {
     pub sticker_items: Vec<MessageSticker>,
     /// Timestamp of when the message was created.
     pub timestamp: Timestamp,
+    /// Thread started from this message.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub thread: Option<Channel>,
     pub tts: bool,
@@ -145,12 +169,14 @@ mod tests {
     use super::{
         sticker::{MessageSticker, StickerFormatType},
         ChannelMention, Message, MessageActivity, MessageActivityType, MessageApplication,
-        MessageFlags, MessageReaction, MessageReference, MessageType,
+        MessageFlags, MessageNonce, MessageReaction, MessageReference, MessageType,
+        ReactionCountDetails,
     };
     use crate::{
         channel::{ChannelType, ReactionType},
         guild::PartialMember,
         id::Id,
+        poll::PollLayoutType,
         test::image_hash,
         user::User,
         util::datetime::{Timestamp, TimestampParseError},
@@ -212,7 +238,10 @@ mod tests {
             mention_everyone: false,
             mention_roles: Vec::new(),
             mentions: Vec::new(),
+            nonce: None,
             pinned: false,
+            poll: None,
+            position: None,
             reactions: Vec::new(),
             reference: None,
             sticker_items: vec![MessageSticker {
@@ -411,13 +440,22 @@ mod tests {
             mention_everyone: false,
             mention_roles: Vec::new(),
             mentions: Vec::new(),
+            nonce: Some(MessageNonce::String("123".to_owned())),
             pinned: false,
+            poll: None,
+            position: None,
             reactions: vec![MessageReaction {
+                burst_colors: Vec::new(),
                 count: 7,
+                count_details: ReactionCountDetails {
+                    burst: 0,
+                    normal: 7,
+                },
                 emoji: ReactionType::Unicode {
                     name: "a".to_owned(),
                 },
                 me: true,
+                me_burst: false,
             }],
             reference: Some(MessageReference {
                 channel_id: Some(Id::new(1)),
@@ -442,7 +480,7 @@ mod tests {
             &[
                 Token::Struct {
                     name: "Message",
-                    len: 25,
+                    len: 26,
                 },
                 Token::Str("activity"),
                 Token::Some,
@@ -576,16 +614,32 @@ mod tests {
                 Token::Str("mentions"),
                 Token::Seq { len: Some(0) },
                 Token::SeqEnd,
+                Token::Str("nonce"),
+                Token::Some,
+                Token::Str("123"),
                 Token::Str("pinned"),
                 Token::Bool(false),
                 Token::Str("reactions"),
                 Token::Seq { len: Some(1) },
                 Token::Struct {
                     name: "MessageReaction",
-                    len: 3,
+                    len: 6,
                 },
+                Token::Str("burst_colors"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
                 Token::Str("count"),
                 Token::U64(7),
+                Token::Str("count_details"),
+                Token::Struct {
+                    name: "ReactionCountDetails",
+                    len: 2,
+                },
+                Token::Str("burst"),
+                Token::U64(0),
+                Token::Str("normal"),
+                Token::U64(7),
+                Token::StructEnd,
                 Token::Str("emoji"),
                 Token::Struct {
                     name: "ReactionType",
@@ -596,6 +650,8 @@ mod tests {
                 Token::StructEnd,
                 Token::Str("me"),
                 Token::Bool(true),
+                Token::Str("me_burst"),
+                Token::Bool(false),
                 Token::StructEnd,
                 Token::SeqEnd,
                 Token::Str("message_reference"),
@@ -638,4 +694,137 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn thread_starter() -> Result<(), serde_json::Error> {
+        let json = r#"{
+            "attachments": [],
+            "author": {
+                "avatar": null,
+                "discriminator": "0001",
+                "id": "3",
+                "username": "test"
+            },
+            "channel_id": "2",
+            "content": "starting a thread",
+            "edited_timestamp": null,
+            "embeds": [],
+            "guild_id": "1",
+            "id": "4",
+            "mention_everyone": false,
+            "mention_roles": [],
+            "mentions": [],
+            "pinned": false,
+            "position": 0,
+            "thread": {
+                "guild_id": "1",
+                "id": "4",
+                "message_count": 1,
+                "name": "thread",
+                "parent_id": "2",
+                "thread_metadata": {
+                    "archive_timestamp": "2020-02-02T02:02:02.020000+00:00",
+                    "archived": false,
+                    "auto_archive_duration": 1440,
+                    "locked": false
+                },
+                "type": 11
+            },
+            "timestamp": "2020-02-02T02:02:02.020000+00:00",
+            "tts": false,
+            "type": 0
+        }"#;
+
+        let message = serde_json::from_str::<Message>(json)?;
+        assert_eq!(Some(0), message.position);
+
+        let thread = message.thread.as_ref().expect("thread present");
+        assert_eq!(message.id.cast(), thread.id);
+        assert_eq!(ChannelType::GuildPublicThread, thread.kind);
+        assert_eq!(Some("thread"), thread.name.as_deref());
+        assert_eq!(Some(1), thread.message_count);
+        assert!(thread.thread_metadata.is_some());
+
+        let value = serde_json::to_value(&message)?;
+        assert_eq!(Some(0), value["position"].as_u64());
+        assert_eq!(Some("4"), value["thread"]["id"].as_str());
+
+        Ok(())
+    }
+
+    #[test]
+    fn poll() -> Result<(), serde_json::Error> {
+        let json = r#"{
+            "attachments": [],
+            "author": {
+                "avatar": null,
+                "discriminator": "0001",
+                "id": "3",
+                "username": "test"
+            },
+            "channel_id": "2",
+            "content": "",
+            "edited_timestamp": null,
+            "embeds": [],
+            "id": "4",
+            "mention_everyone": false,
+            "mention_roles": [],
+            "mentions": [],
+            "pinned": false,
+            "poll": {
+                "allow_multiselect": true,
+                "answers": [
+                    {
+                        "answer_id": 1,
+                        "poll_media": {
+                            "emoji": {"id": null, "name": "🦄"},
+                            "text": "ponies"
+                        }
+                    },
+                    {
+                        "answer_id": 2,
+                        "poll_media": {"text": "dragons"}
+                    }
+                ],
+                "expiry": "2020-02-03T02:02:02.020000+00:00",
+                "layout_type": 1,
+                "question": {"text": "best creature?"},
+                "results": {
+                    "answer_counts": [{"count": 2, "id": 1, "me_voted": false}],
+                    "is_finalized": true
+                }
+            },
+            "timestamp": "2020-02-02T02:02:02.020000+00:00",
+            "tts": false,
+            "type": 0
+        }"#;
+
+        let message = serde_json::from_str::<Message>(json)?;
+        let poll = message.poll.as_ref().expect("poll present");
+
+        assert!(poll.allow_multiselect);
+        assert_eq!(2, poll.answers.len());
+        assert_eq!(
+            Some(&ReactionType::Unicode {
+                name: "🦄".to_owned()
+            }),
+            poll.answers[0].poll_media.emoji.as_ref()
+        );
+        assert_eq!(Some("dragons"), poll.answers[1].poll_media.text.as_deref());
+        assert_eq!(PollLayoutType::Default, poll.layout_type);
+        assert_eq!(Some("best creature?"), poll.question.text.as_deref());
+
+        let results = poll.results.as_ref().expect("results present");
+        assert!(results.is_finalized);
+        assert_eq!(2, results.answer_counts[0].count);
+
+        let value = serde_json::to_value(&message)?;
+        assert_eq!(Some(1), value["poll"]["layout_type"].as_u64());
+        assert_eq!(
+            Some("🦄"),
+            value["poll"]["answers"][0]["poll_media"]["emoji"]["name"].as_str()
+        );
+
+        Ok(())
+    }
 }
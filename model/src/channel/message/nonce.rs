@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+
+/// Nonce of a [`Message`], used to correlate a sent message with the message
+/// received over the gateway.
+///
+/// Discord echoes the nonce back in the same type it was sent as.
+///
+/// [`Message`]: super::Message
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum MessageNonce {
+    /// Integer nonce.
+    Integer(u64),
+    /// String nonce, up to 25 characters long.
+    String(String),
+}
+
+impl From<u64> for MessageNonce {
+    fn from(nonce: u64) -> Self {
+        Self::Integer(nonce)
+    }
+}
+
+impl From<String> for MessageNonce {
+    fn from(nonce: String) -> Self {
+        Self::String(nonce)
+    }
+}
+
+impl From<&str> for MessageNonce {
+    fn from(nonce: &str) -> Self {
+        Self::String(nonce.to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MessageNonce;
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(
+        MessageNonce: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        From<u64>,
+        From<String>,
+        From<&'static str>,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    #[test]
+    fn variants() {
+        serde_test::assert_tokens(&MessageNonce::Integer(123), &[Token::U64(123)]);
+        serde_test::assert_tokens(
+            &MessageNonce::String("abc".to_owned()),
+            &[Token::Str("abc")],
+        );
+    }
+
+    /// Test that string nonces consisting of digits aren't parsed as integers.
+    #[test]
+    fn preserves_type() -> Result<(), serde_json::Error> {
+        assert_eq!(
+            MessageNonce::Integer(123),
+            serde_json::from_str::<MessageNonce>("123")?
+        );
+        assert_eq!(
+            MessageNonce::String("123".to_owned()),
+            serde_json::from_str::<MessageNonce>(r#""123""#)?
+        );
+
+        Ok(())
+    }
+}
@@ -3,27 +3,107 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct MessageReaction {
+    /// HEX colors used for super reactions.
+    #[serde(default)]
+    pub burst_colors: Vec<String>,
     pub count: u64,
+    /// Breakdown of the [`count`] into normal and super reactions.
+    ///
+    /// [`count`]: Self::count
+    #[serde(default)]
+    pub count_details: ReactionCountDetails,
     pub emoji: ReactionType,
     pub me: bool,
+    /// Whether the current user super reacted using this emoji.
+    #[serde(default)]
+    pub me_burst: bool,
+}
+
+/// Breakdown of the number of normal and super reactions of a
+/// [`MessageReaction`].
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ReactionCountDetails {
+    /// Number of super reactions.
+    pub burst: u64,
+    /// Number of normal reactions.
+    pub normal: u64,
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{MessageReaction, ReactionType};
+    use super::{MessageReaction, ReactionCountDetails, ReactionType};
     use serde_test::Token;
 
     #[test]
     fn message_reaction_unicode() {
         let value = MessageReaction {
+            burst_colors: Vec::new(),
             count: 7,
+            count_details: ReactionCountDetails {
+                burst: 0,
+                normal: 7,
+            },
             emoji: ReactionType::Unicode {
                 name: "a".to_owned(),
             },
             me: true,
+            me_burst: false,
         };
 
         serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "MessageReaction",
+                    len: 6,
+                },
+                Token::Str("burst_colors"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::Str("count"),
+                Token::U64(7),
+                Token::Str("count_details"),
+                Token::Struct {
+                    name: "ReactionCountDetails",
+                    len: 2,
+                },
+                Token::Str("burst"),
+                Token::U64(0),
+                Token::Str("normal"),
+                Token::U64(7),
+                Token::StructEnd,
+                Token::Str("emoji"),
+                Token::Struct {
+                    name: "ReactionType",
+                    len: 1,
+                },
+                Token::Str("name"),
+                Token::Str("a"),
+                Token::StructEnd,
+                Token::Str("me"),
+                Token::Bool(true),
+                Token::Str("me_burst"),
+                Token::Bool(false),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    /// Test that reactions without super reaction fields deserialize.
+    #[test]
+    fn message_reaction_without_burst() {
+        let value = MessageReaction {
+            burst_colors: Vec::new(),
+            count: 7,
+            count_details: ReactionCountDetails::default(),
+            emoji: ReactionType::Unicode {
+                name: "a".to_owned(),
+            },
+            me: true,
+            me_burst: false,
+        };
+
+        serde_test::assert_de_tokens(
             &value,
             &[
                 Token::Struct {
@@ -46,4 +126,61 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn message_reaction_burst() {
+        let value = MessageReaction {
+            burst_colors: vec!["#ff0000".to_owned(), "#00ff00".to_owned()],
+            count: 3,
+            count_details: ReactionCountDetails {
+                burst: 2,
+                normal: 1,
+            },
+            emoji: ReactionType::Unicode {
+                name: "a".to_owned(),
+            },
+            me: false,
+            me_burst: true,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "MessageReaction",
+                    len: 6,
+                },
+                Token::Str("burst_colors"),
+                Token::Seq { len: Some(2) },
+                Token::Str("#ff0000"),
+                Token::Str("#00ff00"),
+                Token::SeqEnd,
+                Token::Str("count"),
+                Token::U64(3),
+                Token::Str("count_details"),
+                Token::Struct {
+                    name: "ReactionCountDetails",
+                    len: 2,
+                },
+                Token::Str("burst"),
+                Token::U64(2),
+                Token::Str("normal"),
+                Token::U64(1),
+                Token::StructEnd,
+                Token::Str("emoji"),
+                Token::Struct {
+                    name: "ReactionType",
+                    len: 1,
+                },
+                Token::Str("name"),
+                Token::Str("a"),
+                Token::StructEnd,
+                Token::Str("me"),
+                Token::Bool(false),
+                Token::Str("me_burst"),
+                Token::Bool(true),
+                Token::StructEnd,
+            ],
+        );
+    }
 }
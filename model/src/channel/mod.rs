@@ -6,6 +6,7 @@ pub mod thread;
 pub mod webhook;
 
 mod attachment;
+mod channel_flags;
 mod channel_mention;
 mod channel_type;
 mod followed_channel;
@@ -16,6 +17,7 @@ mod video_quality_mode;
 use self::permission_overwrite::PermissionOverwrite;
 pub use self::{
     attachment::Attachment,
+    channel_flags::ChannelFlags,
     channel_mention::ChannelMention,
     channel_type::ChannelType,
     followed_channel::FollowedChannel,
@@ -30,7 +32,10 @@ pub use self::{
 use crate::{
     channel::thread::{AutoArchiveDuration, ThreadMember, ThreadMetadata},
     id::{
-        marker::{ApplicationMarker, ChannelMarker, GuildMarker, MessageMarker, UserMarker},
+        marker::{
+            ApplicationMarker, ChannelMarker, ForumTagMarker, GuildMarker, MessageMarker,
+            UserMarker,
+        },
         Id,
     },
     user::User,
@@ -74,6 +79,9 @@ pub struct Channel {
     /// ID of the application that created the channel.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub application_id: Option<Id<ApplicationMarker>>,
+    /// IDs of the tags applied to a thread in a forum channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub applied_tags: Option<Vec<Id<ForumTagMarker>>>,
     /// Bitrate setting of audio channels.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bitrate: Option<u64>,
@@ -84,6 +92,9 @@ pub struct Channel {
     /// level.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default_auto_archive_duration: Option<AutoArchiveDuration>,
+    /// Flags of the channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flags: Option<ChannelFlags>,
     /// ID of the guild the channel is in.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub guild_id: Option<Id<GuildMarker>>,
@@ -175,7 +186,9 @@ pub struct Channel {
 
 #[cfg(test)]
 mod tests {
-    use super::{AutoArchiveDuration, Channel, ChannelType, ThreadMember, ThreadMetadata};
+    use super::{
+        AutoArchiveDuration, Channel, ChannelFlags, ChannelType, ThreadMember, ThreadMetadata,
+    };
     use crate::{
         channel::permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
         guild::Permissions,
@@ -216,8 +229,10 @@ mod tests {
 
         let value = Channel {
             application_id: None,
+            applied_tags: None,
             bitrate: None,
             default_auto_archive_duration: None,
+            flags: None,
             guild_id: Some(Id::new(1)),
             icon: None,
             id: Id::new(2),
@@ -251,8 +266,10 @@ mod tests {
     fn guild_category_channel_deserialization() {
         let value = Channel {
             application_id: None,
+            applied_tags: None,
             bitrate: None,
             default_auto_archive_duration: None,
+            flags: None,
             guild_id: Some(Id::new(2)),
             icon: None,
             id: Id::new(1),
@@ -298,8 +315,10 @@ mod tests {
     fn guild_news_channel_deserialization() {
         let value = Channel {
             application_id: None,
+            applied_tags: None,
             bitrate: None,
             default_auto_archive_duration: None,
+            flags: None,
             guild_id: Some(Id::new(2)),
             icon: None,
             id: Id::new(1),
@@ -352,8 +371,10 @@ mod tests {
 
         let value = Channel {
             application_id: None,
+            applied_tags: None,
             bitrate: None,
             default_auto_archive_duration: Some(AutoArchiveDuration::Hour),
+            flags: None,
             guild_id: Some(Id::new(1)),
             icon: None,
             id: Id::new(6),
@@ -427,14 +448,82 @@ mod tests {
         )
     }
 
+    #[test]
+    fn guild_forum_post_pinned() -> Result<(), serde_json::Error> {
+        let timestamp = Timestamp::from_secs(1_632_074_792).expect("non zero");
+
+        let value = Channel {
+            application_id: None,
+            applied_tags: Some(vec![Id::new(5)]),
+            bitrate: None,
+            default_auto_archive_duration: None,
+            flags: Some(ChannelFlags::PINNED),
+            guild_id: Some(Id::new(1)),
+            icon: None,
+            id: Id::new(3),
+            invitable: None,
+            kind: ChannelType::GuildPublicThread,
+            last_message_id: None,
+            last_pin_timestamp: None,
+            member: None,
+            member_count: None,
+            message_count: None,
+            name: Some("forum post".into()),
+            newly_created: None,
+            nsfw: None,
+            owner_id: Some(Id::new(4)),
+            parent_id: Some(Id::new(2)),
+            permission_overwrites: None,
+            position: None,
+            rate_limit_per_user: None,
+            recipients: None,
+            rtc_region: None,
+            thread_metadata: Some(ThreadMetadata {
+                archived: false,
+                auto_archive_duration: AutoArchiveDuration::Day,
+                archive_timestamp: timestamp,
+                create_timestamp: Some(timestamp),
+                invitable: None,
+                locked: false,
+            }),
+            topic: None,
+            user_limit: None,
+            video_quality_mode: None,
+        };
+        let input = serde_json::json!({
+            "applied_tags": ["5"],
+            "flags": 2,
+            "guild_id": "1",
+            "id": "3",
+            "type": ChannelType::GuildPublicThread,
+            "name": "forum post",
+            "owner_id": "4",
+            "parent_id": "2",
+            "thread_metadata": {
+                "archive_timestamp": timestamp,
+                "archived": false,
+                "auto_archive_duration": AutoArchiveDuration::Day,
+                "create_timestamp": timestamp,
+                "locked": false
+            }
+        });
+
+        assert_eq!(value, serde_json::from_value(input.clone())?);
+        assert_eq!(input, serde_json::to_value(&value)?);
+
+        Ok(())
+    }
+
     #[test]
     fn guild_public_thread_deserialization() {
         let timestamp = Timestamp::from_secs(1_632_074_792).expect("non zero");
 
         let value = Channel {
             application_id: None,
+            applied_tags: None,
             bitrate: None,
             default_auto_archive_duration: Some(AutoArchiveDuration::Hour),
+            flags: None,
             guild_id: Some(Id::new(1)),
             icon: None,
             id: Id::new(6),
@@ -515,8 +604,10 @@ mod tests {
 
         let value = Channel {
             application_id: None,
+            applied_tags: None,
             bitrate: None,
             default_auto_archive_duration: Some(AutoArchiveDuration::Hour),
+            flags: None,
             guild_id: Some(Id::new(1)),
             icon: None,
             id: Id::new(6),
@@ -14,6 +14,15 @@ use std::fmt::{Formatter, Result as FmtResult};
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize)]
 pub struct Reaction {
+    /// Whether the reaction is a super reaction.
+    pub burst: bool,
+    /// HEX colors used for the super reaction.
+    ///
+    /// Only present on [`ReactionAdd`] events of super reactions.
+    ///
+    /// [`ReactionAdd`]: crate::gateway::payload::incoming::ReactionAdd
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub burst_colors: Vec<String>,
     pub channel_id: Id<ChannelMarker>,
     pub emoji: ReactionType,
     pub guild_id: Option<Id<GuildMarker>>,
@@ -25,6 +34,8 @@ pub struct Reaction {
 #[derive(Debug, Deserialize)]
 #[serde(field_identifier, rename_all = "snake_case")]
 enum Field {
+    Burst,
+    BurstColors,
     ChannelId,
     Emoji,
     GuildId,
@@ -43,6 +54,8 @@ impl<'de> Visitor<'de> for ReactionVisitor {
     }
 
     fn visit_map<V: MapAccess<'de>>(self, mut map: V) -> Result<Self::Value, V::Error> {
+        let mut burst = None;
+        let mut burst_colors = None;
         let mut channel_id = None;
         let mut emoji = None;
         let mut guild_id = None;
@@ -75,6 +88,20 @@ impl<'de> Visitor<'de> for ReactionVisitor {
             };
 
             match key {
+                Field::Burst => {
+                    if burst.is_some() {
+                        return Err(DeError::duplicate_field("burst"));
+                    }
+
+                    burst = Some(map.next_value()?);
+                }
+                Field::BurstColors => {
+                    if burst_colors.is_some() {
+                        return Err(DeError::duplicate_field("burst_colors"));
+                    }
+
+                    burst_colors = Some(map.next_value()?);
+                }
                 Field::ChannelId => {
                     if channel_id.is_some() {
                         return Err(DeError::duplicate_field("channel_id"));
@@ -136,6 +163,8 @@ impl<'de> Visitor<'de> for ReactionVisitor {
         }
 
         Ok(Reaction {
+            burst: burst.unwrap_or_default(),
+            burst_colors: burst_colors.unwrap_or_default(),
             channel_id,
             emoji,
             guild_id,
@@ -149,6 +178,8 @@ impl<'de> Visitor<'de> for ReactionVisitor {
 impl<'de> Deserialize<'de> for Reaction {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         const FIELDS: &[&str] = &[
+            "burst",
+            "burst_colors",
             "channel_id",
             "emoji",
             "guild_id",
@@ -180,6 +211,8 @@ mod tests {
         let joined_at = Timestamp::from_str("2020-01-01T00:00:00.000000+00:00")?;
 
         let value = Reaction {
+            burst: false,
+            burst_colors: Vec::new(),
             channel_id: Id::new(2),
             emoji: ReactionType::Unicode {
                 name: "a".to_owned(),
@@ -223,8 +256,10 @@ mod tests {
             &[
                 Token::Struct {
                     name: "Reaction",
-                    len: 6,
+                    len: 7,
                 },
+                Token::Str("burst"),
+                Token::Bool(false),
                 Token::Str("channel_id"),
                 Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
@@ -306,6 +341,58 @@ mod tests {
     #[test]
     fn reaction_without_member() {
         let value = Reaction {
+            burst: false,
+            burst_colors: Vec::new(),
+            channel_id: Id::new(2),
+            emoji: ReactionType::Unicode {
+                name: "a".to_owned(),
+            },
+            guild_id: None,
+            member: None,
+            message_id: Id::new(3),
+            user_id: Id::new(4),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Reaction",
+                    len: 7,
+                },
+                Token::Str("burst"),
+                Token::Bool(false),
+                Token::Str("channel_id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("2"),
+                Token::Str("emoji"),
+                Token::Struct {
+                    name: "ReactionType",
+                    len: 1,
+                },
+                Token::Str("name"),
+                Token::Str("a"),
+                Token::StructEnd,
+                Token::Str("guild_id"),
+                Token::None,
+                Token::Str("member"),
+                Token::None,
+                Token::Str("message_id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("3"),
+                Token::Str("user_id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("4"),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn reaction_burst() {
+        let value = Reaction {
+            burst: true,
+            burst_colors: vec!["#ff0000".to_owned()],
             channel_id: Id::new(2),
             emoji: ReactionType::Unicode {
                 name: "a".to_owned(),
@@ -321,8 +408,14 @@ mod tests {
             &[
                 Token::Struct {
                     name: "Reaction",
-                    len: 6,
+                    len: 8,
                 },
+                Token::Str("burst"),
+                Token::Bool(true),
+                Token::Str("burst_colors"),
+                Token::Seq { len: Some(1) },
+                Token::Str("#ff0000"),
+                Token::SeqEnd,
                 Token::Str("channel_id"),
                 Token::NewtypeStruct { name: "Id" },
                 Token::Str("2"),
@@ -1,10 +1,19 @@
 use serde_repr::{Deserialize_repr, Serialize_repr};
 
+/// Privacy level of a [`StageInstance`].
+///
+/// [`StageInstance`]: super::StageInstance
 #[derive(
     Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize_repr,
 )]
 #[repr(u8)]
 pub enum PrivacyLevel {
+    /// Stage instance is visible publicly.
+    ///
+    /// Discord has deprecated this privacy level, and new stage instances may
+    /// no longer be created with it.
+    Public = 1,
+    /// Stage instance is only visible to guild members.
     GuildOnly = 2,
 }
 
@@ -15,6 +24,7 @@ mod tests {
 
     #[test]
     fn variants() {
+        serde_test::assert_tokens(&PrivacyLevel::Public, &[Token::U8(1)]);
         serde_test::assert_tokens(&PrivacyLevel::GuildOnly, &[Token::U8(2)]);
     }
 }
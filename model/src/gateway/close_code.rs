@@ -41,6 +41,39 @@ pub enum CloseCode {
     DisallowedIntents = 4014,
 }
 
+impl CloseCode {
+    /// Whether the shard can reconnect after receiving the close code.
+    ///
+    /// Close codes caused by an invalid configuration, such as an invalid
+    /// token or disallowed intents, won't resolve by reconnecting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_model::gateway::CloseCode;
+    ///
+    /// assert!(CloseCode::SessionTimedOut.can_reconnect());
+    /// assert!(!CloseCode::AuthenticationFailed.can_reconnect());
+    /// ```
+    pub const fn can_reconnect(self) -> bool {
+        !matches!(
+            self,
+            Self::AuthenticationFailed
+                | Self::InvalidShard
+                | Self::ShardingRequired
+                | Self::InvalidApiVersion
+                | Self::InvalidIntents
+                | Self::DisallowedIntents
+        )
+    }
+}
+
+impl From<CloseCode> for u16 {
+    fn from(code: CloseCode) -> Self {
+        code as u16
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub struct CloseCodeConversionError {
     code: u16,
@@ -158,6 +191,27 @@ mod tests {
             CloseCode::try_from(4014).unwrap(),
             CloseCode::DisallowedIntents
         );
+        assert!(CloseCode::try_from(4006).is_err());
         assert!(CloseCode::try_from(5000).is_err());
+        assert_eq!(u16::from(CloseCode::UnknownError), 4000);
+        assert_eq!(u16::from(CloseCode::DisallowedIntents), 4014);
+    }
+
+    #[test]
+    fn can_reconnect() {
+        assert!(CloseCode::UnknownError.can_reconnect());
+        assert!(CloseCode::UnknownOpcode.can_reconnect());
+        assert!(CloseCode::DecodeError.can_reconnect());
+        assert!(CloseCode::NotAuthenticated.can_reconnect());
+        assert!(!CloseCode::AuthenticationFailed.can_reconnect());
+        assert!(CloseCode::AlreadyAuthenticated.can_reconnect());
+        assert!(CloseCode::InvalidSequence.can_reconnect());
+        assert!(CloseCode::RateLimited.can_reconnect());
+        assert!(CloseCode::SessionTimedOut.can_reconnect());
+        assert!(!CloseCode::InvalidShard.can_reconnect());
+        assert!(!CloseCode::ShardingRequired.can_reconnect());
+        assert!(!CloseCode::InvalidApiVersion.can_reconnect());
+        assert!(!CloseCode::InvalidIntents.can_reconnect());
+        assert!(!CloseCode::DisallowedIntents.can_reconnect());
     }
 }
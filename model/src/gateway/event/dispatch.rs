@@ -45,6 +45,8 @@ pub enum DispatchEvent {
     MessageCreate(Box<MessageCreate>),
     MessageDelete(MessageDelete),
     MessageDeleteBulk(MessageDeleteBulk),
+    MessagePollVoteAdd(MessagePollVoteAdd),
+    MessagePollVoteRemove(MessagePollVoteRemove),
     MessageUpdate(Box<MessageUpdate>),
     PresenceUpdate(Box<PresenceUpdate>),
     PresencesReplace,
@@ -109,6 +111,8 @@ impl DispatchEvent {
             Self::MessageCreate(_) => EventType::MessageCreate,
             Self::MessageDelete(_) => EventType::MessageDelete,
             Self::MessageDeleteBulk(_) => EventType::MessageDeleteBulk,
+            Self::MessagePollVoteAdd(_) => EventType::MessagePollVoteAdd,
+            Self::MessagePollVoteRemove(_) => EventType::MessagePollVoteRemove,
             Self::MessageUpdate(_) => EventType::MessageUpdate,
             Self::PresenceUpdate(_) => EventType::PresenceUpdate,
             Self::PresencesReplace => EventType::PresencesReplace,
@@ -176,6 +180,8 @@ impl TryFrom<Event> for DispatchEvent {
             Event::MessageCreate(v) => Self::MessageCreate(v),
             Event::MessageDelete(v) => Self::MessageDelete(v),
             Event::MessageDeleteBulk(v) => Self::MessageDeleteBulk(v),
+            Event::MessagePollVoteAdd(v) => Self::MessagePollVoteAdd(v),
+            Event::MessagePollVoteRemove(v) => Self::MessagePollVoteRemove(v),
             Event::MessageUpdate(v) => Self::MessageUpdate(v),
             Event::PresenceUpdate(v) => Self::PresenceUpdate(v),
             Event::PresencesReplace => Self::PresencesReplace,
@@ -326,6 +332,12 @@ impl<'de, 'a> DeserializeSeed<'de> for DispatchEventWithTypeDeserializer<'a> {
             "MESSAGE_DELETE_BULK" => {
                 DispatchEvent::MessageDeleteBulk(MessageDeleteBulk::deserialize(deserializer)?)
             }
+            "MESSAGE_POLL_VOTE_ADD" => {
+                DispatchEvent::MessagePollVoteAdd(MessagePollVoteAdd::deserialize(deserializer)?)
+            }
+            "MESSAGE_POLL_VOTE_REMOVE" => DispatchEvent::MessagePollVoteRemove(
+                MessagePollVoteRemove::deserialize(deserializer)?,
+            ),
             "MESSAGE_REACTION_ADD" => {
                 DispatchEvent::ReactionAdd(Box::new(ReactionAdd::deserialize(deserializer)?))
             }
@@ -519,7 +519,11 @@ impl Serialize for GatewayEvent {
 #[cfg(test)]
 mod tests {
     use super::{DispatchEvent, GatewayEvent, GatewayEventDeserializer, OpCode};
-    use crate::{gateway::payload::incoming::RoleDelete, id::Id, test::image_hash};
+    use crate::{
+        gateway::payload::incoming::{RoleDelete, WebhooksUpdate},
+        id::Id,
+        test::image_hash,
+    };
     use serde::de::DeserializeSeed;
     use serde_json::de::Deserializer;
     use serde_test::Token;
@@ -542,6 +546,36 @@ mod tests {
         assert!(matches!(event, GatewayEvent::Dispatch(7, _)));
     }
 
+    #[test]
+    fn deserialize_dispatch_webhooks_update() {
+        let input = r#"{
+            "d": {
+                "channel_id": "1",
+                "guild_id": "2"
+            },
+            "op": 0,
+            "s": 3,
+            "t": "WEBHOOKS_UPDATE"
+        }"#;
+
+        let deserializer = GatewayEventDeserializer::from_json(input).unwrap();
+        let mut json_deserializer = Deserializer::from_str(input);
+        let event = deserializer.deserialize(&mut json_deserializer).unwrap();
+
+        let dispatch = match event {
+            GatewayEvent::Dispatch(3, dispatch) => *dispatch,
+            other => panic!("expected webhooks update dispatch, got {other:?}"),
+        };
+
+        assert_eq!(
+            dispatch,
+            DispatchEvent::WebhooksUpdate(WebhooksUpdate {
+                channel_id: Id::new(1),
+                guild_id: Id::new(2),
+            })
+        );
+    }
+
     #[test]
     fn deserialize_dispatch_guild_update() {
         let input = format!(
@@ -31,6 +31,7 @@ pub enum EventType {
     GuildScheduledEventUserRemove,
     GuildStickersUpdate,
     GuildUpdate,
+    GuildsReady,
     IntegrationCreate,
     IntegrationDelete,
     IntegrationUpdate,
@@ -48,6 +49,8 @@ pub enum EventType {
     MessageCreate,
     MessageDelete,
     MessageDeleteBulk,
+    MessagePollVoteAdd,
+    MessagePollVoteRemove,
     MessageUpdate,
     PresenceUpdate,
     PresencesReplace,
@@ -126,6 +129,8 @@ impl EventType {
             Self::MessageCreate => Some("MESSAGE_CREATE"),
             Self::MessageDelete => Some("MESSAGE_DELETE"),
             Self::MessageDeleteBulk => Some("MESSAGE_DELETE_BULK"),
+            Self::MessagePollVoteAdd => Some("MESSAGE_POLL_VOTE_ADD"),
+            Self::MessagePollVoteRemove => Some("MESSAGE_POLL_VOTE_REMOVE"),
             Self::MessageUpdate => Some("MESSAGE_UPDATE"),
             Self::PresenceUpdate => Some("PRESENCE_UPDATE"),
             Self::PresencesReplace => Some("PRESENCES_REPLACE"),
@@ -158,6 +163,7 @@ impl EventType {
             | Self::GatewayHello
             | Self::GatewayInvalidateSession
             | Self::GatewayReconnect
+            | Self::GuildsReady
             | Self::ShardConnected
             | Self::ShardConnecting
             | Self::ShardDisconnected
@@ -205,6 +211,8 @@ impl<'a> TryFrom<&'a str> for EventType {
             "MESSAGE_CREATE" => Ok(Self::MessageCreate),
             "MESSAGE_DELETE" => Ok(Self::MessageDelete),
             "MESSAGE_DELETE_BULK" => Ok(Self::MessageDeleteBulk),
+            "MESSAGE_POLL_VOTE_ADD" => Ok(Self::MessagePollVoteAdd),
+            "MESSAGE_POLL_VOTE_REMOVE" => Ok(Self::MessagePollVoteRemove),
             "MESSAGE_UPDATE" => Ok(Self::MessageUpdate),
             "PRESENCE_UPDATE" => Ok(Self::PresenceUpdate),
             "PRESENCES_REPLACE" => Ok(Self::PresencesReplace),
@@ -301,6 +309,7 @@ mod tests {
             "GUILD_SCHEDULED_EVENT_USER_REMOVE",
         );
         assert_variant(EventType::GuildUpdate, "GUILD_UPDATE");
+        assert_variant(EventType::GuildsReady, "GUILDS_READY");
         assert_variant(EventType::IntegrationCreate, "INTEGRATION_CREATE");
         assert_variant(EventType::IntegrationDelete, "INTEGRATION_DELETE");
         assert_variant(EventType::IntegrationUpdate, "INTEGRATION_UPDATE");
@@ -314,6 +323,8 @@ mod tests {
         assert_variant(EventType::MessageCreate, "MESSAGE_CREATE");
         assert_variant(EventType::MessageDelete, "MESSAGE_DELETE");
         assert_variant(EventType::MessageDeleteBulk, "MESSAGE_DELETE_BULK");
+        assert_variant(EventType::MessagePollVoteAdd, "MESSAGE_POLL_VOTE_ADD");
+        assert_variant(EventType::MessagePollVoteRemove, "MESSAGE_POLL_VOTE_REMOVE");
         assert_variant(EventType::MessageUpdate, "MESSAGE_UPDATE");
         assert_variant(EventType::PresenceUpdate, "PRESENCE_UPDATE");
         assert_variant(EventType::PresencesReplace, "PRESENCES_REPLACE");
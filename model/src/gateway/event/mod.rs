@@ -73,6 +73,9 @@ pub enum Event {
     GuildStickersUpdate(GuildStickersUpdate),
     /// A guild was updated.
     GuildUpdate(Box<GuildUpdate>),
+    /// A shard has received all of the guilds in its [`Ready`] payload, or
+    /// timed out waiting for them.
+    GuildsReady(GuildsReady),
     /// A guild integration was created.
     IntegrationCreate(Box<IntegrationCreate>),
     /// A guild integration was updated.
@@ -99,6 +102,10 @@ pub enum Event {
     MessageDelete(MessageDelete),
     /// Multiple messages were deleted in a channel.
     MessageDeleteBulk(MessageDeleteBulk),
+    /// A user voted for an answer of a poll.
+    MessagePollVoteAdd(MessagePollVoteAdd),
+    /// A user removed their vote for an answer of a poll.
+    MessagePollVoteRemove(MessagePollVoteRemove),
     /// A message was updated in a channel.
     MessageUpdate(Box<MessageUpdate>),
     /// A user's active presence (such as game or online status) was updated.
@@ -203,6 +210,7 @@ impl Event {
             Self::GuildScheduledEventUserRemove(_) => EventType::GuildScheduledEventUserRemove,
             Self::GuildStickersUpdate(_) => EventType::GuildStickersUpdate,
             Self::GuildUpdate(_) => EventType::GuildUpdate,
+            Self::GuildsReady(_) => EventType::GuildsReady,
             Self::IntegrationCreate(_) => EventType::IntegrationCreate,
             Self::IntegrationDelete(_) => EventType::IntegrationDelete,
             Self::IntegrationUpdate(_) => EventType::IntegrationUpdate,
@@ -216,6 +224,8 @@ impl Event {
             Self::MessageCreate(_) => EventType::MessageCreate,
             Self::MessageDelete(_) => EventType::MessageDelete,
             Self::MessageDeleteBulk(_) => EventType::MessageDeleteBulk,
+            Self::MessagePollVoteAdd(_) => EventType::MessagePollVoteAdd,
+            Self::MessagePollVoteRemove(_) => EventType::MessagePollVoteRemove,
             Self::MessageUpdate(_) => EventType::MessageUpdate,
             Self::PresenceUpdate(_) => EventType::PresenceUpdate,
             Self::PresencesReplace => EventType::PresencesReplace,
@@ -293,6 +303,8 @@ impl From<DispatchEvent> for Event {
             DispatchEvent::MessageCreate(v) => Self::MessageCreate(v),
             DispatchEvent::MessageDelete(v) => Self::MessageDelete(v),
             DispatchEvent::MessageDeleteBulk(v) => Self::MessageDeleteBulk(v),
+            DispatchEvent::MessagePollVoteAdd(v) => Self::MessagePollVoteAdd(v),
+            DispatchEvent::MessagePollVoteRemove(v) => Self::MessagePollVoteRemove(v),
             DispatchEvent::MessageUpdate(v) => Self::MessageUpdate(v),
             DispatchEvent::PresenceUpdate(v) => Self::PresenceUpdate(v),
             DispatchEvent::PresencesReplace => Self::PresencesReplace,
@@ -453,6 +465,8 @@ mod tests {
     const_assert!(mem::size_of::<MemberRemove>() <= EVENT_THRESHOLD);
     const_assert!(mem::size_of::<MessageDelete>() <= EVENT_THRESHOLD);
     const_assert!(mem::size_of::<MessageDeleteBulk>() <= EVENT_THRESHOLD);
+    const_assert!(mem::size_of::<MessagePollVoteAdd>() <= EVENT_THRESHOLD);
+    const_assert!(mem::size_of::<MessagePollVoteRemove>() <= EVENT_THRESHOLD);
     const_assert!(mem::size_of::<Payload>() <= EVENT_THRESHOLD);
     const_assert!(mem::size_of::<ReactionRemoveAll>() <= EVENT_THRESHOLD);
     const_assert!(mem::size_of::<Reconnecting>() <= EVENT_THRESHOLD);
@@ -1,4 +1,5 @@
 use super::{Event, EventConversionError};
+use crate::id::{marker::GuildMarker, Id};
 use serde::{Deserialize, Serialize};
 
 /// Indicator that a shard is now fully connected.
@@ -31,6 +32,19 @@ pub struct Disconnected {
     pub shard_id: u64,
 }
 
+/// Indicator that a shard has received all of the guilds in its [`Ready`]
+/// payload, or timed out waiting for them.
+///
+/// [`Ready`]: crate::gateway::payload::incoming::Ready
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct GuildsReady {
+    /// The ID of the shard that received the guilds.
+    pub shard_id: u64,
+    /// Guilds that were received as unavailable or weren't received before
+    /// timing out.
+    pub unavailable: Vec<Id<GuildMarker>>,
+}
+
 /// Indicator that a shard is now identifying with the gateway to create a new
 /// session.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
@@ -58,6 +72,12 @@ pub struct Reconnecting {
 /// Indicator that a shard is now resuming a session after a disconnect.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct Resuming {
+    /// Number of seconds the shard waits before resuming, if any.
+    ///
+    /// This is the case when the gateway closed the connection because the
+    /// shard sent too many payloads.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delay: Option<u64>,
     /// The event sequence sent when resuming was initiated.
     pub seq: u64,
     /// The ID of the shard that began resuming.
@@ -108,9 +128,10 @@ impl TryFrom<Event> for ShardEvent {
 #[cfg(test)]
 mod tests {
     use super::{
-        Connected, Connecting, Disconnected, Event, Identifying, Payload, Reconnecting, Resuming,
-        ShardEvent,
+        Connected, Connecting, Disconnected, Event, GuildsReady, Identifying, Payload,
+        Reconnecting, Resuming, ShardEvent,
     };
+    use crate::id::Id;
     use serde_test::Token;
 
     #[test]
@@ -187,6 +208,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn guilds_ready() {
+        let value = GuildsReady {
+            shard_id: 4,
+            unavailable: Vec::from([Id::new(1)]),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "GuildsReady",
+                    len: 2,
+                },
+                Token::Str("shard_id"),
+                Token::U64(4),
+                Token::Str("unavailable"),
+                Token::Seq { len: Some(1) },
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+
     #[test]
     fn identifying() {
         let value = Identifying {
@@ -252,6 +299,7 @@ mod tests {
     #[test]
     fn resuming() {
         let value = Resuming {
+            delay: None,
             seq: 100,
             shard_id: 4,
         };
@@ -270,6 +318,30 @@ mod tests {
                 Token::StructEnd,
             ],
         );
+
+        let value = Resuming {
+            delay: Some(60),
+            seq: 100,
+            shard_id: 4,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Resuming",
+                    len: 3,
+                },
+                Token::Str("delay"),
+                Token::Some,
+                Token::U64(60),
+                Token::Str("seq"),
+                Token::U64(100),
+                Token::Str("shard_id"),
+                Token::U64(4),
+                Token::StructEnd,
+            ],
+        );
     }
 
     #[test]
@@ -324,6 +396,7 @@ mod tests {
         ));
 
         let resuming = Event::ShardResuming(Resuming {
+            delay: None,
             seq: 100,
             shard_id: 4,
         });
@@ -215,6 +215,60 @@ bitflags! {
         ///
         /// [Discord Docs/Privileged Intents]: https://discord.com/developers/docs/topics/gateway#privileged-intents
         const MESSAGE_CONTENT = 1 << 15;
+        /// Guild message polls intent.
+        ///
+        /// Event(s) received:
+        ///  - [`MESSAGE_POLL_VOTE_ADD`]
+        ///  - [`MESSAGE_POLL_VOTE_REMOVE`]
+        ///
+        /// [`MESSAGE_POLL_VOTE_ADD`]: super::event::Event::MessagePollVoteAdd
+        /// [`MESSAGE_POLL_VOTE_REMOVE`]: super::event::Event::MessagePollVoteRemove
+        const GUILD_MESSAGE_POLLS = 1 << 24;
+        /// Direct message polls intent.
+        ///
+        /// Event(s) received:
+        ///  - [`MESSAGE_POLL_VOTE_ADD`]
+        ///  - [`MESSAGE_POLL_VOTE_REMOVE`]
+        ///
+        /// This is different from the [`GUILD_MESSAGE_POLLS`] intent in that
+        /// the bot will receive poll vote events from locations other than
+        /// guilds.
+        ///
+        /// [`MESSAGE_POLL_VOTE_ADD`]: super::event::Event::MessagePollVoteAdd
+        /// [`MESSAGE_POLL_VOTE_REMOVE`]: super::event::Event::MessagePollVoteRemove
+        /// [`GUILD_MESSAGE_POLLS`]: Self::GUILD_MESSAGE_POLLS
+        const DIRECT_MESSAGE_POLLS = 1 << 25;
+    }
+}
+
+impl Intents {
+    /// Intents that aren't privileged.
+    ///
+    /// This is every intent except for those included in [`privileged`], so
+    /// applications can use them without being approved by Discord.
+    ///
+    /// [`privileged`]: Self::privileged
+    pub const fn non_privileged() -> Self {
+        Self::from_bits_truncate(Self::all().bits() & !Self::privileged().bits())
+    }
+
+    /// Intents that are privileged.
+    ///
+    /// This includes the [`GUILD_MEMBERS`], [`GUILD_PRESENCES`], and
+    /// [`MESSAGE_CONTENT`] intents. Privileged intents must be enabled in the
+    /// application's settings, and verified applications must be approved by
+    /// Discord to use them. See [Discord Docs/Privileged Intents].
+    ///
+    /// [Discord Docs/Privileged Intents]: https://discord.com/developers/docs/topics/gateway#privileged-intents
+    /// [`GUILD_MEMBERS`]: Self::GUILD_MEMBERS
+    /// [`GUILD_PRESENCES`]: Self::GUILD_PRESENCES
+    /// [`MESSAGE_CONTENT`]: Self::MESSAGE_CONTENT
+    pub const fn privileged() -> Self {
+        Self::from_bits_truncate(
+            Self::GUILD_MEMBERS.bits()
+                | Self::GUILD_PRESENCES.bits()
+                | Self::MESSAGE_CONTENT.bits(),
+        )
     }
 }
 
@@ -232,3 +286,31 @@ impl Serialize for Intents {
         serializer.serialize_u64(self.bits())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Intents;
+    use serde_test::Token;
+
+    #[test]
+    fn presets() {
+        assert_eq!(50_462_719, Intents::all().bits());
+        assert_eq!(
+            Intents::GUILD_MEMBERS | Intents::GUILD_PRESENCES | Intents::MESSAGE_CONTENT,
+            Intents::privileged()
+        );
+        assert_eq!(33_026, Intents::privileged().bits());
+        assert_eq!(50_429_693, Intents::non_privileged().bits());
+        assert_eq!(
+            Intents::all(),
+            Intents::non_privileged() | Intents::privileged()
+        );
+        assert!(!Intents::non_privileged().intersects(Intents::privileged()));
+    }
+
+    #[test]
+    fn serde() {
+        serde_test::assert_tokens(&Intents::privileged(), &[Token::U64(33_026)]);
+        serde_test::assert_de_tokens(&Intents::GUILDS, &[Token::U64(1 | 1 << 63)]);
+    }
+}
@@ -18,3 +18,170 @@ impl DerefMut for GuildCreate {
         &mut self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::GuildCreate;
+    use crate::{
+        channel::ChannelType,
+        gateway::presence::Status,
+        id::Id,
+        scheduled_event::{EntityType, Status as ScheduledEventStatus},
+    };
+
+    /// Gateway-only fields are deserialized and have the guild's ID applied
+    /// where the payload omits it.
+    #[allow(clippy::too_many_lines)]
+    #[test]
+    fn gateway_fields() -> Result<(), serde_json::Error> {
+        let input = r#"{
+            "afk_channel_id": null,
+            "afk_timeout": 300,
+            "application_id": null,
+            "banner": null,
+            "channels": [{
+                "id": "2",
+                "type": 0,
+                "name": "general",
+                "position": 0,
+                "permission_overwrites": []
+            }],
+            "default_message_notifications": 1,
+            "description": null,
+            "discovery_splash": null,
+            "emojis": [],
+            "explicit_content_filter": 0,
+            "features": [],
+            "guild_scheduled_events": [{
+                "channel_id": "2",
+                "creator_id": "3",
+                "description": null,
+                "entity_id": null,
+                "entity_metadata": null,
+                "entity_type": 2,
+                "guild_id": "1",
+                "id": "4",
+                "image": null,
+                "name": "movie night",
+                "privacy_level": 2,
+                "scheduled_end_time": null,
+                "scheduled_start_time": "2022-01-01T00:00:00.000000+00:00",
+                "status": 1
+            }],
+            "icon": null,
+            "id": "1",
+            "joined_at": "2021-12-01T00:00:00.000000+00:00",
+            "large": false,
+            "max_video_channel_users": 25,
+            "member_count": 2,
+            "members": [{
+                "deaf": false,
+                "joined_at": "2021-12-01T00:00:00.000000+00:00",
+                "mute": false,
+                "roles": [],
+                "user": {
+                    "avatar": null,
+                    "discriminator": "0001",
+                    "id": "3",
+                    "username": "member"
+                }
+            }],
+            "mfa_level": 0,
+            "name": "twilight",
+            "nsfw_level": 0,
+            "owner_id": "3",
+            "preferred_locale": "en-US",
+            "premium_progress_bar_enabled": false,
+            "premium_subscription_count": 0,
+            "premium_tier": 0,
+            "presences": [{
+                "activities": [],
+                "client_status": {"desktop": "online"},
+                "status": "online",
+                "user": {"id": "3"}
+            }],
+            "roles": [],
+            "rules_channel_id": null,
+            "splash": null,
+            "stage_instances": [{
+                "channel_id": "5",
+                "guild_id": "1",
+                "guild_scheduled_event_id": null,
+                "id": "6",
+                "privacy_level": 2,
+                "topic": "stage"
+            }],
+            "stickers": [],
+            "system_channel_flags": 0,
+            "system_channel_id": null,
+            "threads": [{
+                "id": "7",
+                "type": 11,
+                "name": "thread",
+                "owner_id": "3",
+                "parent_id": "2",
+                "thread_metadata": {
+                    "archive_timestamp": "2021-12-01T00:00:00.000000+00:00",
+                    "archived": false,
+                    "auto_archive_duration": 1440,
+                    "locked": false
+                }
+            }],
+            "unavailable": false,
+            "vanity_url_code": null,
+            "verification_level": 0,
+            "voice_states": [{
+                "channel_id": "2",
+                "deaf": false,
+                "mute": false,
+                "request_to_speak_timestamp": null,
+                "self_deaf": false,
+                "self_mute": false,
+                "self_video": false,
+                "session_id": "session",
+                "suppress": false,
+                "user_id": "3"
+            }]
+        }"#;
+
+        let guild = serde_json::from_str::<GuildCreate>(input)?;
+
+        assert!(guild.joined_at.is_some());
+        assert!(!guild.large);
+        assert!(!guild.unavailable);
+        assert_eq!(Some(2), guild.member_count);
+
+        assert_eq!(1, guild.channels.len());
+        assert_eq!(Some(Id::new(1)), guild.channels[0].guild_id);
+        assert_eq!(ChannelType::GuildText, guild.channels[0].kind);
+
+        assert_eq!(1, guild.guild_scheduled_events.len());
+        assert_eq!(
+            EntityType::Voice,
+            guild.guild_scheduled_events[0].entity_type
+        );
+        assert_eq!(
+            ScheduledEventStatus::Scheduled,
+            guild.guild_scheduled_events[0].status
+        );
+
+        assert_eq!(1, guild.members.len());
+        assert_eq!(Id::new(1), guild.members[0].guild_id);
+
+        assert_eq!(1, guild.presences.len());
+        assert_eq!(Id::new(1), guild.presences[0].guild_id);
+        assert_eq!(Status::Online, guild.presences[0].status);
+
+        assert_eq!(1, guild.stage_instances.len());
+        assert_eq!(Id::new(6), guild.stage_instances[0].id);
+
+        assert_eq!(1, guild.threads.len());
+        assert_eq!(Some(Id::new(1)), guild.threads[0].guild_id);
+        assert_eq!(ChannelType::GuildPublicThread, guild.threads[0].kind);
+
+        assert_eq!(1, guild.voice_states.len());
+        assert_eq!(Some(Id::new(1)), guild.voice_states[0].guild_id);
+
+        Ok(())
+    }
+}
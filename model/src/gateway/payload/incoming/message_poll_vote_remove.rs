@@ -0,0 +1,25 @@
+use crate::id::{
+    marker::{ChannelMarker, GuildMarker, MessageMarker, UserMarker},
+    Id,
+};
+use serde::{Deserialize, Serialize};
+
+/// Sent when a user has removed their vote for an answer of a poll.
+///
+/// Multiple events are sent if the user removed their vote for multiple answers.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct MessagePollVoteRemove {
+    /// [`PollAnswer::answer_id`] of the answer.
+    ///
+    /// [`PollAnswer::answer_id`]: crate::poll::PollAnswer::answer_id
+    pub answer_id: u8,
+    /// ID of the channel the poll's message is in.
+    pub channel_id: Id<ChannelMarker>,
+    /// ID of the guild the poll's message is in, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<Id<GuildMarker>>,
+    /// ID of the poll's message.
+    pub message_id: Id<MessageMarker>,
+    /// ID of the user.
+    pub user_id: Id<UserMarker>,
+}
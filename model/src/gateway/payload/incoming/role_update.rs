@@ -13,7 +13,10 @@ pub struct RoleUpdate {
 #[cfg(test)]
 mod tests {
     use super::{Role, RoleUpdate};
-    use crate::{guild::Permissions, id::Id};
+    use crate::{
+        guild::{Permissions, RoleFlags},
+        id::Id,
+    };
     use serde_test::Token;
 
     #[test]
@@ -22,6 +25,7 @@ mod tests {
             guild_id: Id::new(1),
             role: Role {
                 color: 0,
+                flags: RoleFlags::empty(),
                 hoist: true,
                 icon: None,
                 id: Id::new(1),
@@ -48,10 +52,12 @@ mod tests {
                 Token::Str("role"),
                 Token::Struct {
                     name: "Role",
-                    len: 8,
+                    len: 9,
                 },
                 Token::Str("color"),
                 Token::U32(0),
+                Token::Str("flags"),
+                Token::U64(0),
                 Token::Str("hoist"),
                 Token::Bool(true),
                 Token::Str("id"),
@@ -46,4 +46,113 @@ pub struct Activity {
 #[cfg(test)]
 mod tests {
     // Custom activities is tested by the custom presence test.
+
+    use super::Activity;
+    use crate::{
+        gateway::presence::{ActivityAssets, ActivityParty, ActivityTimestamps, ActivityType},
+        id::Id,
+    };
+    use serde_test::Token;
+
+    #[test]
+    fn game_with_timestamps_and_assets() {
+        let value = Activity {
+            application_id: Some(Id::new(1)),
+            assets: Some(ActivityAssets {
+                large_image: Some("large image hash".to_owned()),
+                large_text: Some("large image text".to_owned()),
+                small_image: None,
+                small_text: None,
+            }),
+            buttons: Vec::new(),
+            created_at: None,
+            details: Some("Ranked".to_owned()),
+            emoji: None,
+            flags: None,
+            id: None,
+            instance: None,
+            kind: ActivityType::Playing,
+            name: "Rocket League".to_owned(),
+            party: Some(ActivityParty {
+                id: Some("party id".to_owned()),
+                size: Some([2, 3]),
+            }),
+            secrets: None,
+            state: Some("In a match".to_owned()),
+            timestamps: Some(ActivityTimestamps {
+                end: Some(1_580_609_522),
+                start: Some(1_580_608_922),
+            }),
+            url: None,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Activity",
+                    len: 9,
+                },
+                Token::Str("application_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::Str("assets"),
+                Token::Some,
+                Token::Struct {
+                    name: "ActivityAssets",
+                    len: 2,
+                },
+                Token::Str("large_image"),
+                Token::Some,
+                Token::Str("large image hash"),
+                Token::Str("large_text"),
+                Token::Some,
+                Token::Str("large image text"),
+                Token::StructEnd,
+                Token::Str("created_at"),
+                Token::None,
+                Token::Str("details"),
+                Token::Some,
+                Token::Str("Ranked"),
+                Token::Str("type"),
+                Token::U8(0),
+                Token::Str("name"),
+                Token::Str("Rocket League"),
+                Token::Str("party"),
+                Token::Some,
+                Token::Struct {
+                    name: "ActivityParty",
+                    len: 2,
+                },
+                Token::Str("id"),
+                Token::Some,
+                Token::Str("party id"),
+                Token::Str("size"),
+                Token::Some,
+                Token::Tuple { len: 2 },
+                Token::U64(2),
+                Token::U64(3),
+                Token::TupleEnd,
+                Token::StructEnd,
+                Token::Str("state"),
+                Token::Some,
+                Token::Str("In a match"),
+                Token::Str("timestamps"),
+                Token::Some,
+                Token::Struct {
+                    name: "ActivityTimestamps",
+                    len: 2,
+                },
+                Token::Str("end"),
+                Token::Some,
+                Token::U64(1_580_609_522),
+                Token::Str("start"),
+                Token::Some,
+                Token::U64(1_580_608_922),
+                Token::StructEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
 }
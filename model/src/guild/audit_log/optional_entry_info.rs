@@ -66,6 +66,7 @@ pub struct AuditLogOptionalEntryInfo {
     /// [`AuditLogEventType::ChannelOverwriteCreate`]: super::AuditLogEventType::ChannelOverwriteCreate
     /// [`AuditLogEventType::ChannelOverwriteDelete`]: super::AuditLogEventType::ChannelOverwriteDelete
     /// [`AuditLogEventType::ChannelOverwriteUpdate`]: super::AuditLogEventType::ChannelOverwriteUpdate
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<Id<GenericMarker>>,
     /// Type of overwritten entity.
     ///
@@ -118,7 +119,9 @@ pub struct AuditLogOptionalEntryInfo {
 #[cfg(test)]
 mod tests {
     use super::AuditLogOptionalEntryInfo;
+    use crate::id::Id;
     use serde::{Deserialize, Serialize};
+    use serde_test::Token;
     use static_assertions::{assert_fields, assert_impl_all};
     use std::{fmt::Debug, hash::Hash};
 
@@ -143,4 +146,69 @@ mod tests {
         Serialize,
         Sync
     );
+
+    /// Test the options of a message delete entry.
+    #[test]
+    fn message_delete() {
+        let value = AuditLogOptionalEntryInfo {
+            channel_id: Some(Id::new(1)),
+            count: Some("3".to_owned()),
+            delete_member_days: None,
+            id: None,
+            kind: None,
+            members_removed: None,
+            message_id: None,
+            role_name: None,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "AuditLogOptionalEntryInfo",
+                    len: 2,
+                },
+                Token::Str("channel_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::Str("count"),
+                Token::Some,
+                Token::Str("3"),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    /// Test the options of a member prune entry.
+    #[test]
+    fn member_prune() {
+        let value = AuditLogOptionalEntryInfo {
+            channel_id: None,
+            count: None,
+            delete_member_days: Some("7".to_owned()),
+            id: None,
+            kind: None,
+            members_removed: Some("12".to_owned()),
+            message_id: None,
+            role_name: None,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "AuditLogOptionalEntryInfo",
+                    len: 2,
+                },
+                Token::Str("delete_member_days"),
+                Token::Some,
+                Token::Str("7"),
+                Token::Str("members_removed"),
+                Token::Some,
+                Token::Str("12"),
+                Token::StructEnd,
+            ],
+        );
+    }
 }
@@ -0,0 +1,52 @@
+use crate::id::{marker::UserMarker, Id};
+use serde::{Deserialize, Serialize};
+
+/// Result of banning multiple users from a guild.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct BulkBan {
+    /// IDs of users that were banned.
+    pub banned_users: Vec<Id<UserMarker>>,
+    /// IDs of users that couldn't be banned.
+    ///
+    /// Users who are already banned or outrank the current user can't be
+    /// banned.
+    pub failed_users: Vec<Id<UserMarker>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BulkBan;
+    use crate::id::Id;
+    use serde_test::Token;
+
+    #[test]
+    fn bulk_ban() {
+        let value = BulkBan {
+            banned_users: vec![Id::new(1), Id::new(2)],
+            failed_users: vec![Id::new(3)],
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "BulkBan",
+                    len: 2,
+                },
+                Token::Str("banned_users"),
+                Token::Seq { len: Some(2) },
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("2"),
+                Token::SeqEnd,
+                Token::Str("failed_users"),
+                Token::Seq { len: Some(1) },
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("3"),
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+}
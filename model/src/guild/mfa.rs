@@ -0,0 +1,37 @@
+use crate::guild::MfaLevel;
+use serde::{Deserialize, Serialize};
+
+/// Multi-factor authentication level of a guild.
+///
+/// Returned when updating the MFA level of a guild.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct GuildMfa {
+    /// MFA level required for moderation actions.
+    pub level: MfaLevel,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GuildMfa, MfaLevel};
+    use serde_test::Token;
+
+    #[test]
+    fn guild_mfa() {
+        let value = GuildMfa {
+            level: MfaLevel::Elevated,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "GuildMfa",
+                    len: 1,
+                },
+                Token::Str("level"),
+                Token::U8(1),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
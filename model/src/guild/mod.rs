@@ -1,7 +1,9 @@
 pub mod audit_log;
 pub mod member;
+pub mod onboarding;
 
 mod ban;
+mod bulk_ban;
 mod default_message_notification_level;
 mod emoji;
 mod explicit_content_filter;
@@ -10,6 +12,7 @@ mod integration;
 mod integration_account;
 mod integration_application;
 mod integration_expire_behavior;
+mod mfa;
 mod mfa_level;
 mod nsfw_level;
 mod partial_guild;
@@ -19,6 +22,7 @@ mod premium_tier;
 mod preview;
 mod prune;
 mod role;
+mod role_flags;
 mod role_tags;
 mod system_channel_flags;
 mod unavailable_guild;
@@ -27,13 +31,15 @@ mod verification_level;
 mod widget;
 
 pub use self::{
-    ban::Ban, default_message_notification_level::DefaultMessageNotificationLevel, emoji::Emoji,
+    ban::Ban, bulk_ban::BulkBan,
+    default_message_notification_level::DefaultMessageNotificationLevel, emoji::Emoji,
     explicit_content_filter::ExplicitContentFilter, info::GuildInfo, integration::GuildIntegration,
     integration_account::IntegrationAccount, integration_application::IntegrationApplication,
-    integration_expire_behavior::IntegrationExpireBehavior, member::Member, mfa_level::MfaLevel,
-    nsfw_level::NSFWLevel, partial_guild::PartialGuild, partial_member::PartialMember,
-    permissions::Permissions, premium_tier::PremiumTier, preview::GuildPreview, prune::GuildPrune,
-    role::Role, role_tags::RoleTags, system_channel_flags::SystemChannelFlags,
+    integration_expire_behavior::IntegrationExpireBehavior, member::Member, mfa::GuildMfa,
+    mfa_level::MfaLevel, nsfw_level::NSFWLevel, partial_guild::PartialGuild,
+    partial_member::PartialMember, permissions::Permissions, premium_tier::PremiumTier,
+    preview::GuildPreview, prune::GuildPrune, role::Role, role_flags::RoleFlags,
+    role_tags::RoleTags, system_channel_flags::SystemChannelFlags,
     unavailable_guild::UnavailableGuild, vanity_url::VanityUrl,
     verification_level::VerificationLevel, widget::GuildWidget,
 };
@@ -47,6 +53,7 @@ use crate::{
         marker::{ApplicationMarker, ChannelMarker, GuildMarker, UserMarker},
         Id,
     },
+    scheduled_event::GuildScheduledEvent,
     util::{ImageHash, Timestamp},
     voice::voice_state::VoiceState,
 };
@@ -74,6 +81,13 @@ pub struct Guild {
     pub emojis: Vec<Emoji>,
     pub explicit_content_filter: ExplicitContentFilter,
     pub features: Vec<String>,
+    /// Scheduled events of the guild.
+    ///
+    /// Only sent in [`GuildCreate`] events.
+    ///
+    /// [`GuildCreate`]: crate::gateway::payload::incoming::GuildCreate
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub guild_scheduled_events: Vec<GuildScheduledEvent>,
     pub icon: Option<ImageHash>,
     pub id: Id<GuildMarker>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -148,6 +162,7 @@ impl<'de> Deserialize<'de> for Guild {
             Emojis,
             ExplicitContentFilter,
             Features,
+            GuildScheduledEvents,
             Icon,
             Id,
             JoinedAt,
@@ -208,6 +223,7 @@ impl<'de> Deserialize<'de> for Guild {
                 let mut emojis = None;
                 let mut explicit_content_filter = None;
                 let mut features = None;
+                let mut guild_scheduled_events = None::<Vec<GuildScheduledEvent>>;
                 let mut icon = None::<Option<_>>;
                 let mut id = None;
                 let mut joined_at = None::<Option<_>>;
@@ -361,6 +377,13 @@ impl<'de> Deserialize<'de> for Guild {
 
                             features = Some(map.next_value()?);
                         }
+                        Field::GuildScheduledEvents => {
+                            if guild_scheduled_events.is_some() {
+                                return Err(DeError::duplicate_field("guild_scheduled_events"));
+                            }
+
+                            guild_scheduled_events = Some(map.next_value()?);
+                        }
                         Field::Icon => {
                             if icon.is_some() {
                                 return Err(DeError::duplicate_field("icon"));
@@ -638,6 +661,7 @@ impl<'de> Deserialize<'de> for Guild {
                 let description = description.unwrap_or_default();
                 let discovery_splash = discovery_splash.unwrap_or_default();
                 let emojis = emojis.unwrap_or_default();
+                let guild_scheduled_events = guild_scheduled_events.unwrap_or_default();
                 let icon = icon.unwrap_or_default();
                 let large = large.unwrap_or_default();
                 let joined_at = joined_at.unwrap_or_default();
@@ -678,6 +702,7 @@ impl<'de> Deserialize<'de> for Guild {
                     ?emojis,
                     ?explicit_content_filter,
                     ?features,
+                    ?guild_scheduled_events,
                     ?icon,
                     %id,
                     ?large,
@@ -751,6 +776,7 @@ impl<'de> Deserialize<'de> for Guild {
                     emojis,
                     explicit_content_filter,
                     features,
+                    guild_scheduled_events,
                     icon,
                     id,
                     joined_at,
@@ -803,6 +829,7 @@ impl<'de> Deserialize<'de> for Guild {
             "emojis",
             "explicit_content_filter",
             "features",
+            "guild_scheduled_events",
             "icon",
             "id",
             "joined_at",
@@ -874,6 +901,7 @@ mod tests {
             emojis: Vec::new(),
             explicit_content_filter: ExplicitContentFilter::MembersWithoutRole,
             features: vec!["a feature".to_owned()],
+            guild_scheduled_events: Vec::new(),
             icon: Some(image_hash::ICON),
             id: Id::new(1),
             joined_at: Some(joined_at),
@@ -0,0 +1,184 @@
+//! Onboarding flow of a guild, shown to new members before they can access
+//! the rest of the guild.
+//!
+//! For additional information refer to [Discord Docs/Guild Onboarding].
+//!
+//! [Discord Docs/Guild Onboarding]: https://discord.com/developers/docs/resources/guild#guild-onboarding-object
+
+mod mode;
+mod prompt;
+mod prompt_option;
+mod prompt_type;
+
+pub use self::{
+    mode::OnboardingMode,
+    prompt::OnboardingPrompt,
+    prompt_option::{OnboardingPromptEmoji, OnboardingPromptOption},
+    prompt_type::OnboardingPromptType,
+};
+
+use crate::id::{
+    marker::{ChannelMarker, GuildMarker},
+    Id,
+};
+use serde::{Deserialize, Serialize};
+
+/// Onboarding flow of a guild.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Onboarding {
+    /// Channels that members are opted into by default.
+    pub default_channel_ids: Vec<Id<ChannelMarker>>,
+    /// Whether onboarding is enabled.
+    pub enabled: bool,
+    /// ID of the guild the onboarding flow belongs to.
+    pub guild_id: Id<GuildMarker>,
+    /// Criteria used to determine whether onboarding is satisfied.
+    pub mode: OnboardingMode,
+    /// Prompts shown during onboarding and in the "Channels & Roles" tab.
+    pub prompts: Vec<OnboardingPrompt>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Onboarding, OnboardingMode, OnboardingPrompt, OnboardingPromptEmoji,
+        OnboardingPromptOption, OnboardingPromptType,
+    };
+    use crate::id::Id;
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_fields!(
+        Onboarding: default_channel_ids,
+        enabled,
+        guild_id,
+        mode,
+        prompts
+    );
+    assert_impl_all!(
+        Onboarding: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    #[allow(clippy::too_many_lines)]
+    #[test]
+    fn onboarding() {
+        let value = Onboarding {
+            default_channel_ids: vec![Id::new(2)],
+            enabled: true,
+            guild_id: Id::new(1),
+            mode: OnboardingMode::Advanced,
+            prompts: vec![OnboardingPrompt {
+                id: Id::new(3),
+                in_onboarding: true,
+                kind: OnboardingPromptType::Dropdown,
+                options: vec![OnboardingPromptOption {
+                    channel_ids: vec![Id::new(2)],
+                    description: None,
+                    emoji: OnboardingPromptEmoji {
+                        animated: false,
+                        id: None,
+                        name: Some("🦀".to_owned()),
+                    },
+                    id: Id::new(4),
+                    role_ids: vec![Id::new(5)],
+                    title: "rust".to_owned(),
+                }],
+                required: false,
+                single_select: true,
+                title: "languages".to_owned(),
+            }],
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Onboarding",
+                    len: 5,
+                },
+                Token::Str("default_channel_ids"),
+                Token::Seq { len: Some(1) },
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("2"),
+                Token::SeqEnd,
+                Token::Str("enabled"),
+                Token::Bool(true),
+                Token::Str("guild_id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::Str("mode"),
+                Token::U8(1),
+                Token::Str("prompts"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "OnboardingPrompt",
+                    len: 7,
+                },
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("3"),
+                Token::Str("in_onboarding"),
+                Token::Bool(true),
+                Token::Str("type"),
+                Token::U8(1),
+                Token::Str("options"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "OnboardingPromptOption",
+                    len: 6,
+                },
+                Token::Str("channel_ids"),
+                Token::Seq { len: Some(1) },
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("2"),
+                Token::SeqEnd,
+                Token::Str("description"),
+                Token::None,
+                Token::Str("emoji"),
+                Token::Struct {
+                    name: "OnboardingPromptEmoji",
+                    len: 3,
+                },
+                Token::Str("animated"),
+                Token::Bool(false),
+                Token::Str("id"),
+                Token::None,
+                Token::Str("name"),
+                Token::Some,
+                Token::Str("🦀"),
+                Token::StructEnd,
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("4"),
+                Token::Str("role_ids"),
+                Token::Seq { len: Some(1) },
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("5"),
+                Token::SeqEnd,
+                Token::Str("title"),
+                Token::Str("rust"),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::Str("required"),
+                Token::Bool(false),
+                Token::Str("single_select"),
+                Token::Bool(true),
+                Token::Str("title"),
+                Token::Str("languages"),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -0,0 +1,23 @@
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Criteria used to determine whether a guild's onboarding is satisfied.
+#[derive(Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, Serialize_repr)]
+#[repr(u8)]
+pub enum OnboardingMode {
+    /// Only default channels count towards the constraints.
+    Default = 0,
+    /// Default channels and questions count towards the constraints.
+    Advanced = 1,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OnboardingMode;
+    use serde_test::Token;
+
+    #[test]
+    fn variants() {
+        serde_test::assert_tokens(&OnboardingMode::Default, &[Token::U8(0)]);
+        serde_test::assert_tokens(&OnboardingMode::Advanced, &[Token::U8(1)]);
+    }
+}
@@ -0,0 +1,26 @@
+use super::{OnboardingPromptOption, OnboardingPromptType};
+use crate::id::{marker::OnboardingPromptMarker, Id};
+use serde::{Deserialize, Serialize};
+
+/// Question shown to members during a guild's onboarding.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct OnboardingPrompt {
+    /// ID of the prompt.
+    pub id: Id<OnboardingPromptMarker>,
+    /// Whether the prompt is shown during onboarding.
+    ///
+    /// Prompts not shown during onboarding are only shown in the
+    /// "Channels & Roles" tab.
+    pub in_onboarding: bool,
+    /// How the options are displayed.
+    #[serde(rename = "type")]
+    pub kind: OnboardingPromptType,
+    /// Options available to choose from.
+    pub options: Vec<OnboardingPromptOption>,
+    /// Whether the prompt must be answered before completing onboarding.
+    pub required: bool,
+    /// Whether only one option may be selected.
+    pub single_select: bool,
+    /// Title of the prompt.
+    pub title: String,
+}
@@ -0,0 +1,38 @@
+use crate::id::{
+    marker::{ChannelMarker, EmojiMarker, OnboardingPromptOptionMarker, RoleMarker},
+    Id,
+};
+use serde::{Deserialize, Serialize};
+
+/// Option of an [`OnboardingPrompt`].
+///
+/// [`OnboardingPrompt`]: super::OnboardingPrompt
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct OnboardingPromptOption {
+    /// Channels the member is added to when selecting the option.
+    pub channel_ids: Vec<Id<ChannelMarker>>,
+    /// Description of the option.
+    pub description: Option<String>,
+    /// Emoji of the option.
+    pub emoji: OnboardingPromptEmoji,
+    /// ID of the option.
+    pub id: Id<OnboardingPromptOptionMarker>,
+    /// Roles assigned to the member when selecting the option.
+    pub role_ids: Vec<Id<RoleMarker>>,
+    /// Title of the option.
+    pub title: String,
+}
+
+/// Emoji of an [`OnboardingPromptOption`].
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct OnboardingPromptEmoji {
+    /// Whether the emoji is animated.
+    #[serde(default)]
+    pub animated: bool,
+    /// ID of the emoji, if it is a custom emoji.
+    pub id: Option<Id<EmojiMarker>>,
+    /// Name of the emoji.
+    ///
+    /// This is the unicode representation for unicode emojis.
+    pub name: Option<String>,
+}
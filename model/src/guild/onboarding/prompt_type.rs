@@ -0,0 +1,25 @@
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// How the options of an [`OnboardingPrompt`] are displayed.
+///
+/// [`OnboardingPrompt`]: super::OnboardingPrompt
+#[derive(Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, Serialize_repr)]
+#[repr(u8)]
+pub enum OnboardingPromptType {
+    /// Options are displayed as a list of buttons.
+    MultipleChoice = 0,
+    /// Options are displayed in a dropdown.
+    Dropdown = 1,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OnboardingPromptType;
+    use serde_test::Token;
+
+    #[test]
+    fn variants() {
+        serde_test::assert_tokens(&OnboardingPromptType::MultipleChoice, &[Token::U8(0)]);
+        serde_test::assert_tokens(&OnboardingPromptType::Dropdown, &[Token::U8(1)]);
+    }
+}
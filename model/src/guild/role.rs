@@ -1,4 +1,4 @@
-use super::RoleTags;
+use super::{RoleFlags, RoleTags};
 use crate::{
     guild::Permissions,
     id::{marker::RoleMarker, Id},
@@ -10,6 +10,9 @@ use std::cmp::{Ord, Ordering, PartialOrd};
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct Role {
     pub color: u32,
+    /// Flags of the role.
+    #[serde(default)]
+    pub flags: RoleFlags,
     pub hoist: bool,
     /// Icon image hash.
     ///
@@ -55,12 +58,13 @@ impl Ord for Role {
     /// Compare the position of two roles:
     ///
     /// ```
-    /// # use twilight_model::{guild::{Permissions, Role}, id::Id};
+    /// # use twilight_model::{guild::{Permissions, Role, RoleFlags}, id::Id};
     /// # use std::cmp::Ordering;
     /// let role_a = Role {
     ///     id: Id::new(123),
     ///     position: 12,
     ///#    color: 0,
+    ///#    flags: RoleFlags::empty(),
     ///#    hoist: true,
     ///#    icon: None,
     ///#    managed: false,
@@ -75,6 +79,7 @@ impl Ord for Role {
     ///     id: Id::new(456),
     ///     position: 13,
     ///#    color: 0,
+    ///#    flags: RoleFlags::empty(),
     ///#    hoist: true,
     ///#    icon: None,
     ///#    managed: false,
@@ -94,12 +99,13 @@ impl Ord for Role {
     /// Compare the position of two roles with the same position:
     ///
     /// ```
-    /// # use twilight_model::{guild::{Permissions, Role}, id::Id};
+    /// # use twilight_model::{guild::{Permissions, Role, RoleFlags}, id::Id};
     /// # use std::cmp::Ordering;
     /// let role_a = Role {
     ///     id: Id::new(123),
     ///     position: 12,
     ///#    color: 0,
+    ///#    flags: RoleFlags::empty(),
     ///#    hoist: true,
     ///#    icon: None,
     ///#    managed: false,
@@ -113,6 +119,7 @@ impl Ord for Role {
     ///     id: Id::new(456),
     ///     position: 12,
     ///#    color: 0,
+    ///#    flags: RoleFlags::empty(),
     ///#    hoist: true,
     ///#    icon: None,
     ///#    managed: false,
@@ -142,7 +149,7 @@ impl PartialOrd for Role {
 
 #[cfg(test)]
 mod tests {
-    use super::{Permissions, Role};
+    use super::{Permissions, Role, RoleFlags};
     use crate::id::Id;
     use serde::{Deserialize, Serialize};
     use serde_test::Token;
@@ -151,6 +158,7 @@ mod tests {
 
     assert_fields!(
         Role: color,
+        flags,
         hoist,
         icon,
         id,
@@ -177,6 +185,7 @@ mod tests {
     fn role() {
         let role = Role {
             color: 0,
+            flags: RoleFlags::empty(),
             hoist: true,
             icon: None,
             id: Id::new(123),
@@ -194,10 +203,12 @@ mod tests {
             &[
                 Token::Struct {
                     name: "Role",
-                    len: 8,
+                    len: 9,
                 },
                 Token::Str("color"),
                 Token::U32(0),
+                Token::Str("flags"),
+                Token::U64(0),
                 Token::Str("hoist"),
                 Token::Bool(true),
                 Token::Str("id"),
@@ -217,4 +228,52 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn role_in_prompt() {
+        let role = Role {
+            color: 0x00_99_ff,
+            flags: RoleFlags::IN_PROMPT,
+            hoist: false,
+            icon: None,
+            id: Id::new(456),
+            managed: false,
+            mentionable: false,
+            name: "rustacean".to_owned(),
+            permissions: Permissions::empty(),
+            position: 3,
+            tags: None,
+            unicode_emoji: None,
+        };
+
+        serde_test::assert_tokens(
+            &role,
+            &[
+                Token::Struct {
+                    name: "Role",
+                    len: 9,
+                },
+                Token::Str("color"),
+                Token::U32(0x00_99_ff),
+                Token::Str("flags"),
+                Token::U64(1),
+                Token::Str("hoist"),
+                Token::Bool(false),
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("456"),
+                Token::Str("managed"),
+                Token::Bool(false),
+                Token::Str("mentionable"),
+                Token::Bool(false),
+                Token::Str("name"),
+                Token::Str("rustacean"),
+                Token::Str("permissions"),
+                Token::Str("0"),
+                Token::Str("position"),
+                Token::I64(3),
+                Token::StructEnd,
+            ],
+        );
+    }
 }
@@ -0,0 +1,52 @@
+use bitflags::bitflags;
+use serde::{
+    de::{Deserialize, Deserializer},
+    ser::{Serialize, Serializer},
+};
+
+bitflags! {
+    #[derive(Default)]
+    pub struct RoleFlags: u64 {
+        /// Role can be selected by members in an onboarding prompt.
+        const IN_PROMPT = 1;
+    }
+}
+
+impl<'de> Deserialize<'de> for RoleFlags {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Role flags newer than the library must survive being sent back in
+        // a role update, so the bits are kept as-is.
+        Ok(Self {
+            bits: u64::deserialize(deserializer)?,
+        })
+    }
+}
+
+impl Serialize for RoleFlags {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u64(self.bits())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RoleFlags;
+    use serde_test::Token;
+
+    #[test]
+    fn variants() {
+        serde_test::assert_tokens(&RoleFlags::IN_PROMPT, &[Token::U64(1)]);
+    }
+
+    #[test]
+    fn unknown_bits() {
+        let value = serde_json::from_str::<RoleFlags>("3").unwrap();
+
+        assert!(value.contains(RoleFlags::IN_PROMPT));
+        assert_eq!(3, value.bits());
+        assert_eq!("3", serde_json::to_string(&value).unwrap());
+    }
+}
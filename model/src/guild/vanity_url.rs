@@ -7,7 +7,11 @@ pub struct VanityUrl {
     ///
     /// For example, in an invite of `discord.gg/applejack`, the code is
     /// `applejack`.
-    pub code: String,
+    ///
+    /// [`None`] if the guild doesn't have a vanity URL.
+    pub code: Option<String>,
+    /// Number of times the vanity URL has been used.
+    pub uses: u64,
 }
 
 #[cfg(test)]
@@ -18,17 +22,43 @@ mod tests {
     #[test]
     fn vanity_url() {
         let url = VanityUrl {
-            code: "a".to_owned(),
+            code: Some("a".to_owned()),
+            uses: 42,
         };
         serde_test::assert_tokens(
             &url,
             &[
                 Token::Struct {
                     name: "VanityUrl",
-                    len: 1,
+                    len: 2,
                 },
                 Token::String("code"),
+                Token::Some,
                 Token::String("a"),
+                Token::String("uses"),
+                Token::U64(42),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn vanity_url_absent() {
+        let url = VanityUrl {
+            code: None,
+            uses: 0,
+        };
+        serde_test::assert_tokens(
+            &url,
+            &[
+                Token::Struct {
+                    name: "VanityUrl",
+                    len: 2,
+                },
+                Token::String("code"),
+                Token::None,
+                Token::String("uses"),
+                Token::U64(0),
                 Token::StructEnd,
             ],
         );
@@ -0,0 +1,253 @@
+//! Models used when banning users.
+
+use serde::{Deserialize, Serialize};
+use std::{
+    convert::TryFrom,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    time::Duration,
+};
+
+/// Number of seconds in a day.
+const SECONDS_PER_DAY: u32 = 24 * 60 * 60;
+
+/// Creating a [`DeleteMessageDuration`] failed.
+#[derive(Debug)]
+pub struct DeleteMessageDurationError {
+    kind: DeleteMessageDurationErrorType,
+}
+
+impl DeleteMessageDurationError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &DeleteMessageDurationErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        DeleteMessageDurationErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, None)
+    }
+}
+
+impl Display for DeleteMessageDurationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.kind {
+            DeleteMessageDurationErrorType::TooLong { seconds } => {
+                f.write_str("provided duration is ")?;
+                Display::fmt(&seconds, f)?;
+                f.write_str(" seconds, but it must be at most ")?;
+
+                Display::fmt(&DeleteMessageDuration::MAX.seconds(), f)
+            }
+        }
+    }
+}
+
+impl Error for DeleteMessageDurationError {}
+
+/// Type of [`DeleteMessageDurationError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum DeleteMessageDurationErrorType {
+    /// Duration is longer than [`DeleteMessageDuration::MAX`].
+    TooLong {
+        /// Provided duration in seconds.
+        seconds: u64,
+    },
+}
+
+/// Duration of messages to delete when banning users.
+///
+/// Messages sent by the banned users within this duration are deleted. The
+/// duration is sent to Discord in seconds and may be at most 7 days long.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use twilight_model::http::ban::DeleteMessageDuration;
+///
+/// let duration = DeleteMessageDuration::from_days(1)?;
+/// assert_eq!(86_400, duration.seconds());
+///
+/// let duration = DeleteMessageDuration::try_from(Duration::from_secs(3_600))?;
+/// assert_eq!(3_600, duration.seconds());
+///
+/// assert!(DeleteMessageDuration::from_days(8).is_err());
+/// # Ok::<(), twilight_model::http::ban::DeleteMessageDurationError>(())
+/// ```
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
+)]
+#[serde(try_from = "u32", into = "u32")]
+pub struct DeleteMessageDuration(u32);
+
+impl DeleteMessageDuration {
+    /// Longest duration of messages that can be deleted, 7 days.
+    pub const MAX: Self = Self(7 * SECONDS_PER_DAY);
+
+    /// Create a duration from a number of days.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`TooLong`] if the number of days is greater
+    /// than 7.
+    ///
+    /// [`TooLong`]: DeleteMessageDurationErrorType::TooLong
+    pub const fn from_days(days: u16) -> Result<Self, DeleteMessageDurationError> {
+        Self::from_seconds(days as u64 * SECONDS_PER_DAY as u64)
+    }
+
+    /// Create a duration from a number of seconds.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`TooLong`] if the number of seconds is
+    /// greater than 604800.
+    ///
+    /// [`TooLong`]: DeleteMessageDurationErrorType::TooLong
+    pub const fn from_seconds(seconds: u64) -> Result<Self, DeleteMessageDurationError> {
+        if seconds > Self::MAX.0 as u64 {
+            return Err(DeleteMessageDurationError {
+                kind: DeleteMessageDurationErrorType::TooLong { seconds },
+            });
+        }
+
+        #[allow(clippy::cast_possible_truncation)]
+        Ok(Self(seconds as u32))
+    }
+
+    /// Number of seconds of the duration.
+    pub const fn seconds(self) -> u32 {
+        self.0
+    }
+}
+
+impl From<DeleteMessageDuration> for Duration {
+    fn from(value: DeleteMessageDuration) -> Self {
+        Duration::from_secs(value.0.into())
+    }
+}
+
+impl From<DeleteMessageDuration> for u32 {
+    fn from(value: DeleteMessageDuration) -> Self {
+        value.0
+    }
+}
+
+impl TryFrom<Duration> for DeleteMessageDuration {
+    type Error = DeleteMessageDurationError;
+
+    /// Create a duration from a [`Duration`], discarding fractions of a
+    /// second.
+    fn try_from(value: Duration) -> Result<Self, Self::Error> {
+        Self::from_seconds(value.as_secs())
+    }
+}
+
+impl TryFrom<u32> for DeleteMessageDuration {
+    type Error = DeleteMessageDurationError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        Self::from_seconds(value.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        DeleteMessageDuration, DeleteMessageDurationError, DeleteMessageDurationErrorType,
+    };
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::assert_impl_all;
+    use std::{convert::TryFrom, error::Error, fmt::Debug, hash::Hash, time::Duration};
+
+    assert_impl_all!(DeleteMessageDurationError: Error, Send, Sync);
+    assert_impl_all!(DeleteMessageDurationErrorType: Debug, Send, Sync);
+    assert_impl_all!(
+        DeleteMessageDuration: Clone,
+        Copy,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+        TryFrom<Duration>,
+        TryFrom<u32>
+    );
+
+    #[test]
+    fn days() -> Result<(), DeleteMessageDurationError> {
+        assert_eq!(0, DeleteMessageDuration::from_days(0)?.seconds());
+        assert_eq!(86_400, DeleteMessageDuration::from_days(1)?.seconds());
+        assert_eq!(604_800, DeleteMessageDuration::from_days(7)?.seconds());
+        assert_eq!(
+            DeleteMessageDuration::MAX,
+            DeleteMessageDuration::from_days(7)?
+        );
+
+        assert!(matches!(
+            DeleteMessageDuration::from_days(8).unwrap_err().kind(),
+            DeleteMessageDurationErrorType::TooLong { seconds: 691_200 }
+        ));
+        assert!(DeleteMessageDuration::from_days(u16::MAX).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn max() -> Result<(), DeleteMessageDurationError> {
+        assert_eq!(604_800, DeleteMessageDuration::MAX.seconds());
+        assert_eq!(
+            DeleteMessageDuration::MAX,
+            DeleteMessageDuration::from_seconds(604_800)?
+        );
+        assert!(DeleteMessageDuration::from_seconds(604_801).is_err());
+        assert!(DeleteMessageDuration::from_seconds(u64::MAX).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn duration() -> Result<(), DeleteMessageDurationError> {
+        let duration = DeleteMessageDuration::try_from(Duration::from_millis(1_500))?;
+        assert_eq!(1, duration.seconds());
+        assert_eq!(Duration::from_secs(1), Duration::from(duration));
+
+        assert!(DeleteMessageDuration::try_from(Duration::from_secs(604_801)).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn serde() -> Result<(), DeleteMessageDurationError> {
+        serde_test::assert_tokens(
+            &DeleteMessageDuration::from_seconds(3_600)?,
+            &[Token::U32(3_600)],
+        );
+        serde_test::assert_de_tokens_error::<DeleteMessageDuration>(
+            &[Token::U32(604_801)],
+            "provided duration is 604801 seconds, but it must be at most 604800",
+        );
+
+        Ok(())
+    }
+}
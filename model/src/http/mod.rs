@@ -1,5 +1,8 @@
 //! Models used when sending data to Discord.
 
 pub mod attachment;
+pub mod ban;
 pub mod interaction;
+pub mod onboarding;
 pub mod permission_overwrite;
+pub mod poll;
@@ -0,0 +1,191 @@
+//! Models for updating a guild's onboarding flow.
+
+use crate::{
+    guild::onboarding::{OnboardingPrompt, OnboardingPromptOption, OnboardingPromptType},
+    id::{
+        marker::{
+            ChannelMarker, EmojiMarker, OnboardingPromptMarker, OnboardingPromptOptionMarker,
+            RoleMarker,
+        },
+        Id,
+    },
+};
+use serde::{Deserialize, Serialize};
+
+/// Prompt to show during a guild's onboarding when updating it.
+///
+/// Can be created from an existing [`OnboardingPrompt`] to keep it when
+/// replacing the prompts of the onboarding flow.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct OnboardingPromptRequest {
+    /// ID of the prompt.
+    pub id: Id<OnboardingPromptMarker>,
+    /// Whether the prompt is shown during onboarding.
+    pub in_onboarding: bool,
+    /// How the options are displayed.
+    #[serde(rename = "type")]
+    pub kind: OnboardingPromptType,
+    /// Options available to choose from.
+    pub options: Vec<OnboardingPromptOptionRequest>,
+    /// Whether the prompt must be answered before completing onboarding.
+    pub required: bool,
+    /// Whether only one option may be selected.
+    pub single_select: bool,
+    /// Title of the prompt.
+    pub title: String,
+}
+
+impl From<OnboardingPrompt> for OnboardingPromptRequest {
+    fn from(prompt: OnboardingPrompt) -> Self {
+        Self {
+            id: prompt.id,
+            in_onboarding: prompt.in_onboarding,
+            kind: prompt.kind,
+            options: prompt.options.into_iter().map(Into::into).collect(),
+            required: prompt.required,
+            single_select: prompt.single_select,
+            title: prompt.title,
+        }
+    }
+}
+
+/// Option of an [`OnboardingPromptRequest`].
+///
+/// Unlike [`OnboardingPromptOption`], the emoji is sent as separate fields.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct OnboardingPromptOptionRequest {
+    /// Channels the member is added to when selecting the option.
+    pub channel_ids: Vec<Id<ChannelMarker>>,
+    /// Description of the option.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Whether the emoji is animated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji_animated: Option<bool>,
+    /// ID of the emoji, if it is a custom emoji.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji_id: Option<Id<EmojiMarker>>,
+    /// Name of the emoji.
+    ///
+    /// This is the unicode representation for unicode emojis.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji_name: Option<String>,
+    /// ID of the option.
+    pub id: Id<OnboardingPromptOptionMarker>,
+    /// Roles assigned to the member when selecting the option.
+    pub role_ids: Vec<Id<RoleMarker>>,
+    /// Title of the option.
+    pub title: String,
+}
+
+impl From<OnboardingPromptOption> for OnboardingPromptOptionRequest {
+    fn from(option: OnboardingPromptOption) -> Self {
+        Self {
+            channel_ids: option.channel_ids,
+            description: option.description,
+            emoji_animated: Some(option.emoji.animated),
+            emoji_id: option.emoji.id,
+            emoji_name: option.emoji.name,
+            id: option.id,
+            role_ids: option.role_ids,
+            title: option.title,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{OnboardingPromptOptionRequest, OnboardingPromptRequest};
+    use crate::{
+        guild::onboarding::{OnboardingPromptEmoji, OnboardingPromptOption},
+        id::Id,
+    };
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_fields!(
+        OnboardingPromptRequest: id,
+        in_onboarding,
+        kind,
+        options,
+        required,
+        single_select,
+        title
+    );
+    assert_fields!(
+        OnboardingPromptOptionRequest: channel_ids,
+        description,
+        emoji_animated,
+        emoji_id,
+        emoji_name,
+        id,
+        role_ids,
+        title
+    );
+    assert_impl_all!(
+        OnboardingPromptOptionRequest: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        From<OnboardingPromptOption>,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    #[test]
+    fn option_from_response() {
+        let option = OnboardingPromptOption {
+            channel_ids: Vec::new(),
+            description: None,
+            emoji: OnboardingPromptEmoji {
+                animated: true,
+                id: Some(Id::new(2)),
+                name: Some("emoji".to_owned()),
+            },
+            id: Id::new(1),
+            role_ids: vec![Id::new(3)],
+            title: "option".to_owned(),
+        };
+
+        let value = OnboardingPromptOptionRequest::from(option);
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "OnboardingPromptOptionRequest",
+                    len: 7,
+                },
+                Token::Str("channel_ids"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::Str("emoji_animated"),
+                Token::Some,
+                Token::Bool(true),
+                Token::Str("emoji_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("2"),
+                Token::Str("emoji_name"),
+                Token::Some,
+                Token::Str("emoji"),
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::Str("role_ids"),
+                Token::Seq { len: Some(1) },
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("3"),
+                Token::SeqEnd,
+                Token::Str("title"),
+                Token::Str("option"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -0,0 +1,132 @@
+//! Models for sending polls to Discord.
+
+use crate::poll::{PollLayoutType, PollMedia};
+use serde::{Deserialize, Serialize};
+
+/// Poll to attach to a message when creating it.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct PollCreateRequest {
+    /// Whether users can vote for more than one answer.
+    pub allow_multiselect: bool,
+    /// Answers users can vote for, up to 10.
+    pub answers: Vec<PollCreateAnswer>,
+    /// Number of hours the poll is open for, up to 32 days.
+    ///
+    /// Defaults to 24 hours.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<u16>,
+    /// Layout of the poll.
+    ///
+    /// Defaults to [`PollLayoutType::Default`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub layout_type: Option<PollLayoutType>,
+    /// Question of the poll.
+    ///
+    /// Only the [`text`] of the question is supported.
+    ///
+    /// [`text`]: PollMedia::text
+    pub question: PollMedia,
+}
+
+/// Answer of a [`PollCreateRequest`].
+///
+/// Answer IDs are assigned by Discord.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct PollCreateAnswer {
+    /// Content of the answer.
+    pub poll_media: PollMedia,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PollCreateAnswer, PollCreateRequest};
+    use crate::poll::{PollLayoutType, PollMedia};
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_fields!(
+        PollCreateRequest: allow_multiselect,
+        answers,
+        duration,
+        layout_type,
+        question
+    );
+    assert_fields!(PollCreateAnswer: poll_media);
+    assert_impl_all!(
+        PollCreateRequest: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    #[test]
+    fn poll_create_request() {
+        let value = PollCreateRequest {
+            allow_multiselect: true,
+            answers: vec![PollCreateAnswer {
+                poll_media: PollMedia {
+                    emoji: None,
+                    text: Some("yes".to_owned()),
+                },
+            }],
+            duration: Some(48),
+            layout_type: Some(PollLayoutType::Default),
+            question: PollMedia {
+                emoji: None,
+                text: Some("twilight?".to_owned()),
+            },
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "PollCreateRequest",
+                    len: 5,
+                },
+                Token::Str("allow_multiselect"),
+                Token::Bool(true),
+                Token::Str("answers"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "PollCreateAnswer",
+                    len: 1,
+                },
+                Token::Str("poll_media"),
+                Token::Struct {
+                    name: "PollMedia",
+                    len: 1,
+                },
+                Token::Str("text"),
+                Token::Some,
+                Token::Str("yes"),
+                Token::StructEnd,
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::Str("duration"),
+                Token::Some,
+                Token::U16(48),
+                Token::Str("layout_type"),
+                Token::Some,
+                Token::U8(1),
+                Token::Str("question"),
+                Token::Struct {
+                    name: "PollMedia",
+                    len: 1,
+                },
+                Token::Str("text"),
+                Token::Some,
+                Token::Str("twilight?"),
+                Token::StructEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -405,8 +405,9 @@ mod tests {
     use super::{
         marker::{
             ApplicationMarker, AttachmentMarker, AuditLogEntryMarker, ChannelMarker, CommandMarker,
-            CommandVersionMarker, EmojiMarker, GenericMarker, GuildMarker, IntegrationMarker,
-            InteractionMarker, MessageMarker, RoleMarker, StageMarker, UserMarker, WebhookMarker,
+            CommandVersionMarker, EmojiMarker, ForumTagMarker, GenericMarker, GuildMarker,
+            IntegrationMarker, InteractionMarker, MessageMarker, RoleMarker, StageMarker,
+            UserMarker, WebhookMarker,
         },
         Id,
     };
@@ -429,6 +430,7 @@ mod tests {
     assert_impl_all!(CommandMarker: Debug, Send, Sync);
     assert_impl_all!(CommandVersionMarker: Debug, Send, Sync);
     assert_impl_all!(EmojiMarker: Debug, Send, Sync);
+    assert_impl_all!(ForumTagMarker: Debug, Send, Sync);
     assert_impl_all!(GenericMarker: Debug, Send, Sync);
     assert_impl_all!(GuildMarker: Debug, Send, Sync);
     assert_impl_all!(IntegrationMarker: Debug, Send, Sync);
@@ -11,7 +11,7 @@ pub use self::{
 };
 
 use super::user::User;
-use crate::util::Timestamp;
+use crate::{scheduled_event::GuildScheduledEvent, util::Timestamp};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
@@ -29,6 +29,8 @@ pub struct Invite {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub guild: Option<InviteGuild>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub guild_scheduled_event: Option<GuildScheduledEvent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub inviter: Option<User>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_age: Option<u64>,
@@ -54,6 +56,7 @@ mod tests {
         channel::ChannelType,
         guild::VerificationLevel,
         id::Id,
+        scheduled_event::{EntityType, GuildScheduledEvent, PrivacyLevel, Status},
         test::image_hash,
         util::datetime::{Timestamp, TimestampParseError},
     };
@@ -104,6 +107,7 @@ mod tests {
             created_at: None,
             expires_at: None,
             guild: None,
+            guild_scheduled_event: None,
             inviter: None,
             max_age: None,
             max_uses: None,
@@ -148,6 +152,26 @@ mod tests {
         );
     }
 
+    /// Invites listed for a channel or guild only have their metadata and
+    /// code set.
+    #[test]
+    fn invite_metadata() -> Result<(), serde_json::Error> {
+        let invites = serde_json::from_str::<Vec<Invite>>(
+            r#"[{"code":"twilight","created_at":"2021-08-03T16:08:36.325000+00:00","max_age":0,"max_uses":10,"temporary":false,"uses":3}]"#,
+        )?;
+
+        assert_eq!(1, invites.len());
+        assert!(invites[0].channel.is_none());
+        assert_eq!(Some(3), invites[0].uses);
+        assert_eq!(Some(10), invites[0].max_uses);
+        assert_eq!(
+            Some(1_628_006_916_325_000),
+            invites[0].created_at.map(Timestamp::as_micros)
+        );
+
+        Ok(())
+    }
+
     #[allow(clippy::too_many_lines)]
     #[test]
     fn invite_complete() -> Result<(), TimestampParseError> {
@@ -194,6 +218,7 @@ mod tests {
                     ],
                 }),
             }),
+            guild_scheduled_event: None,
             inviter: Some(User {
                 accent_color: None,
                 avatar: None,
@@ -413,4 +438,98 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn invite_scheduled_event() -> Result<(), TimestampParseError> {
+        let expires_at = Timestamp::from_str("2021-08-10T16:08:36.325000+00:00")?;
+        let scheduled_start_time = Timestamp::from_str("2022-01-01T00:00:00.000000+00:00")?;
+
+        let value = Invite {
+            approximate_member_count: Some(31),
+            approximate_presence_count: Some(7),
+            channel: None,
+            code: "uniquecode".to_owned(),
+            created_at: None,
+            expires_at: Some(expires_at),
+            guild: None,
+            guild_scheduled_event: Some(GuildScheduledEvent {
+                channel_id: Some(Id::new(2)),
+                creator: None,
+                creator_id: None,
+                description: None,
+                entity_id: None,
+                entity_metadata: None,
+                entity_type: EntityType::Voice,
+                guild_id: Id::new(1),
+                id: Id::new(3),
+                image: None,
+                name: "event".to_owned(),
+                privacy_level: PrivacyLevel::GuildOnly,
+                scheduled_end_time: None,
+                scheduled_start_time,
+                status: Status::Scheduled,
+                user_count: None,
+            }),
+            inviter: None,
+            max_age: None,
+            max_uses: None,
+            target_type: None,
+            target_user: None,
+            temporary: None,
+            uses: None,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Invite",
+                    len: 6,
+                },
+                Token::Str("approximate_member_count"),
+                Token::Some,
+                Token::U64(31),
+                Token::Str("approximate_presence_count"),
+                Token::Some,
+                Token::U64(7),
+                Token::Str("channel"),
+                Token::None,
+                Token::Str("code"),
+                Token::Str("uniquecode"),
+                Token::Str("expires_at"),
+                Token::Some,
+                Token::Str("2021-08-10T16:08:36.325000+00:00"),
+                Token::Str("guild_scheduled_event"),
+                Token::Some,
+                Token::Struct {
+                    name: "GuildScheduledEvent",
+                    len: 8,
+                },
+                Token::Str("channel_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("2"),
+                Token::Str("entity_type"),
+                Token::U8(2),
+                Token::Str("guild_id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("3"),
+                Token::Str("name"),
+                Token::Str("event"),
+                Token::Str("privacy_level"),
+                Token::U8(2),
+                Token::Str("scheduled_start_time"),
+                Token::Str("2022-01-01T00:00:00.000000+00:00"),
+                Token::Str("status"),
+                Token::U8(1),
+                Token::StructEnd,
+                Token::StructEnd,
+            ],
+        );
+
+        Ok(())
+    }
 }
@@ -25,6 +25,7 @@ pub mod http;
 pub mod id;
 pub mod invite;
 pub mod oauth;
+pub mod poll;
 pub mod scheduled_event;
 pub mod template;
 pub mod user;
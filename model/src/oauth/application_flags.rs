@@ -6,6 +6,8 @@ use serde::{
 
 bitflags! {
     pub struct ApplicationFlags: u64 {
+        /// Indicates if an app uses the auto moderation API.
+        const APPLICATION_AUTO_MODERATION_RULE_CREATE_BADGE = 1 << 6;
         /// Intent required for bots in 100 guilds or more to receive
         /// [`PresenceUpdate`] events.
         ///
@@ -40,6 +42,9 @@ bitflags! {
         ///
         /// [message content]: https://support-dev.discord.com/hc/en-us/articles/4404772028055
         const GATEWAY_MESSAGE_CONTENT_LIMITED = 1 << 19;
+        /// Indicates whether an app has registered global application
+        /// commands.
+        const APPLICATION_COMMAND_BADGE = 1 << 23;
     }
 }
 
@@ -57,3 +62,26 @@ impl Serialize for ApplicationFlags {
         serializer.serialize_u64(self.bits())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ApplicationFlags;
+    use serde_test::Token;
+
+    #[test]
+    fn application_flags() {
+        let value = ApplicationFlags::GATEWAY_GUILD_MEMBERS_LIMITED
+            | ApplicationFlags::GATEWAY_MESSAGE_CONTENT
+            | ApplicationFlags::APPLICATION_COMMAND_BADGE;
+
+        serde_test::assert_tokens(&value, &[Token::U64(8_683_520)]);
+    }
+
+    #[test]
+    fn unknown_bits_truncated() {
+        serde_test::assert_de_tokens(
+            &ApplicationFlags::GATEWAY_PRESENCE,
+            &[Token::U64(1 << 12 | 1 << 40)],
+        );
+    }
+}
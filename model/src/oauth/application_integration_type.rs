@@ -0,0 +1,44 @@
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Where an application can be installed, also called its supported
+/// installation contexts.
+///
+/// See [Discord Docs/Application Integration Types].
+///
+/// [Discord Docs/Application Integration Types]: https://discord.com/developers/docs/resources/application#application-object-application-integration-types
+#[derive(Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, Serialize_repr)]
+#[repr(u8)]
+pub enum ApplicationIntegrationType {
+    /// Application is installable to guilds.
+    GuildInstall = 0,
+    /// Application is installable to users.
+    UserInstall = 1,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ApplicationIntegrationType;
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(
+        ApplicationIntegrationType: Clone,
+        Copy,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Serialize,
+        Send,
+        Sync
+    );
+
+    #[test]
+    fn variants() {
+        serde_test::assert_tokens(&ApplicationIntegrationType::GuildInstall, &[Token::U8(0)]);
+        serde_test::assert_tokens(&ApplicationIntegrationType::UserInstall, &[Token::U8(1)]);
+    }
+}
@@ -10,11 +10,13 @@ pub mod current_application_info {
 
 mod application;
 mod application_flags;
+mod application_integration_type;
 mod install_params;
 mod partial_application;
 
 pub use self::{
-    application::Application, application_flags::ApplicationFlags, install_params::InstallParams,
+    application::Application, application_flags::ApplicationFlags,
+    application_integration_type::ApplicationIntegrationType, install_params::InstallParams,
     partial_application::PartialApplication,
 };
 
@@ -0,0 +1,15 @@
+use super::PollMedia;
+use serde::{Deserialize, Serialize};
+
+/// Answer of a [`Poll`].
+///
+/// [`Poll`]: super::Poll
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct PollAnswer {
+    /// ID of the answer, unique within its poll.
+    ///
+    /// IDs are sequential starting at 1.
+    pub answer_id: u8,
+    /// Content of the answer.
+    pub poll_media: PollMedia,
+}
@@ -0,0 +1,11 @@
+use crate::user::User;
+use serde::{Deserialize, Serialize};
+
+/// Users who voted for an answer of a [`Poll`].
+///
+/// [`Poll`]: super::Poll
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct PollAnswerVoters {
+    /// Users who voted for the answer.
+    pub users: Vec<User>,
+}
@@ -0,0 +1,24 @@
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Layout of a [`Poll`].
+///
+/// [`Poll`]: super::Poll
+#[derive(
+    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize_repr,
+)]
+#[repr(u8)]
+pub enum PollLayoutType {
+    /// Default layout.
+    Default = 1,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PollLayoutType;
+    use serde_test::Token;
+
+    #[test]
+    fn variants() {
+        serde_test::assert_tokens(&PollLayoutType::Default, &[Token::U8(1)]);
+    }
+}
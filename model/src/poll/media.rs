@@ -0,0 +1,18 @@
+use crate::channel::ReactionType;
+use serde::{Deserialize, Serialize};
+
+/// Content of a poll's question or of one of its answers.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct PollMedia {
+    /// Emoji of the media.
+    ///
+    /// Only present on answers. Custom emojis only include their ID, unicode
+    /// emojis only their name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<ReactionType>,
+    /// Text of the media.
+    ///
+    /// Up to 300 characters for questions and 55 characters for answers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+}
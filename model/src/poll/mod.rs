@@ -0,0 +1,236 @@
+//! Polls that can be attached to messages.
+
+mod answer;
+mod answer_voters;
+mod layout_type;
+mod media;
+mod results;
+
+pub use self::{
+    answer::PollAnswer,
+    answer_voters::PollAnswerVoters,
+    layout_type::PollLayoutType,
+    media::PollMedia,
+    results::{PollAnswerCount, PollResults},
+};
+
+use crate::util::Timestamp;
+use serde::{Deserialize, Serialize};
+
+/// Poll attached to a [`Message`].
+///
+/// [`Message`]: crate::channel::Message
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Poll {
+    /// Whether users can vote for more than one answer.
+    pub allow_multiselect: bool,
+    /// Answers users can vote for.
+    pub answers: Vec<PollAnswer>,
+    /// When the poll ends.
+    ///
+    /// May be [`None`] for polls that don't expire.
+    pub expiry: Option<Timestamp>,
+    /// Layout of the poll.
+    pub layout_type: PollLayoutType,
+    /// Question of the poll.
+    ///
+    /// Only the [`text`] of the question is supported.
+    ///
+    /// [`text`]: PollMedia::text
+    pub question: PollMedia,
+    /// Vote counts of the poll.
+    ///
+    /// May be [`None`] if the votes haven't been counted yet.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub results: Option<PollResults>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Poll, PollAnswer, PollAnswerCount, PollLayoutType, PollMedia, PollResults};
+    use crate::{channel::ReactionType, id::Id, util::Timestamp};
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{fmt::Debug, hash::Hash, str::FromStr};
+
+    assert_fields!(
+        Poll: allow_multiselect,
+        answers,
+        expiry,
+        layout_type,
+        question,
+        results
+    );
+    assert_fields!(PollAnswer: answer_id, poll_media);
+    assert_fields!(PollAnswerCount: count, id, me_voted);
+    assert_fields!(PollMedia: emoji, text);
+    assert_fields!(PollResults: answer_counts, is_finalized);
+    assert_impl_all!(
+        Poll: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    #[test]
+    #[allow(clippy::too_many_lines)]
+    fn poll() -> Result<(), Box<dyn std::error::Error>> {
+        let expiry = Timestamp::from_str("2024-04-20T16:08:36.325000+00:00")?;
+
+        let value = Poll {
+            allow_multiselect: false,
+            answers: vec![
+                PollAnswer {
+                    answer_id: 1,
+                    poll_media: PollMedia {
+                        emoji: Some(ReactionType::Unicode {
+                            name: "🦄".to_owned(),
+                        }),
+                        text: Some("ponies".to_owned()),
+                    },
+                },
+                PollAnswer {
+                    answer_id: 2,
+                    poll_media: PollMedia {
+                        emoji: Some(ReactionType::Custom {
+                            animated: false,
+                            id: Id::new(3),
+                            name: None,
+                        }),
+                        text: Some("dragons".to_owned()),
+                    },
+                },
+            ],
+            expiry: Some(expiry),
+            layout_type: PollLayoutType::Default,
+            question: PollMedia {
+                emoji: None,
+                text: Some("best creature?".to_owned()),
+            },
+            results: Some(PollResults {
+                answer_counts: vec![PollAnswerCount {
+                    count: 7,
+                    id: 1,
+                    me_voted: true,
+                }],
+                is_finalized: false,
+            }),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Poll",
+                    len: 6,
+                },
+                Token::Str("allow_multiselect"),
+                Token::Bool(false),
+                Token::Str("answers"),
+                Token::Seq { len: Some(2) },
+                Token::Struct {
+                    name: "PollAnswer",
+                    len: 2,
+                },
+                Token::Str("answer_id"),
+                Token::U8(1),
+                Token::Str("poll_media"),
+                Token::Struct {
+                    name: "PollMedia",
+                    len: 2,
+                },
+                Token::Str("emoji"),
+                Token::Some,
+                Token::Struct {
+                    name: "ReactionType",
+                    len: 1,
+                },
+                Token::Str("name"),
+                Token::Str("🦄"),
+                Token::StructEnd,
+                Token::Str("text"),
+                Token::Some,
+                Token::Str("ponies"),
+                Token::StructEnd,
+                Token::StructEnd,
+                Token::Struct {
+                    name: "PollAnswer",
+                    len: 2,
+                },
+                Token::Str("answer_id"),
+                Token::U8(2),
+                Token::Str("poll_media"),
+                Token::Struct {
+                    name: "PollMedia",
+                    len: 2,
+                },
+                Token::Str("emoji"),
+                Token::Some,
+                Token::Struct {
+                    name: "ReactionType",
+                    len: 3,
+                },
+                Token::Str("animated"),
+                Token::Bool(false),
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("3"),
+                Token::Str("name"),
+                Token::None,
+                Token::StructEnd,
+                Token::Str("text"),
+                Token::Some,
+                Token::Str("dragons"),
+                Token::StructEnd,
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::Str("expiry"),
+                Token::Some,
+                Token::Str("2024-04-20T16:08:36.325000+00:00"),
+                Token::Str("layout_type"),
+                Token::U8(1),
+                Token::Str("question"),
+                Token::Struct {
+                    name: "PollMedia",
+                    len: 1,
+                },
+                Token::Str("text"),
+                Token::Some,
+                Token::Str("best creature?"),
+                Token::StructEnd,
+                Token::Str("results"),
+                Token::Some,
+                Token::Struct {
+                    name: "PollResults",
+                    len: 2,
+                },
+                Token::Str("answer_counts"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "PollAnswerCount",
+                    len: 3,
+                },
+                Token::Str("count"),
+                Token::U64(7),
+                Token::Str("id"),
+                Token::U8(1),
+                Token::Str("me_voted"),
+                Token::Bool(true),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::Str("is_finalized"),
+                Token::Bool(false),
+                Token::StructEnd,
+                Token::StructEnd,
+            ],
+        );
+
+        Ok(())
+    }
+}
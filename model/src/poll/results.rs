@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+/// Vote counts of a [`Poll`].
+///
+/// While the poll is ongoing the counts may not be exact.
+///
+/// [`Poll`]: super::Poll
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct PollResults {
+    /// Counts of the answers that have been voted for.
+    ///
+    /// Answers without any votes aren't included.
+    pub answer_counts: Vec<PollAnswerCount>,
+    /// Whether the votes have been precisely counted.
+    pub is_finalized: bool,
+}
+
+/// Vote count of an answer of a [`Poll`].
+///
+/// [`Poll`]: super::Poll
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct PollAnswerCount {
+    /// Number of votes for the answer.
+    pub count: u64,
+    /// [`PollAnswer::answer_id`] of the answer.
+    ///
+    /// [`PollAnswer::answer_id`]: super::PollAnswer::answer_id
+    pub id: u8,
+    /// Whether the current user voted for the answer.
+    pub me_voted: bool,
+}
@@ -203,4 +203,12 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn status() {
+        serde_test::assert_tokens(&Status::Scheduled, &[Token::U8(1)]);
+        serde_test::assert_tokens(&Status::Active, &[Token::U8(2)]);
+        serde_test::assert_tokens(&Status::Completed, &[Token::U8(3)]);
+        serde_test::assert_tokens(&Status::Cancelled, &[Token::U8(4)]);
+    }
 }
@@ -15,3 +15,72 @@ pub struct GuildScheduledEventUser {
     /// User object.
     pub user: User,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{GuildScheduledEventUser, User};
+    use crate::id::Id;
+    use serde_test::Token;
+
+    #[test]
+    fn guild_scheduled_event_user() {
+        let value = GuildScheduledEventUser {
+            guild_scheduled_event_id: Id::new(1),
+            member: None,
+            user: User {
+                accent_color: None,
+                avatar: None,
+                banner: None,
+                bot: false,
+                discriminator: 1,
+                email: None,
+                flags: None,
+                id: Id::new(2),
+                locale: None,
+                mfa_enabled: None,
+                name: "test".to_owned(),
+                premium_type: None,
+                public_flags: None,
+                system: None,
+                verified: None,
+            },
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "GuildScheduledEventUser",
+                    len: 3,
+                },
+                Token::Str("guild_scheduled_event_id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::Str("member"),
+                Token::None,
+                Token::Str("user"),
+                Token::Struct {
+                    name: "User",
+                    len: 7,
+                },
+                Token::Str("accent_color"),
+                Token::None,
+                Token::Str("avatar"),
+                Token::None,
+                Token::Str("banner"),
+                Token::None,
+                Token::Str("bot"),
+                Token::Bool(false),
+                Token::Str("discriminator"),
+                Token::Str("0001"),
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("2"),
+                Token::Str("username"),
+                Token::Str("test"),
+                Token::StructEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+}
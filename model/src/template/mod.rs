@@ -205,8 +205,10 @@ mod tests {
                 channels: Vec::from([
                     Channel {
                         application_id: None,
+                        applied_tags: None,
                         bitrate: None,
                         default_auto_archive_duration: None,
+                        flags: None,
                         guild_id: None,
                         icon: None,
                         id: Id::new(1),
@@ -234,8 +236,10 @@ mod tests {
                     },
                     Channel {
                         application_id: None,
+                        applied_tags: None,
                         bitrate: None,
                         default_auto_archive_duration: None,
+                        flags: None,
                         guild_id: None,
                         icon: None,
                         id: Id::new(2),
@@ -276,8 +280,10 @@ mod tests {
                     },
                     Channel {
                         application_id: None,
+                        applied_tags: None,
                         bitrate: None,
                         default_auto_archive_duration: None,
+                        flags: None,
                         guild_id: None,
                         icon: None,
                         id: Id::new(3),
@@ -305,8 +311,10 @@ mod tests {
                     },
                     Channel {
                         application_id: None,
+                        applied_tags: None,
                         bitrate: Some(64000),
                         default_auto_archive_duration: None,
+                        flags: None,
                         guild_id: None,
                         icon: None,
                         id: Id::new(4),
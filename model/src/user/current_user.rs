@@ -47,6 +47,9 @@ pub struct CurrentUser {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub locale: Option<String>,
     /// Whether the user has two factor enabled on their account.
+    ///
+    /// Defaults to `false` if Discord omits the field.
+    #[serde(default)]
     pub mfa_enabled: bool,
     /// User's username, not unique across the platform.
     #[serde(rename = "username")]
@@ -198,6 +201,52 @@ mod tests {
         serde_test::assert_de_tokens(&value, &user_tokens(Token::U64(9999)));
     }
 
+    /// Test that a current user without any of the optional fields, such as
+    /// when the `email` scope isn't granted, deserializes.
+    #[test]
+    fn current_user_minimal() {
+        let value = CurrentUser {
+            accent_color: None,
+            avatar: None,
+            banner: None,
+            bot: false,
+            discriminator: 9999,
+            email: None,
+            id: Id::new(1),
+            mfa_enabled: false,
+            name: "test name".to_owned(),
+            verified: None,
+            premium_type: None,
+            public_flags: None,
+            flags: None,
+            locale: None,
+        };
+
+        serde_test::assert_de_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "CurrentUser",
+                    len: 6,
+                },
+                Token::Str("accent_color"),
+                Token::None,
+                Token::Str("avatar"),
+                Token::None,
+                Token::Str("banner"),
+                Token::None,
+                Token::Str("discriminator"),
+                Token::Str("9999"),
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::Str("username"),
+                Token::Str("test name"),
+                Token::StructEnd,
+            ],
+        );
+    }
+
     #[test]
     fn current_user_complete() {
         let value = CurrentUser {
@@ -0,0 +1,139 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Mode used to encrypt voice data sent over UDP.
+///
+/// The voice server lists the modes it supports in its ready payload, one of
+/// which is chosen by the client when [selecting the protocol].
+///
+/// Refer to [Discord Docs/Encryption Modes] for additional information.
+///
+/// [selecting the protocol]: super::payload::SelectProtocol
+/// [Discord Docs/Encryption Modes]: https://discord.com/developers/docs/topics/voice-connections#transport-encryption-modes
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum EncryptionMode {
+    /// AES-256 in GCM mode, with a 32-bit incremental nonce appended to the
+    /// payload.
+    AeadAes256Gcm,
+    /// AES-256 in GCM mode, encrypting only the RTP payload and appending a
+    /// 32-bit incremental nonce.
+    AeadAes256GcmRtpsize,
+    /// `XChaCha20` with `Poly1305`, encrypting only the RTP payload and appending a
+    /// 32-bit incremental nonce.
+    AeadXchacha20Poly1305Rtpsize,
+    /// `XSalsa20` with `Poly1305`, using the RTP header as the nonce.
+    Xsalsa20Poly1305,
+    /// `XSalsa20` with `Poly1305`, with a 32-bit incremental nonce appended to the
+    /// payload.
+    Xsalsa20Poly1305Lite,
+    /// `XSalsa20` with `Poly1305`, with a random 24 byte nonce appended to the
+    /// payload.
+    Xsalsa20Poly1305Suffix,
+    /// Encryption mode offered by the voice server that the library doesn't
+    /// know about.
+    ///
+    /// Contains the name of the mode, so that it can still be selected.
+    Unknown(String),
+}
+
+impl EncryptionMode {
+    /// Name of the encryption mode as sent by Discord.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_model::voice::EncryptionMode;
+    ///
+    /// assert_eq!("xsalsa20_poly1305", EncryptionMode::Xsalsa20Poly1305.name());
+    /// ```
+    pub fn name(&self) -> &str {
+        match self {
+            Self::AeadAes256Gcm => "aead_aes256_gcm",
+            Self::AeadAes256GcmRtpsize => "aead_aes256_gcm_rtpsize",
+            Self::AeadXchacha20Poly1305Rtpsize => "aead_xchacha20_poly1305_rtpsize",
+            Self::Xsalsa20Poly1305 => "xsalsa20_poly1305",
+            Self::Xsalsa20Poly1305Lite => "xsalsa20_poly1305_lite",
+            Self::Xsalsa20Poly1305Suffix => "xsalsa20_poly1305_suffix",
+            Self::Unknown(name) => name,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for EncryptionMode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+
+        Ok(match name.as_str() {
+            "aead_aes256_gcm" => Self::AeadAes256Gcm,
+            "aead_aes256_gcm_rtpsize" => Self::AeadAes256GcmRtpsize,
+            "aead_xchacha20_poly1305_rtpsize" => Self::AeadXchacha20Poly1305Rtpsize,
+            "xsalsa20_poly1305" => Self::Xsalsa20Poly1305,
+            "xsalsa20_poly1305_lite" => Self::Xsalsa20Poly1305Lite,
+            "xsalsa20_poly1305_suffix" => Self::Xsalsa20Poly1305Suffix,
+            _ => Self::Unknown(name),
+        })
+    }
+}
+
+impl Serialize for EncryptionMode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EncryptionMode;
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(
+        EncryptionMode: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    #[test]
+    fn variants() {
+        const MAP: &[(EncryptionMode, &str)] = &[
+            (EncryptionMode::AeadAes256Gcm, "aead_aes256_gcm"),
+            (
+                EncryptionMode::AeadAes256GcmRtpsize,
+                "aead_aes256_gcm_rtpsize",
+            ),
+            (
+                EncryptionMode::AeadXchacha20Poly1305Rtpsize,
+                "aead_xchacha20_poly1305_rtpsize",
+            ),
+            (EncryptionMode::Xsalsa20Poly1305, "xsalsa20_poly1305"),
+            (
+                EncryptionMode::Xsalsa20Poly1305Lite,
+                "xsalsa20_poly1305_lite",
+            ),
+            (
+                EncryptionMode::Xsalsa20Poly1305Suffix,
+                "xsalsa20_poly1305_suffix",
+            ),
+        ];
+
+        for (mode, name) in MAP {
+            serde_test::assert_tokens(mode, &[Token::Str(name)]);
+            assert_eq!(*name, mode.name());
+        }
+    }
+
+    #[test]
+    fn unknown() {
+        let value = EncryptionMode::Unknown("aead_aes512_gcm".to_owned());
+
+        serde_test::assert_tokens(&value, &[Token::Str("aead_aes512_gcm")]);
+        assert_eq!("aead_aes512_gcm", value.name());
+    }
+}
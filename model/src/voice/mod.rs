@@ -1,11 +1,14 @@
+pub mod payload;
 pub(crate) mod voice_state;
 
 mod close_code;
+mod encryption_mode;
 mod opcode;
 mod voice_region;
 
 pub use self::{
     close_code::{CloseCode, CloseCodeConversionError},
+    encryption_mode::EncryptionMode,
     opcode::OpCode,
     voice_region::VoiceRegion,
     voice_state::VoiceState,
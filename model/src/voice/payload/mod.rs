@@ -0,0 +1,15 @@
+//! Payloads for sending commands to and receiving events from voice servers,
+//! for use by custom voice client implementations.
+//!
+//! Refer to [Discord Docs/Voice Connections] for Discord's documentation about
+//! the voice gateway.
+//!
+//! [Discord Docs/Voice Connections]: https://discord.com/developers/docs/topics/voice-connections
+
+mod select_protocol;
+mod session_description;
+
+pub use self::{
+    select_protocol::{SelectProtocol, SelectProtocolData, SelectProtocolInfo},
+    session_description::{SessionDescription, SessionDescriptionInfo},
+};
@@ -0,0 +1,111 @@
+use crate::voice::{EncryptionMode, OpCode};
+use serde::{Deserialize, Serialize};
+
+/// Select the protocol and encryption mode used to send voice data, after
+/// discovering the client's external address via IP discovery.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct SelectProtocol {
+    pub d: SelectProtocolInfo,
+    pub op: OpCode,
+}
+
+impl SelectProtocol {
+    /// Create a new select protocol payload using the UDP protocol.
+    pub fn new(address: impl Into<String>, port: u16, mode: EncryptionMode) -> Self {
+        Self {
+            d: SelectProtocolInfo {
+                data: SelectProtocolData {
+                    address: address.into(),
+                    mode,
+                    port,
+                },
+                protocol: "udp".to_owned(),
+            },
+            op: OpCode::SelectProtocol,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct SelectProtocolInfo {
+    /// Connection information of the client.
+    pub data: SelectProtocolData,
+    /// Protocol used to send voice data, such as `udp`.
+    pub protocol: String,
+}
+
+/// Connection information of a client selecting a protocol.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct SelectProtocolData {
+    /// External IP address of the client.
+    pub address: String,
+    /// Encryption mode to use, chosen from the modes supported by the voice
+    /// server.
+    pub mode: EncryptionMode,
+    /// External port of the client.
+    pub port: u16,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SelectProtocol, SelectProtocolData, SelectProtocolInfo};
+    use crate::voice::{EncryptionMode, OpCode};
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_fields!(SelectProtocol: d, op);
+    assert_fields!(SelectProtocolInfo: data, protocol);
+    assert_fields!(SelectProtocolData: address, mode, port);
+    assert_impl_all!(
+        SelectProtocol: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    #[test]
+    fn select_protocol() {
+        let value = SelectProtocol::new("127.0.0.1", 1337, EncryptionMode::Xsalsa20Poly1305);
+
+        assert_eq!(OpCode::SelectProtocol, value.op);
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "SelectProtocol",
+                    len: 2,
+                },
+                Token::Str("d"),
+                Token::Struct {
+                    name: "SelectProtocolInfo",
+                    len: 2,
+                },
+                Token::Str("data"),
+                Token::Struct {
+                    name: "SelectProtocolData",
+                    len: 3,
+                },
+                Token::Str("address"),
+                Token::Str("127.0.0.1"),
+                Token::Str("mode"),
+                Token::Str("xsalsa20_poly1305"),
+                Token::Str("port"),
+                Token::U16(1337),
+                Token::StructEnd,
+                Token::Str("protocol"),
+                Token::Str("udp"),
+                Token::StructEnd,
+                Token::Str("op"),
+                Token::U8(1),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -0,0 +1,84 @@
+use crate::voice::{EncryptionMode, OpCode};
+use serde::{Deserialize, Serialize};
+
+/// Received after selecting a protocol, containing the key used to encrypt
+/// voice data.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct SessionDescription {
+    pub d: SessionDescriptionInfo,
+    pub op: OpCode,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct SessionDescriptionInfo {
+    /// Encryption mode selected by the client.
+    pub mode: EncryptionMode,
+    /// Secret key used to encrypt and decrypt voice data.
+    pub secret_key: [u8; 32],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SessionDescription, SessionDescriptionInfo};
+    use crate::voice::{EncryptionMode, OpCode};
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_fields!(SessionDescription: d, op);
+    assert_fields!(SessionDescriptionInfo: mode, secret_key);
+    assert_impl_all!(
+        SessionDescription: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    #[test]
+    fn session_description() {
+        let mut secret_key = [0; 32];
+        for (byte, value) in secret_key.iter_mut().zip(1..) {
+            *byte = value;
+        }
+
+        let value = SessionDescription {
+            d: SessionDescriptionInfo {
+                mode: EncryptionMode::AeadAes256GcmRtpsize,
+                secret_key,
+            },
+            op: OpCode::SessionDescription,
+        };
+
+        let mut tokens = vec![
+            Token::Struct {
+                name: "SessionDescription",
+                len: 2,
+            },
+            Token::Str("d"),
+            Token::Struct {
+                name: "SessionDescriptionInfo",
+                len: 2,
+            },
+            Token::Str("mode"),
+            Token::Str("aead_aes256_gcm_rtpsize"),
+            Token::Str("secret_key"),
+            Token::Tuple { len: 32 },
+        ];
+        tokens.extend(secret_key.iter().copied().map(Token::U8));
+        tokens.extend([
+            Token::TupleEnd,
+            Token::StructEnd,
+            Token::Str("op"),
+            Token::U8(4),
+            Token::StructEnd,
+        ]);
+
+        serde_test::assert_tokens(&value, &tokens);
+    }
+}
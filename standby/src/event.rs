@@ -49,6 +49,8 @@ pub const fn guild_id(event: &Event) -> Option<Id<GuildMarker>> {
         Event::MemberRemove(e) => Some(e.guild_id),
         Event::MemberUpdate(e) => Some(e.guild_id),
         Event::MessageCreate(e) => e.0.guild_id,
+        Event::MessagePollVoteAdd(e) => e.guild_id,
+        Event::MessagePollVoteRemove(e) => e.guild_id,
         Event::PresenceUpdate(e) => Some(e.0.guild_id),
         Event::ReactionAdd(e) => e.0.guild_id,
         Event::ReactionRemove(e) => e.0.guild_id,
@@ -77,6 +79,7 @@ pub const fn guild_id(event: &Event) -> Option<Id<GuildMarker>> {
         | Event::GatewayInvalidateSession(_)
         | Event::GatewayReconnect
         | Event::GiftCodeUpdate
+        | Event::GuildsReady(_)
         | Event::MessageDelete(_)
         | Event::MessageDeleteBulk(_)
         | Event::MessageUpdate(_)
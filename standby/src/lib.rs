@@ -1064,8 +1064,8 @@ mod tests {
         application::{
             component::ComponentType,
             interaction::{
-                message_component::MessageComponentInteractionData, Interaction, InteractionType,
-                MessageComponentInteraction,
+                message_component::MessageComponentInteractionData, AuthorizingIntegrationOwners,
+                Interaction, InteractionType, MessageComponentInteraction,
             },
         },
         channel::{
@@ -1122,7 +1122,10 @@ mod tests {
             mention_everyone: false,
             mention_roles: Vec::new(),
             mentions: Vec::new(),
+            nonce: None,
             pinned: false,
+            poll: None,
+            position: None,
             reactions: Vec::new(),
             reference: None,
             sticker_items: Vec::new(),
@@ -1136,6 +1139,8 @@ mod tests {
 
     fn reaction() -> Reaction {
         Reaction {
+            burst: false,
+            burst_colors: Vec::new(),
             channel_id: Id::new(2),
             emoji: ReactionType::Unicode {
                 name: "🍎".to_owned(),
@@ -1149,8 +1154,11 @@ mod tests {
 
     fn button() -> MessageComponentInteraction {
         MessageComponentInteraction {
+            app_permissions: None,
             application_id: Id::new(1),
+            authorizing_integration_owners: AuthorizingIntegrationOwners::default(),
             channel_id: Id::new(2),
+            context: None,
             data: MessageComponentInteractionData {
                 custom_id: String::from("Click"),
                 component_type: ComponentType::Button,
@@ -28,14 +28,18 @@
 
 use std::collections::HashMap;
 use twilight_model::{
-    application::command::{
-        BaseCommandOptionData, ChannelCommandOptionData, ChoiceCommandOptionData, Command,
-        CommandOption, CommandOptionChoice, CommandOptionValue, CommandType, Number,
-        NumberCommandOptionData, OptionsCommandOptionData,
+    application::{
+        command::{
+            BaseCommandOptionData, ChannelCommandOptionData, ChoiceCommandOptionData, Command,
+            CommandOption, CommandOptionChoice, CommandOptionValue, CommandType, Number,
+            NumberCommandOptionData, OptionsCommandOptionData,
+        },
+        interaction::InteractionContextType,
     },
     channel::ChannelType,
     guild::Permissions,
     id::{marker::GuildMarker, Id},
+    oauth::ApplicationIntegrationType,
 };
 use twilight_validate::command::{command as validate_command, CommandValidationError};
 
@@ -51,12 +55,14 @@ impl CommandBuilder {
     pub const fn new(name: String, description: String, kind: CommandType) -> Self {
         Self(Command {
             application_id: None,
+            contexts: None,
             default_member_permissions: None,
             dm_permission: None,
             description,
             description_localizations: None,
             guild_id: None,
             id: None,
+            integration_types: None,
             kind,
             name,
             name_localizations: None,
@@ -105,6 +111,15 @@ impl CommandBuilder {
         self
     }
 
+    /// Set the contexts in which the command can be invoked.
+    ///
+    /// Defaults to [`None`].
+    pub fn contexts(mut self, contexts: impl IntoIterator<Item = InteractionContextType>) -> Self {
+        self.0.contexts = Some(contexts.into_iter().collect());
+
+        self
+    }
+
     /// Set whether the command is available in DMs.
     ///
     /// Defaults to [`None`].
@@ -124,6 +139,18 @@ impl CommandBuilder {
         self
     }
 
+    /// Set the installation contexts where the command is available.
+    ///
+    /// Defaults to [`None`].
+    pub fn integration_types(
+        mut self,
+        integration_types: impl IntoIterator<Item = ApplicationIntegrationType>,
+    ) -> Self {
+        self.0.integration_types = Some(integration_types.into_iter().collect());
+
+        self
+    }
+
     /// Set the localization dictionary for the command name.
     ///
     /// Defaults to [`None`].
@@ -1144,11 +1171,13 @@ mod tests {
 
         let command_manual = Command {
             application_id: None,
+            contexts: None,
             default_member_permissions: None,
             dm_permission: None,
             description: String::from("Get or edit permissions for a user or a role"),
             guild_id: None,
             id: None,
+            integration_types: None,
             kind: CommandType::ChatInput,
             name: String::from("permissions"),
             name_localizations: None,
@@ -0,0 +1,197 @@
+//! Build and parse namespaced component custom IDs.
+//!
+//! Custom IDs of the form `namespace:part:part` make it straightforward to
+//! route component interactions to their handler by the namespace, and to pass
+//! the remaining parts along as the handler's payload.
+
+use twilight_validate::component::{component_custom_id, ComponentValidationError};
+
+/// Delimiter between the namespace and parts of a custom ID.
+pub const DELIMITER: char = ':';
+
+/// Create a namespaced custom ID with a builder.
+///
+/// Parts must not contain the [`DELIMITER`], or they will be split into
+/// multiple parts when parsed.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use twilight_util::builder::custom_id::CustomIdBuilder;
+///
+/// let custom_id = CustomIdBuilder::new("poll")
+///     .part("vote")
+///     .part("optionA")
+///     .validate()?
+///     .build();
+///
+/// assert_eq!("poll:vote:optionA", custom_id);
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use = "must be built into a custom ID"]
+pub struct CustomIdBuilder(String);
+
+impl CustomIdBuilder {
+    /// Create a new custom ID builder with a namespace.
+    pub fn new(namespace: impl Into<String>) -> Self {
+        Self(namespace.into())
+    }
+
+    /// Build into a custom ID.
+    #[allow(clippy::missing_const_for_fn)]
+    #[must_use = "should be used as part of a component"]
+    pub fn build(self) -> String {
+        self.0
+    }
+
+    /// Ensure the custom ID is not too long.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ComponentCustomIdLength`] if the custom ID is
+    /// longer than [`COMPONENT_CUSTOM_ID_LENGTH`].
+    ///
+    /// [`COMPONENT_CUSTOM_ID_LENGTH`]: twilight_validate::component::COMPONENT_CUSTOM_ID_LENGTH
+    /// [`ComponentCustomIdLength`]: twilight_validate::component::ComponentValidationErrorType::ComponentCustomIdLength
+    pub fn validate(self) -> Result<Self, ComponentValidationError> {
+        component_custom_id(&self.0)?;
+
+        Ok(self)
+    }
+
+    /// Add a part after the namespace and any previous parts.
+    pub fn part(mut self, part: impl AsRef<str>) -> Self {
+        self.0.push(DELIMITER);
+        self.0.push_str(part.as_ref());
+
+        self
+    }
+}
+
+impl From<CustomIdBuilder> for String {
+    /// Convert a custom ID builder into a custom ID.
+    ///
+    /// This is equivalent to calling [`CustomIdBuilder::build`].
+    fn from(builder: CustomIdBuilder) -> Self {
+        builder.build()
+    }
+}
+
+/// Custom ID parsed into its namespace and parts.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_util::builder::custom_id::ParsedCustomId;
+///
+/// let custom_id = ParsedCustomId::parse("poll:vote:optionA");
+///
+/// assert_eq!("poll", custom_id.namespace());
+/// assert_eq!(vec!["vote", "optionA"], custom_id.parts().collect::<Vec<_>>());
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParsedCustomId<'a> {
+    namespace: &'a str,
+    payload: Option<&'a str>,
+}
+
+impl<'a> ParsedCustomId<'a> {
+    /// Parse a custom ID into its namespace and parts.
+    ///
+    /// A custom ID without a [`DELIMITER`] is treated as only a namespace.
+    pub fn parse(custom_id: &'a str) -> Self {
+        match custom_id.split_once(DELIMITER) {
+            Some((namespace, payload)) => Self {
+                namespace,
+                payload: Some(payload),
+            },
+            None => Self {
+                namespace: custom_id,
+                payload: None,
+            },
+        }
+    }
+
+    /// Namespace of the custom ID.
+    pub const fn namespace(&self) -> &'a str {
+        self.namespace
+    }
+
+    /// Parts of the custom ID after the namespace, delimited by [`DELIMITER`].
+    pub const fn payload(&self) -> Option<&'a str> {
+        self.payload
+    }
+
+    /// Iterator over the parts of the custom ID after the namespace.
+    pub fn parts(&self) -> impl Iterator<Item = &'a str> {
+        self.payload
+            .into_iter()
+            .flat_map(|payload| payload.split(DELIMITER))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_validate::component::{ComponentValidationErrorType, COMPONENT_CUSTOM_ID_LENGTH};
+
+    assert_impl_all!(CustomIdBuilder: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(ParsedCustomId<'_>: Clone, Copy, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(String: From<CustomIdBuilder>);
+
+    #[test]
+    fn builder() {
+        assert_eq!("poll", CustomIdBuilder::new("poll").build());
+        assert_eq!(
+            "poll:vote:optionA",
+            String::from(CustomIdBuilder::new("poll").part("vote").part("optionA"))
+        );
+    }
+
+    #[test]
+    fn parse() {
+        let custom_id = ParsedCustomId::parse("poll:vote:optionA");
+        assert_eq!("poll", custom_id.namespace());
+        assert_eq!(Some("vote:optionA"), custom_id.payload());
+        assert_eq!(
+            Vec::from(["vote", "optionA"]),
+            custom_id.parts().collect::<Vec<_>>()
+        );
+
+        let namespace_only = ParsedCustomId::parse("poll");
+        assert_eq!("poll", namespace_only.namespace());
+        assert!(namespace_only.payload().is_none());
+        assert_eq!(0, namespace_only.parts().count());
+    }
+
+    #[test]
+    fn roundtrip() {
+        let custom_id = CustomIdBuilder::new("poll").part("vote").part("").build();
+        let parsed = ParsedCustomId::parse(&custom_id);
+
+        assert_eq!("poll", parsed.namespace());
+        assert_eq!(Vec::from(["vote", ""]), parsed.parts().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn length_limit() {
+        let namespace = "a".repeat(COMPONENT_CUSTOM_ID_LENGTH - 2);
+        assert!(CustomIdBuilder::new(namespace.as_str())
+            .part("b")
+            .validate()
+            .is_ok());
+
+        let error = CustomIdBuilder::new(namespace)
+            .part("bc")
+            .validate()
+            .unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            ComponentValidationErrorType::ComponentCustomIdLength { chars: 101 }
+        ));
+    }
+}
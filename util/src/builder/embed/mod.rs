@@ -12,7 +12,9 @@ pub use self::{
 };
 
 use twilight_model::{
-    channel::embed::{Embed, EmbedAuthor, EmbedField, EmbedFooter, EmbedImage, EmbedThumbnail},
+    channel::embed::{
+        Embed, EmbedAuthor, EmbedField, EmbedFooter, EmbedImage, EmbedThumbnail, EmbedType,
+    },
     util::Timestamp,
 };
 use twilight_validate::embed::{embed as validate_embed, EmbedValidationError};
@@ -55,7 +57,7 @@ pub struct EmbedBuilder(Embed);
 
 impl EmbedBuilder {
     /// Create a new embed builder.
-    pub fn new() -> Self {
+    pub const fn new() -> Self {
         EmbedBuilder(Embed {
             author: None,
             color: None,
@@ -63,7 +65,7 @@ impl EmbedBuilder {
             fields: Vec::new(),
             footer: None,
             image: None,
-            kind: "rich".to_owned(),
+            kind: EmbedType::Rich,
             provider: None,
             thumbnail: None,
             timestamp: None,
@@ -346,7 +348,7 @@ impl Default for EmbedBuilder {
 impl From<Embed> for EmbedBuilder {
     fn from(value: Embed) -> Self {
         Self(Embed {
-            kind: "rich".to_owned(),
+            kind: EmbedType::Rich,
             ..value
         })
     }
@@ -371,7 +373,7 @@ mod tests {
     use static_assertions::assert_impl_all;
     use std::fmt::Debug;
     use twilight_model::{
-        channel::embed::{Embed, EmbedField, EmbedFooter},
+        channel::embed::{Embed, EmbedField, EmbedFooter, EmbedType},
         util::Timestamp,
     };
 
@@ -413,7 +415,7 @@ mod tests {
                 text: "Warn".to_string(),
             }),
             image: None,
-            kind: "rich".to_string(),
+            kind: EmbedType::Rich,
             provider: None,
             thumbnail: None,
             timestamp: Some(timestamp),
@@ -182,6 +182,7 @@ mod tests {
     use std::fmt::Debug;
     use twilight_model::{
         application::component::{button::ButtonStyle, Button},
+        channel::embed::EmbedType,
         util::Timestamp,
     };
 
@@ -213,7 +214,7 @@ mod tests {
             fields: Vec::new(),
             footer: None,
             image: None,
-            kind: "rich".to_owned(),
+            kind: EmbedType::Rich,
             provider: None,
             thumbnail: None,
             timestamp: Some(Timestamp::from_secs(1_580_608_922).unwrap()),
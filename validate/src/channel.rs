@@ -4,7 +4,13 @@ use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
 };
-use twilight_model::channel::ChannelType;
+use twilight_model::{
+    channel::ChannelType,
+    id::{marker::ForumTagMarker, Id},
+};
+
+/// Maximum number of tags that can be applied to a thread in a forum channel.
+pub const CHANNEL_APPLIED_TAGS_MAX: usize = 5;
 
 /// Maximum length of a channel's name.
 pub const CHANNEL_NAME_LENGTH_MAX: usize = 100;
@@ -54,6 +60,13 @@ impl ChannelValidationError {
 impl Display for ChannelValidationError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match &self.kind {
+            ChannelValidationErrorType::AppliedTagsInvalid { len } => {
+                f.write_str("the number of applied tags is ")?;
+                Display::fmt(len, f)?;
+                f.write_str(", but it must be at most ")?;
+
+                Display::fmt(&CHANNEL_APPLIED_TAGS_MAX, f)
+            }
             ChannelValidationErrorType::NameInvalid => {
                 f.write_str("the length of the name is invalid")
             }
@@ -76,6 +89,11 @@ impl Error for ChannelValidationError {}
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum ChannelValidationErrorType {
+    /// More than 5 tags were applied to a thread.
+    AppliedTagsInvalid {
+        /// Number of provided tags.
+        len: usize,
+    },
     /// The length of the name is either fewer than 1 UTF-16 characters or
     /// more than 100 UTF-16 characters.
     NameInvalid,
@@ -93,6 +111,31 @@ pub enum ChannelValidationErrorType {
     },
 }
 
+/// Ensure the number of tags applied to a thread in a forum channel is
+/// correct.
+///
+/// The number must be at most [`CHANNEL_APPLIED_TAGS_MAX`]. This is based on
+/// [this documentation entry].
+///
+/// # Errors
+///
+/// Returns an error of type [`AppliedTagsInvalid`] if there are too many
+/// tags.
+///
+/// [`AppliedTagsInvalid`]: ChannelValidationErrorType::AppliedTagsInvalid
+/// [this documentation entry]: https://discord.com/developers/docs/resources/channel#channel-object-channel-structure
+pub const fn applied_tags(tags: &[Id<ForumTagMarker>]) -> Result<(), ChannelValidationError> {
+    let len = tags.len();
+
+    if len <= CHANNEL_APPLIED_TAGS_MAX {
+        Ok(())
+    } else {
+        Err(ChannelValidationError {
+            kind: ChannelValidationErrorType::AppliedTagsInvalid { len },
+        })
+    }
+}
+
 /// Ensure a channel is a thread.
 ///
 /// # Errors
@@ -186,6 +229,17 @@ pub fn topic(value: impl AsRef<str>) -> Result<(), ChannelValidationError> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn applied_tags_count() {
+        assert!(applied_tags(&[]).is_ok());
+        assert!(applied_tags(&[Id::new(1); 5]).is_ok());
+
+        assert!(matches!(
+            applied_tags(&[Id::new(1); 6]).unwrap_err().kind(),
+            ChannelValidationErrorType::AppliedTagsInvalid { len: 6 }
+        ));
+    }
+
     #[test]
     fn thread_is_thread() {
         assert!(is_thread(ChannelType::GuildNewsThread).is_ok());
@@ -4,7 +4,13 @@ use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
 };
-use twilight_model::application::command::{Command, CommandOption, CommandType};
+use twilight_model::{
+    application::{
+        command::{Command, CommandOption, CommandType},
+        interaction::InteractionContextType,
+    },
+    oauth::ApplicationIntegrationType,
+};
 
 /// Maximum number of choices an option can have.
 pub const CHOICES_LIMIT: usize = 25;
@@ -98,6 +104,9 @@ impl CommandValidationError {
 impl Display for CommandValidationError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match &self.kind {
+            CommandValidationErrorType::ContextsInvalid => {
+                f.write_str("command contexts must be non-empty and not contain duplicates")
+            }
             CommandValidationErrorType::CountInvalid => {
                 f.write_str("more than ")?;
                 Display::fmt(&GUILD_COMMAND_LIMIT, f)?;
@@ -112,6 +121,9 @@ impl Display for CommandValidationError {
 
                 f.write_str(" characters")
             }
+            CommandValidationErrorType::IntegrationTypesInvalid => f.write_str(
+                "command integration types must be non-empty and not contain duplicates",
+            ),
             CommandValidationErrorType::NameLengthInvalid => {
                 f.write_str("command name must be between ")?;
                 Display::fmt(&NAME_LENGTH_MIN, f)?;
@@ -163,6 +175,9 @@ impl Display for CommandValidationError {
 
                 f.write_str(" permission overwrites were set")
             }
+            CommandValidationErrorType::PrivateChannelContextInvalid => f.write_str(
+                "the private channel context requires the user install integration type",
+            ),
         }
     }
 }
@@ -173,6 +188,8 @@ impl Error for CommandValidationError {}
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum CommandValidationErrorType {
+    /// Command contexts are empty or contain duplicates.
+    ContextsInvalid,
     /// Too many commands have been provided.
     ///
     /// The maximum number of commands is defined by
@@ -180,6 +197,8 @@ pub enum CommandValidationErrorType {
     CountInvalid,
     /// Command description is invalid.
     DescriptionInvalid,
+    /// Command integration types are empty or contain duplicates.
+    IntegrationTypesInvalid,
     /// Command name length is invalid.
     NameLengthInvalid,
     /// Command name contain an invalid character.
@@ -205,6 +224,11 @@ pub enum CommandValidationErrorType {
     },
     /// More than 10 permission overwrites were set.
     PermissionsCountInvalid,
+    /// Command may be invoked in [`PrivateChannel`]s but isn't installable to
+    /// users.
+    ///
+    /// [`PrivateChannel`]: InteractionContextType::PrivateChannel
+    PrivateChannelContextInvalid,
 }
 
 /// Validate a [`Command`].
@@ -217,13 +241,22 @@ pub enum CommandValidationErrorType {
 /// Returns an error of type [`NameLengthInvalid`] or [`NameCharacterInvalid`]
 /// if the name is invalid.
 ///
+/// Returns an error of type [`ContextsInvalid`], [`IntegrationTypesInvalid`],
+/// or [`PrivateChannelContextInvalid`] if the contexts or integration types
+/// are invalid.
+///
+/// [`ContextsInvalid`]: CommandValidationErrorType::ContextsInvalid
 /// [`DescriptionInvalid`]: CommandValidationErrorType::DescriptionInvalid
+/// [`IntegrationTypesInvalid`]: CommandValidationErrorType::IntegrationTypesInvalid
 /// [`NameLengthInvalid`]: CommandValidationErrorType::NameLengthInvalid
 /// [`NameCharacterInvalid`]: CommandValidationErrorType::NameCharacterInvalid
+/// [`PrivateChannelContextInvalid`]: CommandValidationErrorType::PrivateChannelContextInvalid
 pub fn command(value: &Command) -> Result<(), CommandValidationError> {
     let Command {
+        contexts,
         description,
         description_localizations,
+        integration_types,
         name,
         name_localizations,
         kind,
@@ -232,6 +265,15 @@ pub fn command(value: &Command) -> Result<(), CommandValidationError> {
 
     self::description(description)?;
 
+    if let Some(integration_types) = integration_types {
+        self::integration_types(integration_types)?;
+    }
+
+    if let Some(contexts) = contexts {
+        self::contexts(contexts)?;
+        self::contexts_integration_types(contexts, integration_types.as_deref())?;
+    }
+
     if let Some(description_localizations) = description_localizations {
         for description in description_localizations.values() {
             self::description(description)?;
@@ -253,6 +295,59 @@ pub fn command(value: &Command) -> Result<(), CommandValidationError> {
     }
 }
 
+/// Validate the contexts in which a [`Command`] can be invoked.
+///
+/// The contexts must not be empty and must not contain duplicates.
+///
+/// # Errors
+///
+/// Returns an error of type [`ContextsInvalid`] if the contexts are invalid.
+///
+/// [`ContextsInvalid`]: CommandValidationErrorType::ContextsInvalid
+pub fn contexts(contexts: &[InteractionContextType]) -> Result<(), CommandValidationError> {
+    if !contexts.is_empty() && !has_duplicates(contexts) {
+        Ok(())
+    } else {
+        Err(CommandValidationError {
+            kind: CommandValidationErrorType::ContextsInvalid,
+        })
+    }
+}
+
+/// Validate that the contexts of a [`Command`] are allowed by its integration
+/// types.
+///
+/// Commands may only be invoked in [`PrivateChannel`]s if they are
+/// installable to users. Commands without integration types are only
+/// installable to guilds, which is Discord's default of [`GuildInstall`].
+///
+/// # Errors
+///
+/// Returns an error of type [`PrivateChannelContextInvalid`] if the contexts
+/// contain [`PrivateChannel`] but the integration types don't contain
+/// [`UserInstall`].
+///
+/// [`GuildInstall`]: ApplicationIntegrationType::GuildInstall
+/// [`PrivateChannel`]: InteractionContextType::PrivateChannel
+/// [`PrivateChannelContextInvalid`]: CommandValidationErrorType::PrivateChannelContextInvalid
+/// [`UserInstall`]: ApplicationIntegrationType::UserInstall
+pub fn contexts_integration_types(
+    contexts: &[InteractionContextType],
+    integration_types: Option<&[ApplicationIntegrationType]>,
+) -> Result<(), CommandValidationError> {
+    if !contexts.contains(&InteractionContextType::PrivateChannel)
+        || integration_types.map_or(false, |integration_types| {
+            integration_types.contains(&ApplicationIntegrationType::UserInstall)
+        })
+    {
+        Ok(())
+    } else {
+        Err(CommandValidationError {
+            kind: CommandValidationErrorType::PrivateChannelContextInvalid,
+        })
+    }
+}
+
 /// Validate the description of a [`Command`].
 ///
 /// The length of the description must be more than [`DESCRIPTION_LENGTH_MIN`]
@@ -277,6 +372,28 @@ pub fn description(value: impl AsRef<str>) -> Result<(), CommandValidationError>
     }
 }
 
+/// Validate the installation contexts where a [`Command`] is available.
+///
+/// The integration types must not be empty and must not contain duplicates.
+///
+/// # Errors
+///
+/// Returns an error of type [`IntegrationTypesInvalid`] if the integration
+/// types are invalid.
+///
+/// [`IntegrationTypesInvalid`]: CommandValidationErrorType::IntegrationTypesInvalid
+pub fn integration_types(
+    integration_types: &[ApplicationIntegrationType],
+) -> Result<(), CommandValidationError> {
+    if !integration_types.is_empty() && !has_duplicates(integration_types) {
+        Ok(())
+    } else {
+        Err(CommandValidationError {
+            kind: CommandValidationErrorType::IntegrationTypesInvalid,
+        })
+    }
+}
+
 /// Validate the name of a [`User`] or [`Message`] command.
 ///
 /// The length of the name must be more than [`NAME_LENGTH_MIN`] and less than
@@ -502,6 +619,14 @@ pub const fn guild_permissions(count: usize) -> Result<(), CommandValidationErro
     }
 }
 
+/// Whether a list contains any value more than once.
+fn has_duplicates<T: PartialEq>(values: &[T]) -> bool {
+    values
+        .iter()
+        .enumerate()
+        .any(|(index, value)| values[..index].contains(value))
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::non_ascii_literal)]
@@ -515,6 +640,7 @@ mod tests {
     fn command_length() {
         let valid_command = Command {
             application_id: Some(Id::new(1)),
+            contexts: None,
             default_member_permissions: None,
             dm_permission: None,
             description: "a".repeat(100),
@@ -524,6 +650,7 @@ mod tests {
             )])),
             guild_id: Some(Id::new(2)),
             id: Some(Id::new(3)),
+            integration_types: None,
             kind: CommandType::ChatInput,
             name: "b".repeat(32),
             name_localizations: Some(HashMap::from([("en-US".to_string(), "b".repeat(32))])),
@@ -564,4 +691,58 @@ mod tests {
 
         assert!(guild_permissions(11).is_err());
     }
+
+    #[test]
+    fn contexts_and_integration_types() {
+        assert!(contexts(&[InteractionContextType::Guild, InteractionContextType::BotDm]).is_ok());
+        assert!(contexts(&[]).is_err());
+        assert!(contexts(&[InteractionContextType::Guild, InteractionContextType::Guild]).is_err());
+
+        assert!(integration_types(&[
+            ApplicationIntegrationType::GuildInstall,
+            ApplicationIntegrationType::UserInstall
+        ])
+        .is_ok());
+        assert!(integration_types(&[]).is_err());
+        assert!(integration_types(&[
+            ApplicationIntegrationType::UserInstall,
+            ApplicationIntegrationType::UserInstall
+        ])
+        .is_err());
+
+        assert!(contexts_integration_types(
+            &[InteractionContextType::Guild, InteractionContextType::BotDm],
+            Some(&[ApplicationIntegrationType::GuildInstall])
+        )
+        .is_ok());
+        assert!(contexts_integration_types(
+            &[InteractionContextType::Guild, InteractionContextType::BotDm],
+            None
+        )
+        .is_ok());
+        assert!(contexts_integration_types(
+            &[InteractionContextType::PrivateChannel],
+            Some(&[
+                ApplicationIntegrationType::GuildInstall,
+                ApplicationIntegrationType::UserInstall
+            ])
+        )
+        .is_ok());
+        assert!(matches!(
+            contexts_integration_types(
+                &[InteractionContextType::PrivateChannel],
+                Some(&[ApplicationIntegrationType::GuildInstall])
+            )
+            .unwrap_err()
+            .kind(),
+            CommandValidationErrorType::PrivateChannelContextInvalid
+        ));
+        // Commands are only installable to guilds by default.
+        assert!(matches!(
+            contexts_integration_types(&[InteractionContextType::PrivateChannel], None)
+                .unwrap_err()
+                .kind(),
+            CommandValidationErrorType::PrivateChannelContextInvalid
+        ));
+    }
 }
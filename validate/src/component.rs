@@ -5,8 +5,9 @@ use std::{
     fmt::{Debug, Display, Formatter, Result as FmtResult},
 };
 use twilight_model::application::component::{
-    button::ButtonStyle, select_menu::SelectMenuOption, ActionRow, Button, Component,
-    ComponentType, SelectMenu, TextInput,
+    button::ButtonStyle,
+    select_menu::{SelectDefaultValue, SelectMenuOption, SelectMenuType},
+    ActionRow, Button, Component, ComponentType, SelectMenu, TextInput,
 };
 
 /// Maximum number of [`Component`]s allowed inside an [`ActionRow`].
@@ -262,6 +263,28 @@ impl Display for ComponentValidationError {
 
                 f.write_str("' component was provided, but can not be a root component")
             }
+            ComponentValidationErrorType::SelectChannelTypesUnsupported { kind } => {
+                f.write_str("channel types were provided for a ")?;
+                Debug::fmt(kind, f)?;
+
+                f.write_str(" select menu, but are only supported by channel select menus")
+            }
+            ComponentValidationErrorType::SelectDefaultValueCount { count } => {
+                f.write_str("a select menu has ")?;
+                Display::fmt(count, f)?;
+
+                f.write_str(
+                    " default values, but must be within its minimum and maximum number of values",
+                )
+            }
+            ComponentValidationErrorType::SelectDefaultValueUnsupported { idx, kind } => {
+                f.write_str("default value at index ")?;
+                Display::fmt(idx, f)?;
+                f.write_str(" can't be chosen in a ")?;
+                Debug::fmt(kind, f)?;
+
+                f.write_str(" select menu")
+            }
             ComponentValidationErrorType::SelectMaximumValuesCount { count } => {
                 f.write_str("maximum number of values that can be chosen is ")?;
                 Display::fmt(count, f)?;
@@ -299,6 +322,15 @@ impl Display for ComponentValidationError {
 
                 Display::fmt(&SELECT_OPTION_VALUE_LENGTH, f)
             }
+            ComponentValidationErrorType::SelectOptionsMissing => {
+                f.write_str("a text select menu was provided without options")
+            }
+            ComponentValidationErrorType::SelectOptionsUnsupported { kind } => {
+                f.write_str("options were provided for a ")?;
+                Debug::fmt(kind, f)?;
+
+                f.write_str(" select menu, but are only supported by text select menus")
+            }
             ComponentValidationErrorType::SelectPlaceholderLength { chars } => {
                 f.write_str("a select menu's placeholder is ")?;
                 Display::fmt(&chars, f)?;
@@ -407,6 +439,30 @@ pub enum ComponentValidationErrorType {
         /// Type of provided component.
         kind: ComponentType,
     },
+    /// Channel types were provided for a select menu that isn't a
+    /// [`SelectMenuType::Channel`].
+    ///
+    /// [`SelectMenuType::Channel`]: twilight_model::application::component::select_menu::SelectMenuType::Channel
+    SelectChannelTypesUnsupported {
+        /// Type of the provided select menu.
+        kind: SelectMenuType,
+    },
+    /// Number of default values of a select menu is smaller than its minimum
+    /// or larger than its maximum number of values.
+    SelectDefaultValueCount {
+        /// Number of default values that were provided.
+        count: usize,
+    },
+    /// Default value of a select menu can't be chosen in its type of select
+    /// menu, such as a channel in a [`SelectMenuType::User`].
+    ///
+    /// [`SelectMenuType::User`]: twilight_model::application::component::select_menu::SelectMenuType::User
+    SelectDefaultValueUnsupported {
+        /// Index of the default value.
+        idx: usize,
+        /// Type of the provided select menu.
+        kind: SelectMenuType,
+    },
     /// Maximum number of items that can be chosen is smaller than
     /// [the minimum][`SELECT_MAXIMUM_VALUES_REQUIREMENT`] or larger than
     /// [the maximum][`SELECT_MAXIMUM_VALUES_LIMIT`].
@@ -444,6 +500,18 @@ pub enum ComponentValidationErrorType {
         /// Number of codepoints that were provided.
         chars: usize,
     },
+    /// Options were not provided for a [`SelectMenuType::Text`].
+    ///
+    /// [`SelectMenuType::Text`]: twilight_model::application::component::select_menu::SelectMenuType::Text
+    SelectOptionsMissing,
+    /// Options were provided for a select menu that isn't a
+    /// [`SelectMenuType::Text`].
+    ///
+    /// [`SelectMenuType::Text`]: twilight_model::application::component::select_menu::SelectMenuType::Text
+    SelectOptionsUnsupported {
+        /// Type of the provided select menu.
+        kind: SelectMenuType,
+    },
     /// Placeholder of a component is larger than the
     /// [maximum][`SELECT_PLACEHOLDER_LENGTH`].
     SelectPlaceholderLength {
@@ -630,6 +698,16 @@ pub fn button(button: &Button) -> Result<(), ComponentValidationError> {
 /// Returns an error of type [`ComponentLabelLength`] if the provided button
 /// label is too long.
 ///
+/// Returns an error of type [`SelectChannelTypesUnsupported`] if channel types
+/// are provided for a select menu that isn't a [`SelectMenuType::Channel`].
+///
+/// Returns an error of type [`SelectDefaultValueCount`] if the number of
+/// default values is smaller than the select menu's minimum or larger than its
+/// maximum number of values, which both default to 1.
+///
+/// Returns an error of type [`SelectDefaultValueUnsupported`] if a default
+/// value can't be chosen in the type of select menu.
+///
 /// Returns an error of type [`SelectMaximumValuesCount`] if the provided number
 /// of select menu values that can be chosen is smaller than the minimum or
 /// larger than the maximum.
@@ -637,6 +715,12 @@ pub fn button(button: &Button) -> Result<(), ComponentValidationError> {
 /// Returns an error of type [`SelectMinimumValuesCount`] if the provided number
 /// of select menu values that must be chosen is larger than the maximum.
 ///
+/// Returns an error of type [`SelectOptionsMissing`] if options aren't
+/// provided for a [`SelectMenuType::Text`].
+///
+/// Returns an error of type [`SelectOptionsUnsupported`] if options are
+/// provided for a select menu that isn't a [`SelectMenuType::Text`].
+///
 /// Returns an error of type [`SelectOptionDescriptionLength`] if a provided
 /// select option description is too long.
 ///
@@ -651,15 +735,41 @@ pub fn button(button: &Button) -> Result<(), ComponentValidationError> {
 ///
 /// [`ComponentCustomIdLength`]: ComponentValidationErrorType::ComponentCustomIdLength
 /// [`ComponentLabelLength`]: ComponentValidationErrorType::ComponentLabelLength
+/// [`SelectChannelTypesUnsupported`]: ComponentValidationErrorType::SelectChannelTypesUnsupported
+/// [`SelectDefaultValueCount`]: ComponentValidationErrorType::SelectDefaultValueCount
+/// [`SelectDefaultValueUnsupported`]: ComponentValidationErrorType::SelectDefaultValueUnsupported
 /// [`SelectMaximumValuesCount`]: ComponentValidationErrorType::SelectMaximumValuesCount
 /// [`SelectMinimumValuesCount`]: ComponentValidationErrorType::SelectMinimumValuesCount
+/// [`SelectOptionsMissing`]: ComponentValidationErrorType::SelectOptionsMissing
+/// [`SelectOptionsUnsupported`]: ComponentValidationErrorType::SelectOptionsUnsupported
 /// [`SelectOptionDescriptionLength`]: ComponentValidationErrorType::SelectOptionDescriptionLength
 /// [`SelectOptionLabelLength`]: ComponentValidationErrorType::SelectOptionLabelLength
 /// [`SelectOptionValueLength`]: ComponentValidationErrorType::SelectOptionValueLength
 /// [`SelectPlaceholderLength`]: ComponentValidationErrorType::SelectPlaceholderLength
 pub fn select_menu(select_menu: &SelectMenu) -> Result<(), ComponentValidationError> {
     self::component_custom_id(&select_menu.custom_id)?;
-    self::component_select_options(&select_menu.options)?;
+
+    if select_menu.channel_types.is_some() && select_menu.kind != SelectMenuType::Channel {
+        return Err(ComponentValidationError {
+            kind: ComponentValidationErrorType::SelectChannelTypesUnsupported {
+                kind: select_menu.kind,
+            },
+        });
+    }
+
+    match (select_menu.kind, select_menu.options.is_some()) {
+        (SelectMenuType::Text, false) => {
+            return Err(ComponentValidationError {
+                kind: ComponentValidationErrorType::SelectOptionsMissing,
+            });
+        }
+        (SelectMenuType::Text, true) | (_, false) => {}
+        (kind, true) => {
+            return Err(ComponentValidationError {
+                kind: ComponentValidationErrorType::SelectOptionsUnsupported { kind },
+            });
+        }
+    }
 
     if let Some(placeholder) = select_menu.placeholder.as_ref() {
         self::component_select_placeholder(placeholder)?;
@@ -673,12 +783,20 @@ pub fn select_menu(select_menu: &SelectMenu) -> Result<(), ComponentValidationEr
         self::component_select_min_values(usize::from(min_values))?;
     }
 
-    for option in &select_menu.options {
-        self::component_select_option_label(&option.label)?;
-        self::component_select_option_value(&option.value)?;
+    if let Some(default_values) = select_menu.default_values.as_deref() {
+        self::component_select_default_values(select_menu, default_values)?;
+    }
+
+    if let Some(options) = select_menu.options.as_deref() {
+        self::component_select_options(options)?;
+
+        for option in options {
+            self::component_select_option_label(&option.label)?;
+            self::component_select_option_value(&option.value)?;
 
-        if let Some(description) = option.description.as_ref() {
-            self::component_option_description(description)?;
+            if let Some(description) = option.description.as_ref() {
+                self::component_option_description(description)?;
+            }
         }
     }
 
@@ -733,6 +851,63 @@ pub fn text_input(text_input: &TextInput) -> Result<(), ComponentValidationError
     Ok(())
 }
 
+/// Ensure that the components of a modal are correct.
+///
+/// Modals may only contain up to [`COMPONENT_COUNT`] action rows, each of
+/// which may only contain text inputs.
+///
+/// # Errors
+///
+/// Returns an error of type [`ComponentCount`] if there are too many
+/// components.
+///
+/// Returns an error of type [`InvalidRootComponent`] if a component is not an
+/// [`ActionRow`].
+///
+/// Returns an error of type [`InvalidChildComponent`] if an action row contains
+/// a component other than a [`TextInput`].
+///
+/// Refer to [`action_row`] and [`text_input`] for potential errors when
+/// validating the action rows and their text inputs.
+///
+/// [`ComponentCount`]: ComponentValidationErrorType::ComponentCount
+/// [`InvalidChildComponent`]: ComponentValidationErrorType::InvalidChildComponent
+/// [`InvalidRootComponent`]: ComponentValidationErrorType::InvalidRootComponent
+pub fn modal_components(components: &[Component]) -> Result<(), ComponentValidationError> {
+    let count = components.len();
+
+    if count > COMPONENT_COUNT {
+        return Err(ComponentValidationError {
+            kind: ComponentValidationErrorType::ComponentCount { count },
+        });
+    }
+
+    for component in components {
+        let action_row = match component {
+            Component::ActionRow(action_row) => action_row,
+            other => {
+                return Err(ComponentValidationError {
+                    kind: ComponentValidationErrorType::InvalidRootComponent { kind: other.kind() },
+                });
+            }
+        };
+
+        if let Some(other) = action_row
+            .components
+            .iter()
+            .find(|component| !matches!(component, Component::TextInput(_)))
+        {
+            return Err(ComponentValidationError {
+                kind: ComponentValidationErrorType::InvalidChildComponent { kind: other.kind() },
+            });
+        }
+
+        self::action_row(action_row)?;
+    }
+
+    Ok(())
+}
+
 /// Validate that an [`ActionRow`] does not contain too many components.
 ///
 /// [`ActionRow`]s may only have so many components within it, defined by
@@ -787,7 +962,7 @@ fn component_button_label(label: impl AsRef<str>) -> Result<(), ComponentValidat
 /// ID is too long.
 ///
 /// [`ComponentCustomIdLength`]: ComponentValidationErrorType::ComponentCustomIdLength
-fn component_custom_id(custom_id: impl AsRef<str>) -> Result<(), ComponentValidationError> {
+pub fn component_custom_id(custom_id: impl AsRef<str>) -> Result<(), ComponentValidationError> {
     let chars = custom_id.as_ref().chars().count();
 
     if chars > COMPONENT_CUSTOM_ID_LENGTH {
@@ -822,6 +997,57 @@ fn component_option_description(
     Ok(())
 }
 
+/// Ensure that the default values of a select menu can be chosen in it, and
+/// that their number is within its minimum and maximum number of values.
+///
+/// # Errors
+///
+/// Returns an error of type [`SelectDefaultValueUnsupported`] if a default
+/// value can't be chosen in the type of select menu.
+///
+/// Returns an error of type [`SelectDefaultValueCount`] if there are too few or
+/// too many default values.
+///
+/// [`SelectDefaultValueCount`]: ComponentValidationErrorType::SelectDefaultValueCount
+/// [`SelectDefaultValueUnsupported`]: ComponentValidationErrorType::SelectDefaultValueUnsupported
+fn component_select_default_values(
+    select_menu: &SelectMenu,
+    default_values: &[SelectDefaultValue],
+) -> Result<(), ComponentValidationError> {
+    let kind = select_menu.kind;
+
+    for (idx, default_value) in default_values.iter().enumerate() {
+        let supported = matches!(
+            (kind, default_value),
+            (SelectMenuType::Channel, SelectDefaultValue::Channel { .. })
+                | (
+                    SelectMenuType::Mentionable,
+                    SelectDefaultValue::Role { .. } | SelectDefaultValue::User { .. }
+                )
+                | (SelectMenuType::Role, SelectDefaultValue::Role { .. })
+                | (SelectMenuType::User, SelectDefaultValue::User { .. })
+        );
+
+        if !supported {
+            return Err(ComponentValidationError {
+                kind: ComponentValidationErrorType::SelectDefaultValueUnsupported { idx, kind },
+            });
+        }
+    }
+
+    let count = default_values.len();
+    let min_values = usize::from(select_menu.min_values.unwrap_or(1));
+    let max_values = usize::from(select_menu.max_values.unwrap_or(1));
+
+    if count < min_values || count > max_values {
+        return Err(ComponentValidationError {
+            kind: ComponentValidationErrorType::SelectDefaultValueCount { count },
+        });
+    }
+
+    Ok(())
+}
+
 /// Validate a [`SelectMenu::max_values`] amount.
 ///
 /// # Errors
@@ -1078,10 +1304,13 @@ mod tests {
     use std::fmt::Debug;
     use twilight_model::{
         application::component::{
-            button::ButtonStyle, select_menu::SelectMenuOption, ActionRow, Button, Component,
-            SelectMenu,
+            button::ButtonStyle,
+            select_menu::{SelectDefaultValue, SelectMenuOption, SelectMenuType},
+            text_input::TextInputStyle,
+            ActionRow, Button, Component, SelectMenu, TextInput,
         },
-        channel::ReactionType,
+        channel::{ChannelType, ReactionType},
+        id::Id,
     };
 
     assert_fields!(ComponentValidationErrorType::ActionRowComponentCount: count);
@@ -1090,11 +1319,15 @@ mod tests {
     assert_fields!(ComponentValidationErrorType::ComponentLabelLength: chars);
     assert_fields!(ComponentValidationErrorType::InvalidChildComponent: kind);
     assert_fields!(ComponentValidationErrorType::InvalidRootComponent: kind);
+    assert_fields!(ComponentValidationErrorType::SelectChannelTypesUnsupported: kind);
+    assert_fields!(ComponentValidationErrorType::SelectDefaultValueCount: count);
+    assert_fields!(ComponentValidationErrorType::SelectDefaultValueUnsupported: idx, kind);
     assert_fields!(ComponentValidationErrorType::SelectMaximumValuesCount: count);
     assert_fields!(ComponentValidationErrorType::SelectMinimumValuesCount: count);
     assert_fields!(ComponentValidationErrorType::SelectOptionDescriptionLength: chars);
     assert_fields!(ComponentValidationErrorType::SelectOptionLabelLength: chars);
     assert_fields!(ComponentValidationErrorType::SelectOptionValueLength: chars);
+    assert_fields!(ComponentValidationErrorType::SelectOptionsUnsupported: kind);
     assert_fields!(ComponentValidationErrorType::SelectPlaceholderLength: chars);
     assert_impl_all!(ComponentValidationErrorType: Debug, Send, Sync);
     assert_impl_all!(ComponentValidationError: Debug, Send, Sync);
@@ -1137,17 +1370,20 @@ mod tests {
         };
 
         let select_menu = SelectMenu {
+            channel_types: None,
             custom_id: "custom id 2".into(),
+            default_values: None,
             disabled: false,
+            kind: SelectMenuType::Text,
             max_values: Some(2),
             min_values: Some(1),
-            options: Vec::from([SelectMenuOption {
+            options: Some(Vec::from([SelectMenuOption {
                 default: true,
                 description: Some("Book 1 of the Expanse".into()),
                 emoji: None,
                 label: "Leviathan Wakes".into(),
                 value: "9780316129084".into(),
-            }]),
+            }])),
             placeholder: Some("Choose a book".into()),
         };
 
@@ -1317,6 +1553,156 @@ mod tests {
         assert!(component_select_placeholder("a".repeat(151)).is_err());
     }
 
+    #[test]
+    fn select_menu_kind() {
+        let option = SelectMenuOption {
+            default: false,
+            description: None,
+            emoji: None,
+            label: "label".into(),
+            value: "value".into(),
+        };
+        let text = SelectMenu {
+            channel_types: None,
+            custom_id: "custom id".into(),
+            default_values: None,
+            disabled: false,
+            kind: SelectMenuType::Text,
+            max_values: None,
+            min_values: None,
+            options: Some(Vec::from([option.clone()])),
+            placeholder: None,
+        };
+        assert!(select_menu(&text).is_ok());
+
+        let text_without_options = SelectMenu {
+            options: None,
+            ..text.clone()
+        };
+        assert!(matches!(
+            select_menu(&text_without_options).unwrap_err().kind(),
+            ComponentValidationErrorType::SelectOptionsMissing
+        ));
+
+        let text_with_channel_types = SelectMenu {
+            channel_types: Some(Vec::from([ChannelType::GuildText])),
+            ..text.clone()
+        };
+        assert!(matches!(
+            select_menu(&text_with_channel_types).unwrap_err().kind(),
+            ComponentValidationErrorType::SelectChannelTypesUnsupported {
+                kind: SelectMenuType::Text
+            }
+        ));
+
+        let channel = SelectMenu {
+            channel_types: Some(Vec::from([ChannelType::GuildText])),
+            kind: SelectMenuType::Channel,
+            options: None,
+            ..text.clone()
+        };
+        assert!(select_menu(&channel).is_ok());
+
+        let channel_with_options = SelectMenu {
+            options: Some(Vec::from([option])),
+            ..channel.clone()
+        };
+        assert!(matches!(
+            select_menu(&channel_with_options).unwrap_err().kind(),
+            ComponentValidationErrorType::SelectOptionsUnsupported {
+                kind: SelectMenuType::Channel
+            }
+        ));
+
+        let user = SelectMenu {
+            channel_types: None,
+            kind: SelectMenuType::User,
+            ..channel.clone()
+        };
+        assert!(select_menu(&user).is_ok());
+
+        let user_with_channel_types = SelectMenu {
+            kind: SelectMenuType::User,
+            ..channel
+        };
+        assert!(matches!(
+            select_menu(&user_with_channel_types).unwrap_err().kind(),
+            ComponentValidationErrorType::SelectChannelTypesUnsupported {
+                kind: SelectMenuType::User
+            }
+        ));
+    }
+
+    #[test]
+    fn select_menu_default_values() {
+        let user = SelectMenu {
+            channel_types: None,
+            custom_id: "custom id".into(),
+            default_values: Some(Vec::from([SelectDefaultValue::User { id: Id::new(1) }])),
+            disabled: false,
+            kind: SelectMenuType::User,
+            max_values: None,
+            min_values: None,
+            options: None,
+            placeholder: None,
+        };
+        assert!(select_menu(&user).is_ok());
+
+        let user_with_channel = SelectMenu {
+            default_values: Some(Vec::from([SelectDefaultValue::Channel { id: Id::new(2) }])),
+            ..user.clone()
+        };
+        assert!(matches!(
+            select_menu(&user_with_channel).unwrap_err().kind(),
+            ComponentValidationErrorType::SelectDefaultValueUnsupported {
+                idx: 0,
+                kind: SelectMenuType::User
+            }
+        ));
+
+        let mentionable = SelectMenu {
+            default_values: Some(Vec::from([
+                SelectDefaultValue::User { id: Id::new(1) },
+                SelectDefaultValue::Role { id: Id::new(3) },
+            ])),
+            kind: SelectMenuType::Mentionable,
+            max_values: Some(2),
+            ..user.clone()
+        };
+        assert!(select_menu(&mentionable).is_ok());
+
+        let role_with_user = SelectMenu {
+            kind: SelectMenuType::Role,
+            ..mentionable.clone()
+        };
+        assert!(matches!(
+            select_menu(&role_with_user).unwrap_err().kind(),
+            ComponentValidationErrorType::SelectDefaultValueUnsupported {
+                idx: 0,
+                kind: SelectMenuType::Role
+            }
+        ));
+
+        let too_many = SelectMenu {
+            max_values: None,
+            ..mentionable
+        };
+        assert!(matches!(
+            select_menu(&too_many).unwrap_err().kind(),
+            ComponentValidationErrorType::SelectDefaultValueCount { count: 2 }
+        ));
+
+        let too_few = SelectMenu {
+            min_values: Some(2),
+            max_values: Some(3),
+            ..user
+        };
+        assert!(matches!(
+            select_menu(&too_few).unwrap_err().kind(),
+            ComponentValidationErrorType::SelectDefaultValueCount { count: 1 }
+        ));
+    }
+
     #[test]
     fn component_text_input_label_length() {
         assert!(component_text_input_label("a").is_ok());
@@ -1361,4 +1747,58 @@ mod tests {
 
         assert!(component_text_input_min(4001).is_err());
     }
+
+    #[test]
+    fn modal_components() {
+        let text_input = Component::ActionRow(ActionRow {
+            components: Vec::from([Component::TextInput(TextInput {
+                custom_id: "name".to_owned(),
+                label: "Name".to_owned(),
+                max_length: None,
+                min_length: None,
+                placeholder: None,
+                required: None,
+                style: TextInputStyle::Short,
+                value: None,
+            })]),
+        });
+
+        assert!(super::modal_components(std::slice::from_ref(&text_input)).is_ok());
+
+        assert!(matches!(
+            super::modal_components(&vec![text_input; 6]),
+            Err(ComponentValidationError {
+                kind: ComponentValidationErrorType::ComponentCount { count: 6 },
+            }),
+        ));
+
+        let button = Component::Button(Button {
+            custom_id: Some("a".to_owned()),
+            disabled: false,
+            emoji: None,
+            label: Some("a".to_owned()),
+            style: ButtonStyle::Primary,
+            url: None,
+        });
+
+        assert!(matches!(
+            super::modal_components(std::slice::from_ref(&button)),
+            Err(ComponentValidationError {
+                kind: ComponentValidationErrorType::InvalidRootComponent {
+                    kind: ComponentType::Button
+                },
+            }),
+        ));
+
+        assert!(matches!(
+            super::modal_components(&[Component::ActionRow(ActionRow {
+                components: Vec::from([button]),
+            })]),
+            Err(ComponentValidationError {
+                kind: ComponentValidationErrorType::InvalidChildComponent {
+                    kind: ComponentType::Button
+                },
+            }),
+        ));
+    }
 }
@@ -357,7 +357,7 @@ mod tests {
     use super::{Embed, EmbedValidationError, EmbedValidationErrorType};
     use static_assertions::assert_impl_all;
     use std::fmt::Debug;
-    use twilight_model::channel::embed::{EmbedAuthor, EmbedField, EmbedFooter};
+    use twilight_model::channel::embed::{EmbedAuthor, EmbedField, EmbedFooter, EmbedType};
 
     assert_impl_all!(EmbedValidationErrorType: Debug, Send, Sync);
     assert_impl_all!(EmbedValidationError: Debug, Send, Sync);
@@ -370,7 +370,7 @@ mod tests {
             fields: Vec::new(),
             footer: None,
             image: None,
-            kind: "rich".to_owned(),
+            kind: EmbedType::Rich,
             provider: None,
             thumbnail: None,
             timestamp: None,
@@ -13,7 +13,8 @@ use std::{
 };
 use twilight_model::{
     application::component::Component,
-    channel::embed::Embed,
+    channel::{embed::Embed, message::MessageNonce},
+    http::poll::PollCreateRequest,
     id::{marker::StickerMarker, Id},
 };
 
@@ -23,6 +24,24 @@ pub const EMBED_COUNT_LIMIT: usize = 10;
 /// Maximum length of message content.
 pub const MESSAGE_CONTENT_LENGTH_MAX: usize = 2000;
 
+/// Maximum length of a string message nonce.
+pub const MESSAGE_NONCE_LENGTH_MAX: usize = 25;
+
+/// Maximum number of answers that a poll may have.
+pub const POLL_ANSWER_COUNT_MAX: usize = 10;
+
+/// Minimum number of answers that a poll may have.
+pub const POLL_ANSWER_COUNT_MIN: usize = 1;
+
+/// Maximum length of a poll answer's text.
+pub const POLL_ANSWER_TEXT_LENGTH_MAX: usize = 55;
+
+/// Maximum duration of a poll in hours, which is 32 days.
+pub const POLL_DURATION_MAX: u16 = 768;
+
+/// Maximum length of a poll question's text.
+pub const POLL_QUESTION_LENGTH_MAX: usize = 300;
+
 /// Maximum amount of stickers.
 pub const STICKER_MAX: usize = 3;
 
@@ -107,6 +126,44 @@ impl Display for MessageValidationError {
 
                 f.write_str(" is invalid")
             }
+            MessageValidationErrorType::Empty => f.write_str(
+                "message must have content, embeds, stickers, components, attachments, or a poll",
+            ),
+            MessageValidationErrorType::NonceInvalid => {
+                f.write_str("message nonce must be at most ")?;
+                Display::fmt(&MESSAGE_NONCE_LENGTH_MAX, f)?;
+
+                f.write_str(" characters")
+            }
+            MessageValidationErrorType::PollAnswerCount { count } => {
+                Display::fmt(count, f)?;
+                f.write_str(" poll answers were provided, but it must be at least ")?;
+                Display::fmt(&POLL_ANSWER_COUNT_MIN, f)?;
+                f.write_str(" and at most ")?;
+
+                Display::fmt(&POLL_ANSWER_COUNT_MAX, f)
+            }
+            MessageValidationErrorType::PollAnswerInvalid { idx } => {
+                f.write_str("text of poll answer at index ")?;
+                Display::fmt(idx, f)?;
+                f.write_str(" must be at most ")?;
+                Display::fmt(&POLL_ANSWER_TEXT_LENGTH_MAX, f)?;
+
+                f.write_str(" characters")
+            }
+            MessageValidationErrorType::PollDurationInvalid { duration } => {
+                f.write_str("provided poll duration is ")?;
+                Display::fmt(duration, f)?;
+                f.write_str(" hours, but it must be at most ")?;
+
+                Display::fmt(&POLL_DURATION_MAX, f)
+            }
+            MessageValidationErrorType::PollQuestionInvalid => {
+                f.write_str("poll question must have text of at most ")?;
+                Display::fmt(&POLL_QUESTION_LENGTH_MAX, f)?;
+
+                f.write_str(" characters")
+            }
             MessageValidationErrorType::StickersInvalid { len } => {
                 f.write_str("amount of stickers provided is ")?;
                 Display::fmt(len, f)?;
@@ -159,6 +216,28 @@ pub enum MessageValidationErrorType {
         /// Additional details about the validation failure type.
         kind: EmbedValidationErrorType,
     },
+    /// Message has no content, embeds, stickers, components, attachments, or
+    /// poll.
+    Empty,
+    /// Returned when a string nonce is over 25 characters.
+    NonceInvalid,
+    /// Too few or too many poll answers were provided.
+    PollAnswerCount {
+        /// Number of answers that were provided.
+        count: usize,
+    },
+    /// Text of a poll answer is too long.
+    PollAnswerInvalid {
+        /// Index of the answer.
+        idx: usize,
+    },
+    /// Poll duration is too long.
+    PollDurationInvalid {
+        /// Invalid duration in hours.
+        duration: u16,
+    },
+    /// Text of a poll question is missing, empty, or too long.
+    PollQuestionInvalid,
     /// Amount of stickers provided is invalid.
     StickersInvalid {
         /// Invalid length.
@@ -300,6 +379,134 @@ pub fn embeds(embeds: &[Embed]) -> Result<(), MessageValidationError> {
     }
 }
 
+/// Ensure that a message's nonce is correct.
+///
+/// Integer nonces are always valid.
+///
+/// # Errors
+///
+/// Returns an error of type [`NonceInvalid`] if a string nonce is longer than
+/// [`MESSAGE_NONCE_LENGTH_MAX`].
+///
+/// [`NonceInvalid`]: MessageValidationErrorType::NonceInvalid
+pub fn nonce(nonce: &MessageNonce) -> Result<(), MessageValidationError> {
+    match nonce {
+        MessageNonce::String(nonce) if nonce.chars().count() > MESSAGE_NONCE_LENGTH_MAX => {
+            Err(MessageValidationError {
+                kind: MessageValidationErrorType::NonceInvalid,
+                source: None,
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Ensure that a message isn't empty.
+///
+/// A message must have at least one of non-empty content, an embed, a sticker,
+/// a component, an attachment, or a poll. Messages with a poll are never empty,
+/// so this only needs to be checked for messages without one.
+///
+/// # Errors
+///
+/// Returns an error of type [`Empty`] if the message has none of them.
+///
+/// [`Empty`]: MessageValidationErrorType::Empty
+pub fn not_empty(
+    content: &str,
+    embeds: &[Embed],
+    sticker_ids: &[Id<StickerMarker>],
+    components: &[Component],
+    has_attachments: bool,
+) -> Result<(), MessageValidationError> {
+    if content.is_empty()
+        && embeds.is_empty()
+        && sticker_ids.is_empty()
+        && components.is_empty()
+        && !has_attachments
+    {
+        Err(MessageValidationError {
+            kind: MessageValidationErrorType::Empty,
+            source: None,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/// Ensure that a poll is correct.
+///
+/// A poll must have at least [`POLL_ANSWER_COUNT_MIN`] and at most
+/// [`POLL_ANSWER_COUNT_MAX`] answers. The text of its question must be at
+/// most [`POLL_QUESTION_LENGTH_MAX`] characters long and not empty, the text of its answers
+/// at most [`POLL_ANSWER_TEXT_LENGTH_MAX`], and its duration must be at most
+/// [`POLL_DURATION_MAX`] hours. This is based on [this documentation entry].
+///
+/// # Errors
+///
+/// Returns an error of type [`PollAnswerCount`] if there are too few or too
+/// many answers.
+///
+/// Returns an error of type [`PollAnswerInvalid`] if the text of an answer is
+/// too long.
+///
+/// Returns an error of type [`PollDurationInvalid`] if the duration is too
+/// long.
+///
+/// Returns an error of type [`PollQuestionInvalid`] if the question has no
+/// text, or if its text is empty or too long.
+///
+/// [`PollAnswerCount`]: MessageValidationErrorType::PollAnswerCount
+/// [`PollAnswerInvalid`]: MessageValidationErrorType::PollAnswerInvalid
+/// [`PollDurationInvalid`]: MessageValidationErrorType::PollDurationInvalid
+/// [`PollQuestionInvalid`]: MessageValidationErrorType::PollQuestionInvalid
+/// [this documentation entry]: https://discord.com/developers/docs/resources/poll#poll-create-request-object
+pub fn poll(poll: &PollCreateRequest) -> Result<(), MessageValidationError> {
+    let count = poll.answers.len();
+
+    if !(POLL_ANSWER_COUNT_MIN..=POLL_ANSWER_COUNT_MAX).contains(&count) {
+        return Err(MessageValidationError {
+            kind: MessageValidationErrorType::PollAnswerCount { count },
+            source: None,
+        });
+    }
+
+    for (idx, answer) in poll.answers.iter().enumerate() {
+        if let Some(text) = &answer.poll_media.text {
+            if text.chars().count() > POLL_ANSWER_TEXT_LENGTH_MAX {
+                return Err(MessageValidationError {
+                    kind: MessageValidationErrorType::PollAnswerInvalid { idx },
+                    source: None,
+                });
+            }
+        }
+    }
+
+    if let Some(duration) = poll.duration {
+        if duration > POLL_DURATION_MAX {
+            return Err(MessageValidationError {
+                kind: MessageValidationErrorType::PollDurationInvalid { duration },
+                source: None,
+            });
+        }
+    }
+
+    let question_len = poll
+        .question
+        .text
+        .as_ref()
+        .map_or(0, |text| text.chars().count());
+
+    if !(1..=POLL_QUESTION_LENGTH_MAX).contains(&question_len) {
+        return Err(MessageValidationError {
+            kind: MessageValidationErrorType::PollQuestionInvalid,
+            source: None,
+        });
+    }
+
+    Ok(())
+}
+
 /// Ensure that the amount of stickers in a message is correct.
 ///
 /// There must be at most [`STICKER_MAX`] stickers. This is based on [this
@@ -327,6 +534,7 @@ pub fn sticker_ids(sticker_ids: &[Id<StickerMarker>]) -> Result<(), MessageValid
 #[cfg(test)]
 mod tests {
     use super::*;
+    use twilight_model::{http::poll::PollCreateAnswer, poll::PollMedia};
 
     #[test]
     fn attachment_allowed_filename() {
@@ -345,4 +553,91 @@ mod tests {
 
         assert!(content("a".repeat(2001)).is_err());
     }
+
+    #[test]
+    fn nonce_length() {
+        assert!(nonce(&MessageNonce::Integer(u64::MAX)).is_ok());
+        assert!(nonce(&MessageNonce::String("a".repeat(25))).is_ok());
+
+        assert!(nonce(&MessageNonce::String("a".repeat(26))).is_err());
+    }
+
+    #[test]
+    fn message_not_empty() {
+        assert!(not_empty("", &[], &[], &[], false).is_err());
+
+        assert!(not_empty("a", &[], &[], &[], false).is_ok());
+        assert!(not_empty("", &[], &[Id::new(1)], &[], false).is_ok());
+        assert!(not_empty("", &[], &[], &[], true).is_ok());
+    }
+
+    #[test]
+    fn poll_validation() {
+        fn media(text: String) -> PollMedia {
+            PollMedia {
+                emoji: None,
+                text: Some(text),
+            }
+        }
+
+        fn poll(question: usize, answers: usize, answer: usize) -> PollCreateRequest {
+            PollCreateRequest {
+                allow_multiselect: false,
+                answers: (0..answers)
+                    .map(|_| PollCreateAnswer {
+                        poll_media: media("a".repeat(answer)),
+                    })
+                    .collect(),
+                duration: None,
+                layout_type: None,
+                question: media("a".repeat(question)),
+            }
+        }
+
+        assert!(super::poll(&poll(1, 1, 1)).is_ok());
+        assert!(super::poll(&poll(300, 10, 55)).is_ok());
+
+        assert!(matches!(
+            super::poll(&poll(1, 0, 1)).unwrap_err().kind(),
+            MessageValidationErrorType::PollAnswerCount { count: 0 }
+        ));
+        assert!(matches!(
+            super::poll(&poll(1, 11, 1)).unwrap_err().kind(),
+            MessageValidationErrorType::PollAnswerCount { count: 11 }
+        ));
+        assert!(matches!(
+            super::poll(&poll(1, 2, 56)).unwrap_err().kind(),
+            MessageValidationErrorType::PollAnswerInvalid { idx: 0 }
+        ));
+        assert!(matches!(
+            super::poll(&poll(301, 1, 1)).unwrap_err().kind(),
+            MessageValidationErrorType::PollQuestionInvalid
+        ));
+        assert!(matches!(
+            super::poll(&poll(0, 1, 1)).unwrap_err().kind(),
+            MessageValidationErrorType::PollQuestionInvalid
+        ));
+
+        let mut no_question = poll(1, 1, 1);
+        no_question.question.text = None;
+        assert!(matches!(
+            super::poll(&no_question).unwrap_err().kind(),
+            MessageValidationErrorType::PollQuestionInvalid
+        ));
+
+        let mut long = poll(1, 1, 1);
+        long.duration = Some(POLL_DURATION_MAX + 1);
+        assert!(matches!(
+            super::poll(&long).unwrap_err().kind(),
+            MessageValidationErrorType::PollDurationInvalid { .. }
+        ));
+    }
+
+    #[test]
+    fn sticker_count() {
+        assert!(sticker_ids(&[]).is_ok());
+        assert!(sticker_ids(&[Id::new(1), Id::new(2), Id::new(3)]).is_ok());
+
+        assert!(sticker_ids(&[Id::new(1), Id::new(2), Id::new(3), Id::new(4)]).is_err());
+    }
 }
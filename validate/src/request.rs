@@ -7,11 +7,27 @@ use std::{
     fmt::{Display, Formatter, Result as FmtResult},
     time::{SystemTime, UNIX_EPOCH},
 };
-use twilight_model::util::Timestamp;
+use twilight_model::{
+    http::onboarding::OnboardingPromptOptionRequest,
+    id::{marker::UserMarker, Id},
+    util::Timestamp,
+};
+
+/// Maximum length of an application tag.
+pub const APPLICATION_TAG_LENGTH_MAX: usize = 20;
+
+/// Maximum amount of tags an application can have.
+pub const APPLICATION_TAGS_MAX: usize = 5;
 
 /// The maximum audit log reason length in UTF-16 codepoints.
 pub const AUDIT_REASON_MAX: usize = 512;
 
+/// Maximum amount of users that can be banned at once.
+pub const BULK_BAN_USER_IDS_MAX: usize = 200;
+
+/// Minimum amount of users that can be banned at once.
+pub const BULK_BAN_USER_IDS_MIN: usize = 1;
+
 /// Maximum amount of days for messages to be deleted upon ban.
 pub const CREATE_GUILD_BAN_DELETE_MESSAGE_DAYS_MAX: u16 = 7;
 
@@ -45,6 +61,14 @@ pub const GET_GUILD_MEMBERS_LIMIT_MAX: u16 = 1000;
 /// Minimum amount of guild members to list.
 pub const GET_GUILD_MEMBERS_LIMIT_MIN: u16 = 1;
 
+/// Maximum amount of users to return when getting the voters of a poll
+/// answer.
+pub const GET_POLL_ANSWER_VOTERS_LIMIT_MAX: u16 = 100;
+
+/// Minimum amount of users to return when getting the voters of a poll
+/// answer.
+pub const GET_POLL_ANSWER_VOTERS_LIMIT_MIN: u16 = 1;
+
 /// Maximum amount of users to return when getting reactions.
 pub const GET_REACTIONS_LIMIT_MIN: u16 = 1;
 
@@ -75,6 +99,12 @@ pub const NICKNAME_LIMIT_MAX: usize = 32;
 /// Minimum length of a nickname.
 pub const NICKNAME_LIMIT_MIN: usize = 1;
 
+/// Maximum amount of options of an onboarding prompt.
+pub const ONBOARDING_PROMPT_OPTIONS_MAX: usize = 50;
+
+/// Minimum amount of options of an onboarding prompt.
+pub const ONBOARDING_PROMPT_OPTIONS_MIN: usize = 1;
+
 /// Maximum length of a scheduled event's description.
 pub const SCHEDULED_EVENT_DESCRIPTION_MAX: usize = 1000;
 
@@ -167,6 +197,20 @@ impl Display for ValidationError {
     #[allow(clippy::too_many_lines)]
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match &self.kind {
+            ValidationErrorType::ApplicationTag { len } => {
+                f.write_str("provided application tag length is ")?;
+                Display::fmt(len, f)?;
+                f.write_str(", but it must be at most ")?;
+
+                Display::fmt(&APPLICATION_TAG_LENGTH_MAX, f)
+            }
+            ValidationErrorType::ApplicationTags { len } => {
+                f.write_str("provided application tag count is ")?;
+                Display::fmt(len, f)?;
+                f.write_str(", but it must be at most ")?;
+
+                Display::fmt(&APPLICATION_TAGS_MAX, f)
+            }
             ValidationErrorType::AuditReason { len } => {
                 f.write_str("provided audit reason length is ")?;
                 Display::fmt(len, f)?;
@@ -174,6 +218,15 @@ impl Display for ValidationError {
 
                 Display::fmt(&AUDIT_REASON_MAX, f)
             }
+            ValidationErrorType::BulkBanUserIds { len } => {
+                f.write_str("provided bulk ban user id count is ")?;
+                Display::fmt(len, f)?;
+                f.write_str(", but it must be at least ")?;
+                Display::fmt(&BULK_BAN_USER_IDS_MIN, f)?;
+                f.write_str(" and at most ")?;
+
+                Display::fmt(&BULK_BAN_USER_IDS_MAX, f)
+            }
             ValidationErrorType::CreateGuildBanDeleteMessageDays {
                 days: delete_message_days,
             } => {
@@ -229,6 +282,15 @@ impl Display for ValidationError {
 
                 Display::fmt(&GET_GUILD_MEMBERS_LIMIT_MAX, f)
             }
+            ValidationErrorType::GetPollAnswerVoters { limit } => {
+                f.write_str("provided get poll answer voters limit is ")?;
+                Display::fmt(limit, f)?;
+                f.write_str(", but it must be at least ")?;
+                Display::fmt(&GET_POLL_ANSWER_VOTERS_LIMIT_MIN, f)?;
+                f.write_str(" and at most ")?;
+
+                Display::fmt(&GET_POLL_ANSWER_VOTERS_LIMIT_MAX, f)
+            }
             ValidationErrorType::GetReactions { limit } => {
                 f.write_str("provided get reactions limit is ")?;
                 Display::fmt(limit, f)?;
@@ -279,6 +341,15 @@ impl Display for ValidationError {
 
                 Display::fmt(&NICKNAME_LIMIT_MAX, f)
             }
+            ValidationErrorType::OnboardingPromptOptions { len } => {
+                f.write_str("provided onboarding prompt option count is ")?;
+                Display::fmt(len, f)?;
+                f.write_str(", but it must be at least ")?;
+                Display::fmt(&ONBOARDING_PROMPT_OPTIONS_MIN, f)?;
+                f.write_str(" and at most ")?;
+
+                Display::fmt(&ONBOARDING_PROMPT_OPTIONS_MAX, f)
+            }
             ValidationErrorType::ScheduledEventDescription { len } => {
                 f.write_str("provided scheduled event description is length is ")?;
                 Display::fmt(len, f)?;
@@ -373,11 +444,26 @@ impl Error for ValidationError {}
 /// Type of [`ValidationError`] that occurred.
 #[derive(Debug)]
 pub enum ValidationErrorType {
+    /// Provided application tag was too long.
+    ApplicationTag {
+        /// Invalid length.
+        len: usize,
+    },
+    /// Too many application tags were provided.
+    ApplicationTags {
+        /// Invalid count.
+        len: usize,
+    },
     /// Provided audit reason was too large.
     AuditReason {
         /// Invalid length.
         len: usize,
     },
+    /// Too few or too many user IDs were provided to ban.
+    BulkBanUserIds {
+        /// Invalid count.
+        len: usize,
+    },
     /// Provided create guild ban delete message days was invalid.
     CreateGuildBanDeleteMessageDays {
         /// Invalid days.
@@ -413,6 +499,11 @@ pub enum ValidationErrorType {
         /// Invalid limit.
         limit: u16,
     },
+    /// Provided get poll answer voters limit was invalid.
+    GetPollAnswerVoters {
+        /// Invalid limit.
+        limit: u16,
+    },
     /// Provided get reactions limit was invalid.
     GetReactions {
         /// Invalid limit.
@@ -443,6 +534,11 @@ pub enum ValidationErrorType {
         /// Invalid length.
         len: usize,
     },
+    /// Too few or too many onboarding prompt options were provided.
+    OnboardingPromptOptions {
+        /// Invalid count.
+        len: usize,
+    },
     /// Scheduled event description is invalid.
     ScheduledEventDescription {
         /// Invalid length.
@@ -494,6 +590,54 @@ pub enum ValidationErrorType {
     },
 }
 
+/// Ensure that an application's tag is correct.
+///
+/// The length must be at most [`APPLICATION_TAG_LENGTH_MAX`]. This is based on
+/// [this documentation entry].
+///
+/// # Errors
+///
+/// Returns an error of type [`ApplicationTag`] if the length is invalid.
+///
+/// [`ApplicationTag`]: ValidationErrorType::ApplicationTag
+/// [this documentation entry]: https://discord.com/developers/docs/resources/application#application-object-application-structure
+pub fn application_tag(tag: impl AsRef<str>) -> Result<(), ValidationError> {
+    let len = tag.as_ref().chars().count();
+
+    if len <= APPLICATION_TAG_LENGTH_MAX {
+        Ok(())
+    } else {
+        Err(ValidationError {
+            kind: ValidationErrorType::ApplicationTag { len },
+        })
+    }
+}
+
+/// Ensure that an application's tags are correct.
+///
+/// The amount of tags must be at most [`APPLICATION_TAGS_MAX`] and every tag
+/// must be valid according to [`application_tag`]. This is based on
+/// [this documentation entry].
+///
+/// # Errors
+///
+/// Returns an error of type [`ApplicationTags`] if there are too many tags.
+///
+/// Returns an error of type [`ApplicationTag`] if a tag's length is invalid.
+///
+/// [`ApplicationTag`]: ValidationErrorType::ApplicationTag
+/// [`ApplicationTags`]: ValidationErrorType::ApplicationTags
+/// [this documentation entry]: https://discord.com/developers/docs/resources/application#application-object-application-structure
+pub fn application_tags(tags: &[impl AsRef<str>]) -> Result<(), ValidationError> {
+    if tags.len() > APPLICATION_TAGS_MAX {
+        return Err(ValidationError {
+            kind: ValidationErrorType::ApplicationTags { len: tags.len() },
+        });
+    }
+
+    tags.iter().try_for_each(application_tag)
+}
+
 /// Ensure that an audit reason is correct.
 ///
 /// The length must be at most [`AUDIT_REASON_MAX`]. This is based on
@@ -517,6 +661,30 @@ pub fn audit_reason(audit_reason: impl AsRef<str>) -> Result<(), ValidationError
     }
 }
 
+/// Ensure that the amount of user IDs for the Bulk Guild Ban request is
+/// correct.
+///
+/// The amount must be at least [`BULK_BAN_USER_IDS_MIN`] and at most
+/// [`BULK_BAN_USER_IDS_MAX`]. This is based on [this documentation entry].
+///
+/// # Errors
+///
+/// Returns an error of type [`BulkBanUserIds`] if the amount is invalid.
+///
+/// [`BulkBanUserIds`]: ValidationErrorType::BulkBanUserIds
+/// [this documentation entry]: https://discord.com/developers/docs/resources/guild#bulk-guild-ban
+pub const fn bulk_ban_user_ids(user_ids: &[Id<UserMarker>]) -> Result<(), ValidationError> {
+    let len = user_ids.len();
+
+    if len >= BULK_BAN_USER_IDS_MIN && len <= BULK_BAN_USER_IDS_MAX {
+        Ok(())
+    } else {
+        Err(ValidationError {
+            kind: ValidationErrorType::BulkBanUserIds { len },
+        })
+    }
+}
+
 /// Ensure that the delete message days amount for the Create Guild Ban request
 /// is correct.
 ///
@@ -675,6 +843,28 @@ pub const fn get_guild_members_limit(limit: u16) -> Result<(), ValidationError>
     }
 }
 
+/// Ensure that the limit for the Get Answer Voters endpoint is correct.
+///
+/// The limit must be at least [`GET_POLL_ANSWER_VOTERS_LIMIT_MIN`] and at most
+/// [`GET_POLL_ANSWER_VOTERS_LIMIT_MAX`]. This is based on
+/// [this documentation entry].
+///
+/// # Errors
+///
+/// Returns an error of type [`GetPollAnswerVoters`] if the limit is invalid.
+///
+/// [`GetPollAnswerVoters`]: ValidationErrorType::GetPollAnswerVoters
+/// [this documentation entry]: https://discord.com/developers/docs/resources/poll#get-answer-voters
+pub const fn get_poll_answer_voters_limit(limit: u16) -> Result<(), ValidationError> {
+    if limit >= GET_POLL_ANSWER_VOTERS_LIMIT_MIN && limit <= GET_POLL_ANSWER_VOTERS_LIMIT_MAX {
+        Ok(())
+    } else {
+        Err(ValidationError {
+            kind: ValidationErrorType::GetPollAnswerVoters { limit },
+        })
+    }
+}
+
 /// Ensure that the limit for the Get Reactions endpoint is correct.
 ///
 /// The limit must be at least [`GET_REACTIONS_LIMIT_MIN`] and at most
@@ -805,6 +995,33 @@ pub fn nickname(nickname: impl AsRef<str>) -> Result<(), ValidationError> {
     }
 }
 
+/// Ensure that the amount of options of an onboarding prompt is correct.
+///
+/// The amount must be at least [`ONBOARDING_PROMPT_OPTIONS_MIN`] and at most
+/// [`ONBOARDING_PROMPT_OPTIONS_MAX`]. This is based on
+/// [this documentation entry].
+///
+/// # Errors
+///
+/// Returns an error of type [`OnboardingPromptOptions`] if the amount is
+/// invalid.
+///
+/// [`OnboardingPromptOptions`]: ValidationErrorType::OnboardingPromptOptions
+/// [this documentation entry]: https://discord.com/developers/docs/resources/guild#guild-onboarding-object-onboarding-prompt-structure
+pub const fn onboarding_prompt_options(
+    options: &[OnboardingPromptOptionRequest],
+) -> Result<(), ValidationError> {
+    let len = options.len();
+
+    if len >= ONBOARDING_PROMPT_OPTIONS_MIN && len <= ONBOARDING_PROMPT_OPTIONS_MAX {
+        Ok(())
+    } else {
+        Err(ValidationError {
+            kind: ValidationErrorType::OnboardingPromptOptions { len },
+        })
+    }
+}
+
 /// Ensure that a scheduled event's description is correct.
 ///
 /// The length must be at least [`SCHEDULED_EVENT_DESCRIPTION_MIN`] and at most
@@ -843,7 +1060,7 @@ pub fn scheduled_event_description(description: impl AsRef<str>) -> Result<(), V
 /// [`ScheduledEventGetUsers`]: ValidationErrorType::ScheduledEventGetUsers
 /// [this documentation entry]: https://discord.com/developers/docs/resources/guild-scheduled-event#get-guild-scheduled-event-users-query-string-params
 pub const fn scheduled_event_get_users(limit: u16) -> Result<(), ValidationError> {
-    if limit <= SCHEDULED_EVENT_GET_USERS_MIN && limit >= SCHEDULED_EVENT_GET_USERS_MAX {
+    if limit >= SCHEDULED_EVENT_GET_USERS_MIN && limit <= SCHEDULED_EVENT_GET_USERS_MAX {
         Ok(())
     } else {
         Err(ValidationError {
@@ -1044,6 +1261,7 @@ pub fn webhook_username(value: impl AsRef<str>) -> Result<(), ValidationError> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use twilight_model::id::Id;
 
     #[test]
     fn username_variants() {
@@ -1081,6 +1299,33 @@ mod tests {
         assert_eq!(expected, actual.to_string());
     }
 
+    #[test]
+    fn application_tag_length() {
+        assert!(application_tag("").is_ok());
+        assert!(application_tag("a".repeat(20)).is_ok());
+
+        assert!(application_tag("a".repeat(21)).is_err());
+    }
+
+    #[test]
+    fn application_tags_count() {
+        let empty: [&str; 0] = [];
+        assert!(application_tags(&empty).is_ok());
+        assert!(application_tags(&["a"; 5]).is_ok());
+        assert!(application_tags(&["a".repeat(20)]).is_ok());
+
+        assert!(matches!(
+            application_tags(&["a"; 6]).unwrap_err().kind(),
+            ValidationErrorType::ApplicationTags { len: 6 }
+        ));
+        assert!(matches!(
+            application_tags(&["a", &"a".repeat(21)])
+                .unwrap_err()
+                .kind(),
+            ValidationErrorType::ApplicationTag { len: 21 }
+        ));
+    }
+
     #[test]
     fn audit_reason_length() {
         assert!(audit_reason("").is_ok());
@@ -1091,6 +1336,15 @@ mod tests {
         assert!(audit_reason("a".repeat(513)).is_err());
     }
 
+    #[test]
+    fn bulk_ban_user_ids_length() {
+        assert!(bulk_ban_user_ids(&[Id::new(1)]).is_ok());
+        assert!(bulk_ban_user_ids(&[Id::new(1); 200]).is_ok());
+
+        assert!(bulk_ban_user_ids(&[]).is_err());
+        assert!(bulk_ban_user_ids(&[Id::new(1); 201]).is_err());
+    }
+
     #[test]
     fn create_guild_ban_delete_message_days_length() {
         assert!(create_guild_ban_delete_message_days(0).is_ok());
@@ -1161,6 +1415,15 @@ mod tests {
         assert!(get_guild_members_limit(1001).is_err());
     }
 
+    #[test]
+    fn get_poll_answer_voters_limit_count() {
+        assert!(get_poll_answer_voters_limit(1).is_ok());
+        assert!(get_poll_answer_voters_limit(100).is_ok());
+
+        assert!(get_poll_answer_voters_limit(0).is_err());
+        assert!(get_poll_answer_voters_limit(101).is_err());
+    }
+
     #[test]
     fn get_reactions_limit_count() {
         assert!(get_reactions_limit(1).is_ok());
@@ -1216,6 +1479,26 @@ mod tests {
         assert!(nickname("a".repeat(33)).is_err());
     }
 
+    #[test]
+    fn onboarding_prompt_options_count() {
+        let option = OnboardingPromptOptionRequest {
+            channel_ids: Vec::new(),
+            description: None,
+            emoji_animated: None,
+            emoji_id: None,
+            emoji_name: None,
+            id: Id::new(1),
+            role_ids: Vec::new(),
+            title: "option".to_owned(),
+        };
+
+        assert!(onboarding_prompt_options(std::slice::from_ref(&option)).is_ok());
+        assert!(onboarding_prompt_options(&vec![option.clone(); 50]).is_ok());
+
+        assert!(onboarding_prompt_options(&[]).is_err());
+        assert!(onboarding_prompt_options(&vec![option; 51]).is_err());
+    }
+
     #[test]
     fn scheduled_event_description_length() {
         assert!(scheduled_event_description("a").is_ok());
@@ -1225,6 +1508,15 @@ mod tests {
         assert!(scheduled_event_description("a".repeat(1001)).is_err());
     }
 
+    #[test]
+    fn scheduled_event_get_users_limit() {
+        assert!(scheduled_event_get_users(1).is_ok());
+        assert!(scheduled_event_get_users(100).is_ok());
+
+        assert!(scheduled_event_get_users(0).is_err());
+        assert!(scheduled_event_get_users(101).is_err());
+    }
+
     #[test]
     fn scheduled_event_name_length() {
         assert!(scheduled_event_name("a").is_ok());